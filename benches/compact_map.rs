@@ -0,0 +1,82 @@
+//! Compares `CompactIndexMap` against the hole-based `IndexMap`, on dense and sparse key domains.
+//!
+//! Run with `cargo bench --features alloc --bench compact_map`.
+
+use std::{
+    hint::black_box,
+    mem,
+    time::{Duration, Instant},
+};
+
+use ecne::{
+    chunk::{ArrayChunk, UnsignedChunk},
+    map::{CompactIndexMap, IndexMap},
+    vault::DynamicChunkStore,
+};
+
+type Store = DynamicChunkStore<ArrayChunk<UnsignedChunk<u64>, 4>, u32>;
+
+type Value = [u64; 4];
+
+const COUNT: u32 = 10_000;
+
+fn main() {
+    //  Every index, then every 1,000th index.
+    for (name, stride) in [("dense", 1), ("sparse", 1_000)] {
+        let indexes: Vec<u32> = (0..COUNT).map(|i| i * stride).collect();
+
+        let hole = bench(
+            &indexes,
+            IndexMap::<Store, Value>::new,
+            |map, index| map.insert(index, [index.into(); 4]).unwrap(),
+            |map, index| map.get(index).copied(),
+        );
+
+        let compact = bench(
+            &indexes,
+            CompactIndexMap::<Store, Value>::new,
+            |map, index| map.insert(index, [index.into(); 4]).unwrap(),
+            |map, index| map.get(index).copied(),
+        );
+
+        //  The hole-based map holds one `Option<V>` per index up to the maximum, the compact one one `V` per index.
+        let hole_bytes = (indexes[indexes.len() - 1] as usize + 1) * mem::size_of::<Option<Value>>();
+        let compact_bytes = indexes.len() * mem::size_of::<Value>();
+
+        println!(
+            "{name:>6} IndexMap:        insert {:>10?}, get {:>10?}, values {hole_bytes:>10} bytes",
+            hole.0, hole.1
+        );
+        println!(
+            "{name:>6} CompactIndexMap: insert {:>10?}, get {:>10?}, values {compact_bytes:>10} bytes",
+            compact.0, compact.1
+        );
+    }
+}
+
+//  Returns the time taken to insert all indexes, in reverse so as to exercise the shifting of the compact map, then
+//  to get all indexes.
+fn bench<M, N, I, G>(indexes: &[u32], new: N, mut insert: I, mut get: G) -> (Duration, Duration)
+where
+    N: FnOnce() -> M,
+    I: FnMut(&mut M, u32) -> Option<Value>,
+    G: FnMut(&M, u32) -> Option<Value>,
+{
+    let mut map = new();
+
+    let start = Instant::now();
+
+    for &index in indexes.iter().rev() {
+        black_box(insert(&mut map, index));
+    }
+
+    let inserted = start.elapsed();
+
+    let start = Instant::now();
+
+    for &index in indexes {
+        black_box(get(&map, black_box(index)));
+    }
+
+    (inserted, start.elapsed())
+}
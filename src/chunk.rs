@@ -1,19 +1,31 @@
 //! Chunked iteration.
 
 mod array;
+mod counting;
 mod unsigned;
 
+#[cfg(feature = "simd")]
+mod wide;
+
 use core::{
     cmp::Eq,
-    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Sub, SubAssign},
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Bound, Not, RangeBounds, Sub, SubAssign},
 };
 
 use crate::index::{IndexCollection, IndexStore};
 
 pub use array::ArrayChunk;
+pub use counting::CountingArrayChunk;
 pub use unsigned::UnsignedChunk;
 
+#[cfg(feature = "simd")]
+pub use wide::WideChunk;
+
 /// A chunk of indexes.
+///
+/// Chunks are `Copy`, as they are routinely passed around and returned by value, see `IndexViewChunked::get_chunk`.
+/// As a consequence, compressed representations requiring heap allocations, such as the sorted array containers of
+/// Roaring bitmaps, cannot be used as chunks.
 pub trait IndexChunk:
     Copy
     + Default
@@ -32,4 +44,63 @@ pub trait IndexChunk:
 {
     /// Number of bits in this chunk.
     const BITS: u32;
+
+    /// Creates a new, full, instance, that is containing all `BITS` indexes.
+    ///
+    /// Implementers should override this method if all bits can be set at once.
+    #[inline(always)]
+    fn full() -> Self {
+        !Self::new()
+    }
+
+    /// Returns whether the chunk is full, that is contains all `BITS` indexes.
+    ///
+    /// Implementers should override this method if all bits can be checked at once.
+    #[inline(always)]
+    fn is_full(&self) -> bool {
+        self.len() == Self::BITS as usize
+    }
+}
+
+//  Returns the half-open range `start..end` equivalent to `range`, clamped to `0..bits`.
+//
+//  The range is empty if `start >= end`.
+fn clamp_range<I, R>(range: &R, bits: u32) -> (u32, u32)
+where
+    I: Copy + Into<u32>,
+    R: RangeBounds<I>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start.into(),
+        Bound::Excluded(&start) => start.into().saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.into().saturating_add(1),
+        Bound::Excluded(&end) => end.into(),
+        Bound::Unbounded => bits,
+    };
+
+    (start, end.min(bits))
+}
+
+//  Returns the chunk at `outer` within `chunks`, reusing `cached` if it was already fetched.
+//
+//  Used to probe runs of nearby indexes, fetching their chunk only once.
+pub(crate) fn cached_chunk<'a, C>(
+    chunks: &'a [C],
+    cached: &mut Option<(usize, Option<&'a C>)>,
+    outer: usize,
+) -> Option<&'a C> {
+    match *cached {
+        Some((cached_outer, chunk)) if cached_outer == outer => chunk,
+        _ => {
+            let chunk = chunks.get(outer);
+
+            *cached = Some((outer, chunk));
+
+            chunk
+        }
+    }
 }
@@ -1,13 +1,17 @@
 //! Array chunk.
 
-use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Bound, Not, Sub, SubAssign};
+use core::{
+    fmt,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Bound, Not, RangeBounds, Sub, SubAssign},
+};
 
 use crate::{
     Never,
-    chunk::IndexChunk,
+    chunk::{self, IndexChunk},
     index::{
         IndexBackward, IndexBackwardChunked, IndexCollection, IndexForward, IndexForwardChunked, IndexOrdered,
-        IndexOrderedChunked, IndexStore, IndexStoreChunked, IndexVault, IndexView, IndexViewChunked,
+        IndexOrderedChunked, IndexOrderedStore, IndexStore, IndexStoreChunked, IndexStoreRange, IndexVault, IndexView,
+        IndexViewCanonical, IndexViewChunked,
     },
     not::{
         IndexBackwardChunkedNot, IndexBackwardNot, IndexForwardChunkedNot, IndexForwardNot, IndexOrderedChunkedNot,
@@ -30,7 +34,7 @@ where
 
     /// Creates a new, full, instance.
     pub fn full() -> Self {
-        Self([!C::new(); N])
+        Self([C::full(); N])
     }
 }
 
@@ -84,6 +88,31 @@ where
     }
 }
 
+/// Prints the raw bitmap of each sub-chunk, separated by `_`, the last sub-chunk first.
+///
+/// As a result, index 0 is the right-most bit, as for a single integral. The alternate flag prefixes the bitmap with
+/// `0b`.
+impl<C, const N: usize> fmt::Binary for ArrayChunk<C, N>
+where
+    C: fmt::Binary,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0b")?;
+        }
+
+        for (i, chunk) in self.0.iter().rev().enumerate() {
+            if i > 0 {
+                f.write_str("_")?;
+            }
+
+            write!(f, "{chunk:b}")?;
+        }
+
+        Ok(())
+    }
+}
+
 impl<C, const N: usize> BitAnd for ArrayChunk<C, N>
 where
     C: IndexChunk,
@@ -180,6 +209,14 @@ where
     C: IndexChunk<Index = u8>,
 {
     const BITS: u32 = C::BITS * (N as u32);
+
+    fn full() -> Self {
+        Self([C::full(); N])
+    }
+
+    fn is_full(&self) -> bool {
+        self.0.iter().all(|chunk| chunk.is_full())
+    }
 }
 
 //  Safety:
@@ -207,8 +244,40 @@ where
 
         self.0.get(outer).is_some_and(|chunk| chunk.contains(inner))
     }
+
+    /// Fetches the sub-chunk of an index only if it differs from that of the previous index, stopping at the first
+    /// missing index.
+    fn contains_all<I>(&self, indexes: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Index>,
+    {
+        let mut cached = None;
+
+        indexes.into_iter().all(|index| {
+            let (outer, inner) = Self::split(index);
+
+            chunk::cached_chunk(&self.0, &mut cached, outer.into()).is_some_and(|chunk| chunk.contains(inner))
+        })
+    }
+
+    /// Fetches the sub-chunk of an index only if it differs from that of the previous index, stopping at the first
+    /// contained index.
+    fn contains_any<I>(&self, indexes: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Index>,
+    {
+        let mut cached = None;
+
+        indexes.into_iter().any(|index| {
+            let (outer, inner) = Self::split(index);
+
+            chunk::cached_chunk(&self.0, &mut cached, outer.into()).is_some_and(|chunk| chunk.contains(inner))
+        })
+    }
 }
 
+impl<C, const N: usize> IndexViewCanonical for ArrayChunk<C, N> where C: IndexChunk<Index = u8> + IndexViewCanonical {}
+
 //  Safety:
 //
 //  -   NoPhantom: the store will only ever return that it contains an index if the index was inserted, and was not
@@ -283,6 +352,122 @@ where
 
         self.0.get_mut(outer).is_some_and(|chunk| chunk.remove(inner))
     }
+
+    fn toggle(&mut self, index: Self::Index) -> Result<bool, Self::InsertionError> {
+        let (outer, inner) = Self::split(index);
+
+        let outer: usize = outer.into();
+
+        //  As per `insert`, an out-of-bounds index is silently dropped.
+        let Some(chunk) = self.0.get_mut(outer) else {
+            return Ok(false);
+        };
+
+        chunk.toggle(inner)
+    }
+
+    fn replace_with<F>(&mut self, index: Self::Index, f: F) -> Result<bool, Self::InsertionError>
+    where
+        F: FnOnce(bool) -> bool,
+    {
+        let (outer, inner) = Self::split(index);
+
+        let outer: usize = outer.into();
+
+        //  As per `insert`, an out-of-bounds index is not in the store, and is silently dropped.
+        let Some(chunk) = self.0.get_mut(outer) else {
+            f(false);
+
+            return Ok(false);
+        };
+
+        chunk.replace_with(inner, f)
+    }
+}
+
+//  Safety:
+//
+//  -   NoPhantom: the store will only ever insert indexes within the given range.
+unsafe impl<C, const N: usize> IndexStoreRange for ArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8> + IndexStoreRange,
+{
+    fn insert_range<R>(&mut self, range: R) -> Result<usize, Self::InsertionError>
+    where
+        R: RangeBounds<Self::Index>,
+    {
+        let (start, end) = chunk::clamp_range(&range, Self::BITS);
+
+        let mut inserted = 0;
+
+        for (i, chunk) in self.0.iter_mut().enumerate() {
+            let offset = i as u32 * C::BITS;
+
+            let (start, end) = (start.saturating_sub(offset), end.saturating_sub(offset).min(C::BITS));
+
+            if start >= end {
+                continue;
+            }
+
+            inserted += if start == 0 && end == C::BITS {
+                let inserted = C::BITS as usize - chunk.len();
+
+                *chunk = C::full();
+
+                inserted
+            } else {
+                //  `start < C::BITS`, and `C::Index` is `u8`, hence `start` fits, and so does `end` unless it is
+                //  `C::BITS`.
+                let end = if end == C::BITS {
+                    Bound::Unbounded
+                } else {
+                    Bound::Excluded(end as u8)
+                };
+
+                chunk.insert_range((Bound::Included(start as u8), end))?
+            };
+        }
+
+        Ok(inserted)
+    }
+
+    fn remove_range<R>(&mut self, range: R) -> usize
+    where
+        R: RangeBounds<Self::Index>,
+    {
+        let (start, end) = chunk::clamp_range(&range, Self::BITS);
+
+        let mut removed = 0;
+
+        for (i, chunk) in self.0.iter_mut().enumerate() {
+            let offset = i as u32 * C::BITS;
+
+            let (start, end) = (start.saturating_sub(offset), end.saturating_sub(offset).min(C::BITS));
+
+            if start >= end {
+                continue;
+            }
+
+            removed += if start == 0 && end == C::BITS {
+                let removed = chunk.len();
+
+                chunk.clear();
+
+                removed
+            } else {
+                //  As per `insert_range`.
+                let end = if end == C::BITS {
+                    Bound::Unbounded
+                } else {
+                    Bound::Excluded(end as u8)
+                };
+
+                chunk.remove_range((Bound::Included(start as u8), end))
+            };
+        }
+
+        removed
+    }
 }
 
 //  Safety:
@@ -454,6 +639,24 @@ where
 //  -   Ordered: the `IndexForward` implementation will return indexes in strictly increasing order.
 unsafe impl<C, const N: usize> IndexOrdered for ArrayChunk<C, N> where C: IndexChunk<Index = u8> + IndexOrdered {}
 
+//  Safety:
+//
+//  -   Rank: the store will sum the lengths of the sub-chunks before the sub-chunk of `index`, and the rank within.
+unsafe impl<C, const N: usize> IndexOrderedStore for ArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8> + IndexOrderedStore,
+{
+    fn rank(&self, index: Self::Index) -> usize {
+        let (outer, inner) = Self::split(index);
+
+        let outer: usize = outer.into();
+
+        let before: usize = self.0.iter().take(outer).map(|chunk| chunk.len()).sum();
+
+        before + self.0.get(outer).map_or(0, |chunk| chunk.rank(inner))
+    }
+}
+
 //  Safety:
 //
 //  -   Ordered: the `IndexForward` implementation will return indexes in strictly increasing order.
@@ -512,8 +715,13 @@ where
 
     /// #   Panics
     ///
-    /// If `index >= N`.
+    /// If `index >= N`, even if `chunk` is empty, leaving `self` unmodified.
+    ///
+    /// The chunk indexes returned by `first_chunk`, `next_chunk_after`, and their backward counterparts, are always
+    /// in bounds.
     fn set_chunk(&mut self, index: Self::ChunkIndex, chunk: Self::Chunk) -> Result<(), Self::SetError> {
+        assert!((index as usize) < N, "chunk index {index} out of bounds of {N} chunks");
+
         self.0[index as usize] = chunk;
 
         Ok(())
@@ -610,7 +818,7 @@ unsafe impl<C, const N: usize> IndexOrderedChunkedNot for ArrayChunk<C, N> where
 mod tests {
     use crate::{
         chunk::UnsignedChunk,
-        test::{IndexTester, IndexTesterNot},
+        test::{IndexTester, IndexTesterLossy, IndexTesterNot},
     };
 
     use super::*;
@@ -656,11 +864,37 @@ mod tests {
         }
     }
 
+    //  Indexes beyond the last chunk are silently dropped.
+    struct LossyTester;
+
+    impl IndexTesterLossy for LossyTester {
+        type Index = u16;
+        type Victim = ArrayChunk<UnsignedChunk<u8>, 4>;
+
+        fn upper_bound() -> u8 {
+            Tester::upper_bound()
+        }
+
+        fn victim(indexes: &[u8]) -> Self::Victim {
+            Tester::victim(indexes)
+        }
+
+        fn index(i: u8) -> Self::Index {
+            i.into()
+        }
+
+        fn lossy_index() -> Option<Self::Index> {
+            Some(8 * 4)
+        }
+    }
+
     crate::test_index_view!(Tester);
     crate::test_index_collection!(Tester);
     crate::test_index_store!(Tester);
+    crate::test_index_store_lossy!(LossyTester);
     crate::test_index_forward!(Tester);
     crate::test_index_backward!(Tester);
+    crate::test_index_ordered_store!(Tester);
     crate::test_index_view_chunked!(Tester);
     crate::test_index_forward_chunked!(Tester);
     crate::test_index_backward_chunked!(Tester);
@@ -670,4 +904,111 @@ mod tests {
     crate::test_index_backward_not!(Tester);
     crate::test_index_forward_chunked_not!(Tester);
     crate::test_index_backward_chunked_not!(Tester);
+
+    #[test]
+    fn full() {
+        type Victim = <Tester as IndexTester>::Victim;
+
+        let full = <Victim as IndexChunk>::full();
+
+        assert!(full.is_full());
+        assert_eq!(Victim::BITS as usize, full.len());
+        assert_eq!(Victim::new(), !full);
+
+        let mut victim = full;
+        victim.remove(17);
+
+        assert!(!victim.is_full());
+        assert!(!Victim::new().is_full());
+    }
+
+    #[test]
+    fn insert_range() {
+        const INDEXES: [u8; 5] = [0, 7, 8, 15, 31];
+
+        for start in 0..=32 {
+            for end in start..=32 {
+                let mut expected = Tester::victim(&INDEXES);
+                let mut victim = expected;
+
+                let inserted = (start..end).filter(|&index| expected.insert(index).unwrap()).count();
+
+                assert_eq!(Ok(inserted), victim.insert_range(start..end), "{start}..{end}");
+                assert_eq!(expected, victim, "{start}..{end}");
+            }
+        }
+    }
+
+    #[test]
+    fn remove_range() {
+        const INDEXES: [u8; 9] = [0, 5, 6, 7, 8, 9, 10, 15, 31];
+
+        //  Across the boundary of the first two sub-chunks.
+        let mut victim = Tester::victim(&INDEXES);
+
+        assert_eq!(4, victim.remove_range(6..10));
+        assert_eq!(Tester::victim(&[0, 5, 10, 15, 31]), victim);
+
+        for start in 0..=32 {
+            for end in start..=32 {
+                let mut expected = Tester::victim(&INDEXES);
+                let mut victim = expected;
+
+                let removed = (start..end).filter(|&index| expected.remove(index)).count();
+
+                assert_eq!(removed, victim.remove_range(start..end), "{start}..{end}");
+                assert_eq!(expected, victim, "{start}..{end}");
+            }
+        }
+    }
+
+    #[test]
+    fn range_beyond_span() {
+        let mut victim = Tester::victim(&[0, 7, 31]);
+
+        assert_eq!(Ok(29), victim.insert_range(..=200));
+        assert!(victim.is_full());
+
+        assert_eq!(12, victim.remove_range(20..=200));
+        assert_eq!(0, victim.remove_range(40..));
+        assert_eq!(20, victim.len());
+    }
+
+    #[test]
+    fn set_chunk() {
+        let mut victim = Tester::victim(&[0, 9, 31]);
+
+        assert_eq!(Ok(()), victim.set_chunk(3, UnsignedChunk(0b11)));
+        assert_eq!(Tester::victim(&[0, 9, 24, 25]), victim);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk index 4 out of bounds of 4 chunks")]
+    fn set_chunk_out_of_bounds() {
+        let mut victim = Tester::victim(&[]);
+
+        let _ = victim.set_chunk(4, UnsignedChunk::new());
+    }
+
+    #[test]
+    fn insert_all_chunks() {
+        let mut victim = Tester::victim(&[2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31]);
+        let evens = Tester::victim(&[0, 2, 4, 6, 8, 10, 12, 14]);
+
+        assert_eq!(Ok(7), victim.insert_all_chunks(&evens));
+        assert_eq!(
+            Tester::victim(&[0, 2, 3, 4, 5, 6, 7, 8, 10, 11, 12, 13, 14, 17, 19, 23, 29, 31]),
+            victim
+        );
+
+        assert_eq!(Ok(0), victim.insert_all_chunks(&evens));
+    }
+
+    #[test]
+    fn binary() {
+        let victim = Tester::victim(&[0, 7, 9, 31]);
+
+        assert_eq!("10000000_00000000_00000010_10000001", format!("{victim:b}"));
+        assert_eq!("0b10000000_00000000_00000010_10000001", format!("{victim:#b}"));
+    }
 } // mod tests
@@ -0,0 +1,676 @@
+//! Counting array chunk.
+
+use core::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Bound, Not, RangeBounds, Sub, SubAssign,
+};
+
+use crate::{
+    Never,
+    chunk::{ArrayChunk, IndexChunk},
+    index::{
+        IndexBackward, IndexBackwardChunked, IndexCollection, IndexForward, IndexForwardChunked, IndexOrdered,
+        IndexOrderedChunked, IndexOrderedStore, IndexStore, IndexStoreChunked, IndexStoreRange, IndexVault, IndexView,
+        IndexViewCached, IndexViewCanonical, IndexViewChunked,
+    },
+    not::{
+        IndexBackwardChunkedNot, IndexBackwardNot, IndexForwardChunkedNot, IndexForwardNot, IndexOrderedChunkedNot,
+        IndexOrderedNot, IndexViewNot,
+    },
+};
+
+/// An `ArrayChunk` which caches its number of indexes.
+///
+/// `ArrayChunk::len` sums the lengths of its sub-chunks on every call, whereas `CountingArrayChunk::len` returns a
+/// count maintained on every modification. This trades a slightly more expensive modification for a constant-time
+/// `len` and `is_empty`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CountingArrayChunk<C, const N: usize> {
+    count: usize,
+    chunk: ArrayChunk<C, N>,
+}
+
+impl<C, const N: usize> CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    /// Creates a new, empty, instance.
+    pub fn new() -> Self {
+        Self::from(ArrayChunk::new())
+    }
+
+    /// Creates a new, full, instance.
+    pub fn full() -> Self {
+        Self::from(ArrayChunk::full())
+    }
+
+    /// Returns the underlying array chunk.
+    pub fn into_inner(self) -> ArrayChunk<C, N> {
+        self.chunk
+    }
+}
+
+impl<C, const N: usize> From<ArrayChunk<C, N>> for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    fn from(chunk: ArrayChunk<C, N>) -> Self {
+        let count = chunk.len();
+
+        Self { count, chunk }
+    }
+}
+
+impl<C, const N: usize> Default for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C, const N: usize> BitAnd for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        Self::from(self.chunk & other.chunk)
+    }
+}
+
+impl<C, const N: usize> BitAndAssign for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    fn bitand_assign(&mut self, other: Self) {
+        *self = *self & other;
+    }
+}
+
+impl<C, const N: usize> BitOr for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self::from(self.chunk | other.chunk)
+    }
+}
+
+impl<C, const N: usize> BitOrAssign for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    fn bitor_assign(&mut self, other: Self) {
+        *self = *self | other;
+    }
+}
+
+impl<C, const N: usize> BitXor for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    type Output = Self;
+
+    fn bitxor(self, other: Self) -> Self {
+        Self::from(self.chunk ^ other.chunk)
+    }
+}
+
+impl<C, const N: usize> BitXorAssign for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    fn bitxor_assign(&mut self, other: Self) {
+        *self = *self ^ other;
+    }
+}
+
+impl<C, const N: usize> Not for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        let count = Self::BITS as usize - self.count;
+        let chunk = !self.chunk;
+
+        Self { count, chunk }
+    }
+}
+
+impl<C, const N: usize> Sub for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self::from(self.chunk - other.chunk)
+    }
+}
+
+impl<C, const N: usize> SubAssign for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<C, const N: usize> IndexChunk for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    const BITS: u32 = ArrayChunk::<C, N>::BITS;
+
+    fn full() -> Self {
+        Self::full()
+    }
+
+    fn is_full(&self) -> bool {
+        self.count == Self::BITS as usize
+    }
+}
+
+//  Safety:
+//
+//  -   NoPhantom: as per `ArrayChunk`.
+unsafe impl<C, const N: usize> IndexView for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    type Index = u16;
+
+    fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    fn len(&self) -> usize {
+        self.count
+    }
+
+    fn contains(&self, index: Self::Index) -> bool {
+        self.chunk.contains(index)
+    }
+
+    fn contains_all<I>(&self, indexes: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Index>,
+    {
+        self.chunk.contains_all(indexes)
+    }
+
+    fn contains_any<I>(&self, indexes: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Index>,
+    {
+        self.chunk.contains_any(indexes)
+    }
+}
+
+impl<C, const N: usize> IndexViewCached for CountingArrayChunk<C, N> where C: IndexChunk<Index = u8> {}
+
+impl<C, const N: usize> IndexViewCanonical for CountingArrayChunk<C, N> where
+    C: IndexChunk<Index = u8> + IndexViewCanonical
+{
+}
+
+//  Safety:
+//
+//  -   NoPhantom: as per `ArrayChunk`.
+unsafe impl<C, const N: usize> IndexViewNot for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    fn len_not(&self) -> usize {
+        Self::BITS as usize - self.count
+    }
+}
+
+impl<C, const N: usize> IndexCollection for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    fn span() -> (Bound<Self::Index>, Bound<Self::Index>) {
+        ArrayChunk::<C, N>::span()
+    }
+
+    fn new() -> Self {
+        Self::new()
+    }
+
+    fn with_span(_: (Bound<Self::Index>, Bound<Self::Index>)) -> Self {
+        Self::new()
+    }
+}
+
+//  Safety:
+//
+//  -   NoPhantom: as per `ArrayChunk`.
+unsafe impl<C, const N: usize> IndexStore for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    type InsertionError = C::InsertionError;
+
+    fn clear(&mut self) {
+        self.count = 0;
+        self.chunk.clear();
+    }
+
+    fn insert(&mut self, index: Self::Index) -> Result<bool, Self::InsertionError> {
+        let inserted = self.chunk.insert(index)?;
+
+        if inserted {
+            self.count += 1;
+        }
+
+        Ok(inserted)
+    }
+
+    fn remove(&mut self, index: Self::Index) -> bool {
+        let removed = self.chunk.remove(index);
+
+        if removed {
+            self.count -= 1;
+        }
+
+        removed
+    }
+}
+
+//  Safety:
+//
+//  -   NoPhantom: as per `ArrayChunk`.
+unsafe impl<C, const N: usize> IndexStoreRange for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8> + IndexStoreRange,
+{
+    fn insert_range<R>(&mut self, range: R) -> Result<usize, Self::InsertionError>
+    where
+        R: RangeBounds<Self::Index>,
+    {
+        match self.chunk.insert_range(range) {
+            Ok(inserted) => {
+                self.count += inserted;
+
+                Ok(inserted)
+            }
+            Err(error) => {
+                //  Some indexes may have been inserted prior to the error.
+                self.count = self.chunk.len();
+
+                Err(error)
+            }
+        }
+    }
+
+    fn remove_range<R>(&mut self, range: R) -> usize
+    where
+        R: RangeBounds<Self::Index>,
+    {
+        let removed = self.chunk.remove_range(range);
+
+        self.count -= removed;
+
+        removed
+    }
+}
+
+//  Safety:
+//
+//  -   NoTheft: as per `ArrayChunk`.
+unsafe impl<C, const N: usize> IndexVault for CountingArrayChunk<C, N> where C: IndexChunk<Index = u8> + IndexVault {}
+
+//  Safety:
+//
+//  -   NoDuplicate, NoPhantom, NoTheft: as per `ArrayChunk`.
+unsafe impl<C, const N: usize> IndexForward for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8> + IndexForward,
+{
+    fn first(&self) -> Option<Self::Index> {
+        self.chunk.first()
+    }
+
+    fn next_after(&self, current: Self::Index) -> Option<Self::Index> {
+        self.chunk.next_after(current)
+    }
+}
+
+//  Safety:
+//
+//  -   NoDuplicate, NoPhantom, NoTheft: as per `ArrayChunk`.
+unsafe impl<C, const N: usize> IndexForwardNot for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8> + IndexForwardNot,
+{
+    fn first_not(&self) -> Option<Self::Index> {
+        self.chunk.first_not()
+    }
+
+    fn next_after_not(&self, current: Self::Index) -> Option<Self::Index> {
+        self.chunk.next_after_not(current)
+    }
+}
+
+//  Safety:
+//
+//  -   Reverse: as per `ArrayChunk`.
+unsafe impl<C, const N: usize> IndexBackward for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8> + IndexBackward,
+{
+    fn last(&self) -> Option<Self::Index> {
+        self.chunk.last()
+    }
+
+    fn next_before(&self, current: Self::Index) -> Option<Self::Index> {
+        self.chunk.next_before(current)
+    }
+}
+
+//  Safety:
+//
+//  -   Reverse: as per `ArrayChunk`.
+unsafe impl<C, const N: usize> IndexBackwardNot for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8> + IndexBackwardNot,
+{
+    fn last_not(&self) -> Option<Self::Index> {
+        self.chunk.last_not()
+    }
+
+    fn next_before_not(&self, current: Self::Index) -> Option<Self::Index> {
+        self.chunk.next_before_not(current)
+    }
+}
+
+//  Safety:
+//
+//  -   Ordered: as per `ArrayChunk`.
+unsafe impl<C, const N: usize> IndexOrdered for CountingArrayChunk<C, N> where C: IndexChunk<Index = u8> + IndexOrdered {}
+
+//  Safety:
+//
+//  -   Rank: as per `ArrayChunk`.
+unsafe impl<C, const N: usize> IndexOrderedStore for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8> + IndexOrderedStore,
+{
+    fn rank(&self, index: Self::Index) -> usize {
+        self.chunk.rank(index)
+    }
+}
+
+//  Safety:
+//
+//  -   Ordered: as per `ArrayChunk`.
+unsafe impl<C, const N: usize> IndexOrderedNot for CountingArrayChunk<C, N> where
+    C: IndexChunk<Index = u8> + IndexForwardNot + IndexOrdered
+{
+}
+
+//  Safety:
+//
+//  -   NoPhantom, SplitFuse, TwoLevels: as per `ArrayChunk`.
+unsafe impl<C, const N: usize> IndexViewChunked for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    type ChunkIndex = u16;
+    type Chunk = C;
+
+    fn fuse(outer: Self::ChunkIndex, inner: C::Index) -> Self::Index {
+        ArrayChunk::<C, N>::fuse(outer, inner)
+    }
+
+    fn split(index: Self::Index) -> (Self::ChunkIndex, C::Index) {
+        ArrayChunk::<C, N>::split(index)
+    }
+
+    fn get_chunk(&self, index: Self::ChunkIndex) -> Option<Self::Chunk> {
+        self.chunk.get_chunk(index)
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: as per `ArrayChunk`.
+unsafe impl<C, const N: usize> IndexStoreChunked for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    type SetError = Never;
+
+    /// #   Panics
+    ///
+    /// If `index >= N`, even if `chunk` is empty, leaving `self` unmodified.
+    ///
+    /// The chunk indexes returned by `first_chunk`, `next_chunk_after`, and their backward counterparts, are always
+    /// in bounds.
+    fn set_chunk(&mut self, index: Self::ChunkIndex, chunk: Self::Chunk) -> Result<(), Self::SetError> {
+        assert!((index as usize) < N, "chunk index {index} out of bounds of {N} chunks");
+
+        let previous = self.chunk.0[index as usize];
+
+        self.chunk.0[index as usize] = chunk;
+        self.count = self.count - previous.len() + chunk.len();
+
+        Ok(())
+    }
+}
+
+//  #   Safety
+//
+//  -   NoDuplicate, NoPhantom, NoTheft: as per `ArrayChunk`.
+unsafe impl<C, const N: usize> IndexForwardChunked for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    fn first_chunk(&self) -> Option<Self::ChunkIndex> {
+        self.chunk.first_chunk()
+    }
+
+    fn next_chunk_after(&self, current: Self::ChunkIndex) -> Option<Self::ChunkIndex> {
+        self.chunk.next_chunk_after(current)
+    }
+}
+
+//  #   Safety
+//
+//  -   NoDuplicate, NoPhantom, NoTheft: as per `ArrayChunk`.
+unsafe impl<C, const N: usize> IndexForwardChunkedNot for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    #[inline(always)]
+    fn first_chunk_not(&self) -> Option<Self::ChunkIndex> {
+        self.first_chunk()
+    }
+
+    #[inline(always)]
+    fn next_chunk_after_not(&self, current: Self::ChunkIndex) -> Option<Self::ChunkIndex> {
+        self.next_chunk_after(current)
+    }
+}
+
+//  Safety:
+//
+//  -   Reverse: as per `ArrayChunk`.
+unsafe impl<C, const N: usize> IndexBackwardChunked for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    fn last_chunk(&self) -> Option<Self::ChunkIndex> {
+        self.chunk.last_chunk()
+    }
+
+    fn next_chunk_before(&self, current: Self::ChunkIndex) -> Option<Self::ChunkIndex> {
+        self.chunk.next_chunk_before(current)
+    }
+}
+
+//  Safety:
+//
+//  -   Reverse: as per `ArrayChunk`.
+unsafe impl<C, const N: usize> IndexBackwardChunkedNot for CountingArrayChunk<C, N>
+where
+    C: IndexChunk<Index = u8>,
+{
+    #[inline(always)]
+    fn last_chunk_not(&self) -> Option<Self::ChunkIndex> {
+        self.last_chunk()
+    }
+
+    #[inline(always)]
+    fn next_chunk_before_not(&self, current: Self::ChunkIndex) -> Option<Self::ChunkIndex> {
+        self.next_chunk_before(current)
+    }
+}
+
+//  #   Safety
+//
+//  -   Ordered: as per `ArrayChunk`.
+unsafe impl<C, const N: usize> IndexOrderedChunked for CountingArrayChunk<C, N> where C: IndexChunk<Index = u8> {}
+
+//  #   Safety
+//
+//  -   Ordered: as per `ArrayChunk`.
+unsafe impl<C, const N: usize> IndexOrderedChunkedNot for CountingArrayChunk<C, N> where C: IndexChunk<Index = u8> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        chunk::UnsignedChunk,
+        test::{IndexTester, IndexTesterNot},
+    };
+
+    use super::*;
+
+    struct Tester;
+
+    impl IndexTester for Tester {
+        type Index = u16;
+        type Victim = CountingArrayChunk<UnsignedChunk<u8>, 4>;
+
+        fn upper_bound() -> u8 {
+            8 * 4 - 1
+        }
+
+        fn victim(indexes: &[u8]) -> Self::Victim {
+            let mut array: Self::Victim = CountingArrayChunk::new();
+
+            for &index in indexes {
+                let _ = array.insert(index.into());
+            }
+
+            array
+        }
+
+        fn index(i: u8) -> Self::Index {
+            i.into()
+        }
+    }
+
+    impl IndexTesterNot for Tester {
+        fn capacity() -> usize {
+            Self::upper_bound() as usize + 1
+        }
+
+        fn victim_not(indexes: &[u8]) -> Self::Victim {
+            let mut array: Self::Victim = CountingArrayChunk::full();
+
+            for &index in indexes {
+                array.remove(index.into());
+            }
+
+            array
+        }
+    }
+
+    crate::test_index_view!(Tester);
+    crate::test_index_collection!(Tester);
+    crate::test_index_store!(Tester);
+    crate::test_index_forward!(Tester);
+    crate::test_index_backward!(Tester);
+    crate::test_index_ordered_store!(Tester);
+    crate::test_index_view_chunked!(Tester);
+    crate::test_index_forward_chunked!(Tester);
+    crate::test_index_backward_chunked!(Tester);
+
+    crate::test_index_view_not!(Tester);
+    crate::test_index_forward_not!(Tester);
+    crate::test_index_backward_not!(Tester);
+    crate::test_index_forward_chunked_not!(Tester);
+    crate::test_index_backward_chunked_not!(Tester);
+
+    #[test]
+    fn count() {
+        type Victim = CountingArrayChunk<UnsignedChunk<u8>, 4>;
+
+        let mut victim = Victim::new();
+
+        for index in [1, 2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31] {
+            victim.insert(index).unwrap();
+            victim.insert(index).unwrap();
+        }
+
+        assert_eq!(12, victim.len());
+        assert_eq!(victim.into_inner().len(), victim.len());
+
+        victim.remove(2);
+        victim.remove(2);
+
+        assert_eq!(11, victim.len());
+
+        let _ = victim.set_chunk(0, UnsignedChunk::new());
+
+        assert_eq!(7, victim.len());
+        assert_eq!(victim.into_inner().len(), victim.len());
+
+        let other = Victim::from(ArrayChunk::full());
+
+        assert_eq!(32, other.len());
+        assert_eq!(25, (!victim).len());
+        assert_eq!(32, (victim | other).len());
+        assert_eq!(7, (victim & other).len());
+        assert_eq!(25, (victim ^ other).len());
+        assert_eq!(25, (other - victim).len());
+
+        victim.clear();
+
+        assert!(victim.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk index 4 out of bounds of 4 chunks")]
+    fn set_chunk_out_of_bounds() {
+        let mut victim = Tester::victim(&[0, 9, 31]);
+
+        let _ = victim.set_chunk(4, UnsignedChunk(0b11));
+    }
+
+    #[test]
+    fn full() {
+        type Victim = CountingArrayChunk<UnsignedChunk<u8>, 4>;
+
+        let full = <Victim as IndexChunk>::full();
+
+        assert!(full.is_full());
+        assert_eq!(Victim::BITS as usize, full.len());
+        assert_eq!(Victim::new(), !full);
+
+        let mut victim = full;
+        victim.remove(17);
+
+        assert!(!victim.is_full());
+        assert!(!Victim::new().is_full());
+    }
+} // mod tests
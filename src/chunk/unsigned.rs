@@ -1,11 +1,17 @@
 //! Unsigned chunk.
 
-use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Bound, Not, Sub, SubAssign};
+use core::{
+    fmt,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Bound, Not, RangeBounds, Sub, SubAssign},
+};
 
 use crate::{
     Never,
-    chunk::IndexChunk,
-    index::{IndexBackward, IndexCollection, IndexForward, IndexOrdered, IndexStore, IndexVault, IndexView},
+    chunk::{self, IndexChunk},
+    index::{
+        IndexBackward, IndexCollection, IndexForward, IndexOrdered, IndexOrderedStore, IndexStore, IndexStoreRange,
+        IndexVault, IndexView, IndexViewCached, IndexViewCanonical,
+    },
     not::{IndexBackwardNot, IndexForwardNot, IndexOrderedNot, IndexViewNot},
 };
 
@@ -106,8 +112,29 @@ where
 
 macro_rules! impl_indexes_chunk_for_chunk {
     ($($u:ident)*) => { $(
+        /// Prints the raw bitmap, zero-padded to the full width of the chunk, index 0 being the right-most bit.
+        ///
+        /// The alternate flag prefixes the bitmap with `0b`.
+        impl fmt::Binary for UnsignedChunk<$u> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if f.alternate() {
+                    f.write_str("0b")?;
+                }
+
+                write!(f, "{:0width$b}", self.0, width = $u::BITS as usize)
+            }
+        }
+
         impl IndexChunk for UnsignedChunk<$u> {
             const BITS: u32 = $u::BITS;
+
+            fn full() -> Self {
+                Self(!0)
+            }
+
+            fn is_full(&self) -> bool {
+                self.0 == !0
+            }
         }
 
         //  #   Safety
@@ -135,6 +162,10 @@ macro_rules! impl_indexes_chunk_for_chunk {
             }
         }
 
+        impl IndexViewCached for UnsignedChunk<$u> {}
+
+        impl IndexViewCanonical for UnsignedChunk<$u> {}
+
         //  #   Safety
         //
         //  -   NoPhantom: the store WILL only ever return that it contains an index if the index was inserted, and was
@@ -199,6 +230,67 @@ macro_rules! impl_indexes_chunk_for_chunk {
 
                 existed
             }
+
+            fn toggle(&mut self, index: Self::Index) -> Result<bool, Never> {
+                use core::ops::RangeBounds;
+
+                debug_assert!(Self::span().contains(&index), "{index}");
+
+                let mask = (1 << (index as u32));
+
+                self.0 ^= mask;
+
+                Ok((self.0 & mask) != 0)
+            }
+        }
+
+        //  #   Safety
+        //
+        //  -   NoPhantom: the store WILL only ever insert indexes within the given range.
+        unsafe impl IndexStoreRange for UnsignedChunk<$u> {
+            fn insert_range<R>(&mut self, range: R) -> Result<usize, Never>
+            where
+                R: RangeBounds<Self::Index>,
+            {
+                let mask = Self::range_mask(&range);
+
+                let inserted = (mask & !self.0).count_ones() as usize;
+
+                self.0 |= mask;
+
+                Ok(inserted)
+            }
+
+            fn remove_range<R>(&mut self, range: R) -> usize
+            where
+                R: RangeBounds<Self::Index>,
+            {
+                let mask = Self::range_mask(&range);
+
+                let removed = (mask & self.0).count_ones() as usize;
+
+                self.0 &= !mask;
+
+                removed
+            }
+        }
+
+        impl UnsignedChunk<$u> {
+            //  Returns the mask of the bits within `range`.
+            fn range_mask<R>(range: &R) -> $u
+            where
+                R: RangeBounds<u8>,
+            {
+                let ones = |n: u32| if n >= $u::BITS { !0 } else { (1 << n) - 1 };
+
+                let (start, end) = chunk::clamp_range(range, $u::BITS);
+
+                if start >= end {
+                    return 0;
+                }
+
+                ones(end) & !ones(start)
+            }
         }
 
         //  #   Safety
@@ -306,6 +398,17 @@ macro_rules! impl_indexes_chunk_for_chunk {
         //  -   Ordered: the `IndexForward` implementation WILL return indexes in strictly increasing order.
         unsafe impl IndexOrdered for UnsignedChunk<$u> {}
 
+        //  #   Safety
+        //
+        //  -   Rank: the store WILL count the bits strictly below `index`.
+        unsafe impl IndexOrderedStore for UnsignedChunk<$u> {
+            fn rank(&self, index: Self::Index) -> usize {
+                let below = $u::checked_shl(1, index.into()).map_or(!0, |bit| bit - 1);
+
+                (self.0 & below).count_ones() as usize
+            }
+        }
+
         //  #   Safety
         //
         //  -   Ordered: the `IndexForward` implementation WILL return indexes in strictly increasing order.
@@ -352,13 +455,71 @@ mod tests {
                 crate::test_index_store!(Tester);
                 crate::test_index_forward!(Tester);
                 crate::test_index_backward!(Tester);
+                crate::test_index_ordered_store!(Tester);
 
                 crate::test_index_view_not!(Tester);
                 crate::test_index_forward_not!(Tester);
                 crate::test_index_backward_not!(Tester);
+
+                #[test]
+                fn full() {
+                    use crate::{chunk::IndexChunk, index::{IndexCollection, IndexStore, IndexView}};
+
+                    let full = <UnsignedChunk<$u> as IndexChunk>::full();
+
+                    assert!(full.is_full());
+                    assert_eq!($u::BITS as usize, full.len());
+                    assert_eq!(UnsignedChunk::<$u>::new(), !full);
+
+                    let mut victim = full;
+                    victim.remove(0);
+
+                    assert!(!victim.is_full());
+                    assert!(!UnsignedChunk::<$u>::new().is_full());
+                }
+
+                #[test]
+                fn insert_range() {
+                    use crate::{chunk::IndexChunk, index::{IndexCollection, IndexStoreRange}};
+
+                    let mut victim = UnsignedChunk::<$u>::new();
+
+                    assert_eq!(Ok(4), victim.insert_range(2..=5));
+                    assert_eq!(UnsignedChunk(0b111100), victim);
+
+                    assert_eq!(Ok(0), victim.insert_range(3..3));
+                    assert_eq!(Ok($u::BITS as usize - 4), victim.insert_range(..));
+                    assert!(victim.is_full());
+                }
+
+                #[test]
+                fn remove_range() {
+                    use crate::{chunk::IndexChunk, index::{IndexStoreRange, IndexView}};
+
+                    let mut victim = <UnsignedChunk<$u> as IndexChunk>::full();
+
+                    assert_eq!(4, victim.remove_range(2..=5));
+                    assert_eq!(UnsignedChunk(!0b111100), victim);
+
+                    assert_eq!(0, victim.remove_range(3..=4));
+                    assert_eq!($u::BITS as usize - 4, victim.remove_range(..));
+                    assert!(victim.is_empty());
+                }
             }
        )* };
     }
 
     test_unsigned_chunk!(u8 u16 u32 u64 u128 usize);
+
+    mod binary {
+        use crate::chunk::UnsignedChunk;
+
+        #[test]
+        fn bitmap() {
+            assert_eq!("00000000", format!("{:b}", UnsignedChunk(0u8)));
+            assert_eq!("10100001", format!("{:b}", UnsignedChunk(0b1010_0001u8)));
+            assert_eq!("0000000010100001", format!("{:b}", UnsignedChunk(0b1010_0001u16)));
+            assert_eq!("0b00000101", format!("{:#b}", UnsignedChunk(0b101u8)));
+        }
+    }
 } // mod tests
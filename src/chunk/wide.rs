@@ -0,0 +1,615 @@
+//! Wide, SIMD-accelerated, chunk.
+
+use core::{
+    array,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Bound, Not, RangeBounds, Sub, SubAssign},
+    simd::{
+        Select, Simd,
+        cmp::{SimdOrd, SimdPartialEq},
+        num::SimdUint,
+    },
+};
+
+use crate::{
+    Never,
+    chunk::{self, IndexChunk},
+    index::{
+        IndexBackward, IndexCollection, IndexForward, IndexOrdered, IndexOrderedStore, IndexStore, IndexStoreRange,
+        IndexVault, IndexView, IndexViewCached, IndexViewCanonical,
+    },
+    not::{IndexBackwardNot, IndexForwardNot, IndexOrderedNot, IndexViewNot},
+};
+
+/// A chunk of `64 * LANES` indexes, operated on with SIMD instructions.
+///
+/// Semantically equivalent to `ArrayChunk<UnsignedChunk<u64>, LANES>`, of which it is a drop-in replacement, the
+/// bitwise operations, the population count, and the search for the first or last index are all performed on all
+/// lanes at once.
+///
+/// Only available with the `simd` feature, which requires a nightly compiler, use `ArrayChunk` otherwise.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct WideChunk<const LANES: usize>(pub Simd<u64, LANES>);
+
+impl<const LANES: usize> WideChunk<LANES> {
+    /// Creates a new, empty, chunk.
+    pub const fn new() -> Self {
+        Self(Simd::from_array([0; LANES]))
+    }
+
+    //  Splits an index into its lane and its bit within the lane, if within bounds.
+    fn split(index: u16) -> Option<(usize, u32)> {
+        let index: u32 = index.into();
+
+        let lane = (index / u64::BITS) as usize;
+
+        (lane < LANES).then_some((lane, index % u64::BITS))
+    }
+
+    //  Returns the mask of the bits within `start..end`, which is empty if `start >= end`.
+    fn range_mask(start: u32, end: u32) -> Simd<u64, LANES> {
+        let bits = Simd::splat(u64::from(u64::BITS));
+        let low = Simd::from_array(array::from_fn(|lane| lane as u64 * u64::from(u64::BITS)));
+
+        //  The shift wraps on a full lane, which is then selected away.
+        let ones = |n: Simd<u64, LANES>| {
+            n.simd_eq(bits)
+                .select(Simd::splat(!0), (Simd::splat(1) << n) - Simd::splat(1))
+        };
+
+        let start = Simd::splat(u64::from(start)).saturating_sub(low).simd_min(bits);
+        let end = Simd::splat(u64::from(end)).saturating_sub(low).simd_min(bits);
+
+        ones(end) & !ones(start)
+    }
+
+    //  Returns the first index set in `lanes`, if any.
+    fn first_in(lanes: Simd<u64, LANES>) -> Option<u16> {
+        let lane = lanes.simd_ne(Simd::splat(0)).first_set()?;
+
+        Some((lane as u32 * u64::BITS + lanes[lane].trailing_zeros()) as u16)
+    }
+
+    //  Returns the last index set in `lanes`, if any.
+    fn last_in(lanes: Simd<u64, LANES>) -> Option<u16> {
+        let bitmask = lanes.simd_ne(Simd::splat(0)).to_bitmask();
+
+        let lane = u64::BITS.checked_sub(bitmask.leading_zeros() + 1)? as usize;
+
+        Some((lane as u32 * u64::BITS + (u64::BITS - 1 - lanes[lane].leading_zeros())) as u16)
+    }
+}
+
+impl<const LANES: usize> BitAnd for WideChunk<LANES> {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+}
+
+impl<const LANES: usize> BitAndAssign for WideChunk<LANES> {
+    fn bitand_assign(&mut self, other: Self) {
+        self.0 &= other.0;
+    }
+}
+
+impl<const LANES: usize> BitOr for WideChunk<LANES> {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl<const LANES: usize> BitOrAssign for WideChunk<LANES> {
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+}
+
+impl<const LANES: usize> BitXor for WideChunk<LANES> {
+    type Output = Self;
+
+    fn bitxor(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+}
+
+impl<const LANES: usize> BitXorAssign for WideChunk<LANES> {
+    fn bitxor_assign(&mut self, other: Self) {
+        self.0 ^= other.0;
+    }
+}
+
+impl<const LANES: usize> Not for WideChunk<LANES> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self(!self.0)
+    }
+}
+
+impl<const LANES: usize> Sub for WideChunk<LANES> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self(self.0 & !other.0)
+    }
+}
+
+impl<const LANES: usize> SubAssign for WideChunk<LANES> {
+    fn sub_assign(&mut self, other: Self) {
+        self.0 &= !other.0;
+    }
+}
+
+impl<const LANES: usize> IndexChunk for WideChunk<LANES> {
+    const BITS: u32 = u64::BITS * (LANES as u32);
+
+    fn full() -> Self {
+        Self(Simd::splat(!0))
+    }
+
+    fn is_full(&self) -> bool {
+        self.0 == Simd::splat(!0)
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: the store WILL only ever return that it contains an index if the index was inserted, and was not
+//      removed since.
+unsafe impl<const LANES: usize> IndexView for WideChunk<LANES> {
+    type Index = u16;
+
+    fn is_empty(&self) -> bool {
+        self.0 == Simd::splat(0)
+    }
+
+    fn len(&self) -> usize {
+        self.0.count_ones().reduce_sum() as usize
+    }
+
+    fn contains(&self, index: Self::Index) -> bool {
+        Self::split(index).is_some_and(|(lane, bit)| (self.0[lane] & (1 << bit)) != 0)
+    }
+}
+
+impl<const LANES: usize> IndexViewCached for WideChunk<LANES> {}
+
+impl<const LANES: usize> IndexViewCanonical for WideChunk<LANES> {}
+
+//  #   Safety
+//
+//  -   NoPhantom: the store WILL only ever return that it contains an index if the index was inserted, and was not
+//      removed since.
+unsafe impl<const LANES: usize> IndexViewNot for WideChunk<LANES> {
+    fn len_not(&self) -> usize {
+        Self::BITS as usize - self.len()
+    }
+}
+
+impl<const LANES: usize> IndexCollection for WideChunk<LANES> {
+    fn span() -> (Bound<Self::Index>, Bound<Self::Index>) {
+        const {
+            assert!((Self::BITS - 1) <= (Self::Index::MAX as u32));
+        };
+
+        let upper = if Self::BITS == 0 {
+            Bound::Excluded(0)
+        } else {
+            Bound::Included((Self::BITS - 1) as Self::Index)
+        };
+
+        (Bound::Included(0), upper)
+    }
+
+    fn new() -> Self {
+        Self::new()
+    }
+
+    fn with_span(_: (Bound<Self::Index>, Bound<Self::Index>)) -> Self {
+        Self::new()
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: the store WILL only ever return that it contains an index if the index was inserted, and was not
+//      removed since.
+unsafe impl<const LANES: usize> IndexStore for WideChunk<LANES> {
+    type InsertionError = Never;
+
+    fn clear(&mut self) {
+        self.0 = Simd::splat(0);
+    }
+
+    fn insert(&mut self, index: Self::Index) -> Result<bool, Never> {
+        //  As per `ArrayChunk`, an out-of-bounds index is silently dropped.
+        let Some((lane, bit)) = Self::split(index) else {
+            return Ok(false);
+        };
+
+        let mask = 1 << bit;
+
+        let existed = (self.0[lane] & mask) != 0;
+
+        self.0[lane] |= mask;
+
+        Ok(!existed)
+    }
+
+    fn remove(&mut self, index: Self::Index) -> bool {
+        let Some((lane, bit)) = Self::split(index) else {
+            return false;
+        };
+
+        let mask = 1 << bit;
+
+        let existed = (self.0[lane] & mask) != 0;
+
+        self.0[lane] &= !mask;
+
+        existed
+    }
+
+    fn toggle(&mut self, index: Self::Index) -> Result<bool, Never> {
+        //  As per `insert`, an out-of-bounds index is silently dropped.
+        let Some((lane, bit)) = Self::split(index) else {
+            return Ok(false);
+        };
+
+        let mask = 1 << bit;
+
+        self.0[lane] ^= mask;
+
+        Ok((self.0[lane] & mask) != 0)
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: the store WILL only ever insert indexes within the given range.
+unsafe impl<const LANES: usize> IndexStoreRange for WideChunk<LANES> {
+    fn insert_range<R>(&mut self, range: R) -> Result<usize, Never>
+    where
+        R: RangeBounds<Self::Index>,
+    {
+        let (start, end) = chunk::clamp_range(&range, Self::BITS);
+        let mask = Self::range_mask(start, end);
+
+        let inserted = Self(mask & !self.0).len();
+
+        self.0 |= mask;
+
+        Ok(inserted)
+    }
+
+    fn remove_range<R>(&mut self, range: R) -> usize
+    where
+        R: RangeBounds<Self::Index>,
+    {
+        let (start, end) = chunk::clamp_range(&range, Self::BITS);
+        let mask = Self::range_mask(start, end);
+
+        let removed = Self(mask & self.0).len();
+
+        self.0 &= !mask;
+
+        removed
+    }
+}
+
+//  #   Safety
+//
+//  -   NoTheft: the vault WILL never return that it does not contain an index if the index was inserted, and was not
+//      removed since.
+unsafe impl<const LANES: usize> IndexVault for WideChunk<LANES> {}
+
+//  #   Safety
+//
+//  -   NoDuplicate: the view WILL never return the same index a second time.
+//  -   NoPhantom: the view WILL only ever return that it contains an index if the index was inserted, and was not
+//      removed since.
+//  -   NoTheft: the view WILL return all indexes.
+unsafe impl<const LANES: usize> IndexForward for WideChunk<LANES> {
+    fn first(&self) -> Option<Self::Index> {
+        Self::first_in(self.0)
+    }
+
+    fn next_after(&self, index: Self::Index) -> Option<Self::Index> {
+        let index: u32 = index.into();
+
+        Self::first_in(self.0 & Self::range_mask(index + 1, Self::BITS))
+    }
+}
+
+//  #   Safety
+//
+//  -   NoDuplicate: the view WILL never return the same index a second time.
+//  -   NoPhantom: the view WILL only ever return that it contains an index if the index was inserted, and was not
+//      removed since.
+//  -   NoTheft: the view WILL return all indexes.
+unsafe impl<const LANES: usize> IndexForwardNot for WideChunk<LANES> {
+    fn first_not(&self) -> Option<Self::Index> {
+        Self::first_in(!self.0)
+    }
+
+    fn next_after_not(&self, index: Self::Index) -> Option<Self::Index> {
+        let index: u32 = index.into();
+
+        Self::first_in(!self.0 & Self::range_mask(index + 1, Self::BITS))
+    }
+}
+
+//  #   Safety
+//
+//  -   Reverse: the view WILL return indexes in the exact opposite sequence than `IndexForward` does.
+unsafe impl<const LANES: usize> IndexBackward for WideChunk<LANES> {
+    fn last(&self) -> Option<Self::Index> {
+        Self::last_in(self.0)
+    }
+
+    fn next_before(&self, index: Self::Index) -> Option<Self::Index> {
+        Self::last_in(self.0 & Self::range_mask(0, index.into()))
+    }
+}
+
+//  #   Safety
+//
+//  -   Reverse: the view WILL return indexes in the exact opposite sequence than `IndexForward` does.
+unsafe impl<const LANES: usize> IndexBackwardNot for WideChunk<LANES> {
+    fn last_not(&self) -> Option<Self::Index> {
+        Self::last_in(!self.0)
+    }
+
+    fn next_before_not(&self, index: Self::Index) -> Option<Self::Index> {
+        Self::last_in(!self.0 & Self::range_mask(0, index.into()))
+    }
+}
+
+//  #   Safety
+//
+//  -   Ordered: the `IndexForward` implementation WILL return indexes in strictly increasing order.
+unsafe impl<const LANES: usize> IndexOrdered for WideChunk<LANES> {}
+
+//  #   Safety
+//
+//  -   Rank: the store WILL count the bits strictly below `index`, in all lanes at once.
+unsafe impl<const LANES: usize> IndexOrderedStore for WideChunk<LANES> {
+    fn rank(&self, index: Self::Index) -> usize {
+        (self.0 & Self::range_mask(0, index.into())).count_ones().reduce_sum() as usize
+    }
+}
+
+//  #   Safety
+//
+//  -   Ordered: the `IndexForward` implementation WILL return indexes in strictly increasing order.
+unsafe impl<const LANES: usize> IndexOrderedNot for WideChunk<LANES> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        chunk::{ArrayChunk, UnsignedChunk},
+        test::{IndexTester, IndexTesterLossy, IndexTesterNot},
+    };
+
+    use super::*;
+
+    struct Tester;
+
+    impl IndexTester for Tester {
+        type Index = u16;
+        type Victim = WideChunk<2>;
+
+        fn upper_bound() -> u8 {
+            64 * 2 - 1
+        }
+
+        fn victim(indexes: &[u8]) -> Self::Victim {
+            let mut victim = WideChunk::new();
+
+            for &index in indexes {
+                let _ = victim.insert(index.into());
+            }
+
+            victim
+        }
+
+        fn index(i: u8) -> Self::Index {
+            i.into()
+        }
+    }
+
+    impl IndexTesterNot for Tester {
+        fn capacity() -> usize {
+            Self::upper_bound() as usize + 1
+        }
+
+        fn victim_not(indexes: &[u8]) -> Self::Victim {
+            let mut victim = WideChunk::full();
+
+            for &index in indexes {
+                victim.remove(index.into());
+            }
+
+            victim
+        }
+    }
+
+    //  Indexes beyond the last lane are silently dropped.
+    struct LossyTester;
+
+    impl IndexTesterLossy for LossyTester {
+        type Index = u16;
+        type Victim = WideChunk<2>;
+
+        fn upper_bound() -> u8 {
+            Tester::upper_bound()
+        }
+
+        fn victim(indexes: &[u8]) -> Self::Victim {
+            Tester::victim(indexes)
+        }
+
+        fn index(i: u8) -> Self::Index {
+            i.into()
+        }
+
+        fn lossy_index() -> Option<Self::Index> {
+            Some(64 * 2)
+        }
+    }
+
+    crate::test_index_view!(Tester);
+    crate::test_index_collection!(Tester);
+    crate::test_index_store!(Tester);
+    crate::test_index_store_lossy!(LossyTester);
+    crate::test_index_forward!(Tester);
+    crate::test_index_backward!(Tester);
+    crate::test_index_ordered_store!(Tester);
+
+    crate::test_index_view_not!(Tester);
+    crate::test_index_forward_not!(Tester);
+    crate::test_index_backward_not!(Tester);
+
+    type Wide = WideChunk<4>;
+    type Array = ArrayChunk<UnsignedChunk<u8>, 32>;
+
+    //  Pseudo-random sets of indexes, both sparse and dense, including the boundaries of the lanes.
+    fn samples() -> impl Iterator<Item = (Wide, Array)> {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+
+        (0..16u64).map(move |round| {
+            let (mut wide, mut array) = (Wide::new(), Array::new());
+
+            for index in [0, 63, 64, 127, 128, 191, 192, 255]
+                .into_iter()
+                .take(round as usize % 9)
+            {
+                wide.insert(index).unwrap();
+                array.insert(index).unwrap();
+            }
+
+            for _ in 0..(round * round) {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+
+                let index = (state % 256) as u16;
+
+                wide.insert(index).unwrap();
+                array.insert(index).unwrap();
+            }
+
+            (wide, array)
+        })
+    }
+
+    #[track_caller]
+    fn assert_equivalent(wide: Wide, array: Array) {
+        assert_eq!(array.len(), wide.len());
+        assert_eq!(array.is_empty(), wide.is_empty());
+        assert_eq!(array.is_full(), wide.is_full());
+        assert_eq!(array.len_not(), wide.len_not());
+
+        assert_eq!(array.first(), wide.first());
+        assert_eq!(array.last(), wide.last());
+        assert_eq!(array.first_not(), wide.first_not());
+        assert_eq!(array.last_not(), wide.last_not());
+
+        for index in 0..256 {
+            assert_eq!(array.contains(index), wide.contains(index), "{index}");
+            assert_eq!(array.next_after(index), wide.next_after(index), "{index}");
+            assert_eq!(array.next_before(index), wide.next_before(index), "{index}");
+            assert_eq!(array.next_after_not(index), wide.next_after_not(index), "{index}");
+            assert_eq!(array.next_before_not(index), wide.next_before_not(index), "{index}");
+        }
+    }
+
+    #[test]
+    fn equivalence() {
+        for (wide, array) in samples() {
+            assert_equivalent(wide, array);
+            assert_equivalent(!wide, !array);
+
+            for (other_wide, other_array) in samples() {
+                assert_equivalent(wide & other_wide, array & other_array);
+                assert_equivalent(wide | other_wide, array | other_array);
+                assert_equivalent(wide ^ other_wide, array ^ other_array);
+                assert_equivalent(wide - other_wide, array - other_array);
+            }
+        }
+    }
+
+    #[test]
+    fn full() {
+        let full = Wide::full();
+
+        assert!(full.is_full());
+        assert_eq!(Wide::BITS as usize, full.len());
+        assert_eq!(Wide::new(), !full);
+
+        let mut victim = full;
+        victim.remove(130);
+
+        assert!(!victim.is_full());
+        assert!(!Wide::new().is_full());
+    }
+
+    #[test]
+    fn insert_range() {
+        const INDEXES: [u16; 6] = [0, 7, 63, 64, 200, 255];
+
+        for start in 0..=256 {
+            for end in (start..=256).step_by(7).chain([256]) {
+                let (mut wide, mut array) = (Wide::new(), Array::new());
+
+                for index in INDEXES {
+                    wide.insert(index).unwrap();
+                    array.insert(index).unwrap();
+                }
+
+                assert_eq!(
+                    array.insert_range(start..end),
+                    wide.insert_range(start..end),
+                    "{start}..{end}"
+                );
+                assert_equivalent(wide, array);
+            }
+        }
+    }
+
+    #[test]
+    fn remove_range() {
+        for start in 0..=256 {
+            for end in (start..=256).step_by(7).chain([256]) {
+                let (mut wide, mut array) = (Wide::full(), Array::full());
+
+                assert_eq!(
+                    array.remove_range(start..end),
+                    wide.remove_range(start..end),
+                    "{start}..{end}"
+                );
+                assert_equivalent(wide, array);
+            }
+        }
+    }
+
+    #[test]
+    fn dynamic_chunk_store() {
+        use crate::{set::IndexChunkedSet, vault::DynamicChunkStore};
+
+        const INDEXES: [u32; 6] = [0, 63, 64, 255, 256, 1_000];
+
+        let left: IndexChunkedSet<DynamicChunkStore<Wide, u32>> = INDEXES.into_iter().collect();
+        let right: IndexChunkedSet<DynamicChunkStore<Wide, u32>> = [63, 256, 2_000].into_iter().collect();
+
+        assert_eq!(INDEXES.len(), left.len());
+        assert!(left.iter().eq(INDEXES));
+        assert!(left.iter_rev().eq(INDEXES.into_iter().rev()));
+
+        assert!(left.intersection(&right).eq([63, 256]));
+        assert!(left.union(&right).eq([0, 63, 64, 255, 256, 1_000, 2_000]));
+    }
+} // mod tests
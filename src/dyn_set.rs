@@ -0,0 +1,139 @@
+//! An object-safe adapter over `IndexSet`, for dynamic dispatch.
+//!
+//! The `IndexXxx` traits are not object-safe, hence a set cannot be used as a trait object directly. The `DynIndexSet`
+//! trait bridges the gap, allowing the store backing a set to be selected at run-time.
+
+use core::fmt;
+
+#[cfg(any(feature = "alloc", test))]
+use alloc::boxed::Box;
+
+use crate::{
+    index::{IndexForward, IndexStore},
+    set::IndexSet,
+};
+
+/// An object-safe set of indexes.
+pub trait DynIndexSet<I> {
+    /// Returns whether the set is empty, or not.
+    fn is_empty(&self) -> bool;
+
+    /// Returns the number of indexes in the set.
+    fn len(&self) -> usize;
+
+    /// Returns whether the index is contained in the set.
+    fn contains(&self, index: I) -> bool;
+
+    /// Removes all indexes from the set.
+    fn clear(&mut self);
+
+    /// Inserts the index in the set, returns whether it is newly inserted.
+    ///
+    /// The insertion error, if any, is boxed so as to erase its type.
+    #[cfg(any(feature = "alloc", test))]
+    fn insert_boxed(&mut self, index: I) -> Result<bool, Box<dyn fmt::Debug>>;
+
+    /// Removes the index from the set, returns whether it was in the set prior to removal.
+    fn remove(&mut self, index: I) -> bool;
+
+    /// Returns an iterator over the indexes in the set.
+    #[cfg(any(feature = "alloc", test))]
+    fn iter_boxed(&self) -> Box<dyn Iterator<Item = I> + '_>;
+}
+
+impl<I, S> DynIndexSet<I> for IndexSet<S>
+where
+    I: Copy + 'static,
+    S: IndexForward<Index = I> + IndexStore<Index = I, InsertionError: fmt::Debug + 'static>,
+{
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn contains(&self, index: I) -> bool {
+        self.contains(index)
+    }
+
+    fn clear(&mut self) {
+        self.clear()
+    }
+
+    #[cfg(any(feature = "alloc", test))]
+    fn insert_boxed(&mut self, index: I) -> Result<bool, Box<dyn fmt::Debug>> {
+        self.insert(index).map_err(|e| Box::new(e) as Box<dyn fmt::Debug>)
+    }
+
+    fn remove(&mut self, index: I) -> bool {
+        self.remove(index)
+    }
+
+    #[cfg(any(feature = "alloc", test))]
+    fn iter_boxed(&self) -> Box<dyn Iterator<Item = I> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeSet, vec::Vec};
+
+    use crate::{
+        chunk::{ArrayChunk, UnsignedChunk},
+        vault::DynamicChunkStore,
+    };
+
+    use super::*;
+
+    fn victim(chunked: bool) -> Box<dyn DynIndexSet<u64>> {
+        if chunked {
+            Box::new(IndexSet::<DynamicChunkStore<ArrayChunk<UnsignedChunk<u64>, 2>>>::new())
+        } else {
+            Box::new(IndexSet::<BTreeSet<u64>>::new())
+        }
+    }
+
+    #[test]
+    fn insert_remove() {
+        for chunked in [false, true] {
+            let mut victim = victim(chunked);
+
+            assert!(victim.is_empty());
+
+            assert!(victim.insert_boxed(42).unwrap());
+            assert!(!victim.insert_boxed(42).unwrap());
+
+            assert!(victim.contains(42));
+            assert_eq!(1, victim.len());
+
+            assert!(victim.remove(42));
+            assert!(!victim.remove(42));
+
+            assert!(victim.is_empty());
+        }
+    }
+
+    #[test]
+    fn iter_boxed() {
+        const INDEXES: [u64; 5] = [1, 2, 3, 5, 130];
+
+        for chunked in [false, true] {
+            let mut victim = victim(chunked);
+
+            for index in INDEXES {
+                victim.insert_boxed(index).unwrap();
+            }
+
+            let indexes: Vec<_> = victim.iter_boxed().collect();
+
+            assert_eq!(&INDEXES[..], &indexes[..]);
+
+            victim.clear();
+
+            assert_eq!(0, victim.iter_boxed().count());
+        }
+    }
+} // mod tests
@@ -1,6 +1,10 @@
 //! A collection of traits for index-based vaults.
 
-use core::{fmt, num::NonZeroUsize, ops::Bound};
+use core::{
+    fmt,
+    num::NonZeroUsize,
+    ops::{Bound, RangeBounds},
+};
 
 #[cfg(feature = "nightly")]
 use core::ops::Try;
@@ -28,8 +32,59 @@ pub unsafe trait IndexView {
 
     /// Returns whether the given index is contained in the store.
     fn contains(&self, index: Self::Index) -> bool;
+
+    /// Returns whether all the given indexes are contained in the store.
+    ///
+    /// #   Note to Implementors
+    ///
+    /// Try to implement this method if probing many nearby indexes can be amortized, for example by fetching each
+    /// chunk only once.
+    fn contains_all<I>(&self, indexes: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Index>,
+    {
+        indexes.into_iter().all(|index| self.contains(index))
+    }
+
+    /// Returns whether any of the given indexes is contained in the store.
+    ///
+    /// #   Note to Implementors
+    ///
+    /// Try to implement this method if probing many nearby indexes can be amortized, for example by fetching each
+    /// chunk only once.
+    fn contains_any<I>(&self, indexes: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Index>,
+    {
+        indexes.into_iter().any(|index| self.contains(index))
+    }
 }
 
+/// A view of indexes, which caches its number of indexes.
+///
+/// Implementing this trait signals that `len` and `is_empty` execute in constant time, rather than recounting the
+/// indexes on each call, so that generic algorithms may call them freely, even in loops.
+///
+/// The trait is opt-in: simple views whose count is cheap enough, or not worth caching, need not implement it.
+pub trait IndexViewCached: IndexView {
+    /// Returns the number of indexes in the collection, in constant time.
+    ///
+    /// Defaults to `len`, which implementers guarantee executes in constant time.
+    #[inline(always)]
+    fn cached_len(&self) -> usize {
+        self.len()
+    }
+}
+
+/// A view of indexes, whose representation is canonical.
+///
+/// Implementing this trait signals that two views compare equal, as per `Eq`, if and only if they contain the same
+/// indexes, so that generic algorithms may compare the views directly, rather than iterating over their indexes.
+///
+/// Views whose representation depends on their history, such as stores keeping trailing empty chunks around, or hash
+/// tables, should not implement this trait, even if their `Eq` implementation is correct: there is nothing to gain.
+pub trait IndexViewCanonical: IndexView + Eq {}
+
 /// A collection of indexes.
 pub trait IndexCollection: IndexView {
     /// Returns the span of index values which MAY be inserted.
@@ -46,6 +101,20 @@ pub trait IndexCollection: IndexView {
     ///
     /// Implementers should attempt to pre-reserve the necessary space for the given span, if possible.
     fn with_span(range: (Bound<Self::Index>, Bound<Self::Index>)) -> Self;
+
+    /// Reserves the appropriate capacity for storing indexes within the given span, if possible.
+    ///
+    /// By default, does nothing, as is appropriate for collections which cannot reserve.
+    fn reserve(&mut self, range: (Bound<Self::Index>, Bound<Self::Index>)) {
+        let _ = range;
+    }
+
+    /// Returns the span of index values which MAY be inserted without further memory allocation.
+    ///
+    /// By default, returns `Self::span()`, as is appropriate for collections which cannot reserve.
+    fn capacity_span(&self) -> (Bound<Self::Index>, Bound<Self::Index>) {
+        Self::span()
+    }
 }
 
 /// A store of indexes.
@@ -67,6 +136,86 @@ pub unsafe trait IndexStore: IndexView {
 
     /// Removes the index from the store, returns whether it was in the store prior to removal.
     fn remove(&mut self, index: Self::Index) -> bool;
+
+    /// Toggles the index in the store, returns whether it is in the store after toggling.
+    ///
+    /// May return an error if the insertion fails, see `insert`.
+    ///
+    /// #   Note to Implementors
+    ///
+    /// Try to implement this method if it can be implemented without probing the store twice.
+    fn toggle(&mut self, index: Self::Index) -> Result<bool, Self::InsertionError> {
+        if self.remove(index) {
+            return Ok(false);
+        }
+
+        //  A store silently dropping the index returns `Ok(false)`, as the index is not in the store.
+        self.insert(index)
+    }
+
+    /// Calls `f` with whether the index is in the store, then inserts or removes the index so that it is in the store
+    /// if and only if `f` returned `true`, returns whether the store changed.
+    ///
+    /// May return an error if the insertion fails, see `insert`.
+    ///
+    /// #   Note to Implementors
+    ///
+    /// Try to implement this method if it can be implemented without probing the store twice.
+    fn replace_with<F>(&mut self, index: Self::Index, f: F) -> Result<bool, Self::InsertionError>
+    where
+        F: FnOnce(bool) -> bool,
+    {
+        let contained = self.contains(index);
+
+        match (contained, f(contained)) {
+            (false, true) => self.insert(index),
+            (true, false) => Ok(self.remove(index)),
+            _ => Ok(false),
+        }
+    }
+
+    /// Inserts all the indexes of `other` in the store, returns the number of newly inserted indexes.
+    ///
+    /// On error, the indexes of `other` preceding the failing index have been inserted.
+    ///
+    /// For chunked stores, see `IndexStoreChunked::insert_all_chunks`, which operates a whole chunk at a time.
+    fn insert_all<OS>(&mut self, other: &OS) -> Result<usize, Self::InsertionError>
+    where
+        OS: IndexForward<Index = Self::Index>,
+    {
+        let mut inserted = 0;
+        let mut current = other.first();
+
+        while let Some(index) = current {
+            inserted += usize::from(self.insert(index)?);
+
+            current = other.next_after(index);
+        }
+
+        Ok(inserted)
+    }
+}
+
+/// A store of indexes, able to insert or remove whole ranges of indexes at once.
+///
+/// There is no blanket implementation in terms of `IndexStore::insert` and `IndexStore::remove`, as indexes cannot, in
+/// general, be stepped over.
+///
+/// #   Safety
+///
+/// -   NoPhantom: the store SHALL only ever insert indexes within the given range.
+pub unsafe trait IndexStoreRange: IndexStore {
+    /// Inserts all indexes within `range` in the store, returns the number of newly inserted indexes.
+    ///
+    /// May return an error if the insertion fails, or _panic_ or _abort_. Check the implementation documentation.
+    fn insert_range<R>(&mut self, range: R) -> Result<usize, Self::InsertionError>
+    where
+        R: RangeBounds<Self::Index>;
+
+    /// Removes all indexes within `range` from the store, returns the number of indexes removed.
+    fn remove_range<R>(&mut self, range: R) -> usize
+    where
+        R: RangeBounds<Self::Index>;
 }
 
 /// A trustworthy vault of indexes.
@@ -96,6 +245,10 @@ pub unsafe trait IndexForward: IndexView {
     fn first(&self) -> Option<Self::Index>;
 
     /// Returns the next index after the provided one, if any.
+    ///
+    /// `current` need not be contained: for an `IndexOrdered` view, this is then the smallest index greater than
+    /// `current`, otherwise the result is unspecified. Hence `retain` and co. fetch the next index prior to removing
+    /// the current one.
     fn next_after(&self, current: Self::Index) -> Option<Self::Index>;
 
     /// Returns the n-th index after the provided one, or the remainder of `n`.
@@ -115,6 +268,23 @@ pub unsafe trait IndexForward: IndexView {
         self.next_after(current).ok_or(NonZeroUsize::MIN)
     }
 
+    /// Returns the first index which is not before `from`, if any.
+    ///
+    /// For an `IndexOrdered` view, this is the smallest index greater than or equal to `from`.
+    ///
+    /// #   Note to Implementors
+    ///
+    /// Try to implement this method if it can seek `from`, rather than advance one at a time from the first index.
+    fn first_at(&self, from: Self::Index) -> Option<Self::Index> {
+        let mut current = self.first()?;
+
+        while current < from {
+            current = self.next_after(current)?;
+        }
+
+        Some(current)
+    }
+
     /// Applies the function `f` as long as it returns successfully, producing a single, final value.
     ///
     /// #   Note to Implementors
@@ -169,6 +339,31 @@ pub unsafe trait IndexBackward: IndexForward {
         self.next_before(current).ok_or(NonZeroUsize::MIN)
     }
 
+    /// Returns the last index which is not after `to`, if any.
+    ///
+    /// For an `IndexOrdered` view, this is the greatest index less than or equal to `to`.
+    ///
+    /// #   Note to Implementors
+    ///
+    /// Try to implement this method if it can seek `to`, rather than advance one at a time from the last index.
+    fn last_at(&self, to: Self::Index) -> Option<Self::Index> {
+        let mut current = self.last()?;
+
+        while current > to {
+            current = self.next_before(current)?;
+        }
+
+        Some(current)
+    }
+
+    /// Returns the first and last indexes of the store, if any.
+    ///
+    /// For an `IndexOrdered` view, this is the range of indexes actually occupied, as opposed to `span`, the range of
+    /// indexes which may be inserted.
+    fn bounds(&self) -> Option<(Self::Index, Self::Index)> {
+        Some((self.first()?, self.last()?))
+    }
+
     /// Applies the function `f` as long as it returns successfully, producing a single, final value.
     ///
     /// #   Note to Implementors
@@ -197,8 +392,49 @@ pub unsafe trait IndexBackward: IndexForward {
 /// #   Safety
 ///
 /// -   Ordered: the `IndexForward` implementation SHALL return indexes in strictly increasing order.
+/// -   Seek: `next_after(current)` SHALL return the smallest index greater than `current`, whether `current` is
+///     contained or not.
 pub unsafe trait IndexOrdered: IndexForward {}
 
+/// An _ordered_ store of indexes, able to tell the rank of an index.
+///
+/// The rank of an index is the number of indexes of the store strictly less than it, which is notably the position at
+/// which its value lives in a dense, rank-compacted, array of values.
+///
+/// #   Safety
+///
+/// -   Rank: `rank(index)` SHALL return the number of indexes of the store strictly less than `index`, whether
+///     `index` is contained or not.
+pub unsafe trait IndexOrderedStore: IndexOrdered + IndexStore {
+    /// Returns the number of indexes of the store strictly less than `index`.
+    ///
+    /// #   Note to Implementors
+    ///
+    /// Try to implement this method if it can be implemented without iterating over the indexes one at a time, for
+    /// example by counting the bits of a chunk.
+    fn rank(&self, index: Self::Index) -> usize {
+        let mut rank = 0;
+        let mut current = self.first();
+
+        while let Some(i) = current.filter(|&i| i < index) {
+            rank += 1;
+            current = self.next_after(i);
+        }
+
+        rank
+    }
+
+    /// Inserts the index in the store, returns whether it is newly inserted, and its rank.
+    ///
+    /// May return an error if the insertion fails, see `IndexStore::insert`. A store silently dropping the index
+    /// returns the rank the index would have had.
+    fn insert_ranked(&mut self, index: Self::Index) -> Result<(bool, usize), Self::InsertionError> {
+        let inserted = self.insert(index)?;
+
+        Ok((inserted, self.rank(index)))
+    }
+}
+
 /// A chunked _view_ of the indexes in the store.
 ///
 /// #   Safety
@@ -229,6 +465,16 @@ pub unsafe trait IndexViewChunked: IndexView {
     ///
     /// The chunk is _purposefully_ returned by value to allow implementations to materialize it on the fly.
     fn get_chunk(&self, index: Self::ChunkIndex) -> Option<Self::Chunk>;
+
+    /// Returns the chunk containing the index, if the index is contained.
+    ///
+    /// Equivalent to `contains`, while handing out the chunk for further queries, without a second `split` and
+    /// `get_chunk`.
+    fn contains_with_chunk(&self, index: Self::Index) -> Option<Self::Chunk> {
+        let (outer, inner) = Self::split(index);
+
+        self.get_chunk(outer).filter(|chunk| chunk.contains(inner))
+    }
 }
 
 /// A store of indexes.
@@ -246,6 +492,41 @@ pub unsafe trait IndexStoreChunked: IndexViewChunked {
     ///
     /// Implementers are encouraged to make the operation atomic.
     fn set_chunk(&mut self, index: Self::ChunkIndex, chunk: Self::Chunk) -> Result<(), Self::SetError>;
+
+    /// Inserts all the indexes of `other` in the store, returns the number of newly inserted indexes.
+    ///
+    /// Operates a whole chunk at a time, only setting the chunks which gain at least one index.
+    ///
+    /// On error, the chunks of `other` preceding the failing chunk have been inserted.
+    fn insert_all_chunks<OS>(&mut self, other: &OS) -> Result<usize, Self::SetError>
+    where
+        OS: IndexForwardChunked<Index = Self::Index, ChunkIndex = Self::ChunkIndex, Chunk = Self::Chunk>,
+    {
+        let mut inserted = 0;
+        let mut current = other.first_chunk();
+
+        while let Some(outer) = current {
+            current = other.next_chunk_after(outer);
+
+            let Some(other) = other.get_chunk(outer) else { continue };
+
+            if other.is_empty() {
+                continue;
+            }
+
+            let chunk = self.get_chunk(outer).unwrap_or_default();
+
+            let new = chunk | other;
+
+            if new != chunk {
+                self.set_chunk(outer, new)?;
+
+                inserted += new.len() - chunk.len();
+            }
+        }
+
+        Ok(inserted)
+    }
 }
 
 /// An iterable _chunked_ view of the indexes in the store.
@@ -266,6 +547,25 @@ pub unsafe trait IndexForwardChunked: IndexViewChunked {
 
     /// Returns the next index after the provided one, if any.
     fn next_chunk_after(&self, current: Self::ChunkIndex) -> Option<Self::ChunkIndex>;
+
+    /// Returns the number of chunks holding at least one index.
+    ///
+    /// Unlike `len`, which counts indexes, this counts chunks, which is useful to gauge how sparse a store is.
+    ///
+    /// #   Note to Implementors
+    ///
+    /// Try to implement this method if the chunks can be scanned without going through `get_chunk`.
+    fn populated_chunks(&self) -> usize {
+        let mut count = 0;
+        let mut cursor = self.first_chunk();
+
+        while let Some(outer) = cursor {
+            count += usize::from(self.get_chunk(outer).is_some_and(|chunk| !chunk.is_empty()));
+            cursor = self.next_chunk_after(outer);
+        }
+
+        count
+    }
 }
 
 /// An iterable _chunked_ view of the indexes in the store.
@@ -319,6 +619,22 @@ where
     fn contains(&self, index: Self::Index) -> bool {
         (**self).contains(index)
     }
+
+    #[inline(always)]
+    fn contains_all<I>(&self, indexes: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Index>,
+    {
+        (**self).contains_all(indexes)
+    }
+
+    #[inline(always)]
+    fn contains_any<I>(&self, indexes: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Index>,
+    {
+        (**self).contains_any(indexes)
+    }
 }
 
 //  #   Safety
@@ -344,6 +660,42 @@ where
     fn contains(&self, index: Self::Index) -> bool {
         (**self).contains(index)
     }
+
+    #[inline(always)]
+    fn contains_all<I>(&self, indexes: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Index>,
+    {
+        (**self).contains_all(indexes)
+    }
+
+    #[inline(always)]
+    fn contains_any<I>(&self, indexes: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Index>,
+    {
+        (**self).contains_any(indexes)
+    }
+}
+
+impl<T> IndexViewCached for &T
+where
+    T: IndexViewCached,
+{
+    #[inline(always)]
+    fn cached_len(&self) -> usize {
+        (**self).cached_len()
+    }
+}
+
+impl<T> IndexViewCached for &mut T
+where
+    T: IndexViewCached,
+{
+    #[inline(always)]
+    fn cached_len(&self) -> usize {
+        (**self).cached_len()
+    }
 }
 
 //  #   Safety
@@ -369,6 +721,51 @@ where
     fn remove(&mut self, index: Self::Index) -> bool {
         (**self).remove(index)
     }
+
+    #[inline(always)]
+    fn toggle(&mut self, index: Self::Index) -> Result<bool, Self::InsertionError> {
+        (**self).toggle(index)
+    }
+
+    #[inline(always)]
+    fn replace_with<F>(&mut self, index: Self::Index, f: F) -> Result<bool, Self::InsertionError>
+    where
+        F: FnOnce(bool) -> bool,
+    {
+        (**self).replace_with(index, f)
+    }
+
+    #[inline(always)]
+    fn insert_all<OS>(&mut self, other: &OS) -> Result<usize, Self::InsertionError>
+    where
+        OS: IndexForward<Index = Self::Index>,
+    {
+        (**self).insert_all(other)
+    }
+}
+
+//  #   Safety
+//
+//  -   As per T.
+unsafe impl<T> IndexStoreRange for &mut T
+where
+    T: IndexStoreRange,
+{
+    #[inline(always)]
+    fn insert_range<R>(&mut self, range: R) -> Result<usize, Self::InsertionError>
+    where
+        R: RangeBounds<Self::Index>,
+    {
+        (**self).insert_range(range)
+    }
+
+    #[inline(always)]
+    fn remove_range<R>(&mut self, range: R) -> usize
+    where
+        R: RangeBounds<Self::Index>,
+    {
+        (**self).remove_range(range)
+    }
 }
 
 //  #   Safety
@@ -403,6 +800,11 @@ where
         (**self).nth_after(n, current)
     }
 
+    #[inline(always)]
+    fn first_at(&self, from: Self::Index) -> Option<Self::Index> {
+        (**self).first_at(from)
+    }
+
     #[cfg(feature = "nightly")]
     #[inline(always)]
     fn try_fold_after<B, F, R>(&self, current: Self::Index, accumulator: B, f: F) -> R
@@ -436,6 +838,11 @@ where
         (**self).nth_after(n, current)
     }
 
+    #[inline(always)]
+    fn first_at(&self, from: Self::Index) -> Option<Self::Index> {
+        (**self).first_at(from)
+    }
+
     #[cfg(feature = "nightly")]
     #[inline(always)]
     fn try_fold_after<B, F, R>(&self, current: Self::Index, accumulator: B, f: F) -> R
@@ -469,6 +876,16 @@ where
         (**self).nth_before(n, current)
     }
 
+    #[inline(always)]
+    fn last_at(&self, to: Self::Index) -> Option<Self::Index> {
+        (**self).last_at(to)
+    }
+
+    #[inline(always)]
+    fn bounds(&self) -> Option<(Self::Index, Self::Index)> {
+        (**self).bounds()
+    }
+
     #[cfg(feature = "nightly")]
     #[inline(always)]
     fn try_fold_before<B, F, R>(&self, current: Self::Index, accumulator: B, f: F) -> R
@@ -502,6 +919,16 @@ where
         (**self).nth_before(n, current)
     }
 
+    #[inline(always)]
+    fn last_at(&self, to: Self::Index) -> Option<Self::Index> {
+        (**self).last_at(to)
+    }
+
+    #[inline(always)]
+    fn bounds(&self) -> Option<(Self::Index, Self::Index)> {
+        (**self).bounds()
+    }
+
     #[cfg(feature = "nightly")]
     #[inline(always)]
     fn try_fold_before<B, F, R>(&self, current: Self::Index, accumulator: B, f: F) -> R
@@ -523,6 +950,24 @@ unsafe impl<T> IndexOrdered for &T where T: IndexOrdered {}
 //  -   As per T.
 unsafe impl<T> IndexOrdered for &mut T where T: IndexOrdered {}
 
+//  #   Safety
+//
+//  -   As per T.
+unsafe impl<T> IndexOrderedStore for &mut T
+where
+    T: IndexOrderedStore,
+{
+    #[inline(always)]
+    fn rank(&self, index: Self::Index) -> usize {
+        (**self).rank(index)
+    }
+
+    #[inline(always)]
+    fn insert_ranked(&mut self, index: Self::Index) -> Result<(bool, usize), Self::InsertionError> {
+        (**self).insert_ranked(index)
+    }
+}
+
 //  #   Safety
 //
 //  -   As per T.
@@ -548,6 +993,11 @@ where
     fn get_chunk(&self, index: Self::ChunkIndex) -> Option<Self::Chunk> {
         (**self).get_chunk(index)
     }
+
+    #[inline(always)]
+    fn contains_with_chunk(&self, index: Self::Index) -> Option<Self::Chunk> {
+        (**self).contains_with_chunk(index)
+    }
 }
 
 //  #   Safety
@@ -575,6 +1025,11 @@ where
     fn get_chunk(&self, index: Self::ChunkIndex) -> Option<Self::Chunk> {
         (**self).get_chunk(index)
     }
+
+    #[inline(always)]
+    fn contains_with_chunk(&self, index: Self::Index) -> Option<Self::Chunk> {
+        (**self).contains_with_chunk(index)
+    }
 }
 
 //  #   Safety
@@ -590,6 +1045,14 @@ where
     fn set_chunk(&mut self, index: Self::ChunkIndex, chunk: Self::Chunk) -> Result<(), Self::SetError> {
         (**self).set_chunk(index, chunk)
     }
+
+    #[inline(always)]
+    fn insert_all_chunks<OS>(&mut self, other: &OS) -> Result<usize, Self::SetError>
+    where
+        OS: IndexForwardChunked<Index = Self::Index, ChunkIndex = Self::ChunkIndex, Chunk = Self::Chunk>,
+    {
+        (**self).insert_all_chunks(other)
+    }
 }
 
 //  #   Safety
@@ -608,6 +1071,11 @@ where
     fn next_chunk_after(&self, current: Self::ChunkIndex) -> Option<Self::ChunkIndex> {
         (**self).next_chunk_after(current)
     }
+
+    #[inline(always)]
+    fn populated_chunks(&self) -> usize {
+        (**self).populated_chunks()
+    }
 }
 
 //  #   Safety
@@ -626,6 +1094,11 @@ where
     fn next_chunk_after(&self, current: Self::ChunkIndex) -> Option<Self::ChunkIndex> {
         (**self).next_chunk_after(current)
     }
+
+    #[inline(always)]
+    fn populated_chunks(&self) -> usize {
+        (**self).populated_chunks()
+    }
 }
 
 //  #   Safety
@@ -10,11 +10,13 @@
 //  Attributes.
 #![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
 //  Features (library).
+#![cfg_attr(feature = "nightly", feature(allocator_api))]
 #![cfg_attr(feature = "nightly", feature(exact_size_is_empty))]
 #![cfg_attr(feature = "nightly", feature(extend_one))]
 #![cfg_attr(feature = "nightly", feature(iter_advance_by))]
 #![cfg_attr(feature = "nightly", feature(likely_unlikely))]
 #![cfg_attr(feature = "nightly", feature(never_type))]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 #![cfg_attr(feature = "nightly", feature(try_trait_v2))]
 //  Lints.
 #![deny(missing_docs)]
@@ -23,7 +25,13 @@
 extern crate alloc;
 
 pub mod chunk;
+pub mod dyn_set;
 pub mod index;
+pub mod lossy;
+
+#[cfg(any(feature = "alloc", test))]
+pub mod map;
+
 pub mod not;
 pub mod set;
 pub mod vault;
@@ -0,0 +1,627 @@
+//! Adapter over an IndexVault.
+
+use core::{
+    num::NonZeroUsize,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{
+    index::{
+        IndexBackward, IndexBackwardChunked, IndexCollection, IndexForward, IndexForwardChunked, IndexOrdered,
+        IndexOrderedChunked, IndexOrderedStore, IndexStore, IndexStoreChunked, IndexStoreRange, IndexView,
+        IndexViewCached, IndexViewChunked,
+    },
+    not::{
+        IndexBackwardChunkedNot, IndexBackwardNot, IndexForwardChunkedNot, IndexForwardNot, IndexOrderedChunkedNot,
+        IndexOrderedNot, IndexViewNot,
+    },
+};
+
+#[cfg(feature = "nightly")]
+use core::ops::Try;
+
+/// Adapts an `IndexVault` so as to downgrade it to a mere store.
+///
+/// All index traits are forwarded to the underlying view, with the notable exception of `IndexVault`. This is mostly
+/// useful to exercise the code paths dedicated to stores which are not vaults, with a well-behaved store.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct LossyView<S>(S);
+
+//
+//  Construction
+//
+
+impl<S> LossyView<S> {
+    /// Creates a new instance.
+    #[inline(always)]
+    pub fn new(view: S) -> Self {
+        Self(view)
+    }
+}
+
+//
+//  Deconstruction
+//
+
+impl<S> LossyView<S> {
+    /// Returns a reference to the view.
+    #[inline(always)]
+    pub fn as_view(&self) -> &S {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the view.
+    #[inline(always)]
+    pub fn as_view_mut(&mut self) -> &mut S {
+        &mut self.0
+    }
+
+    /// Returns the view.
+    #[inline(always)]
+    pub fn into_view(self) -> S {
+        self.0
+    }
+}
+
+//
+//  Index trait implementations
+//
+
+//  #   Safety
+//
+//  -   NoPhantom: inherited.
+unsafe impl<S> IndexView for LossyView<S>
+where
+    S: IndexView,
+{
+    type Index = S::Index;
+
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline(always)]
+    fn contains(&self, index: Self::Index) -> bool {
+        self.0.contains(index)
+    }
+
+    #[inline(always)]
+    fn contains_all<I>(&self, indexes: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Index>,
+    {
+        self.0.contains_all(indexes)
+    }
+
+    #[inline(always)]
+    fn contains_any<I>(&self, indexes: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Index>,
+    {
+        self.0.contains_any(indexes)
+    }
+}
+
+impl<S> IndexViewCached for LossyView<S>
+where
+    S: IndexViewCached,
+{
+    #[inline(always)]
+    fn cached_len(&self) -> usize {
+        self.0.cached_len()
+    }
+}
+
+impl<S> IndexCollection for LossyView<S>
+where
+    S: IndexCollection,
+{
+    #[inline(always)]
+    fn span() -> (Bound<Self::Index>, Bound<Self::Index>) {
+        S::span()
+    }
+
+    #[inline(always)]
+    fn new() -> Self {
+        Self(S::new())
+    }
+
+    #[inline(always)]
+    fn with_span(range: (Bound<Self::Index>, Bound<Self::Index>)) -> Self {
+        Self(S::with_span(range))
+    }
+
+    #[inline(always)]
+    fn reserve(&mut self, range: (Bound<Self::Index>, Bound<Self::Index>)) {
+        self.0.reserve(range);
+    }
+
+    #[inline(always)]
+    fn capacity_span(&self) -> (Bound<Self::Index>, Bound<Self::Index>) {
+        self.0.capacity_span()
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: inherited.
+unsafe impl<S> IndexStore for LossyView<S>
+where
+    S: IndexStore,
+{
+    type InsertionError = S::InsertionError;
+
+    #[inline(always)]
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    #[inline(always)]
+    fn insert(&mut self, index: Self::Index) -> Result<bool, Self::InsertionError> {
+        self.0.insert(index)
+    }
+
+    #[inline(always)]
+    fn remove(&mut self, index: Self::Index) -> bool {
+        self.0.remove(index)
+    }
+
+    #[inline(always)]
+    fn toggle(&mut self, index: Self::Index) -> Result<bool, Self::InsertionError> {
+        self.0.toggle(index)
+    }
+
+    #[inline(always)]
+    fn replace_with<F>(&mut self, index: Self::Index, f: F) -> Result<bool, Self::InsertionError>
+    where
+        F: FnOnce(bool) -> bool,
+    {
+        self.0.replace_with(index, f)
+    }
+
+    #[inline(always)]
+    fn insert_all<OS>(&mut self, other: &OS) -> Result<usize, Self::InsertionError>
+    where
+        OS: IndexForward<Index = Self::Index>,
+    {
+        self.0.insert_all(other)
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: inherited.
+unsafe impl<S> IndexStoreRange for LossyView<S>
+where
+    S: IndexStoreRange,
+{
+    #[inline(always)]
+    fn insert_range<R>(&mut self, range: R) -> Result<usize, Self::InsertionError>
+    where
+        R: RangeBounds<Self::Index>,
+    {
+        self.0.insert_range(range)
+    }
+
+    #[inline(always)]
+    fn remove_range<R>(&mut self, range: R) -> usize
+    where
+        R: RangeBounds<Self::Index>,
+    {
+        self.0.remove_range(range)
+    }
+}
+
+//  #   Safety
+//
+//  -   NoDuplicate: inherited.
+//  -   NoPhantom: inherited.
+//  -   NoTheft: trivially satisfied, as `Self` never implements `IndexVault`.
+unsafe impl<S> IndexForward for LossyView<S>
+where
+    S: IndexForward,
+{
+    #[inline(always)]
+    fn first(&self) -> Option<Self::Index> {
+        self.0.first()
+    }
+
+    #[inline(always)]
+    fn next_after(&self, current: Self::Index) -> Option<Self::Index> {
+        self.0.next_after(current)
+    }
+
+    #[inline(always)]
+    fn nth_after(&self, n: usize, current: Self::Index) -> Result<Self::Index, NonZeroUsize> {
+        self.0.nth_after(n, current)
+    }
+
+    #[inline(always)]
+    fn first_at(&self, from: Self::Index) -> Option<Self::Index> {
+        self.0.first_at(from)
+    }
+
+    #[cfg(feature = "nightly")]
+    #[inline(always)]
+    fn try_fold_after<B, F, R>(&self, current: Self::Index, accumulator: B, f: F) -> R
+    where
+        F: FnMut(B, Self::Index) -> R,
+        R: Try<Output = B>,
+    {
+        self.0.try_fold_after(current, accumulator, f)
+    }
+}
+
+//  #   Safety
+//
+//  -   Reverse: inherited.
+unsafe impl<S> IndexBackward for LossyView<S>
+where
+    S: IndexBackward,
+{
+    #[inline(always)]
+    fn last(&self) -> Option<Self::Index> {
+        self.0.last()
+    }
+
+    #[inline(always)]
+    fn next_before(&self, current: Self::Index) -> Option<Self::Index> {
+        self.0.next_before(current)
+    }
+
+    #[inline(always)]
+    fn nth_before(&self, n: usize, current: Self::Index) -> Result<Self::Index, NonZeroUsize> {
+        self.0.nth_before(n, current)
+    }
+
+    #[inline(always)]
+    fn last_at(&self, to: Self::Index) -> Option<Self::Index> {
+        self.0.last_at(to)
+    }
+
+    #[inline(always)]
+    fn bounds(&self) -> Option<(Self::Index, Self::Index)> {
+        self.0.bounds()
+    }
+
+    #[cfg(feature = "nightly")]
+    #[inline(always)]
+    fn try_fold_before<B, F, R>(&self, current: Self::Index, accumulator: B, f: F) -> R
+    where
+        F: FnMut(B, Self::Index) -> R,
+        R: Try<Output = B>,
+    {
+        self.0.try_fold_before(current, accumulator, f)
+    }
+}
+
+//  #   Safety
+//
+//  -   Ordered: inherited.
+unsafe impl<S> IndexOrdered for LossyView<S> where S: IndexOrdered {}
+
+//  #   Safety
+//
+//  -   Rank: inherited.
+unsafe impl<S> IndexOrderedStore for LossyView<S>
+where
+    S: IndexOrderedStore,
+{
+    #[inline(always)]
+    fn rank(&self, index: Self::Index) -> usize {
+        self.0.rank(index)
+    }
+
+    #[inline(always)]
+    fn insert_ranked(&mut self, index: Self::Index) -> Result<(bool, usize), Self::InsertionError> {
+        self.0.insert_ranked(index)
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: inherited.
+//  -   SplitFuse: inherited.
+//  -   TwoLevels: inherited.
+unsafe impl<S> IndexViewChunked for LossyView<S>
+where
+    S: IndexViewChunked,
+{
+    type ChunkIndex = S::ChunkIndex;
+    type Chunk = S::Chunk;
+
+    #[inline(always)]
+    fn fuse(outer: Self::ChunkIndex, inner: <Self::Chunk as IndexView>::Index) -> Self::Index {
+        S::fuse(outer, inner)
+    }
+
+    #[inline(always)]
+    fn split(index: Self::Index) -> (Self::ChunkIndex, <Self::Chunk as IndexView>::Index) {
+        S::split(index)
+    }
+
+    #[inline(always)]
+    fn get_chunk(&self, index: Self::ChunkIndex) -> Option<Self::Chunk> {
+        self.0.get_chunk(index)
+    }
+
+    #[inline(always)]
+    fn contains_with_chunk(&self, index: Self::Index) -> Option<Self::Chunk> {
+        self.0.contains_with_chunk(index)
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: inherited.
+unsafe impl<S> IndexStoreChunked for LossyView<S>
+where
+    S: IndexStoreChunked,
+{
+    type SetError = S::SetError;
+
+    #[inline(always)]
+    fn set_chunk(&mut self, index: Self::ChunkIndex, chunk: Self::Chunk) -> Result<(), Self::SetError> {
+        self.0.set_chunk(index, chunk)
+    }
+
+    #[inline(always)]
+    fn insert_all_chunks<OS>(&mut self, other: &OS) -> Result<usize, Self::SetError>
+    where
+        OS: IndexForwardChunked<Index = Self::Index, ChunkIndex = Self::ChunkIndex, Chunk = Self::Chunk>,
+    {
+        self.0.insert_all_chunks(other)
+    }
+}
+
+//  #   Safety
+//
+//  -   NoDuplicate: inherited.
+//  -   NoPhantom: inherited.
+//  -   NoTheft: trivially satisfied, as `Self` never implements `IndexVault`.
+unsafe impl<S> IndexForwardChunked for LossyView<S>
+where
+    S: IndexForwardChunked,
+{
+    #[inline(always)]
+    fn first_chunk(&self) -> Option<Self::ChunkIndex> {
+        self.0.first_chunk()
+    }
+
+    #[inline(always)]
+    fn next_chunk_after(&self, current: Self::ChunkIndex) -> Option<Self::ChunkIndex> {
+        self.0.next_chunk_after(current)
+    }
+
+    #[inline(always)]
+    fn populated_chunks(&self) -> usize {
+        self.0.populated_chunks()
+    }
+}
+
+//  #   Safety
+//
+//  -   Reverse: inherited.
+unsafe impl<S> IndexBackwardChunked for LossyView<S>
+where
+    S: IndexBackwardChunked,
+{
+    #[inline(always)]
+    fn last_chunk(&self) -> Option<Self::ChunkIndex> {
+        self.0.last_chunk()
+    }
+
+    #[inline(always)]
+    fn next_chunk_before(&self, index: Self::ChunkIndex) -> Option<Self::ChunkIndex> {
+        self.0.next_chunk_before(index)
+    }
+}
+
+//  #   Safety
+//
+//  -   Ordered: inherited.
+unsafe impl<S> IndexOrderedChunked for LossyView<S> where S: IndexOrderedChunked {}
+
+//
+//  Not trait implementations
+//
+
+//  #   Safety
+//
+//  -   NoPhantom: inherited.
+unsafe impl<S> IndexViewNot for LossyView<S>
+where
+    S: IndexViewNot,
+{
+    #[inline(always)]
+    fn len_not(&self) -> usize {
+        self.0.len_not()
+    }
+}
+
+//  #   Safety
+//
+//  -   NoDuplicate: inherited.
+//  -   NoPhantom: inherited.
+//  -   NoTheft: trivially satisfied, as `Self` never implements `IndexVault`.
+unsafe impl<S> IndexForwardNot for LossyView<S>
+where
+    S: IndexForwardNot,
+{
+    #[inline(always)]
+    fn first_not(&self) -> Option<Self::Index> {
+        self.0.first_not()
+    }
+
+    #[inline(always)]
+    fn next_after_not(&self, current: Self::Index) -> Option<Self::Index> {
+        self.0.next_after_not(current)
+    }
+
+    #[inline(always)]
+    fn nth_after_not(&self, n: usize, current: Self::Index) -> Result<Self::Index, NonZeroUsize> {
+        self.0.nth_after_not(n, current)
+    }
+
+    #[cfg(feature = "nightly")]
+    #[inline(always)]
+    fn try_fold_after_not<B, F, R>(&self, current: Self::Index, accumulator: B, f: F) -> R
+    where
+        F: FnMut(B, Self::Index) -> R,
+        R: Try<Output = B>,
+    {
+        self.0.try_fold_after_not(current, accumulator, f)
+    }
+}
+
+//  #   Safety
+//
+//  -   Reverse: inherited.
+unsafe impl<S> IndexBackwardNot for LossyView<S>
+where
+    S: IndexBackwardNot,
+{
+    #[inline(always)]
+    fn last_not(&self) -> Option<Self::Index> {
+        self.0.last_not()
+    }
+
+    #[inline(always)]
+    fn next_before_not(&self, current: Self::Index) -> Option<Self::Index> {
+        self.0.next_before_not(current)
+    }
+
+    #[inline(always)]
+    fn nth_before_not(&self, n: usize, current: Self::Index) -> Result<Self::Index, NonZeroUsize> {
+        self.0.nth_before_not(n, current)
+    }
+
+    #[cfg(feature = "nightly")]
+    #[inline(always)]
+    fn try_fold_before_not<B, F, R>(&self, current: Self::Index, accumulator: B, f: F) -> R
+    where
+        F: FnMut(B, Self::Index) -> R,
+        R: Try<Output = B>,
+    {
+        self.0.try_fold_before_not(current, accumulator, f)
+    }
+}
+
+//  #   Safety
+//
+//  -   Ordered: inherited.
+unsafe impl<S> IndexOrderedNot for LossyView<S> where S: IndexOrderedNot {}
+
+//  #   Safety
+//
+//  -   NoDuplicate: inherited.
+//  -   NoPhantom: inherited.
+//  -   NoTheft: trivially satisfied, as `Self` never implements `IndexVault`.
+unsafe impl<S> IndexForwardChunkedNot for LossyView<S>
+where
+    S: IndexForwardChunkedNot,
+{
+    #[inline(always)]
+    fn first_chunk_not(&self) -> Option<Self::ChunkIndex> {
+        self.0.first_chunk_not()
+    }
+
+    #[inline(always)]
+    fn next_chunk_after_not(&self, current: Self::ChunkIndex) -> Option<Self::ChunkIndex> {
+        self.0.next_chunk_after_not(current)
+    }
+}
+
+//  #   Safety
+//
+//  -   Reverse: inherited.
+unsafe impl<S> IndexBackwardChunkedNot for LossyView<S>
+where
+    S: IndexBackwardChunkedNot,
+{
+    #[inline(always)]
+    fn last_chunk_not(&self) -> Option<Self::ChunkIndex> {
+        self.0.last_chunk_not()
+    }
+
+    #[inline(always)]
+    fn next_chunk_before_not(&self, index: Self::ChunkIndex) -> Option<Self::ChunkIndex> {
+        self.0.next_chunk_before_not(index)
+    }
+}
+
+//  #   Safety
+//
+//  -   Ordered: inherited.
+unsafe impl<S> IndexOrderedChunkedNot for LossyView<S> where S: IndexOrderedChunkedNot {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        chunk::UnsignedChunk,
+        index::{IndexCollection, IndexStore},
+        set::IndexSet,
+        test::IndexTesterLossy,
+    };
+
+    use super::LossyView;
+
+    type Victim = LossyView<UnsignedChunk<u8>>;
+
+    struct LossyTester;
+
+    impl IndexTesterLossy for LossyTester {
+        type Index = u8;
+        type Victim = Victim;
+
+        fn upper_bound() -> u8 {
+            7
+        }
+
+        fn victim(indexes: &[u8]) -> Self::Victim {
+            let mut victim = <Victim as IndexCollection>::new();
+
+            for &index in indexes {
+                victim.insert(index).unwrap();
+            }
+
+            victim
+        }
+
+        fn index(i: u8) -> Self::Index {
+            i
+        }
+
+        fn lossy_index() -> Option<Self::Index> {
+            None
+        }
+    }
+
+    crate::test_index_store_lossy!(LossyTester);
+
+    #[test]
+    fn set_insert_iterate() {
+        let mut set = IndexSet::<Victim>::new();
+
+        assert_eq!(Ok(true), set.insert(5));
+        assert_eq!(Ok(true), set.insert(1));
+        assert_eq!(Ok(false), set.insert(5));
+        assert_eq!(Ok(true), set.insert(7));
+
+        assert_eq!(3, set.len());
+        assert!(set.contains(1));
+        assert!(!set.contains(2));
+        assert!(set.iter().eq([1, 5, 7]));
+        assert!(set.iter().rev().eq([7, 5, 1]));
+
+        assert!(set.remove(5));
+        assert!(set.iter().eq([1, 7]));
+        assert_eq!(0b1000_0010, set.as_store().as_view().0);
+    }
+} // mod tests
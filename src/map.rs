@@ -0,0 +1,829 @@
+//! The `IndexMap` struct is an index-keyed map built above any type implementing `IndexStore`.
+//!
+//! The membership of the indexes is tracked by the store, while the values are held in a dense array keyed by index,
+//! alongside.
+//!
+//! The `CompactIndexMap` struct is its counterpart for sparse key domains, holding the values in a dense array keyed
+//! by rank instead, see `IndexOrderedStore`.
+
+use core::{iter::FusedIterator, marker::PhantomData, ops::Bound};
+
+use alloc::vec::Vec;
+
+use crate::{
+    Never,
+    index::{IndexCollection, IndexForward, IndexStore, IndexVault, IndexView},
+    set::IndexSet,
+};
+
+mod compact;
+
+pub use compact::{CompactIndexMap, CompactIter, CompactIterMut};
+
+/// A map of indexes to values.
+#[derive(Clone, Debug)]
+pub struct IndexMap<S, V> {
+    store: S,
+    values: Vec<Option<V>>,
+}
+
+//
+//  Construction.
+//
+
+impl<S, V> IndexMap<S, V>
+where
+    S: IndexCollection,
+{
+    /// Returns the span of index values which MAY be inserted.
+    ///
+    /// Attempts to insert values outside this span WILL fail, possibly via panicking or aborting.
+    #[inline(always)]
+    pub fn span() -> (Bound<S::Index>, Bound<S::Index>) {
+        S::span()
+    }
+
+    /// Creates a new, empty, instance.
+    #[inline(always)]
+    pub fn new() -> Self {
+        let store = S::new();
+        let values = Vec::new();
+
+        Self { store, values }
+    }
+
+    /// Creates a new, empty, instance, with appropriate capacity for storing the span if possible.
+    ///
+    /// This is purely a _best effort_ method, as not all collections allow reserving extra space.
+    #[inline(always)]
+    pub fn with_span(range: (Bound<S::Index>, Bound<S::Index>)) -> Self {
+        let store = S::with_span(range);
+        let values = Vec::new();
+
+        Self { store, values }
+    }
+}
+
+impl<S, V> IndexMap<S, V>
+where
+    S: IndexForward + IndexVault,
+    usize: TryFrom<S::Index>,
+{
+    /// Creates a new instance from the indexes of `set`, materializing the value of each index with `f`.
+    ///
+    /// The store of `set` is reused as is, hence the indexes of the map are exactly those of `set`.
+    pub fn from_set_with<F>(set: IndexSet<S>, mut f: F) -> Self
+    where
+        F: FnMut(S::Index) -> V,
+    {
+        let store = set.into_store();
+        let mut values = Vec::new();
+
+        let mut cursor = store.first();
+
+        while let Some(index) = cursor {
+            let position = position(index);
+
+            if position >= values.len() {
+                values.resize_with(position + 1, || None);
+            }
+
+            values[position] = Some(f(index));
+
+            cursor = store.next_after(index);
+        }
+
+        Self { store, values }
+    }
+}
+
+impl<S, V> Default for IndexMap<S, V>
+where
+    S: IndexCollection,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, V> FromIterator<(S::Index, V)> for IndexMap<S, V>
+where
+    S: IndexCollection + IndexStore<InsertionError = Never>,
+    usize: TryFrom<S::Index>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (S::Index, V)>,
+    {
+        let mut this = Self::new();
+
+        this.extend(iter);
+
+        this
+    }
+}
+
+impl<S, V> Extend<(S::Index, V)> for IndexMap<S, V>
+where
+    S: IndexStore<InsertionError = Never>,
+    usize: TryFrom<S::Index>,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (S::Index, V)>,
+    {
+        for (index, value) in iter {
+            let _ = self.insert(index, value);
+        }
+    }
+}
+
+//
+//  Deconstruction operations.
+//
+
+impl<S, V> IndexMap<S, V> {
+    /// Returns a reference to the underlying store.
+    ///
+    /// No mutable access is provided, as the store and the values must be kept in sync.
+    pub fn as_store(&self) -> &S {
+        &self.store
+    }
+}
+
+//
+//  View Operations.
+//
+
+impl<S, V> IndexMap<S, V>
+where
+    S: IndexView,
+    usize: TryFrom<S::Index>,
+{
+    /// Returns whether the map is empty, or not.
+    pub fn is_empty(&self) -> bool {
+        self.store.is_empty()
+    }
+
+    /// Returns the number of indexes in the map.
+    pub fn len(&self) -> usize {
+        self.store.len()
+    }
+
+    /// Returns whether the index is contained in the map.
+    pub fn contains_key(&self, index: S::Index) -> bool {
+        self.store.contains(index)
+    }
+
+    /// Returns a reference to the value associated to the index, if any.
+    ///
+    /// The store is checked first, so that the stale value of an index stolen from the store is not returned.
+    pub fn get(&self, index: S::Index) -> Option<&V> {
+        if !self.store.contains(index) {
+            return None;
+        }
+
+        self.values.get(position(index))?.as_ref()
+    }
+
+    /// Returns a mutable reference to the value associated to the index, if any.
+    pub fn get_mut(&mut self, index: S::Index) -> Option<&mut V> {
+        if !self.store.contains(index) {
+            return None;
+        }
+
+        self.values.get_mut(position(index))?.as_mut()
+    }
+}
+
+//
+//  Store operations.
+//
+
+impl<S, V> IndexMap<S, V>
+where
+    S: IndexStore,
+    usize: TryFrom<S::Index>,
+{
+    /// Removes all indexes, and their values, from the map.
+    pub fn clear(&mut self) {
+        self.store.clear();
+        self.values.clear();
+    }
+
+    /// Inserts the index and its value in the map, returns the previous value, if any.
+    ///
+    /// If the index cannot be inserted in the store, the map is left unmodified. This includes stores silently
+    /// ignoring indexes outside their span, in which case `value` is dropped.
+    pub fn insert(&mut self, index: S::Index, value: V) -> Result<Option<V>, S::InsertionError> {
+        let position = position(index);
+
+        let inserted = self.store.insert(index)?;
+
+        if !inserted && !self.store.contains(index) {
+            return Ok(None);
+        }
+
+        if position >= self.values.len() {
+            self.values.resize_with(position + 1, || None);
+        }
+
+        let previous = self.values[position].replace(value);
+
+        //  A newly inserted index has no previous value, at most the stale value of an index stolen from the store.
+        Ok(previous.filter(|_| !inserted))
+    }
+
+    /// Removes the index from the map, returns its value if it was in the map prior to removal.
+    pub fn remove(&mut self, index: S::Index) -> Option<V> {
+        if !self.store.remove(index) {
+            return None;
+        }
+
+        self.values.get_mut(position(index))?.take()
+    }
+}
+
+//
+//  Entry API.
+//
+
+impl<S, V> IndexMap<S, V>
+where
+    S: IndexStore,
+    usize: TryFrom<S::Index>,
+{
+    /// Returns the entry.
+    pub fn entry(&mut self, index: S::Index) -> Entry<'_, S::Index, S, V> {
+        if self.contains_key(index) {
+            Entry::Occupied(OccupiedEntry {
+                index,
+                store: &mut self.store,
+                values: &mut self.values,
+            })
+        } else {
+            Entry::Vacant(VacantEntry {
+                index,
+                store: &mut self.store,
+                values: &mut self.values,
+            })
+        }
+    }
+}
+
+/// A view into a single entry in a map, which may either be vacant or occupied.
+pub enum Entry<'a, I, S, V> {
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, I, S, V>),
+    /// A vacant entry.
+    Vacant(VacantEntry<'a, I, S, V>),
+}
+
+impl<'a, I, S, V> Entry<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexStore<Index = I>,
+    usize: TryFrom<I>,
+{
+    /// Returns the index.
+    pub fn key(&self) -> I {
+        match self {
+            Self::Occupied(o) => o.key(),
+            Self::Vacant(v) => v.key(),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting `value` if vacant, and returns a mutable reference to the value.
+    ///
+    /// Returns `None` if the index cannot be inserted in the store, see `VacantEntry::insert`.
+    pub fn or_insert(self, value: V) -> Result<Option<&'a mut V>, S::InsertionError> {
+        self.or_insert_with(|| value)
+    }
+
+    /// Ensures a value is in the entry by inserting the result of `f` if vacant, and returns a mutable reference to
+    /// the value.
+    ///
+    /// Returns `None` if the index cannot be inserted in the store, see `VacantEntry::insert`.
+    pub fn or_insert_with<F>(self, f: F) -> Result<Option<&'a mut V>, S::InsertionError>
+    where
+        F: FnOnce() -> V,
+    {
+        self.or_insert_with_key(|_| f())
+    }
+
+    /// Ensures a value is in the entry by inserting the result of `f` if vacant, and returns a mutable reference to
+    /// the value.
+    ///
+    /// The index is passed to `f`, sparing the need to clone or copy it ahead of time.
+    ///
+    /// Returns `None` if the index cannot be inserted in the store, see `VacantEntry::insert`.
+    pub fn or_insert_with_key<F>(self, f: F) -> Result<Option<&'a mut V>, S::InsertionError>
+    where
+        F: FnOnce(I) -> V,
+    {
+        match self {
+            Self::Occupied(o) => Ok(Some(o.into_mut())),
+            Self::Vacant(v) => {
+                let value = f(v.index);
+
+                v.insert(value)
+            }
+        }
+    }
+}
+
+/// An occupied entry in a map.
+pub struct OccupiedEntry<'a, I, S, V> {
+    index: I,
+    store: &'a mut S,
+    values: &'a mut Vec<Option<V>>,
+}
+
+impl<'a, I, S, V> OccupiedEntry<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexStore<Index = I>,
+    usize: TryFrom<I>,
+{
+    /// Returns the index.
+    pub fn key(&self) -> I {
+        self.index
+    }
+
+    /// Returns a reference to the value.
+    pub fn get(&self) -> &V {
+        self.values[position(self.index)].as_ref().expect("occupied")
+    }
+
+    /// Returns a mutable reference to the value.
+    pub fn get_mut(&mut self) -> &mut V {
+        self.values[position(self.index)].as_mut().expect("occupied")
+    }
+
+    /// Converts the entry into a mutable reference to the value, with the lifetime of the map.
+    pub fn into_mut(self) -> &'a mut V {
+        self.values[position(self.index)].as_mut().expect("occupied")
+    }
+
+    /// Replaces the value, returns the previous value.
+    pub fn insert(&mut self, value: V) -> V {
+        self.values[position(self.index)].replace(value).expect("occupied")
+    }
+
+    /// Removes the index from the map, returns its value.
+    pub fn remove(self) -> V {
+        let _removed = self.store.remove(self.index);
+
+        debug_assert!(_removed);
+
+        self.values[position(self.index)].take().expect("occupied")
+    }
+}
+
+/// A vacant entry in a map.
+pub struct VacantEntry<'a, I, S, V> {
+    index: I,
+    store: &'a mut S,
+    values: &'a mut Vec<Option<V>>,
+}
+
+impl<'a, I, S, V> VacantEntry<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexStore<Index = I>,
+    usize: TryFrom<I>,
+{
+    /// Returns the index.
+    pub fn key(&self) -> I {
+        self.index
+    }
+
+    /// Consumes the entry and returns the index.
+    pub fn into_key(self) -> I {
+        self.index
+    }
+
+    /// Inserts the index and its value in the map, returns a mutable reference to the value.
+    ///
+    /// If the index cannot be inserted in the store, the map is left unmodified. This includes stores silently
+    /// ignoring indexes outside their span, in which case `value` is dropped, and `None` is returned.
+    pub fn insert(self, value: V) -> Result<Option<&'a mut V>, S::InsertionError> {
+        let position = position(self.index);
+
+        let inserted = self.store.insert(self.index)?;
+
+        if !inserted && !self.store.contains(self.index) {
+            return Ok(None);
+        }
+
+        if position >= self.values.len() {
+            self.values.resize_with(position + 1, || None);
+        }
+
+        Ok(Some(self.values[position].insert(value)))
+    }
+}
+
+#[cfg(test)]
+mod entry_tests;
+
+//
+//  Iterator operations.
+//
+
+impl<S, V> IndexMap<S, V>
+where
+    S: IndexForward,
+    usize: TryFrom<S::Index>,
+{
+    /// Returns an iterator over the indexes and values of the map, in the order of the store.
+    pub fn iter(&self) -> Iter<'_, S::Index, S, V> {
+        Iter {
+            next: self.store.first(),
+            yielded: 0,
+            store: &self.store,
+            values: &self.values,
+        }
+    }
+
+    /// Returns an iterator over the indexes and mutable values of the map, in the order of the store.
+    pub fn iter_mut(&mut self) -> IterMut<'_, S::Index, S, V> {
+        IterMut {
+            next: self.store.first(),
+            yielded: 0,
+            store: &self.store,
+            values: self.values.as_mut_ptr(),
+            length: self.values.len(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the indexes of the map, in the order of the store.
+    pub fn keys(&self) -> Keys<'_, S::Index, S, V> {
+        Keys { inner: self.iter() }
+    }
+
+    /// Returns an iterator over the values of the map, in the order of the store.
+    pub fn values(&self) -> Values<'_, S::Index, S, V> {
+        Values { inner: self.iter() }
+    }
+
+    /// Returns an iterator over the mutable values of the map, in the order of the store.
+    pub fn values_mut(&mut self) -> ValuesMut<'_, S::Index, S, V> {
+        ValuesMut { inner: self.iter_mut() }
+    }
+}
+
+impl<S, V> IndexMap<S, V>
+where
+    S: IndexForward + IndexStore,
+    usize: TryFrom<S::Index>,
+{
+    /// Retains only the entries specified by the predicate, which may mutate the values it retains.
+    ///
+    /// The entries are visited in the order of the store.
+    pub fn retain<F>(&mut self, mut pred: F)
+    where
+        F: FnMut(S::Index, &mut V) -> bool,
+    {
+        let mut cursor = self.store.first();
+
+        while let Some(index) = cursor {
+            //  Advance prior to removal, so as not to rely on `next_after` of a removed index.
+            cursor = self.store.next_after(index);
+
+            let position = position(index);
+
+            let Some(value) = self.values.get_mut(position).and_then(Option::as_mut) else {
+                continue;
+            };
+
+            if !pred(index, value) {
+                self.store.remove(index);
+                self.values[position] = None;
+            }
+        }
+    }
+}
+
+impl<'a, S, V> IntoIterator for &'a IndexMap<S, V>
+where
+    S: IndexForward,
+    usize: TryFrom<S::Index>,
+{
+    type Item = (S::Index, &'a V);
+    type IntoIter = Iter<'a, S::Index, S, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, S, V> IntoIterator for &'a mut IndexMap<S, V>
+where
+    S: IndexForward,
+    usize: TryFrom<S::Index>,
+{
+    type Item = (S::Index, &'a mut V);
+    type IntoIter = IterMut<'a, S::Index, S, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Iterator over the indexes and values of a map.
+pub struct Iter<'a, I, S, V> {
+    next: Option<I>,
+    yielded: usize,
+    store: &'a S,
+    values: &'a [Option<V>],
+}
+
+impl<'a, I, S, V> Clone for Iter<'a, I, S, V>
+where
+    I: Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            next: self.next,
+            yielded: self.yielded,
+            store: self.store,
+            values: self.values,
+        }
+    }
+}
+
+impl<'a, I, S, V> Iterator for Iter<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+    usize: TryFrom<I>,
+{
+    type Item = (I, &'a V);
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let length = self.len();
+
+        (length, Some(length))
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.next.take()?;
+
+        self.yielded += 1;
+        self.next = self.store.next_after(index);
+
+        let value = self.values.get(position(index))?.as_ref()?;
+
+        Some((index, value))
+    }
+}
+
+impl<'a, I, S, V> ExactSizeIterator for Iter<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+    usize: TryFrom<I>,
+{
+    fn len(&self) -> usize {
+        self.store.len() - self.yielded
+    }
+}
+
+impl<'a, I, S, V> FusedIterator for Iter<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+    usize: TryFrom<I>,
+{
+}
+
+/// Iterator over the indexes and mutable values of a map.
+pub struct IterMut<'a, I, S, V> {
+    next: Option<I>,
+    yielded: usize,
+    store: &'a S,
+    values: *mut Option<V>,
+    length: usize,
+    _marker: PhantomData<&'a mut [Option<V>]>,
+}
+
+impl<'a, I, S, V> Iterator for IterMut<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+    usize: TryFrom<I>,
+{
+    type Item = (I, &'a mut V);
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let length = self.len();
+
+        (length, Some(length))
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.next.take()?;
+
+        self.yielded += 1;
+        self.next = self.store.next_after(index);
+
+        let position = position(index);
+
+        if position >= self.length {
+            return None;
+        }
+
+        //  Safety:
+        //  -   InBounds: `position < self.length`, the length of the slice of values.
+        //  -   NoAlias: `S` being `IndexForward`, it SHALL not yield the same index twice (NoDuplicate), hence no
+        //      two mutable references to the same value are ever handed out.
+        let value = unsafe { &mut *self.values.add(position) };
+
+        Some((index, value.as_mut()?))
+    }
+}
+
+impl<'a, I, S, V> ExactSizeIterator for IterMut<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+    usize: TryFrom<I>,
+{
+    fn len(&self) -> usize {
+        self.store.len() - self.yielded
+    }
+}
+
+impl<'a, I, S, V> FusedIterator for IterMut<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+    usize: TryFrom<I>,
+{
+}
+
+/// Iterator over the indexes of a map.
+pub struct Keys<'a, I, S, V> {
+    inner: Iter<'a, I, S, V>,
+}
+
+impl<'a, I, S, V> Clone for Keys<'a, I, S, V>
+where
+    I: Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, I, S, V> Iterator for Keys<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+    usize: TryFrom<I>,
+{
+    type Item = I;
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(index, _)| index)
+    }
+}
+
+impl<'a, I, S, V> ExactSizeIterator for Keys<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+    usize: TryFrom<I>,
+{
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<'a, I, S, V> FusedIterator for Keys<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+    usize: TryFrom<I>,
+{
+}
+
+/// Iterator over the values of a map.
+pub struct Values<'a, I, S, V> {
+    inner: Iter<'a, I, S, V>,
+}
+
+impl<'a, I, S, V> Clone for Values<'a, I, S, V>
+where
+    I: Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, I, S, V> Iterator for Values<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+    usize: TryFrom<I>,
+{
+    type Item = &'a V;
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, value)| value)
+    }
+}
+
+impl<'a, I, S, V> ExactSizeIterator for Values<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+    usize: TryFrom<I>,
+{
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<'a, I, S, V> FusedIterator for Values<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+    usize: TryFrom<I>,
+{
+}
+
+/// Iterator over the mutable values of a map.
+pub struct ValuesMut<'a, I, S, V> {
+    inner: IterMut<'a, I, S, V>,
+}
+
+impl<'a, I, S, V> Iterator for ValuesMut<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+    usize: TryFrom<I>,
+{
+    type Item = &'a mut V;
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, value)| value)
+    }
+}
+
+impl<'a, I, S, V> ExactSizeIterator for ValuesMut<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+    usize: TryFrom<I>,
+{
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<'a, I, S, V> FusedIterator for ValuesMut<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+    usize: TryFrom<I>,
+{
+}
+
+#[cfg(test)]
+mod basic_tests;
+
+//
+//  Implementation
+//
+
+//  Returns the position of the value of `index` in the dense array of values.
+//
+//  #   Panics
+//
+//  If `index` is not representable as `usize`, as it could not be stored in the dense array anyway.
+fn position<I>(index: I) -> usize
+where
+    usize: TryFrom<I>,
+{
+    usize::try_from(index).unwrap_or_else(|_| panic!("index not representable as usize"))
+}
@@ -0,0 +1,234 @@
+//! Unit tests for individual operations.
+
+mod btree_set {
+    use alloc::{collections::BTreeSet, vec::Vec};
+
+    use crate::{map::IndexMap, set::IndexSet};
+
+    type Victim = IndexMap<BTreeSet<u8>, u32>;
+
+    #[test]
+    fn empty() {
+        let victim = Victim::new();
+
+        assert!(victim.is_empty());
+        assert_eq!(0, victim.len());
+        assert!(!victim.contains_key(1));
+        assert_eq!(None, victim.get(1));
+        assert_eq!(0, victim.iter().count());
+    }
+
+    #[test]
+    fn insert_get_remove() {
+        let mut victim = Victim::new();
+
+        assert_eq!(Ok(None), victim.insert(3, 30));
+        assert_eq!(Ok(None), victim.insert(1, 10));
+        assert_eq!(Ok(Some(30)), victim.insert(3, 33));
+
+        assert_eq!(2, victim.len());
+        assert!(victim.contains_key(3));
+        assert_eq!(Some(&33), victim.get(3));
+        assert_eq!(None, victim.get(2));
+
+        *victim.get_mut(1).unwrap() += 1;
+
+        assert_eq!(Some(&11), victim.get(1));
+
+        assert_eq!(Some(33), victim.remove(3));
+        assert_eq!(None, victim.remove(3));
+
+        assert!(!victim.contains_key(3));
+        assert_eq!(None, victim.get(3));
+        assert_eq!(1, victim.len());
+
+        victim.clear();
+
+        assert!(victim.is_empty());
+        assert_eq!(None, victim.get(1));
+    }
+
+    #[test]
+    fn iteration() {
+        let mut victim = Victim::from_iter([(5, 50), (1, 10), (3, 30)]);
+
+        assert_eq!(vec![(1, &10), (3, &30), (5, &50)], victim.iter().collect::<Vec<_>>());
+        assert_eq!(vec![1, 3, 5], victim.keys().collect::<Vec<_>>());
+        assert_eq!(vec![&10, &30, &50], victim.values().collect::<Vec<_>>());
+
+        for (index, value) in victim.iter_mut() {
+            *value += u32::from(index);
+        }
+
+        victim.values_mut().for_each(|value| *value *= 2);
+
+        assert_eq!(vec![22, 66, 110], victim.values().copied().collect::<Vec<_>>());
+        assert_eq!(3, victim.iter().len());
+    }
+
+    #[test]
+    fn retain() {
+        let mut victim = Victim::from_iter((0..10).map(|index| (index, u32::from(index))));
+
+        let mut visited = Vec::new();
+
+        victim.retain(|index, value| {
+            visited.push(index);
+
+            *value *= 2;
+
+            index % 2 == 0
+        });
+
+        assert_eq!((0..10).collect::<Vec<_>>(), visited);
+        assert_eq!(5, victim.len());
+        assert_eq!(
+            vec![(0, &0), (2, &4), (4, &8), (6, &12), (8, &16)],
+            victim.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(None, victim.get(3));
+    }
+
+    #[test]
+    fn from_set_with() {
+        const INDEXES: [u8; 5] = [0, 2, 3, 7, 12];
+
+        let set = IndexSet::<BTreeSet<u8>>::from_iter(INDEXES);
+
+        let victim = Victim::from_set_with(set, |index| u32::from(index) * u32::from(index));
+
+        assert_eq!(INDEXES.len(), victim.len());
+        assert!(victim.keys().eq(INDEXES));
+        assert!(victim.values().copied().eq([0, 4, 9, 49, 144]));
+        assert!((0..16).all(|index| victim.contains_key(index) == INDEXES.contains(&index)));
+    }
+} // mod btree_set
+
+mod array_chunk {
+    use alloc::vec::Vec;
+
+    use crate::{
+        chunk::{ArrayChunk, UnsignedChunk},
+        map::IndexMap,
+    };
+
+    type Victim = IndexMap<ArrayChunk<UnsignedChunk<u8>, 2>, &'static str>;
+
+    #[test]
+    fn insert_out_of_span() {
+        let mut victim = Victim::new();
+
+        assert_eq!(Ok(None), victim.insert(15, "fifteen"));
+        assert_eq!(Ok(None), victim.insert(16, "sixteen"));
+
+        assert_eq!(1, victim.len());
+        assert!(!victim.contains_key(16));
+        assert_eq!(None, victim.get(16));
+    }
+
+    #[test]
+    fn iteration() {
+        let mut victim = Victim::new();
+
+        for (index, value) in [(9, "nine"), (2, "two"), (4, "four")] {
+            victim.insert(index, value).unwrap();
+        }
+
+        assert_eq!(
+            vec![(2, &"two"), (4, &"four"), (9, &"nine")],
+            victim.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn retain() {
+        let mut victim = Victim::new();
+
+        for (index, value) in [(1, "one"), (2, "two"), (3, "three"), (15, "fifteen")] {
+            victim.insert(index, value).unwrap();
+        }
+
+        victim.retain(|index, _| index % 2 == 0);
+
+        assert_eq!(vec![(2, &"two")], victim.iter().collect::<Vec<_>>());
+    }
+} // mod array_chunk
+
+mod stealing {
+    use crate::map::IndexMap;
+
+    //  A store which only holds the last index inserted, stealing any prior index.
+    #[derive(Default)]
+    struct Latest(Option<u8>);
+
+    //  #   Safety
+    //
+    //  -   NoPhantom: only the last index inserted, if not removed since, is contained.
+    unsafe impl crate::index::IndexView for Latest {
+        type Index = u8;
+
+        fn is_empty(&self) -> bool {
+            self.0.is_none()
+        }
+
+        fn len(&self) -> usize {
+            usize::from(self.0.is_some())
+        }
+
+        fn contains(&self, index: Self::Index) -> bool {
+            self.0 == Some(index)
+        }
+    }
+
+    impl crate::index::IndexCollection for Latest {
+        fn span() -> (core::ops::Bound<Self::Index>, core::ops::Bound<Self::Index>) {
+            (core::ops::Bound::Unbounded, core::ops::Bound::Unbounded)
+        }
+
+        fn new() -> Self {
+            Self::default()
+        }
+
+        fn with_span(_range: (core::ops::Bound<Self::Index>, core::ops::Bound<Self::Index>)) -> Self {
+            Self::default()
+        }
+    }
+
+    //  #   Safety
+    //
+    //  -   NoPhantom: only the last index inserted, if not removed since, is contained.
+    unsafe impl crate::index::IndexStore for Latest {
+        type InsertionError = crate::Never;
+
+        fn clear(&mut self) {
+            self.0 = None;
+        }
+
+        fn insert(&mut self, index: Self::Index) -> Result<bool, Self::InsertionError> {
+            Ok(self.0.replace(index) != Some(index))
+        }
+
+        fn remove(&mut self, index: Self::Index) -> bool {
+            self.0.take_if(|current| *current == index).is_some()
+        }
+    }
+
+    type Victim = IndexMap<Latest, &'static str>;
+
+    #[test]
+    fn stolen_value() {
+        let mut victim = Victim::new();
+
+        assert_eq!(Ok(None), victim.insert(1, "one"));
+        assert_eq!(Ok(None), victim.insert(2, "two"));
+
+        //  1 was stolen by the insertion of 2, hence its stale value is neither readable, nor returned.
+        assert!(!victim.contains_key(1));
+        assert_eq!(None, victim.get(1));
+        assert_eq!(None, victim.get_mut(1));
+
+        assert_eq!(Ok(None), victim.insert(1, "uno"));
+        assert_eq!(Some(&"uno"), victim.get(1));
+        assert_eq!(None, victim.get(2));
+    }
+} // mod stealing
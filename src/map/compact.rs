@@ -0,0 +1,566 @@
+//! The `CompactIndexMap` struct is an index-keyed map holding its values densely, by rank.
+
+use core::{iter::FusedIterator, mem, ops::Bound, slice};
+
+use alloc::vec::Vec;
+
+use crate::{
+    Never,
+    index::{IndexCollection, IndexForward, IndexOrderedStore, IndexVault, IndexView},
+};
+
+/// A map of indexes to values, holding the values densely, by rank.
+///
+/// Whereas `IndexMap` holds the value of each index at the position of the index, in a dense array of `Option<V>`,
+/// `CompactIndexMap` holds the value of each index at the position of its rank -- the number of indexes of the map
+/// strictly less than it -- in a dense array of `V`.
+///
+/// #   Tradeoff
+///
+/// The memory footprint of the values is `len * size_of::<V>()`, rather than `(max + 1) * size_of::<Option<V>>()`,
+/// which makes `CompactIndexMap` the better choice for sparse key domains.
+///
+/// On the other hand, each access computes the rank of the index, see `IndexOrderedStore::rank`, and each insertion
+/// or removal of an index shifts all the values of greater indexes, which is linear in the number of indexes. Prefer
+/// `IndexMap` for dense key domains, or write-heavy workloads.
+///
+/// The `compact_map` benchmark compares both maps on dense and sparse key domains.
+#[derive(Clone, Debug)]
+pub struct CompactIndexMap<S, V> {
+    store: S,
+    //  Invariant: `values.len() == store.len()`, and `values[store.rank(index)]` is the value of `index`.
+    //
+    //  The invariant only holds as long as the store does not lose indexes behind the back of the map, hence all
+    //  operations relying on it require an `IndexVault`, as `IndexMap::from_set_with` does.
+    values: Vec<V>,
+}
+
+//
+//  Construction.
+//
+
+impl<S, V> CompactIndexMap<S, V>
+where
+    S: IndexCollection,
+{
+    /// Returns the span of index values which MAY be inserted.
+    ///
+    /// Attempts to insert values outside this span WILL fail, possibly via panicking or aborting.
+    #[inline(always)]
+    pub fn span() -> (Bound<S::Index>, Bound<S::Index>) {
+        S::span()
+    }
+
+    /// Creates a new, empty, instance.
+    #[inline(always)]
+    pub fn new() -> Self {
+        let store = S::new();
+        let values = Vec::new();
+
+        Self { store, values }
+    }
+
+    /// Creates a new, empty, instance, with appropriate capacity for storing the span if possible.
+    ///
+    /// This is purely a _best effort_ method, as not all collections allow reserving extra space.
+    #[inline(always)]
+    pub fn with_span(range: (Bound<S::Index>, Bound<S::Index>)) -> Self {
+        let store = S::with_span(range);
+        let values = Vec::new();
+
+        Self { store, values }
+    }
+}
+
+impl<S, V> Default for CompactIndexMap<S, V>
+where
+    S: IndexCollection,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, V> FromIterator<(S::Index, V)> for CompactIndexMap<S, V>
+where
+    S: IndexCollection + IndexOrderedStore<InsertionError = Never> + IndexVault,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (S::Index, V)>,
+    {
+        let mut this = Self::new();
+
+        this.extend(iter);
+
+        this
+    }
+}
+
+impl<S, V> Extend<(S::Index, V)> for CompactIndexMap<S, V>
+where
+    S: IndexOrderedStore<InsertionError = Never> + IndexVault,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (S::Index, V)>,
+    {
+        for (index, value) in iter {
+            let _ = self.insert(index, value);
+        }
+    }
+}
+
+//
+//  Deconstruction operations.
+//
+
+impl<S, V> CompactIndexMap<S, V> {
+    /// Returns a reference to the underlying store.
+    ///
+    /// No mutable access is provided, as the store and the values must be kept in sync.
+    pub fn as_store(&self) -> &S {
+        &self.store
+    }
+
+    /// Returns the values, in the order of their indexes.
+    pub fn as_values(&self) -> &[V] {
+        &self.values
+    }
+}
+
+//
+//  View Operations.
+//
+
+impl<S, V> CompactIndexMap<S, V>
+where
+    S: IndexView,
+{
+    /// Returns whether the map is empty, or not.
+    pub fn is_empty(&self) -> bool {
+        self.store.is_empty()
+    }
+
+    /// Returns the number of indexes in the map.
+    pub fn len(&self) -> usize {
+        self.store.len()
+    }
+
+    /// Returns whether the index is contained in the map.
+    pub fn contains_key(&self, index: S::Index) -> bool {
+        self.store.contains(index)
+    }
+}
+
+impl<S, V> CompactIndexMap<S, V>
+where
+    S: IndexOrderedStore + IndexVault,
+{
+    /// Returns a reference to the value associated to the index, if any.
+    pub fn get(&self, index: S::Index) -> Option<&V> {
+        if !self.store.contains(index) {
+            return None;
+        }
+
+        self.values.get(self.store.rank(index))
+    }
+
+    /// Returns a mutable reference to the value associated to the index, if any.
+    pub fn get_mut(&mut self, index: S::Index) -> Option<&mut V> {
+        if !self.store.contains(index) {
+            return None;
+        }
+
+        self.values.get_mut(self.store.rank(index))
+    }
+}
+
+//
+//  Store operations.
+//
+
+impl<S, V> CompactIndexMap<S, V>
+where
+    S: IndexOrderedStore + IndexVault,
+{
+    /// Removes all indexes, and their values, from the map.
+    pub fn clear(&mut self) {
+        self.store.clear();
+        self.values.clear();
+    }
+
+    /// Inserts the index and its value in the map, returns the previous value, if any.
+    ///
+    /// A newly inserted index shifts the values of all greater indexes.
+    ///
+    /// If the index cannot be inserted in the store, the map is left unmodified. This includes stores silently
+    /// ignoring indexes outside their span, in which case `value` is dropped.
+    pub fn insert(&mut self, index: S::Index, value: V) -> Result<Option<V>, S::InsertionError> {
+        let (inserted, rank) = self.store.insert_ranked(index)?;
+
+        if inserted {
+            self.values.insert(rank, value);
+
+            return Ok(None);
+        }
+
+        if !self.store.contains(index) {
+            return Ok(None);
+        }
+
+        Ok(Some(mem::replace(&mut self.values[rank], value)))
+    }
+
+    /// Removes the index from the map, returns its value if it was in the map prior to removal.
+    ///
+    /// The removal shifts the values of all greater indexes.
+    pub fn remove(&mut self, index: S::Index) -> Option<V> {
+        if !self.store.contains(index) {
+            return None;
+        }
+
+        let rank = self.store.rank(index);
+
+        self.store.remove(index);
+
+        Some(self.values.remove(rank))
+    }
+
+    /// Retains only the entries specified by the predicate, which may mutate the values it retains.
+    ///
+    /// The entries are visited in increasing order of their indexes, and the values are compacted in a single pass.
+    pub fn retain<F>(&mut self, mut pred: F)
+    where
+        F: FnMut(S::Index, &mut V) -> bool,
+    {
+        let store = &mut self.store;
+        let mut cursor = store.first();
+
+        self.values.retain_mut(|value| {
+            //  As per invariant, there are as many indexes as values.
+            let Some(index) = cursor else { return true };
+
+            //  Advance prior to removal, so as not to rely on `next_after` of a removed index.
+            cursor = store.next_after(index);
+
+            let retained = pred(index, value);
+
+            if !retained {
+                store.remove(index);
+            }
+
+            retained
+        });
+    }
+}
+
+//
+//  Iterator operations.
+//
+
+impl<S, V> CompactIndexMap<S, V>
+where
+    S: IndexOrderedStore + IndexVault,
+{
+    /// Returns an iterator over the indexes and values of the map, in increasing order of indexes.
+    pub fn iter(&self) -> CompactIter<'_, S::Index, S, V> {
+        CompactIter {
+            next: self.store.first(),
+            store: &self.store,
+            values: self.values.iter(),
+        }
+    }
+
+    /// Returns an iterator over the indexes and mutable values of the map, in increasing order of indexes.
+    pub fn iter_mut(&mut self) -> CompactIterMut<'_, S::Index, S, V> {
+        CompactIterMut {
+            next: self.store.first(),
+            store: &self.store,
+            values: self.values.iter_mut(),
+        }
+    }
+
+    /// Returns an iterator over the indexes of the map, in increasing order.
+    pub fn keys(&self) -> impl ExactSizeIterator<Item = S::Index> + FusedIterator + Clone + '_ {
+        self.iter().map(|(index, _)| index)
+    }
+
+    /// Returns an iterator over the values of the map, in increasing order of indexes.
+    pub fn values(&self) -> slice::Iter<'_, V> {
+        self.values.iter()
+    }
+
+    /// Returns an iterator over the mutable values of the map, in increasing order of indexes.
+    pub fn values_mut(&mut self) -> slice::IterMut<'_, V> {
+        self.values.iter_mut()
+    }
+}
+
+impl<'a, S, V> IntoIterator for &'a CompactIndexMap<S, V>
+where
+    S: IndexOrderedStore + IndexVault,
+{
+    type Item = (S::Index, &'a V);
+    type IntoIter = CompactIter<'a, S::Index, S, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, S, V> IntoIterator for &'a mut CompactIndexMap<S, V>
+where
+    S: IndexOrderedStore + IndexVault,
+{
+    type Item = (S::Index, &'a mut V);
+    type IntoIter = CompactIterMut<'a, S::Index, S, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Iterator over the indexes and values of a compact map.
+pub struct CompactIter<'a, I, S, V> {
+    next: Option<I>,
+    store: &'a S,
+    values: slice::Iter<'a, V>,
+}
+
+impl<'a, I, S, V> Clone for CompactIter<'a, I, S, V>
+where
+    I: Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            next: self.next,
+            store: self.store,
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl<'a, I, S, V> Iterator for CompactIter<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+{
+    type Item = (I, &'a V);
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.next.take()?;
+
+        self.next = self.store.next_after(index);
+
+        Some((index, self.values.next()?))
+    }
+}
+
+impl<'a, I, S, V> ExactSizeIterator for CompactIter<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+{
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl<'a, I, S, V> FusedIterator for CompactIter<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+{
+}
+
+/// Iterator over the indexes and mutable values of a compact map.
+pub struct CompactIterMut<'a, I, S, V> {
+    next: Option<I>,
+    store: &'a S,
+    values: slice::IterMut<'a, V>,
+}
+
+impl<'a, I, S, V> Iterator for CompactIterMut<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+{
+    type Item = (I, &'a mut V);
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.next.take()?;
+
+        self.next = self.store.next_after(index);
+
+        Some((index, self.values.next()?))
+    }
+}
+
+impl<'a, I, S, V> ExactSizeIterator for CompactIterMut<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+{
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl<'a, I, S, V> FusedIterator for CompactIterMut<'a, I, S, V>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    mod btree_set {
+        use alloc::{collections::BTreeSet, vec::Vec};
+
+        use crate::map::CompactIndexMap;
+
+        type Victim = CompactIndexMap<BTreeSet<u32>, u32>;
+
+        #[test]
+        fn empty() {
+            let victim = Victim::new();
+
+            assert!(victim.is_empty());
+            assert_eq!(0, victim.len());
+            assert!(!victim.contains_key(1));
+            assert_eq!(None, victim.get(1));
+            assert_eq!(0, victim.iter().count());
+        }
+
+        #[test]
+        fn insert_get_remove() {
+            let mut victim = Victim::new();
+
+            assert_eq!(Ok(None), victim.insert(3_000_000, 30));
+            assert_eq!(Ok(None), victim.insert(1, 10));
+            assert_eq!(Ok(None), victim.insert(2_000, 20));
+            assert_eq!(Ok(Some(30)), victim.insert(3_000_000, 33));
+
+            assert_eq!(3, victim.len());
+            assert_eq!([10, 20, 33], victim.as_values());
+
+            assert_eq!(Some(&33), victim.get(3_000_000));
+            assert_eq!(Some(&20), victim.get(2_000));
+            assert_eq!(None, victim.get(2));
+
+            *victim.get_mut(1).unwrap() += 1;
+
+            assert_eq!(Some(&11), victim.get(1));
+
+            assert_eq!(Some(20), victim.remove(2_000));
+            assert_eq!(None, victim.remove(2_000));
+
+            assert_eq!([11, 33], victim.as_values());
+            assert_eq!(Some(&33), victim.get(3_000_000));
+
+            victim.clear();
+
+            assert!(victim.is_empty());
+            assert!(victim.as_values().is_empty());
+        }
+
+        #[test]
+        fn iterate() {
+            let mut victim = Victim::from_iter([(5, 50), (1, 10), (900, 9_000), (3, 30)]);
+
+            assert!(victim.iter().eq([(1, &10), (3, &30), (5, &50), (900, &9_000)]));
+            assert!(victim.keys().eq([1, 3, 5, 900]));
+            assert!(victim.values().eq(&[10, 30, 50, 9_000]));
+            assert_eq!(4, victim.iter().len());
+
+            for (index, value) in &mut victim {
+                *value += index;
+            }
+
+            assert!(victim.values().eq(&[11, 33, 55, 9_900]));
+
+            victim.values_mut().for_each(|value| *value /= 11);
+
+            assert!(victim.values().eq(&[1, 3, 5, 900]));
+        }
+
+        #[test]
+        fn retain() {
+            let mut victim: Victim = (0..10).map(|i| (i * 100, i)).collect();
+
+            victim.retain(|index, value| {
+                *value += 1;
+
+                index % 300 != 0
+            });
+
+            assert!(victim.keys().eq([100, 200, 400, 500, 700, 800]));
+            assert_eq!([2, 3, 5, 6, 8, 9], victim.as_values());
+            assert_eq!(Some(&6), victim.get(500));
+        }
+
+        #[test]
+        fn against_reference() {
+            let mut victim = Victim::new();
+            let mut reference = BTreeSet::new();
+
+            //  A simple LCG, to shuffle insertions and removals.
+            let mut seed = 7u32;
+
+            for _ in 0..500 {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+
+                let index = (seed >> 16) % 64;
+
+                if seed.is_multiple_of(3) {
+                    assert_eq!(reference.remove(&index), victim.remove(index).is_some(), "{index}");
+                } else {
+                    let previous = victim.insert(index, index * 2).unwrap();
+
+                    assert_eq!(!reference.insert(index), previous.is_some(), "{index}");
+                }
+
+                let expected: Vec<_> = reference.iter().map(|&i| i * 2).collect();
+
+                assert_eq!(expected, victim.as_values());
+            }
+        }
+    } // mod btree_set
+
+    mod dynamic_chunk_store {
+        use crate::{
+            chunk::{ArrayChunk, UnsignedChunk},
+            map::CompactIndexMap,
+            vault::DynamicChunkStore,
+        };
+
+        type Victim = CompactIndexMap<DynamicChunkStore<ArrayChunk<UnsignedChunk<u64>, 2>, u32>, &'static str>;
+
+        #[test]
+        fn sparse() {
+            let mut victim = Victim::new();
+
+            assert_eq!(Ok(None), victim.insert(100_000, "c"));
+            assert_eq!(Ok(None), victim.insert(7, "a"));
+            assert_eq!(Ok(None), victim.insert(5_000, "b"));
+
+            assert_eq!(["a", "b", "c"], victim.as_values());
+            assert_eq!(Some(&"b"), victim.get(5_000));
+            assert_eq!(None, victim.get(5_001));
+
+            assert_eq!(Some("a"), victim.remove(7));
+            assert_eq!(Some(&"c"), victim.get(100_000));
+            assert!(victim.iter().eq([(5_000, &"b"), (100_000, &"c")]));
+        }
+    } // mod dynamic_chunk_store
+} // mod tests
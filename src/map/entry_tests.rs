@@ -0,0 +1,124 @@
+//! Unit tests for entry operations.
+
+mod btree_set {
+    use alloc::collections::BTreeSet;
+
+    use crate::map::{Entry, IndexMap};
+
+    type Victim = IndexMap<BTreeSet<u8>, u32>;
+
+    #[test]
+    fn entry() {
+        const IN: u8 = 1;
+        const OUT: u8 = 4;
+
+        let mut victim = Victim::from_iter([(1, 10), (2, 20)]);
+
+        assert!(matches!(victim.entry(IN), Entry::Occupied(_)));
+        assert!(matches!(victim.entry(OUT), Entry::Vacant(_)));
+
+        assert_eq!(IN, victim.entry(IN).key());
+        assert_eq!(OUT, victim.entry(OUT).key());
+
+        assert_eq!(2, victim.len());
+        assert!(!victim.contains_key(OUT));
+    }
+
+    #[test]
+    fn entry_or_insert() {
+        let mut victim = Victim::from_iter([(1, 10)]);
+
+        assert_eq!(Ok(Some(&mut 10)), victim.entry(1).or_insert(11));
+        assert_eq!(Ok(Some(&mut 20)), victim.entry(2).or_insert(20));
+
+        *victim.entry(3).or_insert_with(|| 30).unwrap().unwrap() += 3;
+
+        assert_eq!(
+            Ok(Some(&mut 40)),
+            victim.entry(4).or_insert_with_key(|index| u32::from(index) * 10)
+        );
+        assert_eq!(Ok(Some(&mut 33)), victim.entry(3).or_insert_with(|| unreachable!()));
+
+        assert_eq!(4, victim.len());
+        assert_eq!(Some(&33), victim.get(3));
+    }
+
+    #[test]
+    fn occupied_entry() {
+        let mut victim = Victim::from_iter([(1, 10), (2, 20)]);
+
+        let Entry::Occupied(mut entry) = victim.entry(1) else {
+            unreachable!()
+        };
+
+        assert_eq!(1, entry.key());
+        assert_eq!(&10, entry.get());
+
+        *entry.get_mut() += 1;
+
+        assert_eq!(11, entry.insert(12));
+        assert_eq!(&mut 12, entry.into_mut());
+
+        let Entry::Occupied(entry) = victim.entry(2) else {
+            unreachable!()
+        };
+
+        assert_eq!(20, entry.remove());
+
+        assert_eq!(1, victim.len());
+        assert!(!victim.contains_key(2));
+        assert_eq!(None, victim.get(2));
+        assert_eq!(Some(&12), victim.get(1));
+    }
+
+    #[test]
+    fn vacant_entry() {
+        let mut victim = Victim::new();
+
+        let Entry::Vacant(entry) = victim.entry(7) else {
+            unreachable!()
+        };
+
+        assert_eq!(7, entry.key());
+        assert_eq!(Ok(Some(&mut 70)), entry.insert(70));
+
+        assert_eq!(1, victim.len());
+        assert_eq!(Some(&70), victim.get(7));
+
+        let Entry::Vacant(entry) = victim.entry(8) else {
+            unreachable!()
+        };
+
+        assert_eq!(8, entry.into_key());
+        assert!(!victim.contains_key(8));
+    }
+} // mod btree_set
+
+mod array_chunk {
+    use crate::{
+        chunk::{ArrayChunk, UnsignedChunk},
+        map::{Entry, IndexMap},
+    };
+
+    //  Silently ignores indexes past 15.
+    type Victim = IndexMap<ArrayChunk<UnsignedChunk<u8>, 2>, u32>;
+
+    #[test]
+    fn vacant_entry_out_of_span() {
+        let mut victim = Victim::from_iter([(1, 10)]);
+
+        let Entry::Vacant(entry) = victim.entry(16) else {
+            unreachable!()
+        };
+
+        assert_eq!(Ok(None), entry.insert(160));
+
+        assert_eq!(Ok(None), victim.entry(20).or_insert(200));
+        assert_eq!(Ok(Some(&mut 150)), victim.entry(15).or_insert(150));
+
+        assert_eq!(2, victim.len());
+        assert_eq!(None, victim.get(16));
+        assert_eq!(None, victim.get(20));
+        assert_eq!(Some(&150), victim.get(15));
+    }
+} // mod array_chunk
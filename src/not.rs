@@ -1,6 +1,9 @@
 //! Adapter over an IndexView.
 
-use core::{num::NonZeroUsize, ops::Not};
+use core::{
+    num::NonZeroUsize,
+    ops::{Bound, Not, RangeBounds},
+};
 
 use crate::index::{
     IndexBackward, IndexBackwardChunked, IndexForward, IndexForwardChunked, IndexOrdered, IndexOrderedChunked,
@@ -16,6 +19,29 @@ use core::ops::Try;
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub struct NotView<S>(S);
 
+/// Adapts an `IndexOrderedNot` so as to negate it, within a span.
+///
+/// Returns contained for elements within the span which are not in the view, and not-contained for all others.
+///
+/// Unlike `NotView`, the number of indexes of the adapter is bounded by its span, rather than by the span of the
+/// view, hence iterating over all indexes NOT contained in a `DynamicChunkStore` becomes practical.
+///
+/// The indexes of the span past the capacity of the view, such as the allocated chunks of a `DynamicChunkStore`, are
+/// NOT contained by the view either, and are therefore iterated over too, one at a time.
+///
+/// `len` only walks the indexes of the view within the span, yet it panics if the number of indexes does not fit in
+/// `usize`. Iterating to an unbounded end, past the capacity of a view whose span is unbounded, visits every index up
+/// to the maximum of `S::Index`, and therefore practically never terminates: prefer a bounded end to iterate.
+#[derive(Clone, Copy, Debug)]
+pub struct NotWithin<S>
+where
+    S: IndexView,
+{
+    view: S,
+    start: Bound<S::Index>,
+    end: Bound<S::Index>,
+}
+
 //
 //  Construction
 //
@@ -28,6 +54,26 @@ impl<S> NotView<S> {
     }
 }
 
+impl<S> NotView<S>
+where
+    S: IndexOrderedNot,
+{
+    /// Restricts the negated view to the given span.
+    ///
+    /// Iteration stops at the end of the span, and `len` only counts the indexes within the span.
+    #[inline(always)]
+    pub fn within<R>(self, span: R) -> NotWithin<S>
+    where
+        R: RangeBounds<S::Index>,
+    {
+        NotWithin {
+            view: self.0,
+            start: span.start_bound().cloned(),
+            end: span.end_bound().cloned(),
+        }
+    }
+}
+
 //
 //  Deconstruction
 //
@@ -52,6 +98,139 @@ impl<S> NotView<S> {
     }
 }
 
+impl<S> NotWithin<S>
+where
+    S: IndexView,
+{
+    /// Returns a reference to the view.
+    #[inline(always)]
+    pub fn as_view(&self) -> &S {
+        &self.view
+    }
+
+    /// Returns the view.
+    #[inline(always)]
+    pub fn into_view(self) -> S {
+        self.view
+    }
+
+    /// Returns the span.
+    #[inline(always)]
+    pub fn span(&self) -> (Bound<S::Index>, Bound<S::Index>) {
+        (self.start, self.end)
+    }
+
+    //  Returns whether `index` is after the start of the span.
+    #[inline(always)]
+    fn after_start(&self, index: S::Index) -> bool {
+        match self.start {
+            Bound::Included(start) => index >= start,
+            Bound::Excluded(start) => index > start,
+            Bound::Unbounded => true,
+        }
+    }
+
+    //  Returns whether `index` is before the end of the span.
+    #[inline(always)]
+    fn before_end(&self, index: S::Index) -> bool {
+        match self.end {
+            Bound::Included(end) => index <= end,
+            Bound::Excluded(end) => index < end,
+            Bound::Unbounded => true,
+        }
+    }
+}
+
+impl<S> NotWithin<S>
+where
+    S: IndexOrderedNot<Index: TryFrom<u128>>,
+    u128: TryFrom<S::Index>,
+{
+    //  Returns the first index after `current` NOT contained by the view, within the span.
+    //
+    //  The NOT iteration of a view stops at its capacity, such as the allocated chunks of a `DynamicChunkStore`, yet
+    //  the indexes past it are NOT contained either, hence once it runs out the indexes are probed one at a time, up to
+    //  the end of the span, the way `IndexOrdSet::first_gap_from` does.
+    fn next_after_not(&self, current: S::Index) -> Option<S::Index> {
+        if let Some(next) = self.view.next_after_not(current) {
+            return Some(next).filter(|&index| self.before_end(index));
+        }
+
+        let mut current = current;
+
+        loop {
+            current = Self::successor(current).filter(|&index| self.before_end(index))?;
+
+            if !self.view.contains(current) {
+                return Some(current);
+            }
+        }
+    }
+
+    //  Returns the first index before `current` NOT contained by the view, within the span.
+    //
+    //  The indexes past the capacity of the view are NOT contained, hence only once the preceding index is contained
+    //  is the NOT iteration of the view resumed.
+    fn next_before_not(&self, current: S::Index) -> Option<S::Index>
+    where
+        S: IndexBackwardNot,
+    {
+        let previous = Self::predecessor(current)?;
+
+        let previous = if self.view.contains(previous) {
+            self.view.next_before_not(previous)?
+        } else {
+            previous
+        };
+
+        Some(previous).filter(|&index| self.after_start(index))
+    }
+
+    //  Returns the index following `index`, if any.
+    fn successor(index: S::Index) -> Option<S::Index> {
+        S::Index::try_from(u128::try_from(index).ok()?.checked_add(1)?).ok()
+    }
+
+    //  Returns the index preceding `index`, if any.
+    fn predecessor(index: S::Index) -> Option<S::Index> {
+        S::Index::try_from(u128::try_from(index).ok()?.checked_sub(1)?).ok()
+    }
+
+    //  Returns the lowest and highest indexes of the span, both included, if the span is not empty.
+    fn inclusive_span(&self) -> Option<(S::Index, S::Index)> {
+        let low = match self.start {
+            Bound::Included(start) => start,
+            Bound::Excluded(start) => Self::successor(start)?,
+            Bound::Unbounded => Self::lowest()?,
+        };
+
+        let high = match self.end {
+            Bound::Included(end) => end,
+            Bound::Excluded(end) => Self::predecessor(end)?,
+            Bound::Unbounded => Self::highest()?,
+        };
+
+        Some((low, high)).filter(|(low, high)| low <= high)
+    }
+
+    //  Returns `index`, as a `u128`.
+    fn to_wide(index: S::Index) -> u128 {
+        u128::try_from(index).ok().expect("Index to fit in u128")
+    }
+
+    //  Returns the lowest index, standing in for an unbounded start.
+    fn lowest() -> Option<S::Index> {
+        S::Index::try_from(0).ok()
+    }
+
+    //  Returns the highest index, standing in for an unbounded end.
+    //
+    //  The maximum of an unsigned integer is all ones, hence the first such value which converts is the maximum.
+    fn highest() -> Option<S::Index> {
+        (0..u128::BITS).find_map(|shift| S::Index::try_from(u128::MAX >> shift).ok())
+    }
+}
+
 //
 //  Traits required.
 //
@@ -504,7 +683,7 @@ where
 
     #[inline(always)]
     fn is_empty(&self) -> bool {
-        !self.0.is_empty()
+        self.0.len_not() == 0
     }
 
     #[inline(always)]
@@ -660,3 +839,195 @@ where
 //
 //  -   Ordered: inherited.
 unsafe impl<S> IndexOrderedChunked for NotView<S> where S: IndexOrderedChunkedNot {}
+
+//  #   Safety
+//
+//  -   NoPhantom: inherited, as only indexes within the span NOT contained by the view are contained.
+unsafe impl<S> IndexView for NotWithin<S>
+where
+    S: IndexOrderedNot<Index: TryFrom<u128>>,
+    u128: TryFrom<S::Index>,
+{
+    type Index = S::Index;
+
+    fn is_empty(&self) -> bool {
+        self.first().is_none()
+    }
+
+    //  The width of the span, minus the number of indexes of the view within the span, hence only the indexes of the
+    //  view are walked, not the absent ones.
+    fn len(&self) -> usize {
+        let Some((low, high)) = self.inclusive_span() else {
+            return 0;
+        };
+
+        let mut contained: u128 = 0;
+        let mut current = self.view.first_at(low);
+
+        while let Some(index) = current.filter(|&index| index <= high) {
+            contained += 1;
+            current = self.view.next_after(index);
+        }
+
+        let width = Self::to_wide(high) - Self::to_wide(low);
+
+        //  The width is one less than the number of indexes of the span, so as not to overflow on a full `u128` span.
+        let absent = match contained.checked_sub(1) {
+            Some(excess) => width - excess,
+            None => width.checked_add(1).expect("Number of indexes to fit in u128"),
+        };
+
+        usize::try_from(absent).expect("Number of indexes to fit in usize")
+    }
+
+    fn contains(&self, index: Self::Index) -> bool {
+        (self.start, self.end).contains(&index) && !self.view.contains(index)
+    }
+}
+
+//  #   Safety
+//
+//  -   NoTheft: inherited, within the span, including past the capacity of the view.
+unsafe impl<S> IndexVault for NotWithin<S>
+where
+    S: IndexVault + IndexOrderedNot<Index: TryFrom<u128>>,
+    u128: TryFrom<S::Index>,
+{
+}
+
+//  #   Safety
+//
+//  -   NoDuplicate: inherited.
+//  -   NoPhantom: inherited, as iteration stops at the end of the span.
+//  -   NoTheft: inherited, as iteration starts at the start of the span, and carries on past the capacity of the view.
+unsafe impl<S> IndexForward for NotWithin<S>
+where
+    S: IndexOrderedNot<Index: TryFrom<u128>>,
+    u128: TryFrom<S::Index>,
+{
+    fn first(&self) -> Option<Self::Index> {
+        let (start, included) = match self.start {
+            Bound::Included(start) => (start, true),
+            Bound::Excluded(start) => (start, false),
+            Bound::Unbounded => (Self::lowest()?, true),
+        };
+
+        //  Like `NotView::contains`, an included start absent from the view is deemed part of the negated view.
+        if included && !self.view.contains(start) {
+            return Some(start).filter(|&index| self.before_end(index));
+        }
+
+        self.next_after_not(start)
+    }
+
+    fn next_after(&self, current: Self::Index) -> Option<Self::Index> {
+        //  Seek: any index before the start of the span is followed by the first index of the span.
+        if !self.after_start(current) {
+            return self.first();
+        }
+
+        self.next_after_not(current)
+    }
+}
+
+//  #   Safety
+//
+//  -   Reverse: iteration starts at the end of the span, and stops at its start, hence the indexes are the same as
+//      those returned by `IndexForward`, in the exact opposite sequence.
+unsafe impl<S> IndexBackward for NotWithin<S>
+where
+    S: IndexBackwardNot + IndexOrderedNot<Index: TryFrom<u128>>,
+    u128: TryFrom<S::Index>,
+{
+    fn last(&self) -> Option<Self::Index> {
+        let (end, included) = match self.end {
+            Bound::Included(end) => (end, true),
+            Bound::Excluded(end) => (end, false),
+            Bound::Unbounded => (Self::highest()?, true),
+        };
+
+        //  Like `first`, an included end absent from the view is deemed part of the negated view.
+        if included && !self.view.contains(end) {
+            return Some(end).filter(|&index| self.after_start(index));
+        }
+
+        self.next_before_not(end)
+    }
+
+    fn next_before(&self, current: Self::Index) -> Option<Self::Index> {
+        if !self.before_end(current) {
+            return self.last();
+        }
+
+        self.next_before_not(current)
+    }
+}
+
+//  #   Safety
+//
+//  -   Ordered: inherited.
+//  -   Seek: inherited, within the span, and any index before the span is followed by the first index of the span.
+unsafe impl<S> IndexOrdered for NotWithin<S>
+where
+    S: IndexOrderedNot<Index: TryFrom<u128>>,
+    u128: TryFrom<S::Index>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::Bound;
+
+    use crate::{
+        chunk::UnsignedChunk,
+        index::{IndexBackward, IndexForward},
+    };
+
+    use super::{NotView, NotWithin};
+
+    //  Spans 8..56, hence the indexes absent from the chunk outside the span are excluded.
+    type Victim = NotWithin<UnsignedChunk<u64>>;
+
+    struct Tester;
+
+    impl crate::test::IndexTester for Tester {
+        type Index = u8;
+        type Victim = Victim;
+
+        fn upper_bound() -> u8 {
+            47
+        }
+
+        fn victim(indexes: &[u8]) -> Self::Victim {
+            let span = (8..56).fold(0u64, |acc, i| acc | (1 << i));
+            let chunk = indexes.iter().fold(span, |acc, i| acc & !(1 << (i + 8)));
+
+            NotView::new(UnsignedChunk(chunk)).within(8..56)
+        }
+
+        fn index(i: u8) -> Self::Index {
+            i + 8
+        }
+    }
+
+    crate::test_index_view!(Tester);
+    crate::test_index_forward!(Tester);
+    crate::test_index_backward!(Tester);
+
+    #[test]
+    fn seek_outside_span() {
+        let victim = NotView::new(UnsignedChunk(0b1_0000_0000u64)).within((Bound::Excluded(4), Bound::Included(9)));
+
+        assert_eq!(Some(5), victim.next_after(0));
+        assert_eq!(Some(5), victim.next_after(3));
+        assert_eq!(Some(6), victim.next_after(5));
+        assert_eq!(Some(9), victim.next_after(7));
+        assert_eq!(None, victim.next_after(9));
+
+        assert_eq!(Some(9), victim.next_before(63));
+        assert_eq!(Some(9), victim.next_before(10));
+        assert_eq!(Some(7), victim.next_before(9));
+        assert_eq!(Some(5), victim.next_before(6));
+        assert_eq!(None, victim.next_before(5));
+    }
+} // mod tests
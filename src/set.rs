@@ -5,8 +5,10 @@
 
 use core::{
     cmp::{self, Ordering},
+    fmt,
+    hash::{Hash, Hasher},
     iter::FusedIterator,
-    ops::{self, Bound},
+    ops::{self, Add, Bound, RangeBounds, RangeInclusive, Sub},
 };
 
 #[cfg(feature = "nightly")]
@@ -16,25 +18,49 @@ use crate::{
     Never,
     index::{
         IndexBackward, IndexBackwardChunked, IndexCollection, IndexForward, IndexForwardChunked, IndexOrdered,
-        IndexOrderedChunked, IndexStore, IndexStoreChunked, IndexView, IndexViewChunked,
+        IndexOrderedChunked, IndexOrderedStore, IndexStore, IndexStoreChunked, IndexStoreRange, IndexVault, IndexView,
+        IndexViewCanonical, IndexViewChunked,
     },
-    not::NotView,
+    not::{IndexOrderedChunkedNot, IndexOrderedNot, IndexViewNot, NotView, NotWithin},
 };
 
+#[cfg(any(feature = "alloc", test))]
+use crate::{
+    chunk::IndexChunk,
+    vault::{DynamicChunkAllocator, DynamicChunkIndex, DynamicChunkInnerIndex, DynamicChunkStore},
+};
+
+#[cfg(any(feature = "alloc", test))]
+use alloc::vec::Vec;
+
 /// A set of indexes.
-#[derive(Clone, Copy, Debug)]
+#[derive(Copy)]
 pub struct IndexSet<S> {
     store: S,
 }
 
 /// A set of indexes.
-#[derive(Clone, Copy, Debug)]
+#[derive(Copy)]
 pub struct IndexOrdSet<S> {
     store: S,
 }
 
-/// A set of indexes.
-#[derive(Clone, Copy, Debug)]
+/// A set of indexes, operating a whole chunk of indexes at a time whenever possible.
+///
+/// The constructors mirror those of `IndexSet`, so that either may be swapped for the other.
+///
+/// ```
+/// use ecne::{
+///     chunk::{ArrayChunk, UnsignedChunk},
+///     set::IndexChunkedSet,
+/// };
+///
+/// let set: IndexChunkedSet<ArrayChunk<UnsignedChunk<u64>, 4>> = [200u16, 3, 64, 5].into_iter().collect();
+///
+/// assert_eq!(4, set.len());
+/// assert!(set.iter().eq([3, 5, 64, 200]));
+/// ```
+#[derive(Copy)]
 pub struct IndexChunkedSet<S> {
     store: S,
 }
@@ -74,6 +100,22 @@ where
     pub const fn with_store(store: S) -> Self {
         Self { store }
     }
+
+    /// Reserves the appropriate capacity for storing the span, if possible.
+    ///
+    /// This is purely a _best effort_ method, as not all collections allow reserving extra space.
+    #[inline(always)]
+    pub fn reserve(&mut self, additional_span: (Bound<S::Index>, Bound<S::Index>)) {
+        self.store.reserve(additional_span);
+    }
+
+    /// Returns the span of index values which MAY be inserted without further memory allocation.
+    ///
+    /// For collections which cannot reserve, this is the full span.
+    #[inline(always)]
+    pub fn capacity_span(&self) -> (Bound<S::Index>, Bound<S::Index>) {
+        self.store.capacity_span()
+    }
 }
 
 impl<S> IndexOrdSet<S>
@@ -108,6 +150,22 @@ where
     pub const fn with_store(store: S) -> Self {
         Self { store }
     }
+
+    /// Reserves the appropriate capacity for storing the span, if possible.
+    ///
+    /// This is purely a _best effort_ method, as not all collections allow reserving extra space.
+    #[inline(always)]
+    pub fn reserve(&mut self, additional_span: (Bound<S::Index>, Bound<S::Index>)) {
+        self.store.reserve(additional_span);
+    }
+
+    /// Returns the span of index values which MAY be inserted without further memory allocation.
+    ///
+    /// For collections which cannot reserve, this is the full span.
+    #[inline(always)]
+    pub fn capacity_span(&self) -> (Bound<S::Index>, Bound<S::Index>) {
+        self.store.capacity_span()
+    }
 }
 
 impl<S> IndexChunkedSet<S>
@@ -142,6 +200,116 @@ where
     pub const fn with_store(store: S) -> Self {
         Self { store }
     }
+
+    /// Reserves the appropriate capacity for storing the span, if possible.
+    ///
+    /// This is purely a _best effort_ method, as not all collections allow reserving extra space.
+    #[inline(always)]
+    pub fn reserve(&mut self, additional_span: (Bound<S::Index>, Bound<S::Index>)) {
+        self.store.reserve(additional_span);
+    }
+
+    /// Returns the span of index values which MAY be inserted without further memory allocation.
+    ///
+    /// For collections which cannot reserve, this is the full span.
+    #[inline(always)]
+    pub fn capacity_span(&self) -> (Bound<S::Index>, Bound<S::Index>) {
+        self.store.capacity_span()
+    }
+}
+
+#[cfg(any(feature = "alloc", test))]
+impl<C, I, A> IndexChunkedSet<DynamicChunkStore<C, I, A>>
+where
+    C: IndexChunk<Index: DynamicChunkInnerIndex> + IndexCollection,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator + Default,
+{
+    /// Creates a new, empty, instance, with at least `chunks` chunks pre-allocated.
+    #[inline(always)]
+    pub fn with_capacity(chunks: usize) -> Self {
+        Self::with_store(DynamicChunkStore::with_capacity(chunks))
+    }
+}
+
+impl<S> Clone for IndexSet<S>
+where
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        let store = self.store.clone();
+
+        Self { store }
+    }
+
+    /// Delegates to `S::clone_from`, which may reuse the allocations of `self`.
+    fn clone_from(&mut self, source: &Self) {
+        self.store.clone_from(&source.store);
+    }
+}
+
+impl<S> Clone for IndexOrdSet<S>
+where
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        let store = self.store.clone();
+
+        Self { store }
+    }
+
+    /// Delegates to `S::clone_from`, which may reuse the allocations of `self`.
+    fn clone_from(&mut self, source: &Self) {
+        self.store.clone_from(&source.store);
+    }
+}
+
+impl<S> Clone for IndexChunkedSet<S>
+where
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        let store = self.store.clone();
+
+        Self { store }
+    }
+
+    /// Delegates to `S::clone_from`, which may reuse the allocations of `self`.
+    fn clone_from(&mut self, source: &Self) {
+        self.store.clone_from(&source.store);
+    }
+}
+
+//  A fallback for stores which are not `IndexForward` would require specialization; `HashSet` has a dedicated
+//  implementation instead, any other such store leaves its set without `Debug`.
+impl<S> fmt::Debug for IndexSet<S>
+where
+    S: IndexForward<Index: fmt::Debug>,
+{
+    /// Formats the indexes of the set, as `{1, 2, 3, 5}`, rather than the internals of the store.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<S> fmt::Debug for IndexOrdSet<S>
+where
+    S: IndexForward<Index: fmt::Debug>,
+{
+    /// Formats the indexes of the set, as `{1, 2, 3, 5}`, rather than the internals of the store.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<S> fmt::Debug for IndexChunkedSet<S>
+where
+    S: IndexForward<Index: fmt::Debug>,
+{
+    /// Formats the indexes of the set, as `{1, 2, 3, 5}`, rather than the internals of the store.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
 }
 
 impl<S> Default for IndexSet<S>
@@ -187,6 +355,118 @@ where
     }
 }
 
+impl<S> IndexSet<S>
+where
+    S: IndexCollection + IndexStore<InsertionError = Never>,
+{
+    /// Creates a new instance from an iterator over references to indexes.
+    ///
+    /// `FromIterator<&A>` cannot be implemented alongside `FromIterator<A>`: as a reference to an index may itself be
+    /// an index, the two implementations would overlap, see `extend_copied`.
+    pub fn from_iter_copied<'a, I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = &'a S::Index>,
+        S::Index: 'a,
+    {
+        Self::from_iter(iter.into_iter().copied())
+    }
+}
+
+impl<S> IndexSet<S>
+where
+    S: IndexCollection + IndexStore,
+{
+    /// Creates a new instance from an iterator over indexes, stopping at the first insertion error.
+    ///
+    /// Unlike `FromIterator`, this is available for stores whose insertion may fail, such as fixed-capacity stores.
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, S::InsertionError>
+    where
+        I: IntoIterator<Item = S::Index>,
+    {
+        let mut this = Self::new();
+
+        this.try_extend(iter)?;
+
+        Ok(this)
+    }
+}
+
+impl<A, S, const N: usize> From<[A; N]> for IndexSet<S>
+where
+    S: IndexCollection<Index = A> + IndexStore<Index = A, InsertionError = Never>,
+{
+    fn from(indexes: [A; N]) -> Self {
+        Self::from_iter(indexes)
+    }
+}
+
+impl<A, S> TryFrom<&[A]> for IndexSet<S>
+where
+    A: Copy,
+    S: IndexCollection<Index = A> + IndexStore<Index = A>,
+{
+    type Error = S::InsertionError;
+
+    /// Creates a new instance from a slice of indexes, stopping at the first insertion error.
+    fn try_from(indexes: &[A]) -> Result<Self, Self::Error> {
+        Self::try_from_iter(indexes.iter().copied())
+    }
+}
+
+impl<S> IndexSet<S>
+where
+    S: IndexForward + IndexVault,
+{
+    /// Creates a new instance from the original store, checking the store invariants in debug mode.
+    ///
+    /// This is a testing & debugging aid for custom `IndexStore` implementations: in debug mode, the store is walked
+    /// to check that each yielded index is contained, that no index is yielded twice, and that the number of yielded
+    /// indexes matches `len()`. The store must be an `IndexVault`, as only a vault promises to yield all its indexes,
+    /// and thus `len()` of them.
+    ///
+    /// The walk is linear in the number of indexes: as `next_after` only depends on the current index, a duplicate
+    /// index makes the walk cycle, hence duplicates are only searched for once more than `len()` indexes are yielded.
+    ///
+    /// In release mode, this is equivalent to `with_store`.
+    ///
+    /// #   Panics
+    ///
+    /// In debug mode, if the store violates any of the above invariants.
+    pub fn from_store_checked(store: S) -> Self {
+        if cfg!(debug_assertions) {
+            let len = store.len();
+            let mut yielded = 0;
+            let mut current = store.first();
+
+            while let Some(index) = current {
+                assert!(store.contains(index), "phantom index");
+
+                yielded += 1;
+
+                if yielded > len {
+                    let mut previous = store.first();
+
+                    for _ in 0..len {
+                        let Some(p) = previous else { break };
+
+                        assert!(p != index, "duplicate index");
+
+                        previous = store.next_after(p);
+                    }
+
+                    panic!("more indexes yielded than len() ({len})");
+                }
+
+                current = store.next_after(index);
+            }
+
+            assert_eq!(len, yielded, "fewer indexes yielded than len()");
+        }
+
+        Self { store }
+    }
+}
+
 impl<A, S> FromIterator<A> for IndexOrdSet<S>
 where
     S: IndexCollection<Index = A> + IndexOrdered<Index = A> + IndexStore<Index = A, InsertionError = Never>,
@@ -203,6 +483,136 @@ where
     }
 }
 
+impl<S> IndexOrdSet<S>
+where
+    S: IndexCollection + IndexOrdered + IndexStore<InsertionError = Never>,
+{
+    /// Creates a new instance from an iterator over references to indexes.
+    ///
+    /// `FromIterator<&A>` cannot be implemented alongside `FromIterator<A>`: as a reference to an index may itself be
+    /// an index, the two implementations would overlap, see `extend_copied`.
+    pub fn from_iter_copied<'a, I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = &'a S::Index>,
+        S::Index: 'a,
+    {
+        Self::from_iter(iter.into_iter().copied())
+    }
+}
+
+impl<S> IndexOrdSet<S>
+where
+    S: IndexCollection + IndexOrdered + IndexStore,
+{
+    /// Creates a new instance from an iterator over indexes, stopping at the first insertion error.
+    ///
+    /// Unlike `FromIterator`, this is available for stores whose insertion may fail, such as fixed-capacity stores.
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, S::InsertionError>
+    where
+        I: IntoIterator<Item = S::Index>,
+    {
+        let mut this = Self::new();
+
+        this.try_extend(iter)?;
+
+        Ok(this)
+    }
+}
+
+impl<A, S, const N: usize> From<[A; N]> for IndexOrdSet<S>
+where
+    S: IndexCollection<Index = A> + IndexOrdered<Index = A> + IndexStore<Index = A, InsertionError = Never>,
+{
+    fn from(indexes: [A; N]) -> Self {
+        Self::from_iter(indexes)
+    }
+}
+
+impl<A, S> TryFrom<&[A]> for IndexOrdSet<S>
+where
+    A: Copy,
+    S: IndexCollection<Index = A> + IndexOrdered<Index = A> + IndexStore<Index = A>,
+{
+    type Error = S::InsertionError;
+
+    /// Creates a new instance from a slice of indexes, stopping at the first insertion error.
+    fn try_from(indexes: &[A]) -> Result<Self, Self::Error> {
+        Self::try_from_iter(indexes.iter().copied())
+    }
+}
+
+impl<S> IndexOrdSet<S>
+where
+    S: IndexCollection + IndexOrdered + IndexStore<InsertionError = Never>,
+{
+    /// Creates a new instance from an iterator over indexes in ascending order.
+    ///
+    /// #   Panics
+    ///
+    /// In debug mode, if `iter` does not yield indexes in ascending order.
+    pub fn from_sorted_iter_checked<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = S::Index>,
+    {
+        let mut this = Self::new();
+        let mut previous = None;
+
+        for index in iter {
+            debug_assert!(previous.is_none_or(|previous| previous <= index), "unsorted input");
+
+            previous = Some(index);
+
+            let _ = this.insert(index);
+        }
+
+        this
+    }
+}
+
+impl<S> IndexOrdSet<S>
+where
+    S: IndexOrdered + IndexVault,
+{
+    /// Creates a new instance from the original store, checking the store invariants in debug mode.
+    ///
+    /// This is a testing & debugging aid for custom `IndexStore` implementations: in debug mode, the store is walked
+    /// to check that each yielded index is contained, that indexes are yielded in strictly increasing order, and that
+    /// the number of yielded indexes matches `len()`.
+    ///
+    /// In release mode, this is equivalent to `with_store`.
+    ///
+    /// #   Panics
+    ///
+    /// In debug mode, if the store violates any of the above invariants.
+    pub fn from_store_checked(store: S) -> Self {
+        if cfg!(debug_assertions) {
+            let len = store.len();
+            let mut yielded = 0;
+            let mut previous = None;
+            let mut current = store.first();
+
+            while let Some(index) = current {
+                assert!(store.contains(index), "phantom index");
+                assert!(
+                    previous.is_none_or(|previous| previous < index),
+                    "unordered or duplicate index"
+                );
+
+                yielded += 1;
+
+                assert!(yielded <= len, "more indexes yielded than len() ({len})");
+
+                previous = Some(index);
+                current = store.next_after(index);
+            }
+
+            assert_eq!(len, yielded, "fewer indexes yielded than len()");
+        }
+
+        Self { store }
+    }
+}
+
 impl<A, S> FromIterator<A> for IndexChunkedSet<S>
 where
     S: IndexCollection<Index = A> + IndexOrderedChunked<Index = A> + IndexStore<Index = A, InsertionError = Never>,
@@ -219,6 +629,26 @@ where
     }
 }
 
+impl<S> IndexChunkedSet<S>
+where
+    S: IndexCollection + IndexOrderedChunked + IndexStore<InsertionError = Never>,
+{
+    /// Creates a new instance from an iterator over references to indexes.
+    ///
+    /// `FromIterator<&A>` cannot be implemented alongside `FromIterator<A>`: as a reference to an index may itself be
+    /// an index, the two implementations would overlap, see `extend_copied`.
+    pub fn from_iter_copied<'a, I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = &'a S::Index>,
+        S::Index: 'a,
+    {
+        Self::from_iter(iter.into_iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod clone_tests;
+
 #[cfg(test)]
 mod construction_tests;
 
@@ -293,6 +723,21 @@ impl<S> IndexSet<S> {
     }
 }
 
+impl<S> IndexSet<S>
+where
+    S: IndexViewNot,
+{
+    /// Returns the complement of the set, ie a set of all the indexes not contained in `self`.
+    ///
+    /// Only available for stores with a finite complement, which implement `IndexViewNot`. Iterating over the
+    /// complement walks the absent indexes of `self`.
+    pub fn complement(&self) -> IndexSet<NotView<&S>> {
+        IndexSet {
+            store: NotView::new(&self.store),
+        }
+    }
+}
+
 impl<S> IndexOrdSet<S> {
     /// Returns a negated reference.
     pub fn as_not(&self) -> NotView<&S> {
@@ -305,6 +750,68 @@ impl<S> IndexOrdSet<S> {
     }
 }
 
+impl<S> IndexOrdSet<S>
+where
+    S: IndexOrderedNot,
+{
+    /// Returns the complement of the set, ie a set of all the indexes not contained in `self`.
+    ///
+    /// Only available for stores with a finite complement, which implement `IndexOrderedNot`. Iterating over the
+    /// complement walks the absent indexes of `self`.
+    pub fn complement(&self) -> IndexOrdSet<NotView<&S>> {
+        IndexOrdSet {
+            store: NotView::new(&self.store),
+        }
+    }
+
+    /// Returns the complement of the set within `span`, ie a set of all the indexes within `span` not contained in
+    /// `self`.
+    ///
+    /// Unlike `complement`, the length and iteration of the result are bounded by `span`, making it practical for
+    /// stores whose complement is, for all intents and purposes, infinite, such as `DynamicChunkStore`.
+    pub fn complement_within<R>(&self, span: R) -> IndexOrdSet<NotWithin<&S>>
+    where
+        R: RangeBounds<S::Index>,
+    {
+        IndexOrdSet {
+            store: NotView::new(&self.store).within(span),
+        }
+    }
+
+    /// Returns the first index not before `start` which is not contained in the set, if any.
+    ///
+    /// This is the first free slot when using the set as an allocation bitmap. Chunked stores, such as
+    /// `DynamicChunkStore`, skip over full chunks at once.
+    ///
+    /// The complement of a store only extends to its capacity, hence if the store is full up to its capacity, the
+    /// first index past its capacity is returned, if within its span.
+    pub fn first_gap_from(&self, start: S::Index) -> Option<S::Index>
+    where
+        S: IndexCollection<Index: TryFrom<u128>>,
+        u128: TryFrom<S::Index>,
+    {
+        //  Rather than `NotWithin`, which probes the indexes past the capacity of the store one at a time.
+        let gap = if self.store.contains(start) {
+            self.store.next_after_not(start)
+        } else {
+            Some(start)
+        };
+
+        if gap.is_some() {
+            return gap;
+        }
+
+        let end = match self.store.capacity_span().1 {
+            //  The conversions only fail if `last` is the last index of the index space, leaving no room for a gap.
+            Bound::Included(last) => S::Index::try_from(u128::try_from(last).ok()?.checked_add(1)?).ok()?,
+            Bound::Excluded(end) => end,
+            Bound::Unbounded => return None,
+        };
+
+        Some(cmp::max(start, end)).filter(|gap| S::span().contains(gap))
+    }
+}
+
 impl<S> IndexChunkedSet<S> {
     /// Returns a negated reference.
     pub fn as_not(&self) -> NotView<&S> {
@@ -317,11 +824,26 @@ impl<S> IndexChunkedSet<S> {
     }
 }
 
-#[cfg(test)]
-mod not_tests;
-
-//
-//  View Operations.
+impl<S> IndexChunkedSet<S>
+where
+    S: IndexOrderedChunkedNot,
+{
+    /// Returns the complement of the set, ie a set of all the indexes not contained in `self`.
+    ///
+    /// Only available for stores with a finite complement, which implement `IndexOrderedChunkedNot`. Iterating over the
+    /// complement walks the absent indexes of `self`.
+    pub fn complement(&self) -> IndexChunkedSet<NotView<&S>> {
+        IndexChunkedSet {
+            store: NotView::new(&self.store),
+        }
+    }
+}
+
+#[cfg(test)]
+mod not_tests;
+
+//
+//  View Operations.
 //
 
 impl<S> IndexSet<S>
@@ -364,6 +886,70 @@ where
     }
 }
 
+impl<S> IndexOrdSet<S>
+where
+    S: IndexForward,
+{
+    /// Returns the first index of the set, if any.
+    pub fn first(&self) -> Option<S::Index> {
+        self.store.first()
+    }
+}
+
+impl<S> IndexOrdSet<S>
+where
+    S: IndexBackward,
+{
+    /// Returns the last index of the set, if any.
+    pub fn last(&self) -> Option<S::Index> {
+        self.store.last()
+    }
+}
+
+impl<S> IndexOrdSet<S>
+where
+    S: IndexOrdered + IndexBackward,
+{
+    /// Returns the minimum and maximum indexes of the set, if any.
+    ///
+    /// The set being ordered, the minimum and maximum indexes are `first` and `last`, respectively.
+    ///
+    /// This is the range of indexes actually occupied, as opposed to `span`, the range of indexes which may be
+    /// inserted.
+    pub fn bounds(&self) -> Option<(S::Index, S::Index)> {
+        self.store.bounds()
+    }
+}
+
+impl<S> IndexOrdSet<S>
+where
+    S: IndexOrdered,
+{
+    /// Returns the `n`-th smallest index of the set, if any, counting from 0.
+    ///
+    /// For any index `i` of the set, `set.select(set.rank(i)) == Some(i)`.
+    pub fn select(&self, n: usize) -> Option<S::Index> {
+        self.iter().nth(n)
+    }
+}
+
+impl<S> IndexOrdSet<S>
+where
+    S: IndexOrderedStore,
+{
+    /// Returns the number of indexes of the set strictly less than `index`, as computed by the store.
+    pub fn rank(&self, index: S::Index) -> usize {
+        self.store.rank(index)
+    }
+
+    /// Inserts the index in the set, returns whether it is newly inserted, and its rank.
+    ///
+    /// The rank is the number of indexes of the set strictly less than `index`, as computed by the store.
+    pub fn insert_ranked(&mut self, index: S::Index) -> Result<(bool, usize), S::InsertionError> {
+        self.store.insert_ranked(index)
+    }
+}
+
 impl<S> IndexChunkedSet<S>
 where
     S: IndexView,
@@ -394,6 +980,73 @@ where
     }
 }
 
+impl<S> IndexChunkedSet<S>
+where
+    S: IndexForwardChunked,
+{
+    /// Returns the number of chunks holding at least one index.
+    ///
+    /// Compared to `len`, this gauges how sparse the set is, for example to decide when to switch representations.
+    pub fn populated_chunks(&self) -> usize {
+        self.store.populated_chunks()
+    }
+}
+
+impl<S> IndexChunkedSet<S>
+where
+    S: IndexOrderedChunked<Chunk: IndexForward>,
+{
+    /// Returns the `n`-th smallest index of the set, if any, counting from 0.
+    ///
+    /// Skips over whole chunks according to their number of indexes, rather than iterating over indexes.
+    ///
+    /// For any index `i` of the set, `set.select(set.rank(i)) == Some(i)`.
+    pub fn select(&self, mut n: usize) -> Option<S::Index> {
+        let mut current = self.store.first_chunk();
+
+        while let Some(chunk_index) = current {
+            let chunk = self.store.get_chunk(chunk_index).unwrap_or_default();
+            let len = chunk.len();
+
+            if n < len {
+                let first = chunk.first()?;
+
+                let inner = match n.checked_sub(1) {
+                    None => first,
+                    Some(n) => chunk.nth_after(n, first).ok()?,
+                };
+
+                return Some(S::fuse(chunk_index, inner));
+            }
+
+            n -= len;
+            current = self.store.next_chunk_after(chunk_index);
+        }
+
+        None
+    }
+}
+
+impl<S> IndexChunkedSet<S>
+where
+    S: IndexOrderedStore,
+{
+    /// Returns the number of indexes of the set strictly less than `index`, as computed by the store.
+    ///
+    /// Chunked stores sum the number of indexes of each chunk before the chunk of `index`, rather than iterating
+    /// over indexes.
+    pub fn rank(&self, index: S::Index) -> usize {
+        self.store.rank(index)
+    }
+
+    /// Inserts the index in the set, returns whether it is newly inserted, and its rank.
+    ///
+    /// The rank is the number of indexes of the set strictly less than `index`, as computed by the store.
+    pub fn insert_ranked(&mut self, index: S::Index) -> Result<(bool, usize), S::InsertionError> {
+        self.store.insert_ranked(index)
+    }
+}
+
 #[cfg(test)]
 mod view_tests;
 
@@ -415,10 +1068,43 @@ where
         self.store.insert(index)
     }
 
+    /// Inserts the index in the set, then calls `f` with whether it is newly inserted, and returns its result.
+    ///
+    /// This is typically used to perform additional initialization work only when an index is first inserted.
+    pub fn insert_then<R, F>(&mut self, index: S::Index, f: F) -> Result<R, S::InsertionError>
+    where
+        F: FnOnce(bool) -> R,
+    {
+        self.store.insert(index).map(f)
+    }
+
+    /// Inserts the index in the set if not already present, returns the index.
+    ///
+    /// This is a shortcut for the common case of ensuring an index is present, without going through `entry`.
+    pub fn get_or_insert(&mut self, index: S::Index) -> Result<S::Index, S::InsertionError> {
+        self.store.insert(index).map(|_| index)
+    }
+
     /// Removes the index from the set, returns whether it was in the set prior to removal.
     pub fn remove(&mut self, index: S::Index) -> bool {
         self.store.remove(index)
     }
+
+    /// Toggles the index in the set, returns whether it is in the set after toggling.
+    pub fn toggle(&mut self, index: S::Index) -> Result<bool, S::InsertionError> {
+        self.store.toggle(index)
+    }
+
+    /// Calls `f` with whether the index is in the set, then inserts or removes the index so that it is in the set if
+    /// and only if `f` returned `true`, returns whether the set changed.
+    ///
+    /// Unlike `contains` followed by `insert` or `remove`, the store may only be probed once.
+    pub fn replace_with<F>(&mut self, index: S::Index, f: F) -> Result<bool, S::InsertionError>
+    where
+        F: FnOnce(bool) -> bool,
+    {
+        self.store.replace_with(index, f)
+    }
 }
 
 impl<S> IndexOrdSet<S>
@@ -435,10 +1121,43 @@ where
         self.store.insert(index)
     }
 
+    /// Inserts the index in the set, then calls `f` with whether it is newly inserted, and returns its result.
+    ///
+    /// This is typically used to perform additional initialization work only when an index is first inserted.
+    pub fn insert_then<R, F>(&mut self, index: S::Index, f: F) -> Result<R, S::InsertionError>
+    where
+        F: FnOnce(bool) -> R,
+    {
+        self.store.insert(index).map(f)
+    }
+
+    /// Inserts the index in the set if not already present, returns the index.
+    ///
+    /// This is a shortcut for the common case of ensuring an index is present, without going through `entry`.
+    pub fn get_or_insert(&mut self, index: S::Index) -> Result<S::Index, S::InsertionError> {
+        self.store.insert(index).map(|_| index)
+    }
+
     /// Removes the index from the set, returns whether it was in the set prior to removal.
     pub fn remove(&mut self, index: S::Index) -> bool {
         self.store.remove(index)
     }
+
+    /// Toggles the index in the set, returns whether it is in the set after toggling.
+    pub fn toggle(&mut self, index: S::Index) -> Result<bool, S::InsertionError> {
+        self.store.toggle(index)
+    }
+
+    /// Calls `f` with whether the index is in the set, then inserts or removes the index so that it is in the set if
+    /// and only if `f` returned `true`, returns whether the set changed.
+    ///
+    /// Unlike `contains` followed by `insert` or `remove`, the store may only be probed once.
+    pub fn replace_with<F>(&mut self, index: S::Index, f: F) -> Result<bool, S::InsertionError>
+    where
+        F: FnOnce(bool) -> bool,
+    {
+        self.store.replace_with(index, f)
+    }
 }
 
 impl<S> IndexChunkedSet<S>
@@ -455,384 +1174,365 @@ where
         self.store.insert(index)
     }
 
-    /// Removes the index from the set, returns whether it was in the set prior to removal.
-    pub fn remove(&mut self, index: S::Index) -> bool {
-        self.store.remove(index)
-    }
-}
-
-impl<A, S> Extend<A> for IndexSet<S>
-where
-    S: IndexStore<Index = A, InsertionError = Never>,
-{
-    fn extend<I>(&mut self, iter: I)
+    /// Inserts the index in the set, then calls `f` with whether it is newly inserted, and returns its result.
+    ///
+    /// This is typically used to perform additional initialization work only when an index is first inserted.
+    pub fn insert_then<R, F>(&mut self, index: S::Index, f: F) -> Result<R, S::InsertionError>
     where
-        I: IntoIterator<Item = A>,
+        F: FnOnce(bool) -> R,
     {
-        for index in iter {
-            let _ = self.insert(index);
-        }
+        self.store.insert(index).map(f)
     }
 
-    #[cfg(feature = "nightly")]
-    fn extend_one(&mut self, index: A) {
-        let _ = self.insert(index);
+    /// Inserts the index in the set if not already present, returns the index.
+    ///
+    /// This is a shortcut for the common case of ensuring an index is present, without going through `entry`.
+    pub fn get_or_insert(&mut self, index: S::Index) -> Result<S::Index, S::InsertionError> {
+        self.store.insert(index).map(|_| index)
     }
-}
 
-impl<A, S> Extend<A> for IndexOrdSet<S>
-where
-    S: IndexStore<Index = A, InsertionError = Never>,
-{
-    fn extend<I>(&mut self, iter: I)
-    where
-        I: IntoIterator<Item = A>,
-    {
-        for index in iter {
-            let _ = self.insert(index);
-        }
+    /// Removes the index from the set, returns whether it was in the set prior to removal.
+    pub fn remove(&mut self, index: S::Index) -> bool {
+        self.store.remove(index)
     }
 
-    #[cfg(feature = "nightly")]
-    fn extend_one(&mut self, index: A) {
-        let _ = self.insert(index);
+    /// Toggles the index in the set, returns whether it is in the set after toggling.
+    pub fn toggle(&mut self, index: S::Index) -> Result<bool, S::InsertionError> {
+        self.store.toggle(index)
     }
-}
 
-impl<A, S> Extend<A> for IndexChunkedSet<S>
-where
-    S: IndexStore<Index = A, InsertionError = Never>,
-{
-    fn extend<I>(&mut self, iter: I)
+    /// Calls `f` with whether the index is in the set, then inserts or removes the index so that it is in the set if
+    /// and only if `f` returned `true`, returns whether the set changed.
+    ///
+    /// Unlike `contains` followed by `insert` or `remove`, the store may only be probed once.
+    pub fn replace_with<F>(&mut self, index: S::Index, f: F) -> Result<bool, S::InsertionError>
     where
-        I: IntoIterator<Item = A>,
+        F: FnOnce(bool) -> bool,
     {
-        for index in iter {
-            let _ = self.insert(index);
-        }
-    }
-
-    #[cfg(feature = "nightly")]
-    fn extend_one(&mut self, index: A) {
-        let _ = self.insert(index);
+        self.store.replace_with(index, f)
     }
 }
 
-#[cfg(test)]
-mod store_tests;
-
-//
-//  Inclusion operations.
-//
-
 impl<S> IndexSet<S>
 where
-    S: IndexForward,
+    S: IndexStoreRange,
 {
-    /// Returns whether `self` and `other` are disjoint, ie do not have any index in common.
-    pub fn is_disjoint<OS>(&self, other: &IndexSet<OS>) -> bool
-    where
-        OS: IndexView<Index = S::Index>,
-    {
-        self.iter().all(|index| !other.contains(index))
-    }
-
-    /// Returns whether `self` is a subset of `other`, ie whether all elements of `self` are contained in `other`.
+    /// Inserts all indexes within `range` in the set, returns the number of newly inserted indexes.
     ///
-    /// If `self` is a subset of `other`, then `other` is a superset of `self`, and vice-versa.
-    pub fn is_subset<OS>(&self, other: &IndexSet<OS>) -> bool
+    /// Bitmap-like stores insert whole words at once, rather than one index at a time.
+    ///
+    /// #   Panics
+    ///
+    /// If the store panics, as `DynamicChunkStore` does on a range with an unbounded end.
+    pub fn insert_range<R>(&mut self, range: R) -> Result<usize, S::InsertionError>
     where
-        OS: IndexView<Index = S::Index>,
+        R: RangeBounds<S::Index>,
     {
-        self.iter().all(|index| other.contains(index))
+        self.store.insert_range(range)
     }
 
-    /// Returns whether `self` is a superset of `other`, ie whether all elements of `other` are contained in `self`.
+    /// Removes all indexes within `range` from the set, returns the number of indexes removed.
     ///
-    /// If `self` is a superset of `other`, then `other` is a subset of `self`, and vice-versa.
-    pub fn is_superset<OS>(&self, other: &IndexSet<OS>) -> bool
+    /// Bitmap-like stores remove whole words at once, rather than one index at a time.
+    pub fn remove_range<R>(&mut self, range: R) -> usize
     where
-        OS: IndexForward<Index = S::Index>,
+        R: RangeBounds<S::Index>,
     {
-        other.is_subset(self)
+        self.store.remove_range(range)
     }
 }
 
 impl<S> IndexOrdSet<S>
 where
-    S: IndexForward,
+    S: IndexStoreRange,
 {
-    /// Returns whether `self` and `other` are disjoint, ie do not have any index in common.
-    pub fn is_disjoint<OS>(&self, other: &IndexOrdSet<OS>) -> bool
+    /// Inserts all indexes within `range` in the set, returns the number of newly inserted indexes.
+    ///
+    /// Bitmap-like stores insert whole words at once, rather than one index at a time.
+    ///
+    /// #   Panics
+    ///
+    /// If the store panics, as `DynamicChunkStore` does on a range with an unbounded end.
+    pub fn insert_range<R>(&mut self, range: R) -> Result<usize, S::InsertionError>
     where
-        OS: IndexView<Index = S::Index>,
+        R: RangeBounds<S::Index>,
     {
-        self.iter().all(|index| !other.contains(index))
+        self.store.insert_range(range)
     }
 
-    /// Returns whether `self` is a subset of `other`, ie whether all elements of `self` are contained in `other`.
+    /// Removes all indexes within `range` from the set, returns the number of indexes removed.
     ///
-    /// If `self` is a subset of `other`, then `other` is a superset of `self`, and vice-versa.
-    pub fn is_subset<OS>(&self, other: &IndexOrdSet<OS>) -> bool
+    /// Bitmap-like stores remove whole words at once, rather than one index at a time.
+    pub fn remove_range<R>(&mut self, range: R) -> usize
     where
-        OS: IndexView<Index = S::Index>,
+        R: RangeBounds<S::Index>,
     {
-        self.iter().all(|index| other.contains(index))
+        self.store.remove_range(range)
     }
 
-    /// Returns whether `self` is a superset of `other`, ie whether all elements of `other` are contained in `self`.
+    /// Removes all indexes strictly less than `lo` or strictly greater than `hi`, returns the number of indexes
+    /// removed.
     ///
-    /// If `self` is a superset of `other`, then `other` is a subset of `self`, and vice-versa.
-    pub fn is_superset<OS>(&self, other: &IndexOrdSet<OS>) -> bool
-    where
-        OS: IndexForward<Index = S::Index>,
-    {
-        other.is_subset(self)
+    /// This is equivalent to intersecting the set with `lo..=hi`.
+    pub fn shrink_bounds(&mut self, lo: S::Index, hi: S::Index) -> usize {
+        self.remove_range(..lo) + self.remove_range((Bound::Excluded(hi), Bound::Unbounded))
     }
 }
 
 impl<S> IndexChunkedSet<S>
 where
-    S: IndexForwardChunked,
+    S: IndexStoreRange,
 {
-    /// Returns whether `self` and `other` are disjoint, ie do not have any index in common.
-    pub fn is_disjoint<OS>(&self, other: &IndexChunkedSet<OS>) -> bool
+    /// Inserts all indexes within `range` in the set, returns the number of newly inserted indexes.
+    ///
+    /// Bitmap-like stores insert whole words at once, rather than one index at a time.
+    ///
+    /// #   Panics
+    ///
+    /// If the store panics, as `DynamicChunkStore` does on a range with an unbounded end.
+    pub fn insert_range<R>(&mut self, range: R) -> Result<usize, S::InsertionError>
     where
-        OS: IndexViewChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+        R: RangeBounds<S::Index>,
     {
-        self.iter_chunks().all(|outer| {
-            let Some(chunk) = self.store.get_chunk(outer) else {
-                return true;
-            };
-
-            chunk.is_empty() || other.get_chunk(outer).is_none_or(|c| (chunk & c).is_empty())
-        })
+        self.store.insert_range(range)
     }
 
-    /// Returns whether `self` is a subset of `other`, ie whether all elements of `self` are contained in `other`.
+    /// Removes all indexes within `range` from the set, returns the number of indexes removed.
     ///
-    /// If `self` is a subset of `other`, then `other` is a superset of `self`, and vice-versa.
-    pub fn is_subset<OS>(&self, other: &IndexChunkedSet<OS>) -> bool
+    /// Bitmap-like stores remove whole words at once, rather than one index at a time.
+    pub fn remove_range<R>(&mut self, range: R) -> usize
     where
-        OS: IndexViewChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+        R: RangeBounds<S::Index>,
     {
-        self.iter_chunks().all(|outer| {
-            let Some(chunk) = self.store.get_chunk(outer) else {
-                return true;
-            };
-
-            chunk.is_empty() || other.get_chunk(outer).is_some_and(|c| (chunk & c) == chunk)
-        })
+        self.store.remove_range(range)
     }
 
-    /// Returns whether `self` is a superset of `other`, ie whether all elements of `other` are contained in `self`.
+    /// Removes all indexes strictly less than `lo` or strictly greater than `hi`, returns the number of indexes
+    /// removed.
     ///
-    /// If `self` is a superset of `other`, then `other` is a subset of `self`, and vice-versa.
-    pub fn is_superset<OS>(&self, other: &IndexChunkedSet<OS>) -> bool
-    where
-        OS: IndexForwardChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
-    {
-        other.is_subset(self)
+    /// This is equivalent to intersecting the set with `lo..=hi`.
+    pub fn shrink_bounds(&mut self, lo: S::Index, hi: S::Index) -> usize {
+        self.remove_range(..lo) + self.remove_range((Bound::Excluded(hi), Bound::Unbounded))
     }
 }
 
-#[cfg(test)]
-mod inclusion_tests;
+impl<S> IndexOrdSet<S>
+where
+    S: IndexForward + IndexStore,
+{
+    /// Removes the first index of the set, and returns it, if any.
+    pub fn pop_first(&mut self) -> Option<S::Index> {
+        let index = self.store.first()?;
 
-//
-//  Entry API.
-//
+        let removed = self.store.remove(index);
 
-impl<S> IndexSet<S>
+        debug_assert!(removed);
+
+        Some(index)
+    }
+}
+
+impl<S> IndexOrdSet<S>
 where
-    S: IndexStore,
+    S: IndexBackward + IndexStore,
 {
-    /// Returns the entry.
-    pub fn entry(&mut self, index: S::Index) -> Entry<'_, S::Index, S> {
-        if self.contains(index) {
-            Entry::Occupied(OccupiedEntry {
-                index,
-                store: &mut self.store,
-            })
-        } else {
-            Entry::Vacant(VacantEntry {
-                index,
-                store: &mut self.store,
-            })
-        }
+    /// Removes the last index of the set, and returns it, if any.
+    pub fn pop_last(&mut self) -> Option<S::Index> {
+        let index = self.store.last()?;
+
+        let removed = self.store.remove(index);
+
+        debug_assert!(removed);
+
+        Some(index)
     }
 }
 
-impl<S> IndexOrdSet<S>
+impl<S> IndexSet<S>
 where
-    S: IndexStore,
+    S: IndexStore<InsertionError = Never>,
 {
-    /// Returns the entry.
-    pub fn entry(&mut self, index: S::Index) -> Entry<'_, S::Index, S> {
-        if self.contains(index) {
-            Entry::Occupied(OccupiedEntry {
-                index,
-                store: &mut self.store,
-            })
-        } else {
-            Entry::Vacant(VacantEntry {
-                index,
-                store: &mut self.store,
-            })
-        }
+    /// Extends the set with the indexes referenced by `iter`.
+    ///
+    /// `Extend<&A>` cannot be implemented alongside `Extend<A>`: as a reference to an index may itself be an index,
+    /// the two implementations would overlap.
+    pub fn extend_copied<'a, I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = &'a S::Index>,
+        S::Index: 'a,
+    {
+        self.extend(iter.into_iter().copied());
     }
 }
 
-impl<S> IndexChunkedSet<S>
+impl<S> IndexSet<S>
 where
     S: IndexStore,
 {
-    /// Returns the entry.
-    pub fn entry(&mut self, index: S::Index) -> Entry<'_, S::Index, S> {
-        if self.contains(index) {
-            Entry::Occupied(OccupiedEntry {
-                index,
-                store: &mut self.store,
-            })
-        } else {
-            Entry::Vacant(VacantEntry {
-                index,
-                store: &mut self.store,
-            })
+    /// Extends the set with the indexes of `iter`, stopping at the first insertion error.
+    ///
+    /// Unlike `Extend`, this is available for stores whose insertion may fail, such as fixed-capacity stores. The
+    /// indexes inserted prior to the error remain in the set.
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), S::InsertionError>
+    where
+        I: IntoIterator<Item = S::Index>,
+    {
+        for index in iter {
+            self.insert(index)?;
         }
-    }
-}
 
-/// A view into a single entry in a set, which may either be vacant or occupied.
-pub enum Entry<'a, I, S> {
-    /// An occupied entry.
-    Occupied(OccupiedEntry<'a, I, S>),
-    /// A vacant entry.
-    Vacant(VacantEntry<'a, I, S>),
+        Ok(())
+    }
 }
 
-impl<'a, I, S> Entry<'a, I, S>
+impl<A, S> Extend<A> for IndexSet<S>
 where
-    I: Copy,
-    S: IndexStore<Index = I>,
+    S: IndexStore<Index = A, InsertionError = Never>,
 {
-    /// Returns the index.
-    pub fn get(&self) -> I {
-        match self {
-            Self::Occupied(o) => o.get(),
-            Self::Vacant(v) => v.get(),
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = A>,
+    {
+        for index in iter {
+            let _ = self.insert(index);
         }
     }
 
-    /// Inserts the index, and returns an `OccupiedEntry`.
-    pub fn insert(self) -> Result<OccupiedEntry<'a, I, S>, S::InsertionError> {
-        match self {
-            Self::Occupied(o) => Ok(o),
-            Self::Vacant(VacantEntry { index, store }) => {
-                let _inserted = store.insert(index)?;
-
-                debug_assert!(_inserted);
+    #[cfg(feature = "nightly")]
+    fn extend_one(&mut self, index: A) {
+        let _ = self.insert(index);
+    }
+}
 
-                Ok(OccupiedEntry { index, store })
-            }
-        }
+impl<S> IndexOrdSet<S>
+where
+    S: IndexStore<InsertionError = Never>,
+{
+    /// Extends the set with the indexes referenced by `iter`.
+    ///
+    /// `Extend<&A>` cannot be implemented alongside `Extend<A>`: as a reference to an index may itself be an index,
+    /// the two implementations would overlap.
+    pub fn extend_copied<'a, I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = &'a S::Index>,
+        S::Index: 'a,
+    {
+        self.extend(iter.into_iter().copied());
     }
+}
 
-    /// Ensures the index is in the set.
-    pub fn or_insert(self) -> Result<(), S::InsertionError> {
-        if let Self::Vacant(v) = self {
-            v.insert()?;
+impl<S> IndexOrdSet<S>
+where
+    S: IndexStore,
+{
+    /// Extends the set with the indexes of `iter`, stopping at the first insertion error.
+    ///
+    /// Unlike `Extend`, this is available for stores whose insertion may fail, such as fixed-capacity stores. The
+    /// indexes inserted prior to the error remain in the set.
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), S::InsertionError>
+    where
+        I: IntoIterator<Item = S::Index>,
+    {
+        for index in iter {
+            self.insert(index)?;
         }
 
         Ok(())
     }
 }
 
-/// An occupied entry in a set.
-pub struct OccupiedEntry<'a, I, S> {
-    index: I,
-    store: &'a mut S,
-}
-
-impl<'a, I, S> OccupiedEntry<'a, I, S>
+impl<A, S> Extend<A> for IndexOrdSet<S>
 where
-    I: Copy,
-    S: IndexStore<Index = I>,
+    S: IndexStore<Index = A, InsertionError = Never>,
 {
-    /// Returns the index.
-    pub fn get(&self) -> I {
-        self.index
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = A>,
+    {
+        for index in iter {
+            let _ = self.insert(index);
+        }
     }
 
-    /// Removes the index from the set.
-    pub fn remove(self) -> I {
-        let _removed = self.store.remove(self.index);
-
-        debug_assert!(_removed);
-
-        self.index
+    #[cfg(feature = "nightly")]
+    fn extend_one(&mut self, index: A) {
+        let _ = self.insert(index);
     }
 }
 
-/// A vacant entry in a set.
-pub struct VacantEntry<'a, I, S> {
-    index: I,
-    store: &'a mut S,
-}
-
-impl<'a, I, S> VacantEntry<'a, I, S>
+impl<S> IndexChunkedSet<S>
 where
-    I: Copy,
-    S: IndexStore<Index = I>,
+    S: IndexStore<InsertionError = Never>,
 {
-    /// Returns the index.
-    pub fn get(&self) -> I {
-        self.index
+    /// Extends the set with the indexes referenced by `iter`.
+    ///
+    /// `Extend<&A>` cannot be implemented alongside `Extend<A>`: as a reference to an index may itself be an index,
+    /// the two implementations would overlap.
+    pub fn extend_copied<'a, I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = &'a S::Index>,
+        S::Index: 'a,
+    {
+        self.extend(iter.into_iter().copied());
     }
+}
 
-    /// Consumes the entry and returns the index.
-    pub fn into_value(self) -> I {
-        self.index
+impl<A, S> Extend<A> for IndexChunkedSet<S>
+where
+    S: IndexStore<Index = A, InsertionError = Never>,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = A>,
+    {
+        for index in iter {
+            let _ = self.insert(index);
+        }
     }
 
-    /// Inserts the index in the store.
-    pub fn insert(self) -> Result<(), S::InsertionError> {
-        let _inserted = self.store.insert(self.index)?;
-
-        debug_assert!(_inserted);
-
-        Ok(())
+    #[cfg(feature = "nightly")]
+    fn extend_one(&mut self, index: A) {
+        let _ = self.insert(index);
     }
 }
 
 #[cfg(test)]
-mod entry_tests;
+mod store_tests;
 
 //
-//  Iterator operations: just iteration.
+//  Inclusion operations.
 //
 
 impl<S> IndexSet<S>
 where
     S: IndexForward,
 {
-    /// Returns an iterator over the indexes in the set.
-    pub fn iter(&self) -> Iter<'_, S::Index, S> {
-        Iter {
-            next: self.store.first(),
-            yielded: 0,
-            store: &self.store,
-        }
+    /// Returns whether `self` and `other` are disjoint, ie do not have any index in common.
+    pub fn is_disjoint<OS>(&self, other: &IndexSet<OS>) -> bool
+    where
+        OS: IndexView<Index = S::Index>,
+    {
+        self.iter().all(|index| !other.contains(index))
     }
 
-    /// Returns an iterator over the indexes in the set.
-    #[allow(clippy::should_implement_trait)]
-    pub fn into_iter(self) -> IntoIter<S::Index, S> {
-        IntoIter {
-            next: self.store.first(),
-            yielded: 0,
-            store: self.store,
-        }
+    /// Returns whether `self` is a subset of `other`, ie whether all elements of `self` are contained in `other`.
+    ///
+    /// If `self` is a subset of `other`, then `other` is a superset of `self`, and vice-versa.
+    pub fn is_subset<OS>(&self, other: &IndexSet<OS>) -> bool
+    where
+        OS: IndexView<Index = S::Index>,
+    {
+        self.iter().all(|index| other.contains(index))
+    }
+
+    /// Returns whether `self` is a superset of `other`, ie whether all elements of `other` are contained in `self`.
+    ///
+    /// If `self` is a superset of `other`, then `other` is a subset of `self`, and vice-versa.
+    pub fn is_superset<OS>(&self, other: &IndexSet<OS>) -> bool
+    where
+        OS: IndexForward<Index = S::Index>,
+    {
+        other.is_subset(self)
     }
 }
 
@@ -840,605 +1540,851 @@ impl<S> IndexOrdSet<S>
 where
     S: IndexForward,
 {
-    /// Returns an iterator over the indexes in the set.
-    pub fn iter(&self) -> Iter<'_, S::Index, S> {
-        Iter {
-            next: self.store.first(),
-            yielded: 0,
-            store: &self.store,
-        }
-    }
-
-    /// Returns an iterator over the indexes in the set.
-    #[allow(clippy::should_implement_trait)]
-    pub fn into_iter(self) -> IntoIter<S::Index, S> {
-        IntoIter {
-            next: self.store.first(),
-            yielded: 0,
-            store: self.store,
-        }
+    /// Returns whether `self` and `other` are disjoint, ie do not have any index in common.
+    pub fn is_disjoint<OS>(&self, other: &IndexOrdSet<OS>) -> bool
+    where
+        OS: IndexView<Index = S::Index>,
+    {
+        self.iter().all(|index| !other.contains(index))
     }
-}
 
-impl<S> IndexChunkedSet<S>
-where
-    S: IndexForward,
-{
-    /// Returns an iterator over the indexes in the set.
-    pub fn iter(&self) -> Iter<'_, S::Index, S> {
-        Iter {
-            next: self.store.first(),
-            yielded: 0,
-            store: &self.store,
-        }
+    /// Returns whether `self` is a subset of `other`, ie whether all elements of `self` are contained in `other`.
+    ///
+    /// If `self` is a subset of `other`, then `other` is a superset of `self`, and vice-versa.
+    pub fn is_subset<OS>(&self, other: &IndexOrdSet<OS>) -> bool
+    where
+        OS: IndexView<Index = S::Index>,
+    {
+        self.iter().all(|index| other.contains(index))
     }
 
-    /// Returns an iterator over the indexes in the set.
-    #[allow(clippy::should_implement_trait)]
-    pub fn into_iter(self) -> IntoIter<S::Index, S> {
-        IntoIter {
-            next: self.store.first(),
-            yielded: 0,
-            store: self.store,
-        }
+    /// Returns whether `self` is a superset of `other`, ie whether all elements of `other` are contained in `self`.
+    ///
+    /// If `self` is a superset of `other`, then `other` is a subset of `self`, and vice-versa.
+    pub fn is_superset<OS>(&self, other: &IndexOrdSet<OS>) -> bool
+    where
+        OS: IndexForward<Index = S::Index>,
+    {
+        other.is_subset(self)
     }
 }
 
-impl<S> IndexSet<S>
+impl<S> IndexOrdSet<S>
 where
-    S: IndexBackward,
+    S: IndexOrdered,
 {
-    /// Returns an iterator over the indexes in the set.
-    pub fn iter_rev(&self) -> IterRev<'_, S::Index, S> {
-        IterRev {
-            next: self.store.last(),
-            yielded: 0,
-            store: &self.store,
-        }
-    }
+    /// Returns whether `self` and `other` are disjoint, ie do not have any index in common.
+    ///
+    /// Unlike `is_disjoint`, walks both sets in lockstep rather than probing `other` for each index of `self`, in
+    /// O(n + m), and stops as soon as a common index is found.
+    pub fn is_sorted_disjoint<OS>(&self, other: &IndexOrdSet<OS>) -> bool
+    where
+        OS: IndexOrdered<Index = S::Index>,
+    {
+        let (mut left, mut right) = (self.store.first(), other.store.first());
 
-    /// Returns an iterator over the indexes in the set.
-    pub fn into_iter_rev(self) -> IntoIterRev<S::Index, S> {
-        IntoIterRev {
-            next: self.store.last(),
-            yielded: 0,
-            store: self.store,
+        while let (Some(l), Some(r)) = (left, right) {
+            match l.cmp(&r) {
+                Ordering::Less => left = self.store.next_after(l),
+                Ordering::Greater => right = other.store.next_after(r),
+                Ordering::Equal => return false,
+            }
         }
-    }
-}
 
-impl<S> IndexOrdSet<S>
-where
-    S: IndexBackward,
-{
-    /// Returns an iterator over the indexes in the set.
-    pub fn iter_rev(&self) -> IterRev<'_, S::Index, S> {
-        IterRev {
-            next: self.store.last(),
-            yielded: 0,
-            store: &self.store,
-        }
+        true
     }
 
-    /// Returns an iterator over the indexes in the set.
-    pub fn into_iter_rev(self) -> IntoIterRev<S::Index, S> {
-        IntoIterRev {
-            next: self.store.last(),
-            yielded: 0,
-            store: self.store,
+    /// Returns whether `self` is a subset of `other`, ie whether all elements of `self` are contained in `other`.
+    ///
+    /// Unlike `is_subset`, walks both sets in lockstep rather than probing `other` for each index of `self`, in
+    /// O(n + m), and stops as soon as an index of `self` is found missing from `other`.
+    pub fn is_sorted_subset<OS>(&self, other: &IndexOrdSet<OS>) -> bool
+    where
+        OS: IndexOrdered<Index = S::Index>,
+    {
+        if self.len() > other.len() {
+            return false;
         }
+
+        let mut next = other.store.first();
+
+        self.iter().all(|index| {
+            while let Some(current) = next
+                && current < index
+            {
+                next = other.store.next_after(current);
+            }
+
+            if next != Some(index) {
+                return false;
+            }
+
+            next = other.store.next_after(index);
+
+            true
+        })
     }
 }
 
 impl<S> IndexChunkedSet<S>
 where
-    S: IndexBackward,
+    S: IndexForwardChunked,
 {
-    /// Returns an iterator over the indexes in the set.
-    pub fn iter_rev(&self) -> IterRev<'_, S::Index, S> {
-        IterRev {
-            next: self.store.last(),
-            yielded: 0,
-            store: &self.store,
-        }
+    /// Returns whether `self` and `other` are disjoint, ie do not have any index in common.
+    pub fn is_disjoint<OS>(&self, other: &IndexChunkedSet<OS>) -> bool
+    where
+        OS: IndexViewChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+    {
+        self.iter_chunks().all(|outer| {
+            let Some(chunk) = self.store.get_chunk(outer) else {
+                return true;
+            };
+
+            chunk.is_empty() || other.get_chunk(outer).is_none_or(|c| (chunk & c).is_empty())
+        })
     }
 
-    /// Returns an iterator over the indexes in the set.
-    pub fn into_iter_rev(self) -> IntoIterRev<S::Index, S> {
-        IntoIterRev {
-            next: self.store.last(),
-            yielded: 0,
-            store: self.store,
-        }
+    /// Returns whether `self` is a subset of `other`, ie whether all elements of `self` are contained in `other`.
+    ///
+    /// If `self` is a subset of `other`, then `other` is a superset of `self`, and vice-versa.
+    pub fn is_subset<OS>(&self, other: &IndexChunkedSet<OS>) -> bool
+    where
+        OS: IndexViewChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+    {
+        self.iter_chunks().all(|outer| {
+            let Some(chunk) = self.store.get_chunk(outer) else {
+                return true;
+            };
+
+            chunk.is_empty() || other.get_chunk(outer).is_some_and(|c| (chunk & c) == chunk)
+        })
+    }
+
+    /// Returns whether `self` is a superset of `other`, ie whether all elements of `other` are contained in `self`.
+    ///
+    /// If `self` is a superset of `other`, then `other` is a subset of `self`, and vice-versa.
+    pub fn is_superset<OS>(&self, other: &IndexChunkedSet<OS>) -> bool
+    where
+        OS: IndexForwardChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+    {
+        other.is_subset(self)
     }
 }
 
-impl<'a, S> IntoIterator for &'a IndexSet<S>
+#[cfg(test)]
+mod inclusion_tests;
+
+//
+//  Comparison operations.
+//
+
+impl<S, OS> PartialEq<IndexSet<OS>> for IndexSet<S>
 where
     S: IndexForward,
+    OS: IndexView<Index = S::Index>,
 {
-    type Item = S::Index;
-    type IntoIter = Iter<'a, S::Index, S>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+    /// Returns whether `self` and `other` contain the same indexes, regardless of their respective stores.
+    fn eq(&self, other: &IndexSet<OS>) -> bool {
+        self.len() == other.len() && self.is_subset(other)
     }
 }
 
-impl<S> IntoIterator for IndexSet<S>
+impl<S> Eq for IndexSet<S> where S: IndexForward {}
+
+impl<S, OS> PartialEq<IndexOrdSet<OS>> for IndexOrdSet<S>
 where
-    S: IndexForward,
+    S: IndexOrdered,
+    OS: IndexOrdered<Index = S::Index>,
 {
-    type Item = S::Index;
-    type IntoIter = IntoIter<S::Index, S>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        self.into_iter()
+    /// Returns whether `self` and `other` contain the same indexes, regardless of their respective stores.
+    fn eq(&self, other: &IndexOrdSet<OS>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
     }
 }
 
-impl<'a, S> IntoIterator for &'a IndexOrdSet<S>
+impl<S> Eq for IndexOrdSet<S> where S: IndexOrdered {}
+
+impl<S> PartialEq for IndexChunkedSet<S>
 where
-    S: IndexForward,
+    S: IndexForwardChunked<Chunk: IndexViewCanonical>,
 {
-    type Item = S::Index;
-    type IntoIter = Iter<'a, S::Index, S>;
+    /// Returns whether `self` and `other` contain the same indexes, by comparing their populated chunks directly.
+    ///
+    /// Only the chunks need a canonical representation, see `IndexViewCanonical`: empty chunks are skipped, so that
+    /// equal sets compare equal regardless of the capacity of their stores, like `Hash`.
+    fn eq(&self, other: &Self) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+        //  With equal lengths, if all populated chunks of `self` are in `other`, then `other` has no other index.
+        self.iter_chunks().all(|outer| {
+            let Some(chunk) = self.store.get_chunk(outer) else {
+                return true;
+            };
+
+            chunk.is_empty() || other.store.get_chunk(outer) == Some(chunk)
+        })
     }
 }
 
-impl<S> IntoIterator for IndexOrdSet<S>
+impl<S> Eq for IndexChunkedSet<S> where S: IndexForwardChunked<Chunk: IndexViewCanonical> {}
+
+impl<S> Hash for IndexChunkedSet<S>
 where
-    S: IndexForward,
+    S: IndexForwardChunked<ChunkIndex: Hash, Chunk: IndexViewCanonical + Hash>,
 {
-    type Item = S::Index;
-    type IntoIter = IntoIter<S::Index, S>;
+    /// Hashes the length, then the populated chunks in order, alongside their index.
+    ///
+    /// Empty chunks are skipped, so that equal sets hash equally regardless of the capacity of their stores.
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        state.write_usize(self.len());
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.into_iter()
+        for outer in self.iter_chunks() {
+            let Some(chunk) = self.store.get_chunk(outer) else {
+                continue;
+            };
+
+            if chunk.is_empty() {
+                continue;
+            }
+
+            outer.hash(state);
+            chunk.hash(state);
+        }
     }
 }
 
-impl<'a, S> IntoIterator for &'a IndexChunkedSet<S>
+impl<S, OS> PartialOrd<IndexOrdSet<OS>> for IndexOrdSet<S>
 where
-    S: IndexForward,
+    S: IndexOrdered,
+    OS: IndexOrdered<Index = S::Index>,
 {
-    type Item = S::Index;
-    type IntoIter = Iter<'a, S::Index, S>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+    /// Compares `self` and `other` lexicographically, by their indexes in increasing order, like `BTreeSet` does.
+    fn partial_cmp(&self, other: &IndexOrdSet<OS>) -> Option<Ordering> {
+        Some(self.iter().cmp(other.iter()))
     }
 }
 
-impl<S> IntoIterator for IndexChunkedSet<S>
+impl<S> Ord for IndexOrdSet<S>
 where
-    S: IndexForward,
+    S: IndexOrdered,
 {
-    type Item = S::Index;
-    type IntoIter = IntoIter<S::Index, S>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        self.into_iter()
+    /// Compares `self` and `other` lexicographically, by their indexes in increasing order, like `BTreeSet` does.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
     }
 }
 
-/// Iterator over the elements of S.
-pub struct Iter<'a, I, S> {
-    next: Option<I>,
-    yielded: usize,
-    store: &'a S,
+impl<S> Hash for IndexOrdSet<S>
+where
+    S: IndexOrdered<Index: Hash>,
+{
+    /// Hashes the length, then the indexes in order, so that equal sets hash equally regardless of their stores.
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        state.write_usize(self.len());
+
+        for index in self {
+            index.hash(state);
+        }
+    }
 }
 
-impl<'a, I, S> Iterator for Iter<'a, I, S>
+impl<S> IndexChunkedSet<S>
 where
-    I: Copy,
-    S: IndexForward<Index = I>,
+    S: IndexBackwardChunked<Chunk: IndexBackward + IndexOrdered> + IndexOrderedChunked,
 {
-    type Item = I;
+    /// Compares `self` and `other` as if they were big unsigned integers, in which each index is a bit.
+    ///
+    /// The index `i` is the bit of significance `i`: the chunk with the highest chunk index is the most significant
+    /// word, and within a chunk the highest index is the most significant bit. Missing chunks are treated as zero.
+    ///
+    /// This total order differs from both the subset partial order and the lexicographical order of the indexes. It
+    /// only requires a single pass over the chunks, from the most significant down, hence requires both stores and
+    /// their chunks to be ordered.
+    pub fn cmp_by_words<OS>(&self, other: &IndexChunkedSet<OS>) -> Ordering
+    where
+        OS: IndexBackwardChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk> + IndexOrderedChunked,
+    {
+        let (mut next_left, mut next_right) = (self.store.last_chunk(), other.store.last_chunk());
 
-    fn count(self) -> usize {
-        self.len()
-    }
+        loop {
+            let outer = match (next_left, next_right) {
+                (None, None) => return Ordering::Equal,
+                (Some(left), None) => left,
+                (None, Some(right)) => right,
+                (Some(left), Some(right)) => cmp::max(left, right),
+            };
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let length = self.len();
+            let mut left = S::Chunk::default();
+            let mut right = S::Chunk::default();
 
-        (length, Some(length))
-    }
-
-    fn next(&mut self) -> Option<Self::Item> {
-        let result = self.next.take()?;
-
-        self.yielded += 1;
-        self.next = self.store.next_after(result);
-
-        Some(result)
-    }
+            if next_left == Some(outer) {
+                left = self.store.get_chunk(outer).unwrap_or_default();
+                next_left = self.store.next_chunk_before(outer);
+            }
 
-    fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        if let Some(n) = n.checked_sub(1) {
-            let index = self.next.take()?;
+            if next_right == Some(outer) {
+                right = other.store.get_chunk(outer).unwrap_or_default();
+                next_right = other.store.next_chunk_before(outer);
+            }
 
-            match self.store.nth_after(n, index) {
-                Ok(next) => {
-                    self.next = Some(next);
-                    self.yielded += n;
-                }
-                Err(remainder) => {
-                    self.yielded += n - remainder.get();
-                }
+            if let Some(highest) = (left ^ right).last() {
+                return if left.contains(highest) {
+                    Ordering::Greater
+                } else {
+                    Ordering::Less
+                };
             }
         }
-
-        self.next()
     }
+}
 
-    #[cfg(feature = "nightly")]
-    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R
-    where
-        Self: Sized,
-        F: FnMut(B, Self::Item) -> R,
-        R: Try<Output = B>,
-    {
-        let Some(index) = self.next.take() else {
-            return R::from_output(init);
-        };
-
-        self.yielded = self.store.len();
+#[cfg(test)]
+mod comparison_tests;
 
-        let init = f(init, index)?;
+//
+//  Entry API.
+//
 
-        self.store.try_fold_after(index, init, f)
+impl<S> IndexSet<S>
+where
+    S: IndexStore,
+{
+    /// Returns the entry.
+    pub fn entry(&mut self, index: S::Index) -> Entry<'_, S::Index, S> {
+        if self.contains(index) {
+            Entry::Occupied(OccupiedEntry {
+                index,
+                store: &mut self.store,
+            })
+        } else {
+            Entry::Vacant(VacantEntry {
+                index,
+                store: &mut self.store,
+            })
+        }
     }
 }
 
-impl<'a, I, S> ExactSizeIterator for Iter<'a, I, S>
+impl<S> IndexOrdSet<S>
 where
-    I: Copy,
-    S: IndexForward<Index = I>,
+    S: IndexStore,
 {
-    fn len(&self) -> usize {
-        self.store.len() - self.yielded
-    }
-
-    #[cfg(feature = "nightly")]
-    fn is_empty(&self) -> bool {
-        self.store.len() == self.yielded
+    /// Returns the entry.
+    pub fn entry(&mut self, index: S::Index) -> Entry<'_, S::Index, S> {
+        if self.contains(index) {
+            Entry::Occupied(OccupiedEntry {
+                index,
+                store: &mut self.store,
+            })
+        } else {
+            Entry::Vacant(VacantEntry {
+                index,
+                store: &mut self.store,
+            })
+        }
     }
 }
 
-impl<'a, I, S> FusedIterator for Iter<'a, I, S>
+impl<S> IndexChunkedSet<S>
 where
-    I: Copy,
-    S: IndexForward<Index = I>,
+    S: IndexStore,
 {
+    /// Returns the entry.
+    pub fn entry(&mut self, index: S::Index) -> Entry<'_, S::Index, S> {
+        if self.contains(index) {
+            Entry::Occupied(OccupiedEntry {
+                index,
+                store: &mut self.store,
+            })
+        } else {
+            Entry::Vacant(VacantEntry {
+                index,
+                store: &mut self.store,
+            })
+        }
+    }
 }
 
-/// Iterator over the elements of S, in reverse order.
-pub struct IterRev<'a, I, S> {
-    next: Option<I>,
-    yielded: usize,
-    store: &'a S,
+/// A view into a single entry in a set, which may either be vacant or occupied.
+pub enum Entry<'a, I, S> {
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, I, S>),
+    /// A vacant entry.
+    Vacant(VacantEntry<'a, I, S>),
 }
 
-impl<'a, I, S> Iterator for IterRev<'a, I, S>
+impl<'a, I, S> Entry<'a, I, S>
 where
     I: Copy,
-    S: IndexBackward<Index = I>,
+    S: IndexStore<Index = I>,
 {
-    type Item = I;
-
-    fn count(self) -> usize {
-        self.len()
-    }
-
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let length = self.len();
-
-        (length, Some(length))
+    /// Returns the index.
+    pub fn get(&self) -> I {
+        match self {
+            Self::Occupied(o) => o.get(),
+            Self::Vacant(v) => v.get(),
+        }
     }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let result = self.next.take()?;
-
-        self.yielded += 1;
-        self.next = self.store.next_before(result);
-
-        Some(result)
-    }
+    /// Inserts the index, and returns an `OccupiedEntry`.
+    pub fn insert(self) -> Result<OccupiedEntry<'a, I, S>, S::InsertionError> {
+        match self {
+            Self::Occupied(o) => Ok(o),
+            Self::Vacant(VacantEntry { index, store }) => {
+                let _inserted = store.insert(index)?;
 
-    fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        if let Some(n) = n.checked_sub(1) {
-            let index = self.next.take()?;
+                debug_assert!(_inserted);
 
-            match self.store.nth_before(n, index) {
-                Ok(next) => {
-                    self.next = Some(next);
-                    self.yielded += n;
-                }
-                Err(remainder) => {
-                    self.yielded += n - remainder.get();
-                }
+                Ok(OccupiedEntry { index, store })
             }
         }
-
-        self.next()
     }
 
-    #[cfg(feature = "nightly")]
-    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R
-    where
-        Self: Sized,
-        F: FnMut(B, Self::Item) -> R,
-        R: Try<Output = B>,
-    {
-        let Some(index) = self.next.take() else {
-            return R::from_output(init);
-        };
-
-        self.yielded = self.store.len();
-
-        let init = f(init, index)?;
+    /// Ensures the index is in the set.
+    pub fn or_insert(self) -> Result<(), S::InsertionError> {
+        if let Self::Vacant(v) = self {
+            v.insert()?;
+        }
 
-        self.store.try_fold_before(index, init, f)
+        Ok(())
     }
 }
 
-impl<'a, I, S> ExactSizeIterator for IterRev<'a, I, S>
+/// An occupied entry in a set.
+pub struct OccupiedEntry<'a, I, S> {
+    index: I,
+    store: &'a mut S,
+}
+
+impl<'a, I, S> OccupiedEntry<'a, I, S>
 where
     I: Copy,
-    S: IndexBackward<Index = I>,
+    S: IndexStore<Index = I>,
 {
-    fn len(&self) -> usize {
-        self.store.len() - self.yielded
+    /// Returns the index.
+    pub fn get(&self) -> I {
+        self.index
     }
 
-    #[cfg(feature = "nightly")]
-    fn is_empty(&self) -> bool {
-        self.store.len() == self.yielded
-    }
-}
+    /// Removes the index from the set.
+    pub fn remove(self) -> I {
+        let _removed = self.store.remove(self.index);
 
-impl<'a, I, S> FusedIterator for IterRev<'a, I, S>
-where
-    I: Copy,
-    S: IndexBackward<Index = I>,
-{
+        debug_assert!(_removed);
+
+        self.index
+    }
 }
 
-/// Iterator over the elements of S.
-pub struct IntoIter<I, S> {
-    next: Option<I>,
-    yielded: usize,
-    store: S,
+/// A vacant entry in a set.
+pub struct VacantEntry<'a, I, S> {
+    index: I,
+    store: &'a mut S,
 }
 
-impl<I, S> Iterator for IntoIter<I, S>
+impl<'a, I, S> VacantEntry<'a, I, S>
 where
     I: Copy,
-    S: IndexForward<Index = I>,
+    S: IndexStore<Index = I>,
 {
-    type Item = I;
-
-    fn count(self) -> usize {
-        self.len()
+    /// Returns the index.
+    pub fn get(&self) -> I {
+        self.index
     }
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let length = self.len();
-
-        (length, Some(length))
+    /// Consumes the entry and returns the index.
+    pub fn into_value(self) -> I {
+        self.index
     }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let result = self.next.take()?;
+    /// Inserts the index in the store.
+    pub fn insert(self) -> Result<(), S::InsertionError> {
+        let _inserted = self.store.insert(self.index)?;
 
-        self.yielded += 1;
-        self.next = self.store.next_after(result);
+        debug_assert!(_inserted);
 
-        Some(result)
+        Ok(())
     }
+}
 
-    fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        if let Some(n) = n.checked_sub(1) {
-            let index = self.next.take()?;
+#[cfg(test)]
+mod entry_tests;
 
-            match self.store.nth_after(n, index) {
-                Ok(next) => {
-                    self.next = Some(next);
-                    self.yielded += n;
-                }
-                Err(remainder) => {
-                    self.yielded += n - remainder.get();
-                }
-            }
-        }
+//
+//  Iterator operations: just iteration.
+//
 
-        self.next()
+impl<S> IndexSet<S>
+where
+    S: IndexForward,
+{
+    /// Returns an iterator over the indexes in the set.
+    pub fn iter(&self) -> Iter<'_, S::Index, S> {
+        Iter {
+            next: self.store.first(),
+            back: None,
+            remaining: self.store.len(),
+            store: &self.store,
+        }
     }
 
-    #[cfg(feature = "nightly")]
-    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R
-    where
-        Self: Sized,
-        F: FnMut(B, Self::Item) -> R,
-        R: Try<Output = B>,
-    {
-        let Some(index) = self.next.take() else {
-            return R::from_output(init);
-        };
-
-        self.yielded = self.store.len();
-
-        let init = f(init, index)?;
-
-        self.store.try_fold_after(index, init, f)
+    /// Returns an iterator over the indexes in the set.
+    #[allow(clippy::should_implement_trait)]
+    pub fn into_iter(self) -> IntoIter<S::Index, S> {
+        IntoIter {
+            next: self.store.first(),
+            back: None,
+            remaining: self.store.len(),
+            store: self.store,
+        }
     }
 }
 
-impl<I, S> ExactSizeIterator for IntoIter<I, S>
+impl<S> IndexOrdSet<S>
 where
-    I: Copy,
-    S: IndexForward<Index = I>,
+    S: IndexForward,
 {
-    fn len(&self) -> usize {
-        self.store.len() - self.yielded
+    /// Returns an iterator over the indexes in the set.
+    pub fn iter(&self) -> Iter<'_, S::Index, S> {
+        Iter {
+            next: self.store.first(),
+            back: None,
+            remaining: self.store.len(),
+            store: &self.store,
+        }
     }
 
-    #[cfg(feature = "nightly")]
-    fn is_empty(&self) -> bool {
-        self.store.len() == self.yielded
+    /// Returns an iterator over the indexes in the set.
+    #[allow(clippy::should_implement_trait)]
+    pub fn into_iter(self) -> IntoIter<S::Index, S> {
+        IntoIter {
+            next: self.store.first(),
+            back: None,
+            remaining: self.store.len(),
+            store: self.store,
+        }
     }
 }
 
-impl<I, S> FusedIterator for IntoIter<I, S>
+impl<S> IndexChunkedSet<S>
 where
-    I: Copy,
-    S: IndexForward<Index = I>,
+    S: IndexForward,
 {
-}
+    /// Returns an iterator over the indexes in the set.
+    pub fn iter(&self) -> Iter<'_, S::Index, S> {
+        Iter {
+            next: self.store.first(),
+            back: None,
+            remaining: self.store.len(),
+            store: &self.store,
+        }
+    }
 
-/// Iterator over the elements of S, in reverse order.
-pub struct IntoIterRev<I, S> {
-    next: Option<I>,
-    yielded: usize,
-    store: S,
+    /// Returns an iterator over the indexes in the set.
+    #[allow(clippy::should_implement_trait)]
+    pub fn into_iter(self) -> IntoIter<S::Index, S> {
+        IntoIter {
+            next: self.store.first(),
+            back: None,
+            remaining: self.store.len(),
+            store: self.store,
+        }
+    }
 }
 
-impl<I, S> Iterator for IntoIterRev<I, S>
+impl<S> IndexSet<S>
 where
-    I: Copy,
-    S: IndexBackward<Index = I>,
+    S: IndexBackward,
 {
-    type Item = I;
-
-    fn count(self) -> usize {
-        self.len()
-    }
-
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let length = self.len();
-
-        (length, Some(length))
+    /// Returns an iterator over the indexes in the set.
+    pub fn iter_rev(&self) -> IterRev<'_, S::Index, S> {
+        IterRev {
+            next: self.store.last(),
+            yielded: 0,
+            store: &self.store,
+        }
     }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let result = self.next.take()?;
-
-        self.yielded += 1;
-        self.next = self.store.next_before(result);
-
-        Some(result)
+    /// Returns an iterator over the indexes in the set.
+    pub fn into_iter_rev(self) -> IntoIterRev<S::Index, S> {
+        IntoIterRev {
+            next: self.store.last(),
+            yielded: 0,
+            store: self.store,
+        }
     }
+}
 
-    fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        if let Some(n) = n.checked_sub(1) {
-            let index = self.next.take()?;
-
-            match self.store.nth_before(n, index) {
-                Ok(next) => {
-                    self.next = Some(next);
-                    self.yielded += n;
-                }
-                Err(remainder) => {
-                    self.yielded += n - remainder.get();
-                }
-            }
+impl<S> IndexOrdSet<S>
+where
+    S: IndexBackward,
+{
+    /// Returns an iterator over the indexes in the set.
+    pub fn iter_rev(&self) -> IterRev<'_, S::Index, S> {
+        IterRev {
+            next: self.store.last(),
+            yielded: 0,
+            store: &self.store,
         }
-
-        self.next()
     }
 
-    #[cfg(feature = "nightly")]
-    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R
-    where
-        Self: Sized,
-        F: FnMut(B, Self::Item) -> R,
-        R: Try<Output = B>,
-    {
-        let Some(index) = self.next.take() else {
-            return R::from_output(init);
-        };
-
-        self.yielded = self.store.len();
-
-        let init = f(init, index)?;
-
-        self.store.try_fold_after(index, init, f)
+    /// Returns an iterator over the indexes in the set.
+    pub fn into_iter_rev(self) -> IntoIterRev<S::Index, S> {
+        IntoIterRev {
+            next: self.store.last(),
+            yielded: 0,
+            store: self.store,
+        }
     }
 }
 
-impl<I, S> ExactSizeIterator for IntoIterRev<I, S>
+impl<S> IndexChunkedSet<S>
 where
-    I: Copy,
-    S: IndexBackward<Index = I>,
+    S: IndexBackward,
 {
-    fn len(&self) -> usize {
-        self.store.len() - self.yielded
+    /// Returns an iterator over the indexes in the set.
+    pub fn iter_rev(&self) -> IterRev<'_, S::Index, S> {
+        IterRev {
+            next: self.store.last(),
+            yielded: 0,
+            store: &self.store,
+        }
     }
 
-    #[cfg(feature = "nightly")]
-    fn is_empty(&self) -> bool {
-        self.store.len() == self.yielded
+    /// Returns an iterator over the indexes in the set.
+    pub fn into_iter_rev(self) -> IntoIterRev<S::Index, S> {
+        IntoIterRev {
+            next: self.store.last(),
+            yielded: 0,
+            store: self.store,
+        }
     }
 }
 
-impl<I, S> FusedIterator for IntoIterRev<I, S>
+impl<S> IndexOrdSet<S>
 where
-    I: Copy,
-    S: IndexBackward<Index = I>,
+    S: IndexOrdered,
 {
-}
-
-#[cfg(test)]
-mod basic_iteration_tests;
+    /// Returns an iterator over the indexes in the set within `range`, in increasing order.
+    ///
+    /// The iterator seeks the start of `range` on creation, and stops as soon as an index exceeds the end of `range`.
+    pub fn range<R>(&self, range: R) -> Range<'_, S::Index, S>
+    where
+        R: RangeBounds<S::Index>,
+    {
+        Range {
+            next: first_within(&self.store, range.start_bound()),
+            end: range.end_bound().cloned(),
+            store: &self.store,
+        }
+    }
 
-//
-//  Iterator operations: chunk iteration.
-//
+    /// Returns an iterator over the indexes in the set not before `start`, in increasing order.
+    ///
+    /// The iterator seeks `start` on creation, see `IndexForward::first_at`, which makes it suitable to resume an
+    /// earlier iteration.
+    pub fn iter_from(&self, start: S::Index) -> Range<'_, S::Index, S> {
+        Range {
+            next: self.store.first_at(start),
+            end: Bound::Unbounded,
+            store: &self.store,
+        }
+    }
+}
 
-impl<S> IndexChunkedSet<S>
+impl<S> IndexOrdSet<S>
 where
-    S: IndexForwardChunked,
+    S: IndexOrdered + IndexBackward,
 {
-    /// Returns an iterator over the indexes in the set.
-    pub fn iter_chunks(&self) -> IterChunked<'_, S::ChunkIndex, S> {
-        IterChunked {
-            next: self.store.first_chunk(),
-            yielded: 0,
+    /// Returns an iterator over the indexes in the set not after `end`, in decreasing order.
+    ///
+    /// The iterator seeks `end` on creation, see `IndexBackward::last_at`, which makes it suitable to resume an
+    /// earlier backward iteration.
+    pub fn iter_to(&self, end: S::Index) -> RangeRev<'_, S::Index, S> {
+        RangeRev {
+            next: self.store.last_at(end),
+            start: Bound::Unbounded,
             store: &self.store,
         }
     }
 }
 
-impl<S> IndexChunkedSet<S>
+impl<S> IndexOrdSet<S>
 where
-    S: IndexBackwardChunked,
+    S: IndexOrdered<Index: Add<Output = S::Index> + Sub<Output = S::Index> + From<u8>>,
 {
-    /// Returns an iterator over the indexes in the set.
-    pub fn iter_chunks_rev(&self) -> IterChunkedRev<'_, S::ChunkIndex, S> {
-        IterChunkedRev {
-            next: self.store.last_chunk(),
-            yielded: 0,
+    /// Returns an iterator over the gaps between consecutive indexes in the set, in increasing order.
+    ///
+    /// Each gap is the inclusive range of the indexes missing between two consecutive indexes, hence adjacent indexes
+    /// produce no gap, and neither do the indexes before the first or after the last. For example, `{1, 2, 5, 7}`
+    /// yields `3..=4` and `6..=6`.
+    pub fn windows_of_gaps(&self) -> WindowsOfGaps<'_, S::Index, S> {
+        WindowsOfGaps {
+            previous: self.store.first(),
             store: &self.store,
         }
     }
 }
 
-/// Iterator over the chunk indexes of S.
-pub struct IterChunked<'a, I, S> {
-    next: Option<I>,
-    yielded: usize,
-    store: &'a S,
+impl<'a, S> IntoIterator for &'a IndexSet<S>
+where
+    S: IndexForward,
+{
+    type Item = S::Index;
+    type IntoIter = Iter<'a, S::Index, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
 
-impl<'a, I, S> Iterator for IterChunked<'a, I, S>
+impl<S> IntoIterator for IndexSet<S>
+where
+    S: IndexForward,
+{
+    type Item = S::Index;
+    type IntoIter = IntoIter<S::Index, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_iter()
+    }
+}
+
+impl<'a, S> IntoIterator for &'a IndexOrdSet<S>
+where
+    S: IndexForward,
+{
+    type Item = S::Index;
+    type IntoIter = Iter<'a, S::Index, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<S> IntoIterator for IndexOrdSet<S>
+where
+    S: IndexForward,
+{
+    type Item = S::Index;
+    type IntoIter = IntoIter<S::Index, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_iter()
+    }
+}
+
+impl<'a, S> IntoIterator for &'a IndexChunkedSet<S>
+where
+    S: IndexForward,
+{
+    type Item = S::Index;
+    type IntoIter = Iter<'a, S::Index, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<S> IntoIterator for IndexChunkedSet<S>
+where
+    S: IndexForward,
+{
+    type Item = S::Index;
+    type IntoIter = IntoIter<S::Index, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_iter()
+    }
+}
+
+//  Returns the first index of `view` which is not before `start`, if any.
+fn first_within<V>(view: &V, start: Bound<&V::Index>) -> Option<V::Index>
+where
+    V: IndexForward,
+{
+    match start {
+        Bound::Included(&start) if view.contains(start) => Some(start),
+        Bound::Included(&start) | Bound::Excluded(&start) => view.next_after(start),
+        Bound::Unbounded => view.first(),
+    }
+}
+
+//  Returns whether `index` is strictly before `other`.
+//
+//  The iterators only bound `I: Copy`, and rely on `S::Index = I` to borrow the ordering of the store indexes.
+fn is_before<S>(index: S::Index, other: S::Index) -> bool
+where
+    S: IndexView,
+{
+    index < other
+}
+
+//  Returns the `n`-th index of `store` from `cursor`, included, if any, and moves `cursor` past it.
+//
+//  Skips ahead with `nth_after`, rather than advancing one index at a time.
+fn nth_from<S>(store: &S, cursor: &mut Option<S::Index>, n: usize) -> Option<S::Index>
+where
+    S: IndexForward,
+{
+    let current = cursor.take()?;
+
+    let nth = match n.checked_sub(1) {
+        Some(n) => store.nth_after(n, current).ok()?,
+        None => current,
+    };
+
+    *cursor = store.next_after(nth);
+
+    Some(nth)
+}
+
+/// Iterator over the elements of S.
+pub struct Iter<'a, I, S> {
+    next: Option<I>,
+    //  Last index yielded from the back, if any.
+    back: Option<I>,
+    //  Number of indexes left to yield, from either end, fixed at construction as the store cannot change meanwhile.
+    remaining: usize,
+    store: &'a S,
+}
+
+impl<'a, I, S> Clone for Iter<'a, I, S>
 where
     I: Copy,
-    S: IndexForwardChunked<ChunkIndex = I>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            next: self.next,
+            back: self.back,
+            remaining: self.remaining,
+            store: self.store,
+        }
+    }
+}
+
+impl<'a, I, S> Iter<'a, I, S>
+where
+    I: Copy,
+{
+    /// Returns the index the next call to `next` would return, without advancing the iterator.
+    pub fn peek(&self) -> Option<I> {
+        self.next
+    }
+}
+
+impl<'a, I, S> Iterator for Iter<'a, I, S>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
 {
     type Item = I;
 
@@ -1455,46 +2401,154 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         let result = self.next.take()?;
 
-        self.yielded += 1;
-        self.next = self.store.next_chunk_after(result);
+        self.remaining -= 1;
+
+        //  Once indexes were yielded from the back, stops once all indexes have been yielded, from either end, rather
+        //  than comparing against the last index yielded from the back, as the store need not be ordered.
+        self.next = if self.back.is_none() || self.remaining > 0 {
+            self.store.next_after(result)
+        } else {
+            None
+        };
+
+        Some(result)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.remaining {
+            self.next = None;
+            self.remaining = 0;
+
+            return None;
+        }
+
+        if let Some(n) = n.checked_sub(1) {
+            let index = self.next.take()?;
+
+            match self.store.nth_after(n, index) {
+                Ok(next) => {
+                    self.next = Some(next);
+                    self.remaining -= n;
+                }
+                Err(remainder) => {
+                    self.remaining -= n - remainder.get();
+                }
+            }
+        }
+
+        self.next()
+    }
+
+    #[cfg(feature = "nightly")]
+    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> R,
+        R: Try<Output = B>,
+    {
+        if self.back.is_some() {
+            let mut accumulator = init;
+
+            for index in self.by_ref() {
+                accumulator = f(accumulator, index)?;
+            }
+
+            return R::from_output(accumulator);
+        }
+
+        let Some(index) = self.next.take() else {
+            return R::from_output(init);
+        };
+
+        self.remaining = 0;
+
+        let init = f(init, index)?;
+
+        self.store.try_fold_after(index, init, f)
+    }
+}
+
+impl<'a, I, S> DoubleEndedIterator for Iter<'a, I, S>
+where
+    I: Copy,
+    S: IndexBackward<Index = I>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        //  Exhausted, from either end.
+        self.next?;
+
+        let result = match self.back {
+            Some(back) => self.store.next_before(back),
+            None => self.store.last(),
+        }?;
+
+        self.remaining -= 1;
+        self.back = Some(result);
+
+        if self.remaining == 0 {
+            self.next = None;
+        }
 
         Some(result)
     }
 }
 
-impl<'a, I, S> ExactSizeIterator for IterChunked<'a, I, S>
+impl<'a, I, S> ExactSizeIterator for Iter<'a, I, S>
 where
     I: Copy,
-    S: IndexForwardChunked<ChunkIndex = I>,
+    S: IndexForward<Index = I>,
 {
     fn len(&self) -> usize {
-        self.store.len() - self.yielded
+        self.remaining
     }
 
     #[cfg(feature = "nightly")]
     fn is_empty(&self) -> bool {
-        self.store.len() == self.yielded
+        self.remaining == 0
     }
 }
 
-impl<'a, I, S> FusedIterator for IterChunked<'a, I, S>
+impl<'a, I, S> FusedIterator for Iter<'a, I, S>
 where
     I: Copy,
-    S: IndexForwardChunked<ChunkIndex = I>,
+    S: IndexForward<Index = I>,
 {
 }
 
-/// Iterator over the chunk indexes of S, in reverse order.
-pub struct IterChunkedRev<'a, I, S> {
+/// Iterator over the elements of S, in reverse order.
+pub struct IterRev<'a, I, S> {
     next: Option<I>,
     yielded: usize,
     store: &'a S,
 }
 
-impl<'a, I, S> Iterator for IterChunkedRev<'a, I, S>
+impl<'a, I, S> Clone for IterRev<'a, I, S>
 where
     I: Copy,
-    S: IndexBackwardChunked<ChunkIndex = I>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            next: self.next,
+            yielded: self.yielded,
+            store: self.store,
+        }
+    }
+}
+
+impl<'a, I, S> IterRev<'a, I, S>
+where
+    I: Copy,
+{
+    /// Returns the index the next call to `next` would return, without advancing the iterator.
+    pub fn peek(&self) -> Option<I> {
+        self.next
+    }
+}
+
+impl<'a, I, S> Iterator for IterRev<'a, I, S>
+where
+    I: Copy,
+    S: IndexBackward<Index = I>,
 {
     type Item = I;
 
@@ -1512,16 +2566,52 @@ where
         let result = self.next.take()?;
 
         self.yielded += 1;
-        self.next = self.store.next_chunk_before(result);
+        self.next = self.store.next_before(result);
 
         Some(result)
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if let Some(n) = n.checked_sub(1) {
+            let index = self.next.take()?;
+
+            match self.store.nth_before(n, index) {
+                Ok(next) => {
+                    self.next = Some(next);
+                    self.yielded += n;
+                }
+                Err(remainder) => {
+                    self.yielded += n - remainder.get();
+                }
+            }
+        }
+
+        self.next()
+    }
+
+    #[cfg(feature = "nightly")]
+    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> R,
+        R: Try<Output = B>,
+    {
+        let Some(index) = self.next.take() else {
+            return R::from_output(init);
+        };
+
+        self.yielded = self.store.len();
+
+        let init = f(init, index)?;
+
+        self.store.try_fold_before(index, init, f)
+    }
 }
 
-impl<'a, I, S> ExactSizeIterator for IterChunkedRev<'a, I, S>
+impl<'a, I, S> ExactSizeIterator for IterRev<'a, I, S>
 where
     I: Copy,
-    S: IndexBackwardChunked<ChunkIndex = I>,
+    S: IndexBackward<Index = I>,
 {
     fn len(&self) -> usize {
         self.store.len() - self.yielded
@@ -1533,168 +2623,190 @@ where
     }
 }
 
-impl<'a, I, S> FusedIterator for IterChunkedRev<'a, I, S>
+impl<'a, I, S> FusedIterator for IterRev<'a, I, S>
 where
     I: Copy,
-    S: IndexBackwardChunked<ChunkIndex = I>,
+    S: IndexBackward<Index = I>,
 {
 }
 
-//
-//  Iterator operations: drain, erase_if, retain.
-//
-
-impl<S> IndexSet<S>
+/// Iterator over the elements of S.
+pub struct IntoIter<I, S> {
+    next: Option<I>,
+    //  Last index yielded from the back, if any.
+    back: Option<I>,
+    //  Number of indexes left to yield, from either end, fixed at construction as the store cannot change meanwhile.
+    remaining: usize,
+    store: S,
+}
+
+impl<I, S> IntoIter<I, S>
 where
-    S: IndexForward + IndexStore,
+    I: Copy,
 {
-    /// Clears the set, returning all elements as an iterator.
-    pub fn drain(&mut self) -> Drain<'_, S::Index, S> {
-        Drain {
-            next: self.store.first(),
-            yielded: 0,
-            store: &mut self.store,
-        }
+    /// Returns the index the next call to `next` would return, without advancing the iterator.
+    pub fn peek(&self) -> Option<I> {
+        self.next
     }
+}
 
-    /// Creates an iterator which uses a closure to determine if an element should be removed.
-    ///
-    /// The elements returned by the iterator are removed from the set.
-    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, S::Index, S, F> {
-        ExtractIf {
-            pred,
-            next: self.store.first(),
-            passed: 0,
-            store: &mut self.store,
-        }
+impl<I, S> Iterator for IntoIter<I, S>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+{
+    type Item = I;
+
+    fn count(self) -> usize {
+        self.len()
     }
 
-    /// Retains only the elements specified by the predicate.
-    pub fn retain<F>(&mut self, mut pred: F)
-    where
-        F: FnMut(S::Index) -> bool,
-    {
-        let mut cursor = self.store.first();
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let length = self.len();
 
-        while let Some(index) = cursor {
-            if !pred(index) {
-                self.store.remove(index);
-            }
+        (length, Some(length))
+    }
 
-            cursor = self.store.next_after(index);
-        }
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.next.take()?;
+
+        self.remaining -= 1;
+
+        //  Once indexes were yielded from the back, stops once all indexes have been yielded, from either end, rather
+        //  than comparing against the last index yielded from the back, as the store need not be ordered.
+        self.next = if self.back.is_none() || self.remaining > 0 {
+            self.store.next_after(result)
+        } else {
+            None
+        };
+
+        Some(result)
     }
-}
 
-impl<S> IndexOrdSet<S>
-where
-    S: IndexForward + IndexStore,
-{
-    /// Clears the set, returning all elements as an iterator.
-    pub fn drain(&mut self) -> Drain<'_, S::Index, S> {
-        Drain {
-            next: self.store.first(),
-            yielded: 0,
-            store: &mut self.store,
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.remaining {
+            self.next = None;
+            self.remaining = 0;
+
+            return None;
         }
-    }
 
-    /// Creates an iterator which uses a closure to determine if an element should be removed.
-    ///
-    /// The elements returned by the iterator are removed from the set.
-    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, S::Index, S, F> {
-        ExtractIf {
-            pred,
-            next: self.store.first(),
-            passed: 0,
-            store: &mut self.store,
+        if let Some(n) = n.checked_sub(1) {
+            let index = self.next.take()?;
+
+            match self.store.nth_after(n, index) {
+                Ok(next) => {
+                    self.next = Some(next);
+                    self.remaining -= n;
+                }
+                Err(remainder) => {
+                    self.remaining -= n - remainder.get();
+                }
+            }
         }
+
+        self.next()
     }
 
-    /// Retains only the elements specified by the predicate.
-    pub fn retain<F>(&mut self, mut pred: F)
+    #[cfg(feature = "nightly")]
+    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R
     where
-        F: FnMut(S::Index) -> bool,
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> R,
+        R: Try<Output = B>,
     {
-        let mut cursor = self.store.first();
+        if self.back.is_some() {
+            let mut accumulator = init;
 
-        while let Some(index) = cursor {
-            if !pred(index) {
-                self.store.remove(index);
+            for index in self.by_ref() {
+                accumulator = f(accumulator, index)?;
             }
 
-            cursor = self.store.next_after(index);
+            return R::from_output(accumulator);
         }
+
+        let Some(index) = self.next.take() else {
+            return R::from_output(init);
+        };
+
+        self.remaining = 0;
+
+        let init = f(init, index)?;
+
+        self.store.try_fold_after(index, init, f)
     }
 }
 
-impl<S> IndexChunkedSet<S>
+impl<I, S> DoubleEndedIterator for IntoIter<I, S>
 where
-    S: IndexForward + IndexStore,
+    I: Copy,
+    S: IndexBackward<Index = I>,
 {
-    /// Clears the set, returning all elements as an iterator.
-    pub fn drain(&mut self) -> Drain<'_, S::Index, S> {
-        Drain {
-            next: self.store.first(),
-            yielded: 0,
-            store: &mut self.store,
-        }
-    }
+    fn next_back(&mut self) -> Option<Self::Item> {
+        //  Exhausted, from either end.
+        self.next?;
 
-    /// Creates an iterator which uses a closure to determine if an element should be removed.
-    ///
-    /// The elements returned by the iterator are removed from the set.
-    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, S::Index, S, F> {
-        ExtractIf {
-            pred,
-            next: self.store.first(),
-            passed: 0,
-            store: &mut self.store,
+        let result = match self.back {
+            Some(back) => self.store.next_before(back),
+            None => self.store.last(),
+        }?;
+
+        self.remaining -= 1;
+        self.back = Some(result);
+
+        if self.remaining == 0 {
+            self.next = None;
         }
-    }
 
-    /// Retains only the elements specified by the predicate.
-    pub fn retain<F>(&mut self, mut pred: F)
-    where
-        F: FnMut(S::Index) -> bool,
-    {
-        let mut cursor = self.store.first();
+        Some(result)
+    }
+}
 
-        while let Some(index) = cursor {
-            if !pred(index) {
-                self.store.remove(index);
-            }
+impl<I, S> ExactSizeIterator for IntoIter<I, S>
+where
+    I: Copy,
+    S: IndexForward<Index = I>,
+{
+    fn len(&self) -> usize {
+        self.remaining
+    }
 
-            cursor = self.store.next_after(index);
-        }
+    #[cfg(feature = "nightly")]
+    fn is_empty(&self) -> bool {
+        self.remaining == 0
     }
 }
 
-/// A draining iterator over the items of an `IndexSet`.
-pub struct Drain<'a, I, S>
+impl<I, S> FusedIterator for IntoIter<I, S>
 where
-    S: IndexStore,
+    I: Copy,
+    S: IndexForward<Index = I>,
 {
+}
+
+/// Iterator over the elements of S, in reverse order.
+pub struct IntoIterRev<I, S> {
     next: Option<I>,
     yielded: usize,
-    store: &'a mut S,
+    store: S,
 }
 
-impl<'a, I, S> Drop for Drain<'a, I, S>
+impl<I, S> IntoIterRev<I, S>
 where
-    S: IndexStore,
+    I: Copy,
 {
-    fn drop(&mut self) {
-        self.store.clear();
+    /// Returns the index the next call to `next` would return, without advancing the iterator.
+    pub fn peek(&self) -> Option<I> {
+        self.next
     }
 }
 
-impl<'a, I, S> Iterator for Drain<'a, I, S>
+impl<I, S> Iterator for IntoIterRev<I, S>
 where
     I: Copy,
-    S: IndexForward<Index = I> + IndexStore<Index = I>,
+    S: IndexBackward<Index = I>,
 {
-    type Item = S::Index;
+    type Item = I;
 
     fn count(self) -> usize {
         self.len()
@@ -1707,19 +2819,19 @@ where
     }
 
     fn next(&mut self) -> Option<Self::Item> {
-        let next = self.next.take()?;
+        let result = self.next.take()?;
 
         self.yielded += 1;
-        self.next = self.store.next_after(next);
+        self.next = self.store.next_before(result);
 
-        Some(next)
+        Some(result)
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
         if let Some(n) = n.checked_sub(1) {
             let index = self.next.take()?;
 
-            match self.store.nth_after(n, index) {
+            match self.store.nth_before(n, index) {
                 Ok(next) => {
                     self.next = Some(next);
                     self.yielded += n;
@@ -1732,12 +2844,30 @@ where
 
         self.next()
     }
+
+    #[cfg(feature = "nightly")]
+    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> R,
+        R: Try<Output = B>,
+    {
+        let Some(index) = self.next.take() else {
+            return R::from_output(init);
+        };
+
+        self.yielded = self.store.len();
+
+        let init = f(init, index)?;
+
+        self.store.try_fold_after(index, init, f)
+    }
 }
 
-impl<'a, I, S> ExactSizeIterator for Drain<'a, I, S>
+impl<I, S> ExactSizeIterator for IntoIterRev<I, S>
 where
     I: Copy,
-    S: IndexForward<Index = I> + IndexStore<Index = I>,
+    S: IndexBackward<Index = I>,
 {
     fn len(&self) -> usize {
         self.store.len() - self.yielded
@@ -1749,656 +2879,3217 @@ where
     }
 }
 
-impl<'a, I, S> FusedIterator for Drain<'a, I, S>
+impl<I, S> FusedIterator for IntoIterRev<I, S>
 where
     I: Copy,
-    S: IndexForward<Index = I> + IndexStore<Index = I>,
+    S: IndexBackward<Index = I>,
 {
 }
 
-/// An extractor iterator.
-pub struct ExtractIf<'a, I, S, F> {
-    pred: F,
+/// Iterator over the elements of S within a range, in increasing order.
+pub struct Range<'a, I, S> {
     next: Option<I>,
-    passed: usize,
-    store: &'a mut S,
+    end: Bound<I>,
+    store: &'a S,
 }
 
-impl<'a, I, S, F> Iterator for ExtractIf<'a, I, S, F>
+impl<'a, I, S> Clone for Range<'a, I, S>
 where
     I: Copy,
-    S: IndexForward<Index = I> + IndexStore<Index = I>,
-    F: FnMut(I) -> bool,
 {
-    type Item = S::Index;
-
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let length = self.store.len() - self.passed;
+    fn clone(&self) -> Self {
+        Self {
+            next: self.next,
+            end: self.end,
+            store: self.store,
+        }
+    }
+}
 
-        (0, Some(length))
+impl<'a, I, S> Range<'a, I, S>
+where
+    I: Copy,
+    S: IndexOrdered<Index = I>,
+{
+    /// Returns the index the next call to `next` would return, without advancing the iterator.
+    pub fn peek(&self) -> Option<I> {
+        self.next.filter(|&next| self.is_within(next))
     }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        while let Some(index) = self.next
-            && !(self.pred)(index)
-        {
-            self.passed += 1;
-            self.next = self.store.next_after(index);
+    //  Returns whether `index` is before the end of the range.
+    fn is_within(&self, index: I) -> bool {
+        match self.end {
+            Bound::Included(end) => !is_before::<S>(end, index),
+            Bound::Excluded(end) => is_before::<S>(index, end),
+            Bound::Unbounded => true,
         }
-
-        let index = self.next.take()?;
-
-        self.store.remove(index);
-
-        self.passed += 1;
-        self.next = self.store.next_after(index);
-
-        Some(index)
     }
 }
 
-impl<'a, I, S, F> FusedIterator for ExtractIf<'a, I, S, F>
+impl<'a, I, S> Iterator for Range<'a, I, S>
 where
     I: Copy,
-    S: IndexForward<Index = I> + IndexStore<Index = I>,
-    F: FnMut(I) -> bool,
+    S: IndexOrdered<Index = I>,
 {
-}
+    type Item = I;
 
-//  FIXME: implement chunk versions of the above.
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.next.take()?;
 
-#[cfg(test)]
-mod extract_iteration_tests;
+        if !self.is_within(result) {
+            return None;
+        }
 
-//
-//  Iterator operations: difference, symmetric difference, intersection, union.
-//
+        self.next = self.store.next_after(result);
 
-impl<S> IndexSet<S>
+        Some(result)
+    }
+}
+
+impl<'a, I, S> FusedIterator for Range<'a, I, S>
 where
-    S: IndexForward,
+    I: Copy,
+    S: IndexOrdered<Index = I>,
 {
-    /// Returns the indexes that are in `self`, but not `other`.
-    pub fn difference<'a, OS>(&'a self, other: &'a IndexSet<OS>) -> Difference<'a, S::Index, S, OS>
-    where
-        OS: IndexView<Index = S::Index>,
-    {
-        Difference {
-            next: self.store.first(),
-            passed: 0,
-            left: &self.store,
-            right: &other.store,
+}
+
+/// Iterator over the elements of S within a range, in decreasing order.
+pub struct RangeRev<'a, I, S> {
+    next: Option<I>,
+    start: Bound<I>,
+    store: &'a S,
+}
+
+impl<'a, I, S> Clone for RangeRev<'a, I, S>
+where
+    I: Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            next: self.next,
+            start: self.start,
+            store: self.store,
         }
     }
+}
 
-    /// Returns the indexes that are in `self` or in `other`, but not in both.
-    pub fn symmetric_difference<'a, OS>(&'a self, other: &'a IndexSet<OS>) -> SymmetricDifference<'a, S::Index, S, OS>
-    where
-        OS: IndexForward<Index = S::Index>,
-    {
-        SymmetricDifference {
-            next_left: self.store.first(),
-            next_right: other.store.first(),
-            passed: 0,
-            left: &self.store,
-            right: &other.store,
-        }
+impl<'a, I, S> RangeRev<'a, I, S>
+where
+    I: Copy,
+    S: IndexOrdered<Index = I> + IndexBackward,
+{
+    /// Returns the index the next call to `next` would return, without advancing the iterator.
+    pub fn peek(&self) -> Option<I> {
+        self.next.filter(|&next| self.is_within(next))
     }
 
-    /// Returns the indexes that are both in `self` and in `other`.
-    ///
-    /// Performance: if a set is known to contain less indexes than the other, then this set is used as `self`.
-    pub fn intersection<'a, OS>(&'a self, other: &'a IndexSet<OS>) -> Intersection<'a, S::Index, S, OS>
-    where
-        OS: IndexView<Index = S::Index>,
-    {
-        Intersection {
-            next: self.store.first(),
-            passed: 0,
-            left: &self.store,
-            right: &other.store,
+    //  Returns whether `index` is after the start of the range.
+    fn is_within(&self, index: I) -> bool {
+        match self.start {
+            Bound::Included(start) => !is_before::<S>(index, start),
+            Bound::Excluded(start) => is_before::<S>(start, index),
+            Bound::Unbounded => true,
         }
     }
+}
 
-    /// Returns the indexes that are in either one of `self` or `other`.
-    pub fn union<'a, OS>(&'a self, other: &'a IndexSet<OS>) -> Union<'a, S::Index, S, OS>
-    where
-        OS: IndexForward<Index = S::Index>,
-    {
-        Union {
-            next_left: self.store.first(),
-            next_right: other.store.first(),
-            passed: 0,
-            left: &self.store,
-            right: &other.store,
+impl<'a, I, S> Iterator for RangeRev<'a, I, S>
+where
+    I: Copy,
+    S: IndexOrdered<Index = I> + IndexBackward,
+{
+    type Item = I;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.next.take()?;
+
+        if !self.is_within(result) {
+            return None;
         }
+
+        self.next = self.store.next_before(result);
+
+        Some(result)
     }
 }
 
-impl<S> IndexOrdSet<S>
+impl<'a, I, S> FusedIterator for RangeRev<'a, I, S>
 where
-    S: IndexOrdered,
+    I: Copy,
+    S: IndexOrdered<Index = I> + IndexBackward,
 {
-    /// Returns the indexes that are in `self`, but not `other`.
-    pub fn difference<'a, OS>(&'a self, other: &'a IndexOrdSet<OS>) -> Difference<'a, S::Index, S, OS>
-    where
-        OS: IndexView<Index = S::Index>,
-    {
-        Difference {
-            next: self.store.first(),
-            passed: 0,
-            left: &self.store,
-            right: &other.store,
+}
+
+/// Iterator over the gaps between consecutive elements of S, in increasing order.
+pub struct WindowsOfGaps<'a, I, S> {
+    previous: Option<I>,
+    store: &'a S,
+}
+
+impl<'a, I, S> Clone for WindowsOfGaps<'a, I, S>
+where
+    I: Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            previous: self.previous,
+            store: self.store,
         }
     }
+}
 
-    /// Returns the indexes that are in `self` or in `other`, but not in both.
-    ///
-    /// Takes advantage of iteration over `self` and `other` being ordered to minimize the number of operations.
-    pub fn symmetric_difference<'a, OS>(
-        &'a self,
-        other: &'a IndexOrdSet<OS>,
-    ) -> SymmetricDifferenceOrd<'a, S::Index, S, OS>
-    where
-        OS: IndexOrdered<Index = S::Index>,
-    {
-        SymmetricDifferenceOrd {
-            next_left: self.store.first(),
-            next_right: other.store.first(),
-            left: &self.store,
-            right: &other.store,
+impl<'a, I, S> Iterator for WindowsOfGaps<'a, I, S>
+where
+    I: Copy + Add<Output = I> + Sub<Output = I> + From<u8>,
+    S: IndexOrdered<Index = I>,
+{
+    type Item = RangeInclusive<I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let previous = self.previous?;
+
+            self.previous = self.store.next_after(previous);
+
+            let next = self.previous?;
+
+            //  As `previous < next`, neither the successor of `previous` nor the predecessor of `next` overflow.
+            let start = previous + I::from(1);
+
+            if is_before::<S>(start, next) {
+                return Some(start..=(next - I::from(1)));
+            }
         }
     }
+}
 
-    /// Returns the indexes that are both in `self` and in `other`.
-    ///
-    /// Takes advantage of iteration over `self` and `other` being ordered to minimize the number of operations.
-    pub fn intersection<'a, OS>(&'a self, other: &'a IndexOrdSet<OS>) -> IntersectionOrd<'a, S::Index, S, OS>
-    where
-        OS: IndexOrdered<Index = S::Index>,
-    {
-        IntersectionOrd {
-            next_left: self.store.first(),
-            next_right: other.store.first(),
-            left: &self.store,
-            right: &other.store,
+impl<'a, I, S> FusedIterator for WindowsOfGaps<'a, I, S>
+where
+    I: Copy + Add<Output = I> + Sub<Output = I> + From<u8>,
+    S: IndexOrdered<Index = I>,
+{
+}
+
+#[cfg(test)]
+mod basic_iteration_tests;
+
+//
+//  Iterator operations: chunk iteration.
+//
+
+impl<S> IndexChunkedSet<S>
+where
+    S: IndexForwardChunked,
+{
+    /// Returns an iterator over the indexes in the set.
+    pub fn iter_chunks(&self) -> IterChunked<'_, S::ChunkIndex, S> {
+        IterChunked {
+            next: self.store.first_chunk(),
+            yielded: 0,
+            store: &self.store,
         }
     }
 
-    /// Returns the indexes that are in either one of `self` or `other`.
+    /// Returns an iterator over the chunks in the set, alongside their chunk index.
     ///
-    /// Takes advantage of iteration over `self` and `other` being ordered to minimize the number of operations.
-    pub fn union<'a, OS>(&'a self, other: &'a IndexOrdSet<OS>) -> UnionOrd<'a, S::Index, S, OS>
-    where
-        OS: IndexOrdered<Index = S::Index>,
-    {
-        UnionOrd {
-            next_left: self.store.first(),
-            next_right: other.store.first(),
-            left: &self.store,
-            right: &other.store,
+    /// Chunks are yielded in forward order. Empty chunks may, or may not, be yielded: sparse stores skip them.
+    pub fn chunks(&self) -> Chunks<'_, S::ChunkIndex, S> {
+        Chunks {
+            next: self.store.first_chunk(),
+            store: &self.store,
         }
     }
 }
 
-//  FIXME: implement more efficiently based on chunks.
 impl<S> IndexChunkedSet<S>
 where
-    S: IndexOrdered,
+    S: IndexBackwardChunked,
 {
-    /// Returns the indexes that are in `self`, but not `other`.
-    pub fn difference<'a, OS>(&'a self, other: &'a IndexChunkedSet<OS>) -> Difference<'a, S::Index, S, OS>
-    where
-        OS: IndexView<Index = S::Index>,
-    {
-        Difference {
-            next: self.store.first(),
-            passed: 0,
-            left: &self.store,
-            right: &other.store,
+    /// Returns an iterator over the indexes in the set.
+    pub fn iter_chunks_rev(&self) -> IterChunkedRev<'_, S::ChunkIndex, S> {
+        IterChunkedRev {
+            next: self.store.last_chunk(),
+            yielded: 0,
+            store: &self.store,
         }
     }
 
-    /// Returns the indexes that are in `self` or in `other`, but not in both.
+    /// Returns an iterator over the chunks in the set, alongside their chunk index, in reverse order.
     ///
-    /// Takes advantage of iteration over `self` and `other` being ordered to minimize the number of operations.
-    pub fn symmetric_difference<'a, OS>(
-        &'a self,
-        other: &'a IndexChunkedSet<OS>,
-    ) -> SymmetricDifferenceOrd<'a, S::Index, S, OS>
-    where
-        OS: IndexOrdered<Index = S::Index>,
-    {
-        SymmetricDifferenceOrd {
-            next_left: self.store.first(),
-            next_right: other.store.first(),
-            left: &self.store,
-            right: &other.store,
+    /// Chunks are yielded in backward order. Empty chunks may, or may not, be yielded: sparse stores skip them.
+    pub fn chunks_rev(&self) -> ChunksRev<'_, S::ChunkIndex, S> {
+        ChunksRev {
+            next: self.store.last_chunk(),
+            store: &self.store,
         }
     }
+}
 
-    /// Returns the indexes that are both in `self` and in `other`.
-    ///
-    /// Takes advantage of iteration over `self` and `other` being ordered to minimize the number of operations.
-    pub fn intersection<'a, OS>(&'a self, other: &'a IndexChunkedSet<OS>) -> IntersectionOrd<'a, S::Index, S, OS>
-    where
-        OS: IndexOrdered<Index = S::Index>,
-    {
-        IntersectionOrd {
-            next_left: self.store.first(),
-            next_right: other.store.first(),
-            left: &self.store,
-            right: &other.store,
+/// Iterator over the chunk indexes of S.
+pub struct IterChunked<'a, I, S> {
+    next: Option<I>,
+    yielded: usize,
+    store: &'a S,
+}
+
+impl<'a, I, S> Clone for IterChunked<'a, I, S>
+where
+    I: Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            next: self.next,
+            yielded: self.yielded,
+            store: self.store,
+        }
+    }
+}
+
+impl<'a, I, S> Iterator for IterChunked<'a, I, S>
+where
+    I: Copy,
+    S: IndexForwardChunked<ChunkIndex = I>,
+{
+    type Item = I;
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let length = self.len();
+
+        (length, Some(length))
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.next.take()?;
+
+        self.yielded += 1;
+        self.next = self.store.next_chunk_after(result);
+
+        Some(result)
+    }
+}
+
+impl<'a, I, S> ExactSizeIterator for IterChunked<'a, I, S>
+where
+    I: Copy,
+    S: IndexForwardChunked<ChunkIndex = I>,
+{
+    fn len(&self) -> usize {
+        self.store.len() - self.yielded
+    }
+
+    #[cfg(feature = "nightly")]
+    fn is_empty(&self) -> bool {
+        self.store.len() == self.yielded
+    }
+}
+
+impl<'a, I, S> FusedIterator for IterChunked<'a, I, S>
+where
+    I: Copy,
+    S: IndexForwardChunked<ChunkIndex = I>,
+{
+}
+
+/// Iterator over the chunk indexes of S, in reverse order.
+pub struct IterChunkedRev<'a, I, S> {
+    next: Option<I>,
+    yielded: usize,
+    store: &'a S,
+}
+
+impl<'a, I, S> Clone for IterChunkedRev<'a, I, S>
+where
+    I: Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            next: self.next,
+            yielded: self.yielded,
+            store: self.store,
+        }
+    }
+}
+
+impl<'a, I, S> Iterator for IterChunkedRev<'a, I, S>
+where
+    I: Copy,
+    S: IndexBackwardChunked<ChunkIndex = I>,
+{
+    type Item = I;
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let length = self.len();
+
+        (length, Some(length))
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.next.take()?;
+
+        self.yielded += 1;
+        self.next = self.store.next_chunk_before(result);
+
+        Some(result)
+    }
+}
+
+impl<'a, I, S> ExactSizeIterator for IterChunkedRev<'a, I, S>
+where
+    I: Copy,
+    S: IndexBackwardChunked<ChunkIndex = I>,
+{
+    fn len(&self) -> usize {
+        self.store.len() - self.yielded
+    }
+
+    #[cfg(feature = "nightly")]
+    fn is_empty(&self) -> bool {
+        self.store.len() == self.yielded
+    }
+}
+
+impl<'a, I, S> FusedIterator for IterChunkedRev<'a, I, S>
+where
+    I: Copy,
+    S: IndexBackwardChunked<ChunkIndex = I>,
+{
+}
+
+/// Iterator over the chunks of S, alongside their chunk index.
+pub struct Chunks<'a, I, S> {
+    next: Option<I>,
+    store: &'a S,
+}
+
+impl<'a, I, S> Clone for Chunks<'a, I, S>
+where
+    I: Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            next: self.next,
+            store: self.store,
+        }
+    }
+}
+
+impl<'a, I, S> Iterator for Chunks<'a, I, S>
+where
+    I: Copy,
+    S: IndexForwardChunked<ChunkIndex = I>,
+{
+    type Item = (I, S::Chunk);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let outer = self.next.take()?;
+
+            self.next = self.store.next_chunk_after(outer);
+
+            if let Some(chunk) = self.store.get_chunk(outer) {
+                return Some((outer, chunk));
+            }
+        }
+    }
+}
+
+impl<'a, I, S> FusedIterator for Chunks<'a, I, S>
+where
+    I: Copy,
+    S: IndexForwardChunked<ChunkIndex = I>,
+{
+}
+
+/// Iterator over the chunks of S, alongside their chunk index, in reverse order.
+pub struct ChunksRev<'a, I, S> {
+    next: Option<I>,
+    store: &'a S,
+}
+
+impl<'a, I, S> Clone for ChunksRev<'a, I, S>
+where
+    I: Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            next: self.next,
+            store: self.store,
+        }
+    }
+}
+
+impl<'a, I, S> Iterator for ChunksRev<'a, I, S>
+where
+    I: Copy,
+    S: IndexBackwardChunked<ChunkIndex = I>,
+{
+    type Item = (I, S::Chunk);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let outer = self.next.take()?;
+
+            self.next = self.store.next_chunk_before(outer);
+
+            if let Some(chunk) = self.store.get_chunk(outer) {
+                return Some((outer, chunk));
+            }
+        }
+    }
+}
+
+impl<'a, I, S> FusedIterator for ChunksRev<'a, I, S>
+where
+    I: Copy,
+    S: IndexBackwardChunked<ChunkIndex = I>,
+{
+}
+
+//
+//  Iterator operations: chunk folding.
+//
+
+impl<S> IndexChunkedSet<S>
+where
+    S: IndexForwardChunked,
+{
+    /// Folds every chunk of the set into an accumulator, by applying `f` to each chunk in turn.
+    ///
+    /// Chunks are visited in forward order. Empty chunks may, or may not, be visited: sparse stores skip them.
+    pub fn fold_chunks<B, F>(&self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, S::ChunkIndex, &S::Chunk) -> B,
+    {
+        let mut accumulator = init;
+        let mut next = self.store.first_chunk();
+
+        while let Some(outer) = next {
+            if let Some(chunk) = self.store.get_chunk(outer) {
+                accumulator = f(accumulator, outer, &chunk);
+            }
+
+            next = self.store.next_chunk_after(outer);
+        }
+
+        accumulator
+    }
+
+    /// Folds every chunk of the set into an accumulator, by applying `f` to each chunk in turn, as long as `f`
+    /// returns successfully.
+    ///
+    /// Returns the first error returned by `f`, if any, without visiting any further chunk.
+    ///
+    /// Chunks are visited in forward order. Empty chunks may, or may not, be visited: sparse stores skip them.
+    pub fn try_fold_chunks<B, E, F>(&self, init: B, mut f: F) -> Result<B, E>
+    where
+        F: FnMut(B, S::ChunkIndex, &S::Chunk) -> Result<B, E>,
+    {
+        let mut accumulator = init;
+        let mut next = self.store.first_chunk();
+
+        while let Some(outer) = next {
+            if let Some(chunk) = self.store.get_chunk(outer) {
+                accumulator = f(accumulator, outer, &chunk)?;
+            }
+
+            next = self.store.next_chunk_after(outer);
+        }
+
+        Ok(accumulator)
+    }
+}
+
+//
+//  Iterator operations: drain, erase_if, retain.
+//
+
+impl<S> IndexSet<S>
+where
+    S: IndexForward + IndexStore,
+{
+    /// Clears the set, returning all elements as an iterator.
+    ///
+    /// The set is cleared when the iterator is dropped, whether it was fully consumed or not.
+    #[must_use = "use `clear` if the drained indexes are not needed"]
+    pub fn drain(&mut self) -> Drain<'_, S::Index, S> {
+        Drain {
+            next: self.store.first(),
+            back: None,
+            remaining: self.store.len(),
+            store: &mut self.store,
+        }
+    }
+
+    /// Creates an iterator which uses a closure to determine if an element should be removed.
+    ///
+    /// The elements returned by the iterator are removed from the set. The iterator is lazy: if it is dropped before
+    /// being fully consumed, the elements it did not return are retained.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, S::Index, S, F>
+    where
+        F: FnMut(S::Index) -> bool,
+    {
+        ExtractIf {
+            pred,
+            next: self.store.first(),
+            remaining: self.store.len(),
+            store: &mut self.store,
+        }
+    }
+
+    /// Retains only the elements specified by the predicate.
+    pub fn retain<F>(&mut self, mut pred: F)
+    where
+        F: FnMut(S::Index) -> bool,
+    {
+        let mut cursor = self.store.first();
+
+        while let Some(index) = cursor {
+            //  Fetched prior to removing `index`, as `next_after` need not support indexes which are not contained.
+            cursor = self.store.next_after(index);
+
+            if !pred(index) {
+                self.store.remove(index);
+            }
+        }
+    }
+
+    /// Returns a cursor pointing at the first index of the set, if any.
+    ///
+    /// The cursor allows removing indexes while iterating.
+    pub fn cursor_front(&mut self) -> Cursor<'_, S::Index, S> {
+        Cursor {
+            current: self.store.first(),
+            store: &mut self.store,
+        }
+    }
+}
+
+impl<S> IndexSet<S>
+where
+    S: IndexCollection + IndexForward + IndexStore<InsertionError = Never>,
+{
+    /// Removes all the indexes matching the predicate, returning them as a new set.
+    ///
+    /// Unlike `extract_if`, the removed indexes are collected in one call, and unlike `retain`, they are given back.
+    /// The predicate is called exactly once per index, in forward order.
+    pub fn drain_partition<F>(&mut self, mut pred: F) -> Self
+    where
+        F: FnMut(S::Index) -> bool,
+    {
+        let mut removed = Self::new();
+        let mut cursor = self.store.first();
+
+        while let Some(index) = cursor {
+            cursor = self.store.next_after(index);
+
+            if pred(index) {
+                self.store.remove(index);
+
+                let _ = removed.store.insert(index);
+            }
+        }
+
+        removed
+    }
+}
+
+impl<S> IndexOrdSet<S>
+where
+    S: IndexForward + IndexStore,
+{
+    /// Clears the set, returning all elements as an iterator.
+    ///
+    /// The set is cleared when the iterator is dropped, whether it was fully consumed or not.
+    #[must_use = "use `clear` if the drained indexes are not needed"]
+    pub fn drain(&mut self) -> Drain<'_, S::Index, S> {
+        Drain {
+            next: self.store.first(),
+            back: None,
+            remaining: self.store.len(),
+            store: &mut self.store,
+        }
+    }
+
+    /// Creates an iterator which uses a closure to determine if an element should be removed.
+    ///
+    /// The elements returned by the iterator are removed from the set. The iterator is lazy: if it is dropped before
+    /// being fully consumed, the elements it did not return are retained.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, S::Index, S, F>
+    where
+        F: FnMut(S::Index) -> bool,
+    {
+        ExtractIf {
+            pred,
+            next: self.store.first(),
+            remaining: self.store.len(),
+            store: &mut self.store,
+        }
+    }
+
+    /// Retains only the elements specified by the predicate.
+    pub fn retain<F>(&mut self, mut pred: F)
+    where
+        F: FnMut(S::Index) -> bool,
+    {
+        let mut cursor = self.store.first();
+
+        while let Some(index) = cursor {
+            //  Fetched prior to removing `index`, see `IndexSet::retain`, though an ordered store supports `next_after`
+            //  indexes which are not contained, per Seek.
+            cursor = self.store.next_after(index);
+
+            if !pred(index) {
+                self.store.remove(index);
+            }
+        }
+    }
+
+    /// Returns a cursor pointing at the first index of the set, if any.
+    ///
+    /// The cursor allows removing indexes while iterating.
+    pub fn cursor_front(&mut self) -> Cursor<'_, S::Index, S> {
+        Cursor {
+            current: self.store.first(),
+            store: &mut self.store,
+        }
+    }
+}
+
+impl<S> IndexOrdSet<S>
+where
+    S: IndexCollection + IndexOrdered + IndexStore<InsertionError = Never>,
+{
+    /// Removes all the indexes matching the predicate, returning them as a new set.
+    ///
+    /// Unlike `extract_if`, the removed indexes are collected in one call, and unlike `retain`, they are given back.
+    /// The predicate is called exactly once per index, in forward order.
+    pub fn drain_partition<F>(&mut self, mut pred: F) -> Self
+    where
+        F: FnMut(S::Index) -> bool,
+    {
+        let mut removed = Self::new();
+        let mut cursor = self.store.first();
+
+        while let Some(index) = cursor {
+            cursor = self.store.next_after(index);
+
+            if pred(index) {
+                self.store.remove(index);
+
+                let _ = removed.store.insert(index);
+            }
+        }
+
+        removed
+    }
+}
+
+impl<S> IndexChunkedSet<S>
+where
+    S: IndexForwardChunked + IndexStore + IndexStoreChunked<Chunk: IndexForward>,
+{
+    /// Clears the set, returning all elements as an iterator.
+    ///
+    /// The set is drained chunk by chunk, each chunk being cleared wholesale once all its indexes have been yielded.
+    /// The set is cleared when the iterator is dropped, whether it was fully consumed or not.
+    #[must_use = "use `clear` if the drained indexes are not needed"]
+    pub fn drain(&mut self) -> DrainChunked<'_, S> {
+        let current = self
+            .store
+            .first_chunk()
+            .map(|outer| (outer, self.store.get_chunk(outer).unwrap_or_default()));
+
+        DrainChunked {
+            current,
+            remaining: self.store.len(),
+            store: &mut self.store,
+        }
+    }
+}
+
+impl<S> IndexChunkedSet<S>
+where
+    S: IndexForwardChunked + IndexStoreChunked<Chunk: IndexForward, SetError = Never>,
+{
+    /// Creates an iterator which uses a closure to determine if an element should be removed.
+    ///
+    /// The elements returned by the iterator are removed from the set as they are returned, by setting their chunk.
+    /// The predicate is called exactly once per index, in forward order. The iterator is lazy: if it is dropped before
+    /// being fully consumed, the elements it did not return are retained.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIfChunked<'_, S, F>
+    where
+        F: FnMut(S::Index) -> bool,
+    {
+        let mut this = ExtractIfChunked {
+            pred,
+            outer: self.store.first_chunk(),
+            chunk: S::Chunk::default(),
+            kept: S::Chunk::default(),
+            next: None,
+            remaining: self.store.len(),
+            store: &mut self.store,
+        };
+
+        this.load();
+
+        this
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// The predicate is called exactly once per index, in forward order, and the removals are applied a chunk at a
+    /// time.
+    pub fn retain<F>(&mut self, mut pred: F)
+    where
+        F: FnMut(S::Index) -> bool,
+    {
+        let mut cursor = self.store.first_chunk();
+
+        while let Some(outer) = cursor {
+            if let Some(chunk) = self.store.get_chunk(outer) {
+                let mut kept = chunk;
+                let mut inner_cursor = chunk.first();
+
+                while let Some(inner) = inner_cursor {
+                    if !pred(S::fuse(outer, inner)) {
+                        kept.remove(inner);
+                    }
+
+                    inner_cursor = chunk.next_after(inner);
+                }
+
+                if kept != chunk {
+                    let _ = self.store.set_chunk(outer, kept);
+                }
+            }
+
+            cursor = self.store.next_chunk_after(outer);
+        }
+    }
+}
+
+impl<S> IndexChunkedSet<S>
+where
+    S: IndexCollection + IndexOrderedChunked + IndexStoreChunked<Chunk: IndexForward, SetError = Never>,
+{
+    /// Removes all the indexes matching the predicate, returning them as a new set.
+    ///
+    /// Unlike `extract_if`, the removed indexes are collected in one call, and unlike `retain`, they are given back.
+    /// The predicate is called exactly once per index, in forward order, and the removals are applied a chunk at a
+    /// time.
+    pub fn drain_partition<F>(&mut self, mut pred: F) -> Self
+    where
+        F: FnMut(S::Index) -> bool,
+    {
+        let mut removed = Self::new();
+        let mut cursor = self.store.first_chunk();
+
+        while let Some(outer) = cursor {
+            cursor = self.store.next_chunk_after(outer);
+
+            let Some(chunk) = self.store.get_chunk(outer) else {
+                continue;
+            };
+
+            let mut kept = chunk;
+            let mut inner_cursor = chunk.first();
+
+            while let Some(inner) = inner_cursor {
+                if pred(S::fuse(outer, inner)) {
+                    kept.remove(inner);
+                }
+
+                inner_cursor = chunk.next_after(inner);
+            }
+
+            if kept != chunk {
+                let _ = self.store.set_chunk(outer, kept);
+                let _ = removed.store.set_chunk(outer, chunk - kept);
+            }
+        }
+
+        removed
+    }
+}
+
+impl<S> IndexChunkedSet<S>
+where
+    S: IndexForward + IndexStore,
+{
+    /// Returns a cursor pointing at the first index of the set, if any.
+    ///
+    /// The cursor allows removing indexes while iterating.
+    pub fn cursor_front(&mut self) -> Cursor<'_, S::Index, S> {
+        Cursor {
+            current: self.store.first(),
+            store: &mut self.store,
+        }
+    }
+}
+
+/// A draining iterator over the items of an `IndexSet`.
+pub struct Drain<'a, I, S>
+where
+    S: IndexStore,
+{
+    next: Option<I>,
+    //  Last index yielded from the back, if any.
+    back: Option<I>,
+    //  Number of indexes left to yield, from either end, fixed at construction as the store cannot change meanwhile.
+    remaining: usize,
+    store: &'a mut S,
+}
+
+impl<'a, I, S> Drop for Drain<'a, I, S>
+where
+    S: IndexStore,
+{
+    fn drop(&mut self) {
+        self.store.clear();
+    }
+}
+
+impl<'a, I, S> Iterator for Drain<'a, I, S>
+where
+    I: Copy,
+    S: IndexForward<Index = I> + IndexStore<Index = I>,
+{
+    type Item = S::Index;
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let length = self.len();
+
+        (length, Some(length))
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.next.take()?;
+
+        self.remaining -= 1;
+
+        //  Once indexes were yielded from the back, stops once all indexes have been yielded, from either end, rather
+        //  than comparing against the last index yielded from the back, as the store need not be ordered.
+        self.next = if self.back.is_none() || self.remaining > 0 {
+            self.store.next_after(next)
+        } else {
+            None
+        };
+
+        Some(next)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.remaining {
+            self.next = None;
+            self.remaining = 0;
+
+            return None;
+        }
+
+        if let Some(n) = n.checked_sub(1) {
+            let index = self.next.take()?;
+
+            match self.store.nth_after(n, index) {
+                Ok(next) => {
+                    self.next = Some(next);
+                    self.remaining -= n;
+                }
+                Err(remainder) => {
+                    self.remaining -= n - remainder.get();
+                }
+            }
+        }
+
+        self.next()
+    }
+}
+
+impl<'a, I, S> DoubleEndedIterator for Drain<'a, I, S>
+where
+    I: Copy,
+    S: IndexBackward<Index = I> + IndexStore<Index = I>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        //  Exhausted, from either end.
+        self.next?;
+
+        let result = match self.back {
+            Some(back) => self.store.next_before(back),
+            None => self.store.last(),
+        }?;
+
+        self.remaining -= 1;
+        self.back = Some(result);
+
+        if self.remaining == 0 {
+            self.next = None;
+        }
+
+        Some(result)
+    }
+}
+
+impl<'a, I, S> ExactSizeIterator for Drain<'a, I, S>
+where
+    I: Copy,
+    S: IndexForward<Index = I> + IndexStore<Index = I>,
+{
+    fn len(&self) -> usize {
+        self.remaining
+    }
+
+    #[cfg(feature = "nightly")]
+    fn is_empty(&self) -> bool {
+        self.remaining == 0
+    }
+}
+
+impl<'a, I, S> FusedIterator for Drain<'a, I, S>
+where
+    I: Copy,
+    S: IndexForward<Index = I> + IndexStore<Index = I>,
+{
+}
+
+/// A draining iterator over the items of an `IndexChunkedSet`.
+pub struct DrainChunked<'a, S>
+where
+    S: IndexStore + IndexViewChunked,
+{
+    current: Option<(S::ChunkIndex, S::Chunk)>,
+    remaining: usize,
+    store: &'a mut S,
+}
+
+impl<'a, S> Drop for DrainChunked<'a, S>
+where
+    S: IndexStore + IndexViewChunked,
+{
+    fn drop(&mut self) {
+        self.store.clear();
+    }
+}
+
+impl<'a, S> Iterator for DrainChunked<'a, S>
+where
+    S: IndexForwardChunked + IndexStore + IndexStoreChunked<Chunk: IndexForward>,
+{
+    type Item = S::Index;
+
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let length = self.len();
+
+        (length, Some(length))
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (outer, chunk) = self.current.as_mut()?;
+            let outer = *outer;
+
+            if let Some(inner) = chunk.first() {
+                chunk.remove(inner);
+
+                self.remaining -= 1;
+
+                return Some(S::fuse(outer, inner));
+            }
+
+            //  A failure to clear the chunk is of no consequence, as the store is cleared on drop regardless.
+            let _ = self.store.set_chunk(outer, S::Chunk::default());
+
+            self.current = self
+                .store
+                .next_chunk_after(outer)
+                .map(|outer| (outer, self.store.get_chunk(outer).unwrap_or_default()));
+        }
+    }
+}
+
+impl<'a, S> ExactSizeIterator for DrainChunked<'a, S>
+where
+    S: IndexForwardChunked + IndexStore + IndexStoreChunked<Chunk: IndexForward>,
+{
+    fn len(&self) -> usize {
+        self.remaining
+    }
+
+    #[cfg(feature = "nightly")]
+    fn is_empty(&self) -> bool {
+        self.remaining == 0
+    }
+}
+
+impl<'a, S> FusedIterator for DrainChunked<'a, S> where
+    S: IndexForwardChunked + IndexStore + IndexStoreChunked<Chunk: IndexForward>
+{
+}
+
+/// An extractor iterator.
+pub struct ExtractIf<'a, I, S, F> {
+    pred: F,
+    next: Option<I>,
+    //  Number of indexes left to visit, fixed at construction and decremented for every index visited.
+    remaining: usize,
+    store: &'a mut S,
+}
+
+impl<'a, I, S, F> Iterator for ExtractIf<'a, I, S, F>
+where
+    I: Copy,
+    S: IndexForward<Index = I> + IndexStore<Index = I>,
+    F: FnMut(I) -> bool,
+{
+    type Item = S::Index;
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(index) = self.next
+            && !(self.pred)(index)
+        {
+            self.remaining -= 1;
+            self.next = self.store.next_after(index);
+        }
+
+        let index = self.next.take()?;
+
+        //  Fetched prior to removing `index`, see `retain`.
+        self.next = self.store.next_after(index);
+        self.remaining -= 1;
+
+        self.store.remove(index);
+
+        Some(index)
+    }
+}
+
+impl<'a, I, S, F> FusedIterator for ExtractIf<'a, I, S, F>
+where
+    I: Copy,
+    S: IndexForward<Index = I> + IndexStore<Index = I>,
+    F: FnMut(I) -> bool,
+{
+}
+
+/// An extractor iterator over the items of an `IndexChunkedSet`.
+///
+/// Each index yielded is removed from the set as it is yielded, by setting its chunk, hence even if the iterator is
+/// leaked, the indexes it returned are no longer in the set.
+pub struct ExtractIfChunked<'a, S, F>
+where
+    S: IndexStoreChunked<SetError = Never>,
+{
+    pred: F,
+    outer: Option<S::ChunkIndex>,
+    chunk: S::Chunk,
+    kept: S::Chunk,
+    next: Option<<S::Chunk as IndexView>::Index>,
+    remaining: usize,
+    store: &'a mut S,
+}
+
+impl<'a, S, F> ExtractIfChunked<'a, S, F>
+where
+    S: IndexStoreChunked<Chunk: IndexForward, SetError = Never>,
+{
+    //  Loads the current chunk, if any.
+    fn load(&mut self) {
+        let Some(outer) = self.outer else { return };
+
+        self.chunk = self.store.get_chunk(outer).unwrap_or_default();
+        self.kept = self.chunk;
+        self.next = self.chunk.first();
+    }
+}
+
+impl<'a, S, F> Iterator for ExtractIfChunked<'a, S, F>
+where
+    S: IndexForwardChunked + IndexStoreChunked<Chunk: IndexForward, SetError = Never>,
+    F: FnMut(S::Index) -> bool,
+{
+    type Item = S::Index;
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let outer = self.outer?;
+
+            while let Some(inner) = self.next {
+                self.next = self.chunk.next_after(inner);
+                self.remaining -= 1;
+
+                let index = S::fuse(outer, inner);
+
+                if (self.pred)(index) {
+                    self.kept.remove(inner);
+
+                    //  Iteration proceeds over `self.chunk`, a snapshot, hence is unaffected by the removal.
+                    let _ = self.store.set_chunk(outer, self.kept);
+
+                    return Some(index);
+                }
+            }
+
+            self.outer = self.store.next_chunk_after(outer);
+
+            self.load();
+        }
+    }
+}
+
+impl<'a, S, F> FusedIterator for ExtractIfChunked<'a, S, F>
+where
+    S: IndexForwardChunked + IndexStoreChunked<Chunk: IndexForward, SetError = Never>,
+    F: FnMut(S::Index) -> bool,
+{
+}
+
+/// A cursor over the indexes of a set, allowing removal of the current index.
+///
+/// Once the cursor has moved past either end of the set, it no longer points at any index, and stays so.
+pub struct Cursor<'a, I, S> {
+    current: Option<I>,
+    store: &'a mut S,
+}
+
+impl<'a, I, S> Cursor<'a, I, S>
+where
+    I: Copy,
+    S: IndexForward<Index = I> + IndexStore<Index = I>,
+{
+    /// Returns the index the cursor points at, if any.
+    pub fn current(&self) -> Option<I> {
+        self.current
+    }
+
+    /// Moves the cursor to the next index, if any.
+    pub fn move_next(&mut self) {
+        self.current = self.current.and_then(|current| self.store.next_after(current));
+    }
+
+    /// Moves the cursor to the previous index, if any.
+    pub fn move_prev(&mut self)
+    where
+        S: IndexBackward,
+    {
+        self.current = self.current.and_then(|current| self.store.next_before(current));
+    }
+
+    /// Removes the index the cursor points at, if any, and moves the cursor to the next index.
+    ///
+    /// Returns the removed index, if any.
+    pub fn remove_current(&mut self) -> Option<I> {
+        let current = self.current?;
+
+        //  Fetched prior to removing `current`, see `IndexSet::retain`.
+        self.current = self.store.next_after(current);
+
+        self.store.remove(current);
+
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod extract_iteration_tests;
+
+//
+//  Iterator operations: difference, symmetric difference, intersection, union.
+//
+
+impl<S> IndexSet<S>
+where
+    S: IndexForward,
+{
+    /// Returns the indexes that are in `self`, but not `other`.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn difference<'a, OS>(&'a self, other: &'a IndexSet<OS>) -> Difference<'a, S::Index, S, OS>
+    where
+        OS: IndexView<Index = S::Index>,
+    {
+        Difference {
+            next: self.store.first(),
+            passed: 0,
+            left: &self.store,
+            right: &other.store,
+        }
+    }
+
+    /// Returns the indexes that are in `self` or in `other`, but not in both.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn symmetric_difference<'a, OS>(&'a self, other: &'a IndexSet<OS>) -> SymmetricDifference<'a, S::Index, S, OS>
+    where
+        OS: IndexForward<Index = S::Index>,
+    {
+        SymmetricDifference {
+            next_left: self.store.first(),
+            next_right: other.store.first(),
+            passed: 0,
+            left: &self.store,
+            right: &other.store,
+        }
+    }
+
+    /// Returns the indexes that are in `self` or in `other`, but not in both.
+    ///
+    /// Unlike `symmetric_difference`, `self` is modified in place, see `bitxor_assign`, rather than iterating over both
+    /// sets and probing the opposite set for each index.
+    pub fn symmetric_difference_into<OS>(mut self, other: &IndexSet<OS>) -> Self
+    where
+        S: IndexStore<InsertionError = Never>,
+        OS: IndexForward<Index = S::Index>,
+    {
+        self.bitxor_assign(other);
+
+        self
+    }
+
+    /// Returns the indexes that are both in `self` and in `other`.
+    ///
+    /// Performance: if a set is known to contain less indexes than the other, then this set is used as `self`.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn intersection<'a, OS>(&'a self, other: &'a IndexSet<OS>) -> Intersection<'a, S::Index, S, OS>
+    where
+        OS: IndexView<Index = S::Index>,
+    {
+        Intersection {
+            next: self.store.first(),
+            passed: 0,
+            left: &self.store,
+            right: &other.store,
+        }
+    }
+
+    /// Returns the number of indexes that are both in `self` and in `other`, without materializing the intersection.
+    ///
+    /// Performance: if a set is known to contain less indexes than the other, then this set is used as `self`.
+    pub fn overlap_len<OS>(&self, other: &IndexSet<OS>) -> usize
+    where
+        OS: IndexView<Index = S::Index>,
+    {
+        self.intersection(other).count()
+    }
+
+    /// Returns the number of indexes that are in `self` or in `other`, but not in both, without materializing the
+    /// symmetric difference.
+    ///
+    /// Computed as `|self| + |other| - 2 |self ∩ other|`, see `overlap_len`.
+    ///
+    /// Performance: if a set is known to contain less indexes than the other, then this set is used as `self`.
+    pub fn symmetric_difference_len<OS>(&self, other: &IndexSet<OS>) -> usize
+    where
+        OS: IndexView<Index = S::Index>,
+    {
+        self.len() + other.len() - 2 * self.overlap_len(other)
+    }
+
+    /// Returns the Jaccard similarity of `self` and `other`, ie `|self ∩ other| / |self ∪ other|`.
+    ///
+    /// Two empty sets being identical, their similarity is `1.0`.
+    pub fn jaccard<OS>(&self, other: &IndexSet<OS>) -> f64
+    where
+        OS: IndexView<Index = S::Index>,
+    {
+        let overlap = self.overlap_len(other);
+        let union = self.len() + other.len() - overlap;
+
+        if union == 0 {
+            return 1.0;
+        }
+
+        overlap as f64 / union as f64
+    }
+
+    /// Returns the Sørensen-Dice similarity of `self` and `other`, ie `2 |self ∩ other| / (|self| + |other|)`.
+    ///
+    /// Two empty sets being identical, their similarity is `1.0`.
+    pub fn dice<OS>(&self, other: &IndexSet<OS>) -> f64
+    where
+        OS: IndexView<Index = S::Index>,
+    {
+        let total = self.len() + other.len();
+
+        if total == 0 {
+            return 1.0;
+        }
+
+        (2 * self.overlap_len(other)) as f64 / total as f64
+    }
+
+    /// Returns the indexes that are in either one of `self` or `other`.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn union<'a, OS>(&'a self, other: &'a IndexSet<OS>) -> Union<'a, S::Index, S, OS>
+    where
+        OS: IndexForward<Index = S::Index>,
+    {
+        Union {
+            next_left: self.store.first(),
+            next_right: other.store.first(),
+            passed: 0,
+            left: &self.store,
+            right: &other.store,
+        }
+    }
+}
+
+impl<S> IndexOrdSet<S>
+where
+    S: IndexOrdered,
+{
+    /// Returns the indexes that are in `self`, but not `other`.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn difference<'a, OS>(&'a self, other: &'a IndexOrdSet<OS>) -> Difference<'a, S::Index, S, OS>
+    where
+        OS: IndexView<Index = S::Index>,
+    {
+        Difference {
+            next: self.store.first(),
+            passed: 0,
+            left: &self.store,
+            right: &other.store,
+        }
+    }
+
+    /// Returns the indexes that are in `self` or in `other`, but not in both.
+    ///
+    /// Takes advantage of iteration over `self` and `other` being ordered to minimize the number of operations.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn symmetric_difference<'a, OS>(
+        &'a self,
+        other: &'a IndexOrdSet<OS>,
+    ) -> SymmetricDifferenceOrd<'a, S::Index, S, OS>
+    where
+        OS: IndexOrdered<Index = S::Index>,
+    {
+        SymmetricDifferenceOrd {
+            next_left: self.store.first(),
+            next_right: other.store.first(),
+            left: &self.store,
+            right: &other.store,
+        }
+    }
+
+    /// Returns the indexes that are both in `self` and in `other`.
+    ///
+    /// Takes advantage of iteration over `self` and `other` being ordered to minimize the number of operations.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn intersection<'a, OS>(&'a self, other: &'a IndexOrdSet<OS>) -> IntersectionOrd<'a, S::Index, S, OS>
+    where
+        OS: IndexOrdered<Index = S::Index>,
+    {
+        IntersectionOrd {
+            next_left: self.store.first(),
+            next_right: other.store.first(),
+            left: &self.store,
+            right: &other.store,
+        }
+    }
+
+    /// Returns the number of indexes that are both in `self` and in `other`, without materializing the intersection.
+    ///
+    /// Takes advantage of iteration over `self` and `other` being ordered to merge-count the common indexes.
+    pub fn overlap_len<OS>(&self, other: &IndexOrdSet<OS>) -> usize
+    where
+        OS: IndexOrdered<Index = S::Index>,
+    {
+        self.intersection(other).count()
+    }
+
+    /// Returns the number of indexes that are in `self` or in `other`, but not in both, without materializing the
+    /// symmetric difference.
+    ///
+    /// Computed as `|self| + |other| - 2 |self ∩ other|`, see `overlap_len`.
+    pub fn symmetric_difference_len<OS>(&self, other: &IndexOrdSet<OS>) -> usize
+    where
+        OS: IndexOrdered<Index = S::Index>,
+    {
+        self.len() + other.len() - 2 * self.overlap_len(other)
+    }
+
+    /// Returns the Jaccard similarity of `self` and `other`, ie `|self ∩ other| / |self ∪ other|`.
+    ///
+    /// Two empty sets being identical, their similarity is `1.0`.
+    pub fn jaccard<OS>(&self, other: &IndexOrdSet<OS>) -> f64
+    where
+        OS: IndexOrdered<Index = S::Index>,
+    {
+        let overlap = self.overlap_len(other);
+        let union = self.len() + other.len() - overlap;
+
+        if union == 0 {
+            return 1.0;
+        }
+
+        overlap as f64 / union as f64
+    }
+
+    /// Returns the Sørensen-Dice similarity of `self` and `other`, ie `2 |self ∩ other| / (|self| + |other|)`.
+    ///
+    /// Two empty sets being identical, their similarity is `1.0`.
+    pub fn dice<OS>(&self, other: &IndexOrdSet<OS>) -> f64
+    where
+        OS: IndexOrdered<Index = S::Index>,
+    {
+        let total = self.len() + other.len();
+
+        if total == 0 {
+            return 1.0;
+        }
+
+        (2 * self.overlap_len(other)) as f64 / total as f64
+    }
+
+    /// Returns the indexes that are in either one of `self` or `other`.
+    ///
+    /// Takes advantage of iteration over `self` and `other` being ordered to minimize the number of operations.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn union<'a, OS>(&'a self, other: &'a IndexOrdSet<OS>) -> UnionOrd<'a, S::Index, S, OS>
+    where
+        OS: IndexOrdered<Index = S::Index>,
+    {
+        UnionOrd {
+            next_left: self.store.first(),
+            next_right: other.store.first(),
+            left: &self.store,
+            right: &other.store,
+        }
+    }
+}
+
+impl<S> IndexChunkedSet<S>
+where
+    S: IndexOrderedChunked<Chunk: IndexForward>,
+{
+    /// Returns the indexes that are in `self`, but not `other`.
+    ///
+    /// Takes advantage of the chunks of `self` and `other` to operate on a chunk at a time.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn difference<'a, OS>(&'a self, other: &'a IndexChunkedSet<OS>) -> DifferenceChunked<'a, S, OS>
+    where
+        OS: IndexViewChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+    {
+        DifferenceChunked {
+            merge: ChunkMerge::new_probing(&self.store, &other.store),
+        }
+    }
+
+    /// Returns the indexes that are in `self` or in `other`, but not in both.
+    ///
+    /// Takes advantage of the chunks of `self` and `other` to operate on a chunk at a time.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn symmetric_difference<'a, OS>(
+        &'a self,
+        other: &'a IndexChunkedSet<OS>,
+    ) -> SymmetricDifferenceChunked<'a, S, OS>
+    where
+        OS: IndexOrderedChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+    {
+        SymmetricDifferenceChunked {
+            merge: ChunkMerge::new_merging(&self.store, &other.store),
+        }
+    }
+
+    /// Returns the indexes that are both in `self` and in `other`.
+    ///
+    /// Takes advantage of the chunks of `self` and `other` to operate on a chunk at a time.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn intersection<'a, OS>(&'a self, other: &'a IndexChunkedSet<OS>) -> IntersectionChunked<'a, S, OS>
+    where
+        OS: IndexViewChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+    {
+        IntersectionChunked {
+            merge: ChunkMerge::new_probing(&self.store, &other.store),
+        }
+    }
+
+    /// Returns the number of indexes that are both in `self` and in `other`, without materializing the intersection.
+    ///
+    /// Takes advantage of the chunks of `self` and `other` to count the common indexes a chunk at a time.
+    pub fn overlap_len<OS>(&self, other: &IndexChunkedSet<OS>) -> usize
+    where
+        OS: IndexViewChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+    {
+        self.intersection(other).count()
+    }
+
+    /// Returns the number of indexes that are in `self` or in `other`, but not in both, without materializing the
+    /// symmetric difference.
+    ///
+    /// Computed as `|self| + |other| - 2 |self ∩ other|`, see `overlap_len`.
+    pub fn symmetric_difference_len<OS>(&self, other: &IndexChunkedSet<OS>) -> usize
+    where
+        OS: IndexViewChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+    {
+        self.len() + other.len() - 2 * self.overlap_len(other)
+    }
+
+    /// Returns the Jaccard similarity of `self` and `other`, ie `|self ∩ other| / |self ∪ other|`.
+    ///
+    /// Two empty sets being identical, their similarity is `1.0`.
+    pub fn jaccard<OS>(&self, other: &IndexChunkedSet<OS>) -> f64
+    where
+        OS: IndexViewChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+    {
+        let overlap = self.overlap_len(other);
+        let union = self.len() + other.len() - overlap;
+
+        if union == 0 {
+            return 1.0;
+        }
+
+        overlap as f64 / union as f64
+    }
+
+    /// Returns the Sørensen-Dice similarity of `self` and `other`, ie `2 |self ∩ other| / (|self| + |other|)`.
+    ///
+    /// Two empty sets being identical, their similarity is `1.0`.
+    pub fn dice<OS>(&self, other: &IndexChunkedSet<OS>) -> f64
+    where
+        OS: IndexViewChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+    {
+        let total = self.len() + other.len();
+
+        if total == 0 {
+            return 1.0;
+        }
+
+        (2 * self.overlap_len(other)) as f64 / total as f64
+    }
+
+    /// Returns the indexes that are in either one of `self` or `other`.
+    ///
+    /// Takes advantage of the chunks of `self` and `other` to operate on a chunk at a time.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn union<'a, OS>(&'a self, other: &'a IndexChunkedSet<OS>) -> UnionChunked<'a, S, OS>
+    where
+        OS: IndexOrderedChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+    {
+        UnionChunked {
+            merge: ChunkMerge::new_merging(&self.store, &other.store),
+        }
+    }
+}
+
+impl<S> IndexSet<S>
+where
+    S: IndexForward,
+{
+    /// Returns a new set of the indexes that are in `self`, but not `other`.
+    ///
+    /// Unlike `collect`-ing the lazy iterator, the type of the resulting store may be chosen explicitly.
+    pub fn difference_collect<OS, S2>(&self, other: &IndexSet<OS>) -> IndexSet<S2>
+    where
+        OS: IndexView<Index = S::Index>,
+        S2: IndexCollection<Index = S::Index> + IndexStore<InsertionError = Never>,
+    {
+        self.difference(other).collect()
+    }
+
+    /// Returns a new set of the indexes that are in `self`, but not `other`.
+    ///
+    /// Stops at the first insertion error.
+    pub fn try_difference_collect<OS, S2>(&self, other: &IndexSet<OS>) -> Result<IndexSet<S2>, S2::InsertionError>
+    where
+        OS: IndexView<Index = S::Index>,
+        S2: IndexCollection<Index = S::Index> + IndexStore,
+    {
+        IndexSet::try_from_iter(self.difference(other))
+    }
+
+    /// Returns a new set of the indexes that are both in `self` and in `other`.
+    ///
+    /// Unlike `collect`-ing the lazy iterator, the type of the resulting store may be chosen explicitly.
+    pub fn intersection_collect<OS, S2>(&self, other: &IndexSet<OS>) -> IndexSet<S2>
+    where
+        OS: IndexView<Index = S::Index>,
+        S2: IndexCollection<Index = S::Index> + IndexStore<InsertionError = Never>,
+    {
+        self.intersection(other).collect()
+    }
+
+    /// Returns a new set of the indexes that are both in `self` and in `other`.
+    ///
+    /// Stops at the first insertion error.
+    pub fn try_intersection_collect<OS, S2>(&self, other: &IndexSet<OS>) -> Result<IndexSet<S2>, S2::InsertionError>
+    where
+        OS: IndexView<Index = S::Index>,
+        S2: IndexCollection<Index = S::Index> + IndexStore,
+    {
+        IndexSet::try_from_iter(self.intersection(other))
+    }
+
+    /// Returns a new set of the indexes that are in either one of `self` or `other`.
+    ///
+    /// Unlike `collect`-ing the lazy iterator, the type of the resulting store may be chosen explicitly.
+    pub fn union_collect<OS, S2>(&self, other: &IndexSet<OS>) -> IndexSet<S2>
+    where
+        OS: IndexForward<Index = S::Index>,
+        S2: IndexCollection<Index = S::Index> + IndexStore<InsertionError = Never>,
+    {
+        self.union(other).collect()
+    }
+
+    /// Returns a new set of the indexes that are in either one of `self` or `other`.
+    ///
+    /// Stops at the first insertion error.
+    pub fn try_union_collect<OS, S2>(&self, other: &IndexSet<OS>) -> Result<IndexSet<S2>, S2::InsertionError>
+    where
+        OS: IndexForward<Index = S::Index>,
+        S2: IndexCollection<Index = S::Index> + IndexStore,
+    {
+        IndexSet::try_from_iter(self.union(other))
+    }
+
+    /// Returns a new set of the indexes that are in either one of `self` or `other`, but not both.
+    ///
+    /// Unlike `collect`-ing the lazy iterator, the type of the resulting store may be chosen explicitly.
+    pub fn symmetric_difference_collect<OS, S2>(&self, other: &IndexSet<OS>) -> IndexSet<S2>
+    where
+        OS: IndexForward<Index = S::Index>,
+        S2: IndexCollection<Index = S::Index> + IndexStore<InsertionError = Never>,
+    {
+        self.symmetric_difference(other).collect()
+    }
+
+    /// Returns a new set of the indexes that are in either one of `self` or `other`, but not both.
+    ///
+    /// Stops at the first insertion error.
+    pub fn try_symmetric_difference_collect<OS, S2>(
+        &self,
+        other: &IndexSet<OS>,
+    ) -> Result<IndexSet<S2>, S2::InsertionError>
+    where
+        OS: IndexForward<Index = S::Index>,
+        S2: IndexCollection<Index = S::Index> + IndexStore,
+    {
+        IndexSet::try_from_iter(self.symmetric_difference(other))
+    }
+}
+
+impl<S> IndexOrdSet<S>
+where
+    S: IndexOrdered,
+{
+    /// Returns a new set of the indexes that are in `self`, but not `other`.
+    ///
+    /// The indexes are inserted in ascending order.
+    pub fn difference_collect<OS, S2>(&self, other: &IndexOrdSet<OS>) -> IndexOrdSet<S2>
+    where
+        OS: IndexView<Index = S::Index>,
+        S2: IndexCollection<Index = S::Index> + IndexOrdered + IndexStore<InsertionError = Never>,
+    {
+        self.difference(other).collect()
+    }
+
+    /// Returns a new set of the indexes that are in `self`, but not `other`.
+    ///
+    /// Stops at the first insertion error.
+    pub fn try_difference_collect<OS, S2>(&self, other: &IndexOrdSet<OS>) -> Result<IndexOrdSet<S2>, S2::InsertionError>
+    where
+        OS: IndexView<Index = S::Index>,
+        S2: IndexCollection<Index = S::Index> + IndexOrdered + IndexStore,
+    {
+        IndexOrdSet::try_from_iter(self.difference(other))
+    }
+
+    /// Returns a new set of the indexes that are both in `self` and in `other`.
+    ///
+    /// The indexes are inserted in ascending order.
+    pub fn intersection_collect<OS, S2>(&self, other: &IndexOrdSet<OS>) -> IndexOrdSet<S2>
+    where
+        OS: IndexOrdered<Index = S::Index>,
+        S2: IndexCollection<Index = S::Index> + IndexOrdered + IndexStore<InsertionError = Never>,
+    {
+        self.intersection(other).collect()
+    }
+
+    /// Returns a new set of the indexes that are both in `self` and in `other`.
+    ///
+    /// Stops at the first insertion error.
+    pub fn try_intersection_collect<OS, S2>(
+        &self,
+        other: &IndexOrdSet<OS>,
+    ) -> Result<IndexOrdSet<S2>, S2::InsertionError>
+    where
+        OS: IndexOrdered<Index = S::Index>,
+        S2: IndexCollection<Index = S::Index> + IndexOrdered + IndexStore,
+    {
+        IndexOrdSet::try_from_iter(self.intersection(other))
+    }
+
+    /// Returns a new set of the indexes that are in either one of `self` or `other`.
+    ///
+    /// The indexes are inserted in ascending order.
+    pub fn union_collect<OS, S2>(&self, other: &IndexOrdSet<OS>) -> IndexOrdSet<S2>
+    where
+        OS: IndexOrdered<Index = S::Index>,
+        S2: IndexCollection<Index = S::Index> + IndexOrdered + IndexStore<InsertionError = Never>,
+    {
+        self.union(other).collect()
+    }
+
+    /// Returns a new set of the indexes that are in either one of `self` or `other`.
+    ///
+    /// Stops at the first insertion error.
+    pub fn try_union_collect<OS, S2>(&self, other: &IndexOrdSet<OS>) -> Result<IndexOrdSet<S2>, S2::InsertionError>
+    where
+        OS: IndexOrdered<Index = S::Index>,
+        S2: IndexCollection<Index = S::Index> + IndexOrdered + IndexStore,
+    {
+        IndexOrdSet::try_from_iter(self.union(other))
+    }
+
+    /// Returns a new set of the indexes that are in either one of `self` or `other`, but not both.
+    ///
+    /// The indexes are inserted in ascending order.
+    pub fn symmetric_difference_collect<OS, S2>(&self, other: &IndexOrdSet<OS>) -> IndexOrdSet<S2>
+    where
+        OS: IndexOrdered<Index = S::Index>,
+        S2: IndexCollection<Index = S::Index> + IndexOrdered + IndexStore<InsertionError = Never>,
+    {
+        self.symmetric_difference(other).collect()
+    }
+
+    /// Returns a new set of the indexes that are in either one of `self` or `other`, but not both.
+    ///
+    /// Stops at the first insertion error.
+    pub fn try_symmetric_difference_collect<OS, S2>(
+        &self,
+        other: &IndexOrdSet<OS>,
+    ) -> Result<IndexOrdSet<S2>, S2::InsertionError>
+    where
+        OS: IndexOrdered<Index = S::Index>,
+        S2: IndexCollection<Index = S::Index> + IndexOrdered + IndexStore,
+    {
+        IndexOrdSet::try_from_iter(self.symmetric_difference(other))
+    }
+}
+
+impl<S> IndexChunkedSet<S>
+where
+    S: IndexOrderedChunked,
+{
+    /// Returns a new set of the indexes that are in `self`, but not `other`.
+    ///
+    /// The resulting store is filled a combined chunk at a time, rather than an index at a time.
+    pub fn difference_collect<OS, S2>(&self, other: &IndexChunkedSet<OS>) -> IndexChunkedSet<S2>
+    where
+        OS: IndexViewChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+        S2: IndexCollection
+            + IndexStoreChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk, SetError = Never>,
+    {
+        let Ok(this) = self.try_difference_collect(other);
+
+        this
+    }
+
+    /// Returns a new set of the indexes that are in `self`, but not `other`.
+    ///
+    /// Stops at the first error setting a chunk.
+    ///
+    /// The resulting store is filled a combined chunk at a time, rather than an index at a time.
+    pub fn try_difference_collect<OS, S2>(
+        &self,
+        other: &IndexChunkedSet<OS>,
+    ) -> Result<IndexChunkedSet<S2>, S2::SetError>
+    where
+        OS: IndexViewChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+        S2: IndexCollection + IndexStoreChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+    {
+        ChunkMerge::new_probing(&self.store, &other.store)
+            .collect_with(ChunkMerge::next_probing, |left, right| left - right)
+    }
+
+    /// Returns a new set of the indexes that are both in `self` and in `other`.
+    ///
+    /// The resulting store is filled a combined chunk at a time, rather than an index at a time.
+    pub fn intersection_collect<OS, S2>(&self, other: &IndexChunkedSet<OS>) -> IndexChunkedSet<S2>
+    where
+        OS: IndexViewChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+        S2: IndexCollection
+            + IndexStoreChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk, SetError = Never>,
+    {
+        let Ok(this) = self.try_intersection_collect(other);
+
+        this
+    }
+
+    /// Returns a new set of the indexes that are both in `self` and in `other`.
+    ///
+    /// Stops at the first error setting a chunk.
+    ///
+    /// The resulting store is filled a combined chunk at a time, rather than an index at a time.
+    pub fn try_intersection_collect<OS, S2>(
+        &self,
+        other: &IndexChunkedSet<OS>,
+    ) -> Result<IndexChunkedSet<S2>, S2::SetError>
+    where
+        OS: IndexViewChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+        S2: IndexCollection + IndexStoreChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+    {
+        ChunkMerge::new_probing(&self.store, &other.store)
+            .collect_with(ChunkMerge::next_probing, |left, right| left & right)
+    }
+
+    /// Returns a new set of the indexes that are in either one of `self` or `other`.
+    ///
+    /// The resulting store is filled a combined chunk at a time, rather than an index at a time.
+    pub fn union_collect<OS, S2>(&self, other: &IndexChunkedSet<OS>) -> IndexChunkedSet<S2>
+    where
+        OS: IndexOrderedChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+        S2: IndexCollection
+            + IndexStoreChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk, SetError = Never>,
+    {
+        let Ok(this) = self.try_union_collect(other);
+
+        this
+    }
+
+    /// Returns a new set of the indexes that are in either one of `self` or `other`.
+    ///
+    /// Stops at the first error setting a chunk.
+    ///
+    /// The resulting store is filled a combined chunk at a time, rather than an index at a time.
+    pub fn try_union_collect<OS, S2>(&self, other: &IndexChunkedSet<OS>) -> Result<IndexChunkedSet<S2>, S2::SetError>
+    where
+        OS: IndexOrderedChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+        S2: IndexCollection + IndexStoreChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+    {
+        ChunkMerge::new_merging(&self.store, &other.store)
+            .collect_with(ChunkMerge::next_merging, |left, right| left | right)
+    }
+
+    /// Returns a new set of the indexes that are in either one of `self` or `other`, but not both.
+    ///
+    /// The resulting store is filled a combined chunk at a time, rather than an index at a time.
+    pub fn symmetric_difference_collect<OS, S2>(&self, other: &IndexChunkedSet<OS>) -> IndexChunkedSet<S2>
+    where
+        OS: IndexOrderedChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+        S2: IndexCollection
+            + IndexStoreChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk, SetError = Never>,
+    {
+        let Ok(this) = self.try_symmetric_difference_collect(other);
+
+        this
+    }
+
+    /// Returns a new set of the indexes that are in either one of `self` or `other`, but not both.
+    ///
+    /// Stops at the first error setting a chunk.
+    ///
+    /// The resulting store is filled a combined chunk at a time, rather than an index at a time.
+    pub fn try_symmetric_difference_collect<OS, S2>(
+        &self,
+        other: &IndexChunkedSet<OS>,
+    ) -> Result<IndexChunkedSet<S2>, S2::SetError>
+    where
+        OS: IndexOrderedChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+        S2: IndexCollection + IndexStoreChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
+    {
+        ChunkMerge::new_merging(&self.store, &other.store)
+            .collect_with(ChunkMerge::next_merging, |left, right| left ^ right)
+    }
+}
+
+impl<S> IndexSet<S>
+where
+    S: IndexForward,
+{
+    /// Returns a new set of the indexes of `self`, each transformed by `f`, for example to shift them from one domain
+    /// to another.
+    ///
+    /// Indexes transformed into the same index are only inserted once.
+    pub fn map_indexes<F, S2>(&self, f: F) -> IndexSet<S2>
+    where
+        F: FnMut(S::Index) -> S2::Index,
+        S2: IndexCollection + IndexStore<InsertionError = Never>,
+    {
+        self.iter().map(f).collect()
+    }
+}
+
+impl<S> IndexOrdSet<S>
+where
+    S: IndexOrdered,
+{
+    /// Returns a new set of the indexes of `self`, each transformed by `f`, for example to shift them from one domain
+    /// to another.
+    ///
+    /// `f` is expected to preserve the order of indexes, that is `a < b` implies `f(a) <= f(b)`, so that the indexes
+    /// are inserted in ascending order and keep their relative order. This is checked in Debug mode.
+    pub fn map_indexes<F, S2>(&self, f: F) -> IndexOrdSet<S2>
+    where
+        F: FnMut(S::Index) -> S2::Index,
+        S2: IndexCollection + IndexOrdered + IndexStore<InsertionError = Never>,
+    {
+        self.iter().map(order_checked(f)).collect()
+    }
+}
+
+impl<S> IndexChunkedSet<S>
+where
+    S: IndexOrdered + IndexOrderedChunked,
+{
+    /// Returns a new set of the indexes of `self`, each transformed by `f`, for example to shift them from one domain
+    /// to another.
+    ///
+    /// `f` is expected to preserve the order of indexes, that is `a < b` implies `f(a) <= f(b)`, so that the indexes
+    /// are inserted in ascending order and keep their relative order. This is checked in Debug mode.
+    ///
+    /// The resulting store is filled an index at a time, as `f` may not preserve the chunks.
+    pub fn map_indexes<F, S2>(&self, f: F) -> IndexChunkedSet<S2>
+    where
+        F: FnMut(S::Index) -> S2::Index,
+        S2: IndexCollection + IndexOrderedChunked + IndexStore<InsertionError = Never>,
+    {
+        self.iter().map(order_checked(f)).collect()
+    }
+}
+
+//  Wraps `f`, checking in Debug mode that it preserves the order of the indexes it is successively called with.
+fn order_checked<I, O, F>(mut f: F) -> impl FnMut(I) -> O
+where
+    O: Copy + Ord,
+    F: FnMut(I) -> O,
+{
+    let mut previous = None;
+
+    move |index| {
+        let mapped = f(index);
+
+        debug_assert!(
+            previous.is_none_or(|previous| previous <= mapped),
+            "order-breaking transform"
+        );
+
+        previous = Some(mapped);
+
+        mapped
+    }
+}
+
+//  Counts the indexes of `store` from `next`, included, which are not in `other`.
+fn count_not_in<S, O>(store: &S, mut next: Option<S::Index>, other: &O) -> usize
+where
+    S: IndexForward,
+    O: IndexView<Index = S::Index>,
+{
+    let mut count = 0;
+
+    while let Some(index) = next {
+        count += usize::from(!other.contains(index));
+
+        next = store.next_after(index);
+    }
+
+    count
+}
+
+//  Counts the indexes of `left` from `next_left`, of `right` from `next_right`, and those in common, in a single merge
+//  pass.
+fn merge_count<L, R>(
+    left: &L,
+    mut next_left: Option<L::Index>,
+    right: &R,
+    mut next_right: Option<L::Index>,
+) -> (usize, usize, usize)
+where
+    L: IndexOrdered,
+    R: IndexOrdered<Index = L::Index>,
+{
+    let (mut count_left, mut count_right, mut common) = (0, 0, 0);
+
+    while let (Some(this), Some(that)) = (next_left, next_right) {
+        match this.cmp(&that) {
+            Ordering::Equal => {
+                common += 1;
+
+                next_left = left.next_after(this);
+                next_right = right.next_after(that);
+            }
+            Ordering::Less => {
+                count_left += 1;
+
+                next_left = left.next_after(this);
+            }
+            Ordering::Greater => {
+                count_right += 1;
+
+                next_right = right.next_after(that);
+            }
+        }
+    }
+
+    while let Some(this) = next_left {
+        count_left += 1;
+        next_left = left.next_after(this);
+    }
+
+    while let Some(that) = next_right {
+        count_right += 1;
+        next_right = right.next_after(that);
+    }
+
+    //  The common indexes are counted on both sides.
+    (count_left + common, count_right + common, common)
+}
+
+/// Iterator over the elements in L that are not in R.
+pub struct Difference<'a, I, L, R> {
+    next: Option<I>,
+    passed: usize,
+    left: &'a L,
+    right: &'a R,
+}
+
+impl<'a, I, L, R> Clone for Difference<'a, I, L, R>
+where
+    I: Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            next: self.next,
+            passed: self.passed,
+            left: self.left,
+            right: self.right,
+        }
+    }
+}
+
+impl<'a, I, L, R> Iterator for Difference<'a, I, L, R>
+where
+    I: Copy,
+    L: IndexForward<Index = I>,
+    R: IndexView<Index = I>,
+{
+    type Item = I;
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let length = self.left.len() - self.passed;
+
+        (0, Some(length))
+    }
+
+    fn count(self) -> usize {
+        let Some(mut next) = self.next else { return 0 };
+
+        //  Only the remaining indexes of `left` are left.
+        if self.right.is_empty() {
+            return self.left.len() - self.passed;
+        }
+
+        let mut count = 0;
+
+        loop {
+            count += usize::from(!self.right.contains(next));
+
+            let Some(index) = self.left.next_after(next) else {
+                return count;
+            };
+
+            next = index;
+        }
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(index) = self.next
+            && self.right.contains(index)
+        {
+            self.passed += 1;
+            self.next = self.left.next_after(index);
+        }
+
+        let result = self.next.take()?;
+
+        self.passed += 1;
+        self.next = self.left.next_after(result);
+
+        Some(result)
+    }
+}
+
+impl<'a, I, L, R> FusedIterator for Difference<'a, I, L, R>
+where
+    I: Copy,
+    L: IndexForward<Index = I>,
+    R: IndexView<Index = I>,
+{
+}
+
+/// Iterator over the elements in L xor in R.
+pub struct SymmetricDifference<'a, I, L, R> {
+    next_left: Option<I>,
+    next_right: Option<I>,
+    passed: usize,
+    left: &'a L,
+    right: &'a R,
+}
+
+impl<'a, I, L, R> Clone for SymmetricDifference<'a, I, L, R>
+where
+    I: Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            next_left: self.next_left,
+            next_right: self.next_right,
+            passed: self.passed,
+            left: self.left,
+            right: self.right,
+        }
+    }
+}
+
+impl<'a, I, L, R> Iterator for SymmetricDifference<'a, I, L, R>
+where
+    I: Copy,
+    L: IndexForward<Index = I>,
+    R: IndexForward<Index = I>,
+{
+    type Item = I;
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        //  At least `|left| - |right|` indexes of the larger set are not in the smaller one, and each passed index was
+        //  yielded at most once.
+        let lower = self.left.len().abs_diff(self.right.len()).saturating_sub(self.passed);
+
+        (lower, Some(self.left.len() + self.right.len() - self.passed))
+    }
+
+    fn count(self) -> usize {
+        count_not_in(self.left, self.next_left, self.right) + count_not_in(self.right, self.next_right, self.left)
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(index) = self.next_left
+            && self.right.contains(index)
+        {
+            self.passed += 1;
+            self.next_left = self.left.next_after(index);
+        }
+
+        if let Some(result) = self.next_left.take() {
+            self.passed += 1;
+            self.next_left = self.left.next_after(result);
+
+            return Some(result);
+        }
+
+        while let Some(index) = self.next_right
+            && self.left.contains(index)
+        {
+            self.passed += 1;
+            self.next_right = self.right.next_after(index);
+        }
+
+        let result = self.next_right.take()?;
+
+        self.passed += 1;
+        self.next_right = self.right.next_after(result);
+
+        Some(result)
+    }
+}
+
+impl<'a, I, L, R> FusedIterator for SymmetricDifference<'a, I, L, R>
+where
+    I: Copy,
+    L: IndexForward<Index = I>,
+    R: IndexForward<Index = I>,
+{
+}
+
+/// Iterator over the element in L and in R.
+pub struct Intersection<'a, I, L, R> {
+    next: Option<I>,
+    passed: usize,
+    left: &'a L,
+    right: &'a R,
+}
+
+impl<'a, I, L, R> Clone for Intersection<'a, I, L, R>
+where
+    I: Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            next: self.next,
+            passed: self.passed,
+            left: self.left,
+            right: self.right,
+        }
+    }
+}
+
+impl<'a, I, L, R> Iterator for Intersection<'a, I, L, R>
+where
+    I: Copy,
+    L: IndexForward<Index = I>,
+    R: IndexView<Index = I>,
+{
+    type Item = I;
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.left.len() - self.passed;
+        let right = self.right.len();
+
+        (0, Some(cmp::min(left, right)))
+    }
+
+    fn count(self) -> usize {
+        let Some(mut next) = self.next else { return 0 };
+
+        if self.right.is_empty() {
+            return 0;
+        }
+
+        let mut count = 0;
+
+        loop {
+            count += usize::from(self.right.contains(next));
+
+            let Some(index) = self.left.next_after(next) else {
+                return count;
+            };
+
+            next = index;
+        }
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(index) = self.next
+            && !self.right.contains(index)
+        {
+            self.passed += 1;
+            self.next = self.left.next_after(index);
+        }
+
+        let result = self.next.take()?;
+
+        self.passed += 1;
+        self.next = self.left.next_after(result);
+
+        Some(result)
+    }
+}
+
+impl<'a, I, L, R> FusedIterator for Intersection<'a, I, L, R>
+where
+    I: Copy,
+    L: IndexForward<Index = I>,
+    R: IndexView<Index = I>,
+{
+}
+
+/// Iterator over the elements in L or in R.
+pub struct Union<'a, I, L, R> {
+    next_left: Option<I>,
+    next_right: Option<I>,
+    passed: usize,
+    left: &'a L,
+    right: &'a R,
+}
+
+impl<'a, I, L, R> Clone for Union<'a, I, L, R>
+where
+    I: Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            next_left: self.next_left,
+            next_right: self.next_right,
+            passed: self.passed,
+            left: self.left,
+            right: self.right,
+        }
+    }
+}
+
+impl<'a, I, L, R> Iterator for Union<'a, I, L, R>
+where
+    I: Copy,
+    L: IndexForward<Index = I>,
+    R: IndexForward<Index = I>,
+{
+    type Item = I;
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.left.len() + self.right.len() - self.passed))
+    }
+
+    fn count(self) -> usize {
+        //  As long as `left` is not exhausted, only indexes of `left` were passed.
+        let left = if self.next_left.is_some() {
+            self.left.len() - self.passed
+        } else {
+            0
+        };
+
+        left + count_not_in(self.right, self.next_right, self.left)
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(result) = self.next_left {
+            self.passed += 1;
+            self.next_left = self.left.next_after(result);
+
+            return Some(result);
+        }
+
+        //  Elements in `self.left` were already returned, so skip them.
+        while let Some(index) = self.next_right
+            && self.left.contains(index)
+        {
+            self.passed += 1;
+            self.next_right = self.right.next_after(index);
+        }
+
+        let result = self.next_right.take()?;
+
+        self.passed += 1;
+        self.next_right = self.right.next_after(result);
+
+        Some(result)
+    }
+}
+
+impl<'a, I, L, R> FusedIterator for Union<'a, I, L, R>
+where
+    I: Copy,
+    L: IndexForward<Index = I>,
+    R: IndexForward<Index = I>,
+{
+}
+
+/// Iterator over the elements in L xor in R.
+pub struct SymmetricDifferenceOrd<'a, I, L, R> {
+    next_left: Option<I>,
+    next_right: Option<I>,
+    left: &'a L,
+    right: &'a R,
+}
+
+impl<'a, I, L, R> Clone for SymmetricDifferenceOrd<'a, I, L, R>
+where
+    I: Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            next_left: self.next_left,
+            next_right: self.next_right,
+            left: self.left,
+            right: self.right,
+        }
+    }
+}
+
+impl<'a, I, L, R> Iterator for SymmetricDifferenceOrd<'a, I, L, R>
+where
+    I: Copy + Eq + Ord,
+    L: IndexOrdered<Index = I>,
+    R: IndexOrdered<Index = I>,
+{
+    type Item = I;
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.next_left.map(|_| self.left.len()).unwrap_or(0);
+        let right = self.next_right.map(|_| self.right.len()).unwrap_or(0);
+
+        (0, Some(left + right))
+    }
+
+    fn count(self) -> usize {
+        let (left, right, common) = merge_count(self.left, self.next_left, self.right, self.next_right);
+
+        left + right - 2 * common
+    }
+
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        //  Once either side is exhausted, the remaining indexes are those of the other side, which may be skipped.
+        loop {
+            match (self.next_left, self.next_right) {
+                (_, None) => return nth_from(self.left, &mut self.next_left, n),
+                (None, _) => return nth_from(self.right, &mut self.next_right, n),
+                _ => {}
+            }
+
+            let index = self.next()?;
+
+            let Some(remaining) = n.checked_sub(1) else {
+                return Some(index);
+            };
+
+            n = remaining;
+        }
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match (self.next_left, self.next_right) {
+                (None, None) => return None,
+                (Some(next_left), None) => {
+                    self.next_left = self.left.next_after(next_left);
+
+                    return Some(next_left);
+                }
+                (None, Some(next_right)) => {
+                    self.next_right = self.right.next_after(next_right);
+
+                    return Some(next_right);
+                }
+                (Some(next_left), Some(next_right)) => match next_left.cmp(&next_right) {
+                    Ordering::Equal => {
+                        self.next_left = self.left.next_after(next_left);
+                        self.next_right = self.right.next_after(next_right);
+
+                        continue;
+                    }
+                    Ordering::Less => {
+                        self.next_left = self.left.next_after(next_left);
+
+                        return Some(next_left);
+                    }
+                    Ordering::Greater => {
+                        self.next_right = self.right.next_after(next_right);
+
+                        return Some(next_right);
+                    }
+                },
+            }
         }
     }
+}
 
-    /// Returns the indexes that are in either one of `self` or `other`.
-    ///
-    /// Takes advantage of iteration over `self` and `other` being ordered to minimize the number of operations.
-    pub fn union<'a, OS>(&'a self, other: &'a IndexChunkedSet<OS>) -> UnionOrd<'a, S::Index, S, OS>
-    where
-        OS: IndexOrdered<Index = S::Index>,
-    {
-        UnionOrd {
-            next_left: self.store.first(),
-            next_right: other.store.first(),
-            left: &self.store,
-            right: &other.store,
-        }
-    }
+impl<'a, I, L, R> FusedIterator for SymmetricDifferenceOrd<'a, I, L, R>
+where
+    I: Copy + Eq + Ord,
+    L: IndexOrdered<Index = I>,
+    R: IndexOrdered<Index = I>,
+{
 }
 
-/// Iterator over the elements in L that are not in R.
-pub struct Difference<'a, I, L, R> {
-    next: Option<I>,
-    passed: usize,
+/// Iterator over the element in L and in R.
+///
+/// Unlike `UnionOrd` and `SymmetricDifferenceOrd`, `nth` is not overridden: the intersection ends as soon as either
+/// side is exhausted, hence there is no tail to skip over, and each of the skipped common indexes must still be found.
+/// The default `nth` goes through `next`, which already seeks past the indexes absent from the other side.
+pub struct IntersectionOrd<'a, I, L, R> {
+    next_left: Option<I>,
+    next_right: Option<I>,
     left: &'a L,
     right: &'a R,
 }
 
-impl<'a, I, L, R> Iterator for Difference<'a, I, L, R>
+impl<'a, I, L, R> Clone for IntersectionOrd<'a, I, L, R>
 where
     I: Copy,
-    L: IndexForward<Index = I>,
-    R: IndexView<Index = I>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            next_left: self.next_left,
+            next_right: self.next_right,
+            left: self.left,
+            right: self.right,
+        }
+    }
+}
+
+impl<'a, I, L, R> Iterator for IntersectionOrd<'a, I, L, R>
+where
+    I: Copy + Eq + Ord,
+    L: IndexOrdered<Index = I>,
+    R: IndexOrdered<Index = I>,
 {
     type Item = I;
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let length = self.left.len() - self.passed;
+        let left = self.next_left.map(|_| self.left.len()).unwrap_or(0);
+        let right = self.next_right.map(|_| self.right.len()).unwrap_or(0);
 
-        (0, Some(length))
+        (0, Some(cmp::min(left, right)))
+    }
+
+    fn count(self) -> usize {
+        merge_count(self.left, self.next_left, self.right, self.next_right).2
     }
 
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some(index) = self.next
-            && self.right.contains(index)
-        {
-            self.passed += 1;
-            self.next = self.left.next_after(index);
-        }
+        loop {
+            let next_left = self.next_left.take()?;
+            let next_right = self.next_right.take()?;
 
-        let result = self.next.take()?;
+            match next_left.cmp(&next_right) {
+                Ordering::Equal => {
+                    self.next_left = self.left.next_after(next_left);
+                    self.next_right = self.right.next_after(next_right);
 
-        self.passed += 1;
-        self.next = self.left.next_after(result);
+                    return Some(next_left);
+                }
+                Ordering::Less => {
+                    self.next_left = self.left.next_after(next_right);
+                    self.next_right = self.right.next_after(next_right);
 
-        Some(result)
+                    if self.left.contains(next_right) {
+                        return Some(next_right);
+                    }
+
+                    continue;
+                }
+                Ordering::Greater => {
+                    self.next_left = self.left.next_after(next_left);
+                    self.next_right = self.right.next_after(next_left);
+
+                    if self.right.contains(next_left) {
+                        return Some(next_left);
+                    }
+
+                    continue;
+                }
+            }
+        }
     }
 }
 
-impl<'a, I, L, R> FusedIterator for Difference<'a, I, L, R>
+impl<'a, I, L, R> FusedIterator for IntersectionOrd<'a, I, L, R>
 where
-    I: Copy,
-    L: IndexForward<Index = I>,
-    R: IndexView<Index = I>,
+    I: Copy + Eq + Ord,
+    L: IndexOrdered<Index = I>,
+    R: IndexOrdered<Index = I>,
 {
 }
 
-/// Iterator over the elements in L xor in R.
-pub struct SymmetricDifference<'a, I, L, R> {
+/// Iterator over the elements in L or in R.
+pub struct UnionOrd<'a, I, L, R> {
     next_left: Option<I>,
     next_right: Option<I>,
-    passed: usize,
     left: &'a L,
     right: &'a R,
 }
 
-impl<'a, I, L, R> Iterator for SymmetricDifference<'a, I, L, R>
+impl<'a, I, L, R> Clone for UnionOrd<'a, I, L, R>
 where
     I: Copy,
-    L: IndexForward<Index = I>,
-    R: IndexForward<Index = I>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            next_left: self.next_left,
+            next_right: self.next_right,
+            left: self.left,
+            right: self.right,
+        }
+    }
+}
+
+impl<'a, I, L, R> Iterator for UnionOrd<'a, I, L, R>
+where
+    I: Copy + Eq + Ord,
+    L: IndexOrdered<Index = I>,
+    R: IndexOrdered<Index = I>,
 {
     type Item = I;
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (0, Some(self.left.len() + self.right.len() - self.passed))
+        let left = self.next_left.map(|_| self.left.len()).unwrap_or(0);
+        let right = self.next_right.map(|_| self.right.len()).unwrap_or(0);
+
+        (0, Some(left + right))
     }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        while let Some(index) = self.next_left
-            && self.right.contains(index)
-        {
-            self.passed += 1;
-            self.next_left = self.left.next_after(index);
+    fn count(self) -> usize {
+        let (left, right, common) = merge_count(self.left, self.next_left, self.right, self.next_right);
+
+        left + right - common
+    }
+
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        //  Once either side is exhausted, the remaining indexes are those of the other side, which may be skipped.
+        loop {
+            match (self.next_left, self.next_right) {
+                (_, None) => return nth_from(self.left, &mut self.next_left, n),
+                (None, _) => return nth_from(self.right, &mut self.next_right, n),
+                _ => {}
+            }
+
+            let index = self.next()?;
+
+            let Some(remaining) = n.checked_sub(1) else {
+                return Some(index);
+            };
+
+            n = remaining;
         }
+    }
 
-        if let Some(result) = self.next_left.take() {
-            self.passed += 1;
-            self.next_left = self.left.next_after(result);
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.next_left, self.next_right) {
+            (None, None) => None,
+            (Some(next_left), None) => {
+                self.next_left = self.left.next_after(next_left);
 
-            return Some(result);
+                Some(next_left)
+            }
+            (None, Some(next_right)) => {
+                self.next_right = self.right.next_after(next_right);
+
+                Some(next_right)
+            }
+            (Some(next_left), Some(next_right)) => match next_left.cmp(&next_right) {
+                Ordering::Equal => {
+                    self.next_left = self.left.next_after(next_left);
+                    self.next_right = self.right.next_after(next_right);
+
+                    Some(next_left)
+                }
+                Ordering::Less => {
+                    self.next_left = self.left.next_after(next_left);
+
+                    Some(next_left)
+                }
+                Ordering::Greater => {
+                    self.next_right = self.right.next_after(next_right);
+
+                    Some(next_right)
+                }
+            },
         }
+    }
+}
 
-        while let Some(index) = self.next_right
-            && self.left.contains(index)
-        {
-            self.passed += 1;
-            self.next_right = self.right.next_after(index);
+impl<'a, I, L, R> FusedIterator for UnionOrd<'a, I, L, R>
+where
+    I: Copy + Eq + Ord,
+    L: IndexOrdered<Index = I>,
+    R: IndexOrdered<Index = I>,
+{
+}
+
+/// Iterator over the elements in L that are not in R, a chunk at a time.
+pub struct DifferenceChunked<'a, L, R>
+where
+    L: IndexViewChunked,
+{
+    merge: ChunkMerge<'a, L, R>,
+}
+
+impl<'a, L, R> Clone for DifferenceChunked<'a, L, R>
+where
+    L: IndexViewChunked,
+{
+    fn clone(&self) -> Self {
+        Self {
+            merge: self.merge.clone(),
         }
+    }
+}
 
-        let result = self.next_right.take()?;
+impl<'a, L, R> Iterator for DifferenceChunked<'a, L, R>
+where
+    L: IndexOrderedChunked<Chunk: IndexForward>,
+    R: IndexViewChunked<Index = L::Index, ChunkIndex = L::ChunkIndex, Chunk = L::Chunk>,
+{
+    type Item = L::Index;
 
-        self.passed += 1;
-        self.next_right = self.right.next_after(result);
+    fn count(self) -> usize {
+        self.merge
+            .count_with(ChunkMerge::next_probing, |left, right| left - right)
+    }
 
-        Some(result)
+    fn next(&mut self) -> Option<Self::Item> {
+        self.merge
+            .next_with(ChunkMerge::next_probing, |left, right| left - right)
     }
 }
 
-impl<'a, I, L, R> FusedIterator for SymmetricDifference<'a, I, L, R>
+impl<'a, L, R> FusedIterator for DifferenceChunked<'a, L, R>
 where
-    I: Copy,
-    L: IndexForward<Index = I>,
-    R: IndexForward<Index = I>,
+    L: IndexOrderedChunked<Chunk: IndexForward>,
+    R: IndexViewChunked<Index = L::Index, ChunkIndex = L::ChunkIndex, Chunk = L::Chunk>,
 {
 }
 
-/// Iterator over the element in L and in R.
-pub struct Intersection<'a, I, L, R> {
-    next: Option<I>,
-    passed: usize,
-    left: &'a L,
-    right: &'a R,
+/// Iterator over the elements in L xor in R, a chunk at a time.
+pub struct SymmetricDifferenceChunked<'a, L, R>
+where
+    L: IndexViewChunked,
+{
+    merge: ChunkMerge<'a, L, R>,
 }
 
-impl<'a, I, L, R> Iterator for Intersection<'a, I, L, R>
+impl<'a, L, R> Clone for SymmetricDifferenceChunked<'a, L, R>
 where
-    I: Copy,
-    L: IndexForward<Index = I>,
-    R: IndexView<Index = I>,
+    L: IndexViewChunked,
 {
-    type Item = I;
+    fn clone(&self) -> Self {
+        Self {
+            merge: self.merge.clone(),
+        }
+    }
+}
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let left = self.left.len() - self.passed;
-        let right = self.right.len();
+impl<'a, L, R> Iterator for SymmetricDifferenceChunked<'a, L, R>
+where
+    L: IndexOrderedChunked<Chunk: IndexForward>,
+    R: IndexOrderedChunked<Index = L::Index, ChunkIndex = L::ChunkIndex, Chunk = L::Chunk>,
+{
+    type Item = L::Index;
 
-        (0, Some(cmp::min(left, right)))
+    fn count(self) -> usize {
+        self.merge
+            .count_with(ChunkMerge::next_merging, |left, right| left ^ right)
     }
 
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some(index) = self.next
-            && !self.right.contains(index)
-        {
-            self.passed += 1;
-            self.next = self.left.next_after(index);
+        self.merge
+            .next_with(ChunkMerge::next_merging, |left, right| left ^ right)
+    }
+}
+
+impl<'a, L, R> FusedIterator for SymmetricDifferenceChunked<'a, L, R>
+where
+    L: IndexOrderedChunked<Chunk: IndexForward>,
+    R: IndexOrderedChunked<Index = L::Index, ChunkIndex = L::ChunkIndex, Chunk = L::Chunk>,
+{
+}
+
+/// Iterator over the elements in L and in R, a chunk at a time.
+pub struct IntersectionChunked<'a, L, R>
+where
+    L: IndexViewChunked,
+{
+    merge: ChunkMerge<'a, L, R>,
+}
+
+impl<'a, L, R> Clone for IntersectionChunked<'a, L, R>
+where
+    L: IndexViewChunked,
+{
+    fn clone(&self) -> Self {
+        Self {
+            merge: self.merge.clone(),
         }
+    }
+}
 
-        let result = self.next.take()?;
+impl<'a, L, R> Iterator for IntersectionChunked<'a, L, R>
+where
+    L: IndexOrderedChunked<Chunk: IndexForward>,
+    R: IndexViewChunked<Index = L::Index, ChunkIndex = L::ChunkIndex, Chunk = L::Chunk>,
+{
+    type Item = L::Index;
 
-        self.passed += 1;
-        self.next = self.left.next_after(result);
+    fn count(self) -> usize {
+        self.merge
+            .count_with(ChunkMerge::next_probing, |left, right| left & right)
+    }
 
-        Some(result)
+    fn next(&mut self) -> Option<Self::Item> {
+        self.merge
+            .next_with(ChunkMerge::next_probing, |left, right| left & right)
     }
 }
 
-impl<'a, I, L, R> FusedIterator for Intersection<'a, I, L, R>
+impl<'a, L, R> FusedIterator for IntersectionChunked<'a, L, R>
 where
-    I: Copy,
-    L: IndexForward<Index = I>,
-    R: IndexView<Index = I>,
+    L: IndexOrderedChunked<Chunk: IndexForward>,
+    R: IndexViewChunked<Index = L::Index, ChunkIndex = L::ChunkIndex, Chunk = L::Chunk>,
 {
 }
 
-/// Iterator over the elements in L or in R.
-pub struct Union<'a, I, L, R> {
-    next_left: Option<I>,
-    next_right: Option<I>,
-    passed: usize,
-    left: &'a L,
-    right: &'a R,
+/// Iterator over the elements in L or in R, a chunk at a time.
+pub struct UnionChunked<'a, L, R>
+where
+    L: IndexViewChunked,
+{
+    merge: ChunkMerge<'a, L, R>,
 }
 
-impl<'a, I, L, R> Iterator for Union<'a, I, L, R>
+impl<'a, L, R> Clone for UnionChunked<'a, L, R>
 where
-    I: Copy,
-    L: IndexForward<Index = I>,
-    R: IndexForward<Index = I>,
+    L: IndexViewChunked,
 {
-    type Item = I;
-
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (0, Some(self.left.len() + self.right.len() - self.passed))
-    }
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(result) = self.next_left {
-            self.passed += 1;
-            self.next_left = self.left.next_after(result);
-
-            return Some(result);
-        }
-
-        //  Elements in `self.left` were already returned, so skip them.
-        while let Some(index) = self.next_right
-            && self.left.contains(index)
-        {
-            self.passed += 1;
-            self.next_right = self.right.next_after(index);
+    fn clone(&self) -> Self {
+        Self {
+            merge: self.merge.clone(),
         }
+    }
+}
 
-        let result = self.next_right.take()?;
+impl<'a, L, R> Iterator for UnionChunked<'a, L, R>
+where
+    L: IndexOrderedChunked<Chunk: IndexForward>,
+    R: IndexOrderedChunked<Index = L::Index, ChunkIndex = L::ChunkIndex, Chunk = L::Chunk>,
+{
+    type Item = L::Index;
 
-        self.passed += 1;
-        self.next_right = self.right.next_after(result);
+    fn count(self) -> usize {
+        self.merge
+            .count_with(ChunkMerge::next_merging, |left, right| left | right)
+    }
 
-        Some(result)
+    fn next(&mut self) -> Option<Self::Item> {
+        self.merge
+            .next_with(ChunkMerge::next_merging, |left, right| left | right)
     }
 }
 
-impl<'a, I, L, R> FusedIterator for Union<'a, I, L, R>
+impl<'a, L, R> FusedIterator for UnionChunked<'a, L, R>
 where
-    I: Copy,
-    L: IndexForward<Index = I>,
-    R: IndexForward<Index = I>,
+    L: IndexOrderedChunked<Chunk: IndexForward>,
+    R: IndexOrderedChunked<Index = L::Index, ChunkIndex = L::ChunkIndex, Chunk = L::Chunk>,
 {
 }
 
-/// Iterator over the elements in L xor in R.
-pub struct SymmetricDifferenceOrd<'a, I, L, R> {
-    next_left: Option<I>,
-    next_right: Option<I>,
+//  Walks the chunks of L, and possibly R, in lockstep.
+//
+//  The chunks present in one store, but not the other, are paired with an empty chunk.
+struct ChunkMerge<'a, L, R>
+where
+    L: IndexViewChunked,
+{
+    //  The remaining indexes of the current combined chunk.
+    current: Option<(L::ChunkIndex, L::Chunk)>,
+    next_left: Option<L::ChunkIndex>,
+    next_right: Option<L::ChunkIndex>,
     left: &'a L,
     right: &'a R,
 }
 
-impl<'a, I, L, R> Iterator for SymmetricDifferenceOrd<'a, I, L, R>
+impl<'a, L, R> Clone for ChunkMerge<'a, L, R>
 where
-    I: Copy + Eq + Ord,
-    L: IndexOrdered<Index = I>,
-    R: IndexOrdered<Index = I>,
+    L: IndexViewChunked,
 {
-    type Item = I;
+    fn clone(&self) -> Self {
+        Self {
+            current: self.current,
+            next_left: self.next_left,
+            next_right: self.next_right,
+            left: self.left,
+            right: self.right,
+        }
+    }
+}
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let left = self.next_left.map(|_| self.left.len()).unwrap_or(0);
-        let right = self.next_right.map(|_| self.right.len()).unwrap_or(0);
+impl<'a, L, R> ChunkMerge<'a, L, R>
+where
+    L: IndexForwardChunked,
+    R: IndexViewChunked<Index = L::Index, ChunkIndex = L::ChunkIndex, Chunk = L::Chunk>,
+{
+    //  Creates a new instance, walking the chunks of `left` only, and probing those of `right`.
+    fn new_probing(left: &'a L, right: &'a R) -> Self {
+        Self {
+            current: None,
+            next_left: left.first_chunk(),
+            next_right: None,
+            left,
+            right,
+        }
+    }
 
-        (0, Some(left + right))
+    //  Creates a new instance, walking the chunks of both `left` and `right`.
+    fn new_merging(left: &'a L, right: &'a R) -> Self
+    where
+        R: IndexForwardChunked,
+    {
+        Self {
+            current: None,
+            next_left: left.first_chunk(),
+            next_right: right.first_chunk(),
+            left,
+            right,
+        }
     }
 
-    fn next(&mut self) -> Option<Self::Item> {
+    //  Returns the number of remaining indexes of the combined chunks, fetching the pairs of chunks with `fetch`.
+    fn count_with<N, F>(mut self, mut fetch: N, combine: F) -> usize
+    where
+        N: FnMut(&mut Self) -> Option<(L::ChunkIndex, L::Chunk, L::Chunk)>,
+        F: Fn(L::Chunk, L::Chunk) -> L::Chunk,
+    {
+        let mut count = self.current.take().map_or(0, |(_, chunk)| chunk.len());
+
+        while let Some((_, left, right)) = fetch(&mut self) {
+            count += combine(left, right).len();
+        }
+
+        count
+    }
+
+    //  Returns the next index of the combined chunks, fetching the next pair of chunks with `fetch` as necessary.
+    fn next_with<N, F>(&mut self, mut fetch: N, combine: F) -> Option<L::Index>
+    where
+        L::Chunk: IndexForward,
+        N: FnMut(&mut Self) -> Option<(L::ChunkIndex, L::Chunk, L::Chunk)>,
+        F: Fn(L::Chunk, L::Chunk) -> L::Chunk,
+    {
         loop {
-            match (self.next_left, self.next_right) {
-                (None, None) => return None,
-                (Some(next_left), None) => {
-                    self.next_left = self.left.next_after(next_left);
+            if let Some((outer, chunk)) = &mut self.current
+                && let Some(inner) = chunk.first()
+            {
+                chunk.remove(inner);
 
-                    return Some(next_left);
-                }
-                (None, Some(next_right)) => {
-                    self.next_right = self.right.next_after(next_right);
+                return Some(L::fuse(*outer, inner));
+            }
 
-                    return Some(next_right);
-                }
-                (Some(next_left), Some(next_right)) => match next_left.cmp(&next_right) {
-                    Ordering::Equal => {
-                        self.next_left = self.left.next_after(next_left);
-                        self.next_right = self.right.next_after(next_right);
+            let (outer, left, right) = fetch(self)?;
 
-                        continue;
-                    }
-                    Ordering::Less => {
-                        self.next_left = self.left.next_after(next_left);
+            self.current = Some((outer, combine(left, right)));
+        }
+    }
 
-                        return Some(next_left);
-                    }
-                    Ordering::Greater => {
-                        self.next_right = self.right.next_after(next_right);
+    //  Collects the non-empty combined chunks into a new set, fetching the pairs of chunks with `fetch`.
+    fn collect_with<S, N, F>(mut self, mut fetch: N, combine: F) -> Result<IndexChunkedSet<S>, S::SetError>
+    where
+        S: IndexCollection + IndexStoreChunked<Index = L::Index, ChunkIndex = L::ChunkIndex, Chunk = L::Chunk>,
+        N: FnMut(&mut Self) -> Option<(L::ChunkIndex, L::Chunk, L::Chunk)>,
+        F: Fn(L::Chunk, L::Chunk) -> L::Chunk,
+    {
+        let mut store = S::new();
 
-                        return Some(next_right);
-                    }
-                },
+        while let Some((outer, left, right)) = fetch(&mut self) {
+            let chunk = combine(left, right);
+
+            if !chunk.is_empty() {
+                store.set_chunk(outer, chunk)?;
             }
         }
+
+        Ok(IndexChunkedSet { store })
     }
-}
 
-/// Iterator over the element in L and in R.
-pub struct IntersectionOrd<'a, I, L, R> {
-    next_left: Option<I>,
-    next_right: Option<I>,
-    left: &'a L,
-    right: &'a R,
-}
+    //  Returns the next chunk of `left`, paired with the chunk of `right` at the same chunk index.
+    fn next_probing(&mut self) -> Option<(L::ChunkIndex, L::Chunk, L::Chunk)> {
+        let outer = self.next_left?;
 
-impl<'a, I, L, R> Iterator for IntersectionOrd<'a, I, L, R>
-where
-    I: Copy + Eq + Ord,
-    L: IndexOrdered<Index = I>,
-    R: IndexOrdered<Index = I>,
-{
-    type Item = I;
+        let left = self.left.get_chunk(outer).unwrap_or_default();
+        let right = self.right.get_chunk(outer).unwrap_or_default();
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let left = self.next_left.map(|_| self.left.len()).unwrap_or(0);
-        let right = self.next_right.map(|_| self.right.len()).unwrap_or(0);
+        self.next_left = self.left.next_chunk_after(outer);
 
-        (0, Some(cmp::min(left, right)))
+        Some((outer, left, right))
     }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            let next_left = self.next_left.take()?;
-            let next_right = self.next_right.take()?;
+    //  Returns the next chunk of either `left` or `right`, paired with the chunk of the other at the same chunk index.
+    fn next_merging(&mut self) -> Option<(L::ChunkIndex, L::Chunk, L::Chunk)>
+    where
+        R: IndexForwardChunked,
+    {
+        let outer = match (self.next_left, self.next_right) {
+            (None, None) => return None,
+            (Some(left), None) => left,
+            (None, Some(right)) => right,
+            (Some(left), Some(right)) => cmp::min(left, right),
+        };
 
-            match next_left.cmp(&next_right) {
-                Ordering::Equal => {
-                    self.next_left = self.left.next_after(next_left);
-                    self.next_right = self.right.next_after(next_right);
+        let mut left = L::Chunk::default();
+        let mut right = L::Chunk::default();
 
-                    return Some(next_left);
-                }
-                Ordering::Less => {
-                    self.next_left = self.left.next_after(next_right);
-                    self.next_right = self.right.next_after(next_right);
+        if self.next_left == Some(outer) {
+            left = self.left.get_chunk(outer).unwrap_or_default();
+            self.next_left = self.left.next_chunk_after(outer);
+        }
 
-                    if self.left.contains(next_right) {
-                        return Some(next_right);
-                    }
+        if self.next_right == Some(outer) {
+            right = self.right.get_chunk(outer).unwrap_or_default();
+            self.next_right = self.right.next_chunk_after(outer);
+        }
 
-                    continue;
-                }
-                Ordering::Greater => {
-                    self.next_left = self.left.next_after(next_left);
-                    self.next_right = self.right.next_after(next_left);
+        Some((outer, left, right))
+    }
+}
 
-                    if self.right.contains(next_left) {
-                        return Some(next_left);
-                    }
+#[cfg(test)]
+mod dual_iteration_tests;
 
-                    continue;
-                }
-            }
-        }
+//
+//  Diff operations.
+//
+
+/// The changes to apply to a set to make it equal to another, as computed by `diff`.
+#[cfg(any(feature = "alloc", test))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChangeSet<I> {
+    /// The indexes to insert, in the order they were encountered.
+    pub to_insert: Vec<I>,
+    /// The indexes to remove, in the order they were encountered.
+    pub to_remove: Vec<I>,
+}
+
+#[cfg(any(feature = "alloc", test))]
+impl<S> IndexSet<S>
+where
+    S: IndexForward,
+{
+    /// Returns the changes to apply to `self` to make it equal to `target`.
+    pub fn diff<OS>(&self, target: &IndexSet<OS>) -> ChangeSet<S::Index>
+    where
+        OS: IndexForward<Index = S::Index>,
+    {
+        let to_insert = target.difference(self).collect();
+        let to_remove = self.difference(target).collect();
+
+        ChangeSet { to_insert, to_remove }
     }
 }
 
-/// Iterator over the elements in L or in R.
-pub struct UnionOrd<'a, I, L, R> {
-    next_left: Option<I>,
-    next_right: Option<I>,
-    left: &'a L,
-    right: &'a R,
+#[cfg(any(feature = "alloc", test))]
+impl<S> IndexOrdSet<S>
+where
+    S: IndexOrdered,
+{
+    /// Returns the changes to apply to `self` to make it equal to `target`.
+    ///
+    /// Takes advantage of iteration over `self` and `other` being ordered to compute the changes in a single pass.
+    pub fn diff<OS>(&self, target: &IndexOrdSet<OS>) -> ChangeSet<S::Index>
+    where
+        OS: IndexOrdered<Index = S::Index>,
+    {
+        diff_ordered(&self.store, &target.store)
+    }
 }
 
-impl<'a, I, L, R> Iterator for UnionOrd<'a, I, L, R>
+#[cfg(any(feature = "alloc", test))]
+impl<S> IndexChunkedSet<S>
 where
-    I: Copy + Eq + Ord,
-    L: IndexOrdered<Index = I>,
-    R: IndexOrdered<Index = I>,
+    S: IndexOrdered,
 {
-    type Item = I;
+    /// Returns the changes to apply to `self` to make it equal to `target`.
+    ///
+    /// Takes advantage of iteration over `self` and `other` being ordered to compute the changes in a single pass.
+    pub fn diff<OS>(&self, target: &IndexChunkedSet<OS>) -> ChangeSet<S::Index>
+    where
+        OS: IndexOrdered<Index = S::Index>,
+    {
+        diff_ordered(&self.store, &target.store)
+    }
+}
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let left = self.next_left.map(|_| self.left.len()).unwrap_or(0);
-        let right = self.next_right.map(|_| self.right.len()).unwrap_or(0);
+#[cfg(any(feature = "alloc", test))]
+impl<S> IndexSet<S>
+where
+    S: IndexStore,
+{
+    /// Applies the changes, removing then inserting indexes.
+    ///
+    /// If an insertion fails, the changes are only partially applied.
+    pub fn apply_changeset(&mut self, changes: &ChangeSet<S::Index>) -> Result<(), S::InsertionError> {
+        apply_changeset(&mut self.store, changes)
+    }
+}
 
-        (0, Some(left + right))
+#[cfg(any(feature = "alloc", test))]
+impl<S> IndexOrdSet<S>
+where
+    S: IndexStore,
+{
+    /// Applies the changes, removing then inserting indexes.
+    ///
+    /// If an insertion fails, the changes are only partially applied.
+    pub fn apply_changeset(&mut self, changes: &ChangeSet<S::Index>) -> Result<(), S::InsertionError> {
+        apply_changeset(&mut self.store, changes)
     }
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        match (self.next_left, self.next_right) {
-            (None, None) => None,
-            (Some(next_left), None) => {
-                self.next_left = self.left.next_after(next_left);
+#[cfg(any(feature = "alloc", test))]
+impl<S> IndexChunkedSet<S>
+where
+    S: IndexStore,
+{
+    /// Applies the changes, removing then inserting indexes.
+    ///
+    /// If an insertion fails, the changes are only partially applied.
+    pub fn apply_changeset(&mut self, changes: &ChangeSet<S::Index>) -> Result<(), S::InsertionError> {
+        apply_changeset(&mut self.store, changes)
+    }
+}
 
-                Some(next_left)
-            }
-            (None, Some(next_right)) => {
-                self.next_right = self.right.next_after(next_right);
+//  Merges `left` and `right`, indexes only in `left` are to be removed, and indexes only in `right` are to be inserted.
+#[cfg(any(feature = "alloc", test))]
+fn diff_ordered<I, L, R>(left: &L, right: &R) -> ChangeSet<I>
+where
+    I: Copy + Eq + Ord,
+    L: IndexOrdered<Index = I>,
+    R: IndexOrdered<Index = I>,
+{
+    let mut changes = ChangeSet {
+        to_insert: Vec::new(),
+        to_remove: Vec::new(),
+    };
 
-                Some(next_right)
+    let (mut next_left, mut next_right) = (left.first(), right.first());
+
+    loop {
+        match (next_left, next_right) {
+            (None, None) => return changes,
+            (Some(l), None) => {
+                changes.to_remove.push(l);
+                next_left = left.next_after(l);
             }
-            (Some(next_left), Some(next_right)) => match next_left.cmp(&next_right) {
+            (None, Some(r)) => {
+                changes.to_insert.push(r);
+                next_right = right.next_after(r);
+            }
+            (Some(l), Some(r)) => match l.cmp(&r) {
                 Ordering::Equal => {
-                    self.next_left = self.left.next_after(next_left);
-                    self.next_right = self.right.next_after(next_right);
-
-                    Some(next_left)
+                    next_left = left.next_after(l);
+                    next_right = right.next_after(r);
                 }
                 Ordering::Less => {
-                    self.next_left = self.left.next_after(next_left);
-
-                    Some(next_left)
+                    changes.to_remove.push(l);
+                    next_left = left.next_after(l);
                 }
                 Ordering::Greater => {
-                    self.next_right = self.right.next_after(next_right);
-
-                    Some(next_right)
+                    changes.to_insert.push(r);
+                    next_right = right.next_after(r);
                 }
             },
         }
     }
 }
 
-//  FIXME: implement chunk versions of the above.
+#[cfg(any(feature = "alloc", test))]
+fn apply_changeset<S>(store: &mut S, changes: &ChangeSet<S::Index>) -> Result<(), S::InsertionError>
+where
+    S: IndexStore,
+{
+    for &index in &changes.to_remove {
+        store.remove(index);
+    }
+
+    for &index in &changes.to_insert {
+        store.insert(index)?;
+    }
+
+    Ok(())
+}
 
 #[cfg(test)]
-mod dual_iteration_tests;
+mod diff_tests;
 
 //
 //  Bitwise operations.
@@ -2423,9 +6114,7 @@ where
         S: IndexStore<InsertionError = Never>,
         OS: IndexForward<Index = S::Index>,
     {
-        other.iter().for_each(|index| {
-            let _ = self.store.insert(index);
-        });
+        let _ = self.store.insert_all(&other.store);
     }
 
     /// Removes all indexes of `other` from `self`.
@@ -2437,6 +6126,20 @@ where
             self.store.remove(index);
         });
     }
+
+    /// Inserts all indexes of `other` not contained in `self`, while removing all indexes of `self` also contained in
+    /// `other`.
+    pub fn bitxor_assign<OS>(&mut self, other: &IndexSet<OS>)
+    where
+        S: IndexStore<InsertionError = Never>,
+        OS: IndexForward<Index = S::Index>,
+    {
+        other.iter().for_each(|index| {
+            if !self.store.remove(index) {
+                let _ = self.store.insert(index);
+            }
+        });
+    }
 }
 
 impl<S> IndexOrdSet<S>
@@ -2458,9 +6161,18 @@ where
         S: IndexStore<InsertionError = Never>,
         OS: IndexForward<Index = S::Index>,
     {
-        other.iter().for_each(|index| {
-            let _ = self.store.insert(index);
-        });
+        let _ = self.store.insert_all(&other.store);
+    }
+
+    /// Moves all indexes of `other` into `self`, leaving `other` empty.
+    pub fn append<OS>(&mut self, other: &mut IndexOrdSet<OS>)
+    where
+        S: IndexStore<InsertionError = Never>,
+        OS: IndexForward<Index = S::Index> + IndexStore,
+    {
+        self.bitor_assign(other);
+
+        other.clear();
     }
 
     /// Removes all indexes of `other` from `self`.
@@ -2517,11 +6229,16 @@ where
     }
 }
 
+//  The chunked operations read, combine, and write back a whole chunk at a time, rather than operating index by index.
+//
+//  This requires both operands to share the same chunk layout, as enforced by the `ChunkIndex` and `Chunk` bounds.
 impl<S> IndexChunkedSet<S>
 where
     S: IndexOrderedChunked,
 {
     /// Removes all indexes of `self` not contained in `other`.
+    ///
+    /// Operates a whole chunk at a time.
     pub fn bitand_assign<OS>(&mut self, other: &IndexChunkedSet<OS>)
     where
         S: IndexStoreChunked<SetError = Never>,
@@ -2564,29 +6281,32 @@ where
     }
 
     /// Inserts all indexes of `other` not contained in `self`.
+    ///
+    /// Operates a whole chunk at a time.
     pub fn bitor_assign<OS>(&mut self, other: &IndexChunkedSet<OS>)
     where
         S: IndexStoreChunked<SetError = Never>,
         OS: IndexForwardChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk>,
     {
-        other.iter_chunks().for_each(|outer| {
-            let Some(other) = other.get_chunk(outer) else { return };
-
-            if other.is_empty() {
-                return;
-            }
-
-            let chunk = self.store.get_chunk(outer).unwrap_or_default();
+        let _ = self.store.insert_all_chunks(&other.store);
+    }
 
-            let new = chunk | other;
+    /// Moves all indexes of `other` into `self`, leaving `other` empty.
+    ///
+    /// Operates a whole chunk at a time, see `bitor_assign`.
+    pub fn append<OS>(&mut self, other: &mut IndexChunkedSet<OS>)
+    where
+        S: IndexStoreChunked<SetError = Never>,
+        OS: IndexForwardChunked<Index = S::Index, ChunkIndex = S::ChunkIndex, Chunk = S::Chunk> + IndexStore,
+    {
+        self.bitor_assign(other);
 
-            if new != chunk {
-                let _ = self.store.set_chunk(outer, new);
-            }
-        });
+        other.clear();
     }
 
     /// Removes all indexes of `other` from `self`.
+    ///
+    /// Operates a whole chunk at a time.
     pub fn sub_assign<OS>(&mut self, other: &IndexChunkedSet<OS>)
     where
         S: IndexStoreChunked<SetError = Never>,
@@ -2617,6 +6337,8 @@ where
 
     /// Inserts all indexes of `other` not contained in `self`, while removing all indexes of `self` also contained in
     /// `other`.
+    ///
+    /// Operates a whole chunk at a time.
     pub fn bitxor_assign<OS>(&mut self, other: &IndexChunkedSet<OS>)
     where
         S: IndexStoreChunked<SetError = Never>,
@@ -2630,11 +6352,9 @@ where
                 (_, None) => break,
                 (None, Some(_)) => {
                     while let Some(that) = next_other {
-                        let Some(that_chunk) = other.store.get_chunk(that) else {
-                            continue;
-                        };
-
-                        if !that_chunk.is_empty() {
+                        if let Some(that_chunk) = other.store.get_chunk(that)
+                            && !that_chunk.is_empty()
+                        {
                             let _ = self.store.set_chunk(that, that_chunk);
                         }
 
@@ -2657,11 +6377,9 @@ where
                         next_self = self.store.next_chunk_after(this);
                     }
                     Ordering::Greater => {
-                        let Some(that_chunk) = other.store.get_chunk(that) else {
-                            continue;
-                        };
-
-                        if !that_chunk.is_empty() {
+                        if let Some(that_chunk) = other.store.get_chunk(that)
+                            && !that_chunk.is_empty()
+                        {
                             let _ = self.store.set_chunk(that, that_chunk);
                         }
 
@@ -2676,6 +6394,9 @@ where
 #[cfg(test)]
 mod bitwise_tests;
 
+#[cfg(all(test, feature = "quickcheck"))]
+mod quickcheck_tests;
+
 //
 //  Bitwise operators: IndexSet.
 //
@@ -2720,6 +6441,26 @@ where
     }
 }
 
+impl<S, OS> ops::BitXorAssign<IndexSet<OS>> for IndexSet<S>
+where
+    S: IndexStore<InsertionError = Never>,
+    OS: IndexForward<Index = S::Index>,
+{
+    fn bitxor_assign(&mut self, other: IndexSet<OS>) {
+        self.bitxor_assign(&other);
+    }
+}
+
+impl<S, OS> ops::BitXorAssign<&IndexSet<OS>> for IndexSet<S>
+where
+    S: IndexStore<InsertionError = Never>,
+    OS: IndexForward<Index = S::Index>,
+{
+    fn bitxor_assign(&mut self, other: &IndexSet<OS>) {
+        self.bitxor_assign(other);
+    }
+}
+
 impl<S, OS> ops::SubAssign<IndexSet<OS>> for IndexSet<S>
 where
     S: IndexStore,
@@ -2824,6 +6565,34 @@ where
     }
 }
 
+impl<S, OS> ops::BitXor<IndexSet<OS>> for IndexSet<S>
+where
+    S: IndexStore<InsertionError = Never>,
+    OS: IndexForward<Index = S::Index>,
+{
+    type Output = Self;
+
+    fn bitxor(mut self, other: IndexSet<OS>) -> Self::Output {
+        self.bitxor_assign(&other);
+
+        self
+    }
+}
+
+impl<S, OS> ops::BitXor<&IndexSet<OS>> for IndexSet<S>
+where
+    S: IndexStore<InsertionError = Never>,
+    OS: IndexForward<Index = S::Index>,
+{
+    type Output = Self;
+
+    fn bitxor(mut self, other: &IndexSet<OS>) -> Self::Output {
+        self.bitxor_assign(other);
+
+        self
+    }
+}
+
 //
 //  Bitwise operators: IndexOrdSet.
 //
@@ -3215,3 +6984,306 @@ where
         self
     }
 }
+
+#[cfg(test)]
+mod operator_tests;
+
+//
+//  Parallel iteration.
+//
+//  The chunked sets are iterated in parallel by splitting the chunks in contiguous ranges, each task then yielding the
+//  indexes of its chunks in order. The chunks being independent, no synchronization is necessary.
+//
+
+#[cfg(feature = "rayon")]
+impl<S> IndexChunkedSet<S>
+where
+    S: IndexForwardChunked<ChunkIndex: Send, Chunk: IndexForward, Index: Send> + Sync,
+{
+    /// Returns a parallel iterator over the indexes in the set.
+    ///
+    /// The chunks are first counted, then split in halves across tasks, each half being walked to in turn, hence this
+    /// is most suitable for large sets.
+    pub fn par_iter(&self) -> ParIter<'_, S> {
+        let mut chunks = 0;
+        let mut cursor = self.store.first_chunk();
+
+        while let Some(outer) = cursor {
+            chunks += 1;
+            cursor = self.store.next_chunk_after(outer);
+        }
+
+        ParIter {
+            first: self.store.first_chunk(),
+            chunks,
+            store: &self.store,
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, S> rayon::iter::IntoParallelIterator for &'a IndexChunkedSet<S>
+where
+    S: IndexForwardChunked<ChunkIndex: Send, Chunk: IndexForward, Index: Send> + Sync,
+{
+    type Item = S::Index;
+    type Iter = ParIter<'a, S>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.par_iter()
+    }
+}
+
+/// Parallel iterator over the indexes of an `IndexChunkedSet`, yielded chunk by chunk.
+///
+/// Within a chunk, and across the chunks handled by a single task, the indexes are yielded in forward order.
+#[cfg(feature = "rayon")]
+pub struct ParIter<'a, S>
+where
+    S: IndexViewChunked,
+{
+    first: Option<S::ChunkIndex>,
+    chunks: usize,
+    store: &'a S,
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, S> rayon::iter::ParallelIterator for ParIter<'a, S>
+where
+    S: IndexForwardChunked<ChunkIndex: Send, Chunk: IndexForward, Index: Send> + Sync,
+{
+    type Item = S::Index;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: rayon::iter::plumbing::UnindexedConsumer<Self::Item>,
+    {
+        let producer = ChunksProducer {
+            first: self.first,
+            chunks: self.chunks,
+            store: self.store,
+        };
+
+        rayon::iter::plumbing::bridge_unindexed(producer, consumer)
+    }
+}
+
+//  Producer of the indexes of `chunks` consecutive chunks, starting from `first`.
+#[cfg(feature = "rayon")]
+struct ChunksProducer<'a, S>
+where
+    S: IndexViewChunked,
+{
+    first: Option<S::ChunkIndex>,
+    chunks: usize,
+    store: &'a S,
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, S> rayon::iter::plumbing::UnindexedProducer for ChunksProducer<'a, S>
+where
+    S: IndexForwardChunked<ChunkIndex: Send, Chunk: IndexForward, Index: Send> + Sync,
+{
+    type Item = S::Index;
+
+    fn split(self) -> (Self, Option<Self>) {
+        if self.chunks < 2 {
+            return (self, None);
+        }
+
+        let (left, right) = (self.chunks / 2, self.chunks - self.chunks / 2);
+
+        let store = self.store;
+        let mut middle = self.first;
+
+        for _ in 0..left {
+            middle = middle.and_then(|outer| store.next_chunk_after(outer));
+        }
+
+        (
+            Self {
+                first: self.first,
+                chunks: left,
+                store,
+            },
+            Some(Self {
+                first: middle,
+                chunks: right,
+                store,
+            }),
+        )
+    }
+
+    fn fold_with<F>(self, folder: F) -> F
+    where
+        F: rayon::iter::plumbing::Folder<Self::Item>,
+    {
+        let store = self.store;
+
+        let outers = core::iter::successors(self.first, |&outer| store.next_chunk_after(outer)).take(self.chunks);
+
+        let indexes = outers.flat_map(|outer| {
+            let chunk = store.get_chunk(outer).unwrap_or_default();
+
+            core::iter::successors(chunk.first(), move |&inner| chunk.next_after(inner))
+                .map(move |inner| S::fuse(outer, inner))
+        });
+
+        folder.consume_iter(indexes)
+    }
+}
+
+#[cfg(all(test, feature = "rayon"))]
+mod rayon_tests;
+
+//
+//  Serialization.
+//
+//  The sets are serialized as a sequence of indexes, in iteration order, and deserialized by inserting each index in
+//  turn into a new store.
+//
+
+#[cfg(feature = "serde")]
+impl<S> serde::Serialize for IndexSet<S>
+where
+    S: IndexForward<Index: serde::Serialize>,
+{
+    fn serialize<Z>(&self, serializer: Z) -> Result<Z::Ok, Z::Error>
+    where
+        Z: serde::Serializer,
+    {
+        serialize_indexes(&self.store, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<S> serde::Serialize for IndexOrdSet<S>
+where
+    S: IndexOrdered<Index: serde::Serialize>,
+{
+    fn serialize<Z>(&self, serializer: Z) -> Result<Z::Ok, Z::Error>
+    where
+        Z: serde::Serializer,
+    {
+        serialize_indexes(&self.store, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S> serde::Deserialize<'de> for IndexSet<S>
+where
+    S: IndexCollection + IndexStore<Index: serde::Deserialize<'de>>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let store = deserializer.deserialize_seq(IndexesVisitor::new(false))?;
+
+        Ok(Self { store })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S> serde::Deserialize<'de> for IndexOrdSet<S>
+where
+    S: IndexCollection + IndexOrdered + IndexStore<Index: serde::Deserialize<'de>>,
+{
+    /// Deserializes the set from a sequence of indexes.
+    ///
+    /// Compact formats, which are not human-readable, are expected to have been produced by `serialize`, and therefore
+    /// the sequence of indexes MUST be strictly increasing. Human-readable formats, which may have been edited by hand,
+    /// accept indexes in any order, the ordered store sorting them on insertion.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let strict = !deserializer.is_human_readable();
+
+        let store = deserializer.deserialize_seq(IndexesVisitor::new(strict))?;
+
+        Ok(Self { store })
+    }
+}
+
+#[cfg(feature = "serde")]
+fn serialize_indexes<V, Z>(view: &V, serializer: Z) -> Result<Z::Ok, Z::Error>
+where
+    V: IndexForward<Index: serde::Serialize>,
+    Z: serde::Serializer,
+{
+    use serde::ser::SerializeSeq;
+
+    let mut seq = serializer.serialize_seq(Some(view.len()))?;
+
+    let mut cursor = view.first();
+
+    while let Some(index) = cursor {
+        seq.serialize_element(&index)?;
+
+        cursor = view.next_after(index);
+    }
+
+    seq.end()
+}
+
+//  Builds a store from a sequence of indexes.
+//
+//  If `strict`, the indexes are required to be strictly increasing.
+#[cfg(feature = "serde")]
+struct IndexesVisitor<S> {
+    strict: bool,
+    _marker: core::marker::PhantomData<fn() -> S>,
+}
+
+#[cfg(feature = "serde")]
+impl<S> IndexesVisitor<S> {
+    fn new(strict: bool) -> Self {
+        let _marker = core::marker::PhantomData;
+
+        Self { strict, _marker }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S> serde::de::Visitor<'de> for IndexesVisitor<S>
+where
+    S: IndexCollection + IndexStore<Index: serde::Deserialize<'de>>,
+{
+    type Value = S;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if self.strict {
+            formatter.write_str("a strictly increasing sequence of indexes")
+        } else {
+            formatter.write_str("a sequence of indexes")
+        }
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        use serde::de::Error;
+
+        let mut store = S::new();
+        let mut previous = None;
+
+        while let Some(index) = seq.next_element::<S::Index>()? {
+            if self.strict && previous.is_some_and(|previous| previous >= index) {
+                return Err(A::Error::custom("indexes are not strictly increasing"));
+            }
+
+            previous = Some(index);
+
+            store
+                .insert(index)
+                .map_err(|error| A::Error::custom(format_args!("failed to insert index: {error:?}")))?;
+        }
+
+        Ok(store)
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests;
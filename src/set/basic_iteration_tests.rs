@@ -1,5 +1,95 @@
 //! Unit tests for individual operations.
 
+use std::collections::BTreeSet;
+
+//  A store iterating in decreasing order, locking in that the double-ended iterators of `IndexSet` do not rely on the
+//  order of the indexes.
+#[derive(Default)]
+struct Descending(BTreeSet<u8>);
+
+//  #   Safety
+//
+//  -   NoPhantom: only indexes inserted, and not removed since, are in the set.
+unsafe impl crate::index::IndexView for Descending {
+    type Index = u8;
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn contains(&self, index: Self::Index) -> bool {
+        self.0.contains(&index)
+    }
+}
+
+impl crate::index::IndexCollection for Descending {
+    fn span() -> (core::ops::Bound<Self::Index>, core::ops::Bound<Self::Index>) {
+        (core::ops::Bound::Unbounded, core::ops::Bound::Unbounded)
+    }
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_span(_range: (core::ops::Bound<Self::Index>, core::ops::Bound<Self::Index>)) -> Self {
+        Self::default()
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: only indexes inserted, and not removed since, are in the set.
+unsafe impl crate::index::IndexStore for Descending {
+    type InsertionError = crate::Never;
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn insert(&mut self, index: Self::Index) -> Result<bool, Self::InsertionError> {
+        Ok(self.0.insert(index))
+    }
+
+    fn remove(&mut self, index: Self::Index) -> bool {
+        self.0.remove(&index)
+    }
+}
+
+//  #   Safety
+//
+//  -   NoTheft: the set never loses an index.
+unsafe impl crate::index::IndexVault for Descending {}
+
+//  #   Safety
+//
+//  -   NoDuplicate, NoPhantom, NoTheft: inherited from `BTreeSet`, in reverse.
+unsafe impl crate::index::IndexForward for Descending {
+    fn first(&self) -> Option<Self::Index> {
+        self.0.last().copied()
+    }
+
+    fn next_after(&self, current: Self::Index) -> Option<Self::Index> {
+        self.0.range(..current).next_back().copied()
+    }
+}
+
+//  #   Safety
+//
+//  -   Reverse: iterates in increasing order, the exact opposite of `IndexForward`.
+unsafe impl crate::index::IndexBackward for Descending {
+    fn last(&self) -> Option<Self::Index> {
+        self.0.first().copied()
+    }
+
+    fn next_before(&self, current: Self::Index) -> Option<Self::Index> {
+        self.0.range(current + 1..).next().copied()
+    }
+}
+
 mod index_set {
     use std::collections::BTreeSet;
 
@@ -44,12 +134,129 @@ mod index_set {
 
         helper::assert_exact_iterator(victim.into_iter_rev(), INDEXES.into_iter().rev());
     }
+
+    #[test]
+    fn double_ended_iter() {
+        const PRIMES: [u8; 6] = [2, 3, 5, 7, 11, 13];
+
+        let victim = Victim::from_iter(PRIMES);
+
+        helper::assert_exact_iterator(victim.iter().rev(), PRIMES.into_iter().rev());
+
+        for pattern in helper::PATTERNS {
+            helper::assert_double_ended_iterator(victim.iter(), PRIMES, pattern);
+        }
+    }
+
+    #[test]
+    fn double_ended_into_iter() {
+        const PRIMES: [u8; 6] = [2, 3, 5, 7, 11, 13];
+
+        for pattern in helper::PATTERNS {
+            let victim = Victim::from_iter(PRIMES);
+
+            helper::assert_double_ended_iterator(victim.into_iter(), PRIMES, pattern);
+        }
+    }
+
+    #[test]
+    fn double_ended_drain() {
+        const PRIMES: [u8; 6] = [2, 3, 5, 7, 11, 13];
+
+        for pattern in helper::PATTERNS {
+            let mut victim = Victim::from_iter(PRIMES);
+
+            helper::assert_double_ended_iterator(victim.drain(), PRIMES, pattern);
+
+            assert!(victim.is_empty());
+        }
+    }
+
+    #[test]
+    fn double_ended_unordered() {
+        const PRIMES: [u8; 6] = [13, 11, 7, 5, 3, 2];
+
+        for pattern in helper::PATTERNS {
+            let mut victim = IndexSet::<super::Descending>::from_iter(PRIMES);
+
+            helper::assert_double_ended_iterator(victim.iter(), PRIMES, pattern);
+            helper::assert_double_ended_iterator(victim.drain(), PRIMES, pattern);
+
+            let victim = IndexSet::<super::Descending>::from_iter(PRIMES);
+
+            helper::assert_double_ended_iterator(victim.into_iter(), PRIMES, pattern);
+        }
+    }
+
+    #[test]
+    fn clone_iter() {
+        const PRIMES: [u8; 6] = [2, 3, 5, 7, 11, 13];
+
+        let victim = Victim::from_iter(PRIMES);
+
+        let mut iter = victim.iter();
+
+        assert_eq!(Some(2), iter.next());
+        assert_eq!(Some(13), iter.next_back());
+
+        let clone = iter.clone();
+
+        //  Both continue independently, from where the original was.
+        helper::assert_exact_iterator(iter, [3, 5, 7, 11]);
+        helper::assert_exact_iterator(clone, [3, 5, 7, 11]);
+    }
+
+    #[test]
+    fn peek() {
+        const PRIMES: [u8; 6] = [2, 3, 5, 7, 11, 13];
+
+        let victim = Victim::from_iter(PRIMES);
+
+        let mut iter = victim.iter();
+
+        while let Some(peeked) = iter.peek() {
+            let length = iter.len();
+
+            assert_eq!(Some(peeked), iter.peek());
+            assert_eq!(length, iter.len());
+
+            assert_eq!(Some(peeked), iter.next());
+        }
+
+        assert_eq!(0, iter.len());
+
+        //  Meeting in the middle.
+        let mut iter = victim.iter();
+
+        assert_eq!(Some(2), iter.peek());
+        assert_eq!(Some(13), iter.next_back());
+        assert_eq!(Some(2), iter.peek());
+
+        assert_eq!(Some(3), iter.nth_back(3));
+        assert_eq!(Some(2), iter.peek());
+        assert_eq!(Some(2), iter.next_back());
+        assert_eq!(None, iter.peek());
+
+        let mut iter = victim.iter_rev();
+
+        assert_eq!(Some(13), iter.peek());
+        assert_eq!(Some(13), iter.next());
+        assert_eq!(Some(11), iter.peek());
+        assert_eq!(5, iter.len());
+
+        let mut iter = victim.into_iter();
+
+        assert_eq!(Some(2), iter.peek());
+        assert_eq!(Some(2), iter.next());
+        assert_eq!(Some(3), iter.peek());
+        assert_eq!(5, iter.len());
+    }
 } // mod index_set
 
 mod index_ord_set {
     use alloc::collections::BTreeSet;
 
-    use crate::set::IndexOrdSet;
+    use crate::{index::IndexBackward, set::IndexOrdSet};
 
     use super::helper;
 
@@ -90,12 +297,168 @@ mod index_ord_set {
 
         helper::assert_exact_iterator(victim.into_iter_rev(), INDEXES.into_iter().rev());
     }
+
+    #[test]
+    fn double_ended_iter() {
+        const PRIMES: [u8; 6] = [2, 3, 5, 7, 11, 13];
+
+        let victim = Victim::from_iter(PRIMES);
+
+        helper::assert_exact_iterator(victim.iter().rev(), PRIMES.into_iter().rev());
+
+        for pattern in helper::PATTERNS {
+            helper::assert_double_ended_iterator(victim.iter(), PRIMES, pattern);
+        }
+    }
+
+    #[test]
+    fn double_ended_into_iter() {
+        const PRIMES: [u8; 6] = [2, 3, 5, 7, 11, 13];
+
+        for pattern in helper::PATTERNS {
+            let victim = Victim::from_iter(PRIMES);
+
+            helper::assert_double_ended_iterator(victim.into_iter(), PRIMES, pattern);
+        }
+    }
+
+    #[test]
+    fn double_ended_drain() {
+        const PRIMES: [u8; 6] = [2, 3, 5, 7, 11, 13];
+
+        for pattern in helper::PATTERNS {
+            let mut victim = Victim::from_iter(PRIMES);
+
+            helper::assert_double_ended_iterator(victim.drain(), PRIMES, pattern);
+
+            assert!(victim.is_empty());
+        }
+    }
+
+    #[test]
+    fn range() {
+        use core::ops::Bound::{self, Excluded, Included, Unbounded};
+
+        const PRIMES: [u8; 6] = [2, 3, 5, 7, 11, 13];
+
+        #[track_caller]
+        fn assert_range(expected: &[u8], range: (Bound<u8>, Bound<u8>)) {
+            let victim = Victim::from_iter(PRIMES);
+
+            let actual: Vec<_> = victim.range(range).collect();
+
+            assert_eq!(expected, &actual[..], "{range:?}");
+        }
+
+        assert_range(&PRIMES, (Unbounded, Unbounded));
+
+        assert_range(&[5, 7, 11, 13], (Included(5), Unbounded));
+        assert_range(&[7, 11, 13], (Excluded(5), Unbounded));
+        assert_range(&[5, 7, 11, 13], (Included(4), Unbounded));
+        assert_range(&[5, 7, 11, 13], (Excluded(4), Unbounded));
+
+        assert_range(&[2, 3, 5, 7], (Unbounded, Included(7)));
+        assert_range(&[2, 3, 5], (Unbounded, Excluded(7)));
+        assert_range(&[2, 3, 5, 7], (Unbounded, Included(8)));
+        assert_range(&[2, 3, 5, 7], (Unbounded, Excluded(8)));
+
+        assert_range(&[3, 5, 7], (Included(3), Included(7)));
+        assert_range(&[3, 5], (Included(3), Excluded(7)));
+        assert_range(&[5, 7], (Excluded(3), Included(7)));
+        assert_range(&[5], (Excluded(3), Excluded(7)));
+
+        assert_range(&[], (Included(14), Unbounded));
+        assert_range(&[], (Unbounded, Excluded(2)));
+        assert_range(&[], (Excluded(3), Excluded(5)));
+        assert_range(&[], (Included(7), Excluded(7)));
+        assert_range(&[], (Included(11), Included(7)));
+    }
+
+    #[test]
+    fn iter_from() {
+        const PRIMES: [u8; 6] = [2, 3, 5, 7, 11, 13];
+
+        let victim = Victim::from_iter(PRIMES);
+
+        assert!(victim.iter_from(3).take(2).eq([3, 5]));
+        assert!(victim.iter_from(4).eq([5, 7, 11, 13]));
+        assert!(victim.iter_from(0).eq(PRIMES));
+        assert_eq!(None, victim.iter_from(14).next());
+    }
+
+    #[test]
+    fn iter_to() {
+        const PRIMES: [u8; 6] = [2, 3, 5, 7, 11, 13];
+
+        let victim = Victim::from_iter(PRIMES);
+
+        assert_eq!(Some(3), victim.as_store().last_at(4));
+        assert!(victim.iter_to(3).eq([3, 2]));
+        assert!(victim.iter_to(4).eq([3, 2]));
+        assert!(victim.iter_to(20).eq(PRIMES.into_iter().rev()));
+        assert_eq!(None, victim.iter_to(1).next());
+    }
+
+    #[test]
+    fn peek_range() {
+        const PRIMES: [u8; 6] = [2, 3, 5, 7, 11, 13];
+
+        let victim = Victim::from_iter(PRIMES);
+
+        //  The next index of the store, beyond the end of the range, is not peeked.
+        let mut range = victim.range(3..7);
+
+        assert_eq!(Some(3), range.peek());
+        assert_eq!(Some(3), range.next());
+        assert_eq!(Some(5), range.peek());
+        assert_eq!(Some(5), range.next());
+        assert_eq!(None, range.peek());
+        assert_eq!(None, range.next());
+
+        let mut range = victim.iter_to(5);
+
+        assert_eq!(Some(5), range.peek());
+        assert!(range.by_ref().eq([5, 3, 2]));
+        assert_eq!(None, range.peek());
+    }
+
+    #[test]
+    fn windows_of_gaps() {
+        let victim = Victim::from_iter([1, 2, 5, 7]);
+
+        assert!(victim.windows_of_gaps().eq([3..=4, 6..=6]));
+
+        //  Adjacent indexes, including at the boundaries of the index type.
+        let victim = Victim::from_iter([0, 1, 2, 254, 255]);
+
+        assert!(victim.windows_of_gaps().eq([3..=253]));
+
+        let victim = Victim::from_iter([3, 4, 5]);
+
+        assert_eq!(None, victim.windows_of_gaps().next());
+    }
+
+    #[test]
+    fn windows_of_gaps_single() {
+        let victim = Victim::from_iter([42]);
+
+        let mut gaps = victim.windows_of_gaps();
+
+        assert_eq!(None, gaps.next());
+        assert_eq!(None, gaps.next());
+
+        assert_eq!(None, Victim::new().windows_of_gaps().next());
+    }
 } // mod index_ord_set
 
 mod index_chunked_set {
+    use core::iter;
+
     use crate::{
         chunk::{ArrayChunk, UnsignedChunk},
+        index::{IndexForward, IndexView},
         set::IndexChunkedSet,
+        vault::DynamicChunkStore,
     };
 
     use super::helper;
@@ -137,11 +500,146 @@ mod index_chunked_set {
 
         helper::assert_exact_iterator(victim.into_iter_rev(), INDEXES.into_iter().rev());
     }
+
+    #[test]
+    fn fold_chunks() {
+        const INDEXES: [u16; 5] = [1, 2, 3, 5, 11];
+
+        let victim = Victim::from_iter(INDEXES);
+
+        let visited = victim.fold_chunks(Vec::new(), |mut acc, outer, chunk| {
+            acc.push((outer, chunk.len()));
+            acc
+        });
+
+        assert_eq!(vec![(0, 4), (1, 1)], visited);
+    }
+
+    #[test]
+    fn try_fold_chunks() {
+        const INDEXES: [u16; 5] = [1, 2, 3, 5, 11];
+
+        let victim = Victim::from_iter(INDEXES);
+
+        let total: Result<usize, u16> = victim.try_fold_chunks(0, |acc, _, chunk| Ok(acc + chunk.len()));
+
+        assert_eq!(Ok(5), total);
+
+        let mut visited = 0;
+
+        let failed: Result<usize, u16> = victim.try_fold_chunks(0, |_, outer, _| {
+            visited += 1;
+            Err(outer)
+        });
+
+        assert_eq!(Err(0), failed);
+        assert_eq!(1, visited);
+    }
+
+    #[test]
+    fn chunks() {
+        const INDEXES: [u16; 5] = [1, 2, 3, 5, 11];
+
+        let victim = Victim::from_iter(INDEXES);
+
+        let forward: Vec<_> = victim.chunks().map(|(outer, chunk)| (outer, chunk.len())).collect();
+        let backward: Vec<_> = victim.chunks_rev().map(|(outer, chunk)| (outer, chunk.len())).collect();
+
+        assert_eq!(vec![(0, 4), (1, 1)], forward);
+        assert_eq!(vec![(1, 1), (0, 4)], backward);
+
+        //  Fusing the indexes of each chunk yields back the indexes of the set.
+        let fused: Vec<_> = victim
+            .chunks()
+            .flat_map(|(outer, chunk)| {
+                iter::successors(chunk.first(), move |&inner| chunk.next_after(inner))
+                    .map(move |inner| outer * 8 + u16::from(inner))
+            })
+            .collect();
+
+        assert_eq!(INDEXES.to_vec(), fused);
+    }
+
+    #[test]
+    fn chunks_sparse() {
+        let victim: IndexChunkedSet<DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>>> =
+            [3, 40, 70].into_iter().collect();
+
+        let popcounts: Vec<_> = victim
+            .chunks()
+            .filter(|(_, chunk)| !chunk.is_empty())
+            .map(|(outer, chunk)| (outer, chunk.len()))
+            .collect();
+
+        assert_eq!(vec![(0, 1), (2, 1), (4, 1)], popcounts);
+        assert_eq!(victim.chunks().count(), victim.chunks_rev().count());
+    }
+
+    #[test]
+    fn double_ended_iter() {
+        const PRIMES: [u16; 6] = [2, 3, 5, 7, 11, 13];
+
+        let victim = Victim::from_iter(PRIMES);
+
+        helper::assert_exact_iterator(victim.iter().rev(), PRIMES.into_iter().rev());
+
+        for pattern in helper::PATTERNS {
+            helper::assert_double_ended_iterator(victim.iter(), PRIMES, pattern);
+        }
+    }
+
+    #[test]
+    fn double_ended_into_iter() {
+        const PRIMES: [u16; 6] = [2, 3, 5, 7, 11, 13];
+
+        for pattern in helper::PATTERNS {
+            let victim = Victim::from_iter(PRIMES);
+
+            helper::assert_double_ended_iterator(victim.into_iter(), PRIMES, pattern);
+        }
+    }
 } // mod index_chunked_set
 
 mod helper {
     use core::fmt;
 
+    //  Patterns of calls to `next` (false) and `next_back` (true), cycled through.
+    pub(super) const PATTERNS: [&[bool]; 4] = [
+        &[false, true],
+        &[true, false],
+        &[false, false, true],
+        &[true, true, false],
+    ];
+
+    #[track_caller]
+    pub(super) fn assert_double_ended_iterator<I, E>(mut victim: I, expected: E, pattern: &[bool])
+    where
+        I: DoubleEndedIterator<Item: fmt::Debug + Eq> + ExactSizeIterator,
+        E: IntoIterator<IntoIter: DoubleEndedIterator<Item = I::Item> + ExactSizeIterator>,
+    {
+        let mut expected = expected.into_iter();
+
+        for (i, &back) in pattern.iter().cycle().enumerate() {
+            assert_eq!(expected.len(), victim.len(), "{i}");
+
+            let (expected, victim) = if back {
+                (expected.next_back(), victim.next_back())
+            } else {
+                (expected.next(), victim.next())
+            };
+
+            assert_eq!(expected, victim, "{i}");
+
+            if expected.is_none() {
+                break;
+            }
+        }
+
+        assert_eq!(0, victim.len());
+        assert_eq!(None, victim.next());
+        assert_eq!(None, victim.next_back());
+    }
+
     #[track_caller]
     pub(super) fn assert_exact_iterator<I, E>(mut victim: I, expected: E)
     where
@@ -134,6 +134,40 @@ mod index_set {
         assert_sub_assign(EVENS, ODDS, EVENS);
         assert_sub_assign(ODDS, EVENS, ODDS);
     }
+
+    #[test]
+    fn bitxor_assign() {
+        #[track_caller]
+        fn assert_bitxor_assign<V, O, E>(victim: V, other: O, expected: E)
+        where
+            V: IntoIterator<Item = u8>,
+            O: IntoIterator<Item = u8>,
+            E: IntoIterator<Item = u8>,
+        {
+            let mut victim = Victim::from_iter(victim);
+            let other = Victim::from_iter(other);
+
+            victim.bitxor_assign(&other);
+
+            helper::assert_iterator(victim.iter(), expected);
+        }
+
+        assert_bitxor_assign(EMPTY, PRIMES, PRIMES);
+        assert_bitxor_assign(EMPTY, EVENS, EVENS);
+        assert_bitxor_assign(EMPTY, ODDS, ODDS);
+
+        assert_bitxor_assign(PRIMES, EMPTY, PRIMES);
+        assert_bitxor_assign(EVENS, EMPTY, EVENS);
+        assert_bitxor_assign(ODDS, EMPTY, ODDS);
+
+        assert_bitxor_assign(PRIMES, EVENS, [1, 3, 4, 5, 6, 8]);
+        assert_bitxor_assign(EVENS, ODDS, [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_bitxor_assign(ODDS, EVENS, [1, 2, 3, 4, 5, 6, 7, 8]);
+
+        assert_bitxor_assign(PRIMES, PRIMES, EMPTY);
+        assert_bitxor_assign(EVENS, EVENS, EMPTY);
+        assert_bitxor_assign(ODDS, ODDS, EMPTY);
+    }
 } // mod index_set
 
 mod index_ord_set {
@@ -231,6 +265,40 @@ mod index_ord_set {
         assert_bitor_assign(ODDS, EVENS, [1, 2, 3, 4, 5, 6, 7, 8]);
     }
 
+    #[test]
+    fn append() {
+        #[track_caller]
+        fn assert_append<V, O, E>(victim: V, other: O, expected: E)
+        where
+            V: IntoIterator<Item = u8>,
+            O: IntoIterator<Item = u8>,
+            E: IntoIterator<Item = u8> + Clone,
+        {
+            let mut victim = Victim::from_iter(victim);
+            let mut other = Victim::from_iter(other);
+
+            victim.append(&mut other);
+
+            assert!(other.is_empty());
+            assert_eq!(expected.clone().into_iter().count(), victim.len());
+
+            helper::assert_iterator(victim.iter(), expected);
+        }
+
+        assert_append(EMPTY, EMPTY, EMPTY);
+        assert_append(EMPTY, PRIMES, PRIMES);
+        assert_append(PRIMES, EMPTY, PRIMES);
+
+        //  Overlapping.
+        assert_append(PRIMES, EVENS, [1, 2, 3, 4, 5, 6, 8]);
+        assert_append(EVENS, PRIMES, [1, 2, 3, 4, 5, 6, 8]);
+        assert_append(PRIMES, PRIMES, PRIMES);
+
+        //  Disjoint.
+        assert_append(EVENS, ODDS, [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_append(ODDS, EVENS, [1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
     #[test]
     fn sub_assign() {
         #[track_caller]
@@ -310,6 +378,7 @@ mod index_chunked_set {
     use crate::{
         chunk::{ArrayChunk, UnsignedChunk},
         set::IndexChunkedSet,
+        vault::DynamicChunkStore,
     };
 
     use super::helper;
@@ -402,6 +471,40 @@ mod index_chunked_set {
         assert_bitor_assign(ODDS, EVENS, [1, 2, 3, 4, 5, 6, 7, 8]);
     }
 
+    #[test]
+    fn append() {
+        #[track_caller]
+        fn assert_append<V, O, E>(victim: V, other: O, expected: E)
+        where
+            V: IntoIterator<Item = u16>,
+            O: IntoIterator<Item = u16>,
+            E: IntoIterator<Item = u16> + Clone,
+        {
+            let mut victim = Victim::from_iter(victim);
+            let mut other = Victim::from_iter(other);
+
+            victim.append(&mut other);
+
+            assert!(other.is_empty());
+            assert_eq!(expected.clone().into_iter().count(), victim.len());
+
+            helper::assert_iterator(victim.iter(), expected);
+        }
+
+        assert_append(EMPTY, EMPTY, EMPTY);
+        assert_append(EMPTY, PRIMES, PRIMES);
+        assert_append(PRIMES, EMPTY, PRIMES);
+
+        //  Overlapping.
+        assert_append(PRIMES, EVENS, [1, 2, 3, 4, 5, 6, 8]);
+        assert_append(EVENS, PRIMES, [1, 2, 3, 4, 5, 6, 8]);
+        assert_append(PRIMES, PRIMES, PRIMES);
+
+        //  Disjoint.
+        assert_append(EVENS, ODDS, [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_append(ODDS, EVENS, [1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
     #[test]
     fn sub_assign() {
         #[track_caller]
@@ -475,6 +578,45 @@ mod index_chunked_set {
         assert_bitxor_assign(EVENS, EVENS, EMPTY);
         assert_bitxor_assign(ODDS, ODDS, EMPTY);
     }
+
+    #[test]
+    fn mismatched_chunks() {
+        type DynamicVictim = IndexChunkedSet<DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>>>;
+
+        const SHORT: [u64; 4] = [1, 2, 3, 5];
+        const LONG: [u64; 4] = [2, 4, 40, 70];
+
+        let short = DynamicVictim::from_iter(SHORT);
+        let long = DynamicVictim::from_iter(LONG);
+
+        let mut victim = DynamicVictim::from_iter(SHORT);
+        victim.bitand_assign(&long);
+        helper::assert_iterator(victim.iter(), [2]);
+
+        let mut victim = DynamicVictim::from_iter(LONG);
+        victim.bitand_assign(&short);
+        helper::assert_iterator(victim.iter(), [2]);
+
+        let mut victim = DynamicVictim::from_iter(SHORT);
+        victim.bitor_assign(&long);
+        helper::assert_iterator(victim.iter(), [1, 2, 3, 4, 5, 40, 70]);
+
+        let mut victim = DynamicVictim::from_iter(SHORT);
+        victim.sub_assign(&long);
+        helper::assert_iterator(victim.iter(), [1, 3, 5]);
+
+        let mut victim = DynamicVictim::from_iter(LONG);
+        victim.sub_assign(&short);
+        helper::assert_iterator(victim.iter(), [4, 40, 70]);
+
+        let mut victim = DynamicVictim::from_iter(SHORT);
+        victim.bitxor_assign(&long);
+        helper::assert_iterator(victim.iter(), [1, 3, 4, 5, 40, 70]);
+
+        let mut victim = DynamicVictim::from_iter(LONG);
+        victim.bitxor_assign(&short);
+        helper::assert_iterator(victim.iter(), [1, 3, 4, 5, 40, 70]);
+    }
 } // mod index_chunked_set
 
 mod helper {
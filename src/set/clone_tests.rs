@@ -0,0 +1,144 @@
+//! Unit tests for cloning.
+//!
+//! The allocations are counted, per thread, so as to check that `clone_from` reuses the allocations of the target.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+mod index_set {
+    use std::collections::HashSet;
+
+    use crate::set::IndexSet;
+
+    use super::allocations;
+
+    type Victim = IndexSet<HashSet<u32>>;
+
+    #[test]
+    fn clone_from() {
+        let source: Victim = (0..64).collect();
+        let mut target: Victim = (64..128).collect();
+
+        let allocated = allocations(|| target.clone_from(&source));
+
+        assert_eq!(0, allocated);
+        assert_eq!(source.len(), target.len());
+        assert!((0..64).all(|index| target.contains(index)));
+
+        let allocated = allocations(|| target = source.clone());
+
+        assert_ne!(0, allocated);
+    }
+} // mod index_set
+
+mod index_ord_set {
+    use crate::{
+        chunk::{ArrayChunk, UnsignedChunk},
+        set::IndexOrdSet,
+        vault::DynamicChunkStore,
+    };
+
+    use super::allocations;
+
+    type Victim = IndexOrdSet<DynamicChunkStore<ArrayChunk<UnsignedChunk<u64>, 2>>>;
+
+    #[test]
+    fn clone_from() {
+        let source: Victim = [1, 2, 3, 5, 130].into_iter().collect();
+        let mut target: Victim = [7, 11, 13, 255, 1000].into_iter().collect();
+
+        let allocated = allocations(|| target.clone_from(&source));
+
+        assert_eq!(0, allocated);
+        assert_eq!(source.len(), target.len());
+        assert!(source.iter().eq(target.iter()));
+
+        let allocated = allocations(|| target = source.clone());
+
+        assert_ne!(0, allocated);
+    }
+} // mod index_ord_set
+
+mod index_chunked_set {
+    use crate::{
+        chunk::{ArrayChunk, UnsignedChunk},
+        set::IndexChunkedSet,
+        vault::DynamicChunkStore,
+    };
+
+    use super::allocations;
+
+    type Victim = IndexChunkedSet<DynamicChunkStore<ArrayChunk<UnsignedChunk<u64>, 2>>>;
+
+    #[test]
+    fn clone_from() {
+        let source: Victim = [1, 2, 3, 5, 130].into_iter().collect();
+        let mut target: Victim = [7, 11, 13, 255, 1000].into_iter().collect();
+
+        let allocated = allocations(|| target.clone_from(&source));
+
+        assert_eq!(0, allocated);
+        assert_eq!(source.len(), target.len());
+        assert!(source.iter().eq(target.iter()));
+
+        //  A smaller target cannot be reused.
+        let source: Victim = [7, 1000].into_iter().collect();
+        let mut target: Victim = [7].into_iter().collect();
+
+        let allocated = allocations(|| target.clone_from(&source));
+
+        assert_ne!(0, allocated);
+        assert!(source.iter().eq(target.iter()));
+    }
+} // mod index_chunked_set
+
+//  Returns the number of allocations performed by the current thread while executing `fun`.
+fn allocations<F>(fun: F) -> usize
+where
+    F: FnOnce(),
+{
+    let before = ALLOCATIONS.get();
+
+    fun();
+
+    ALLOCATIONS.get() - before
+}
+
+std::thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+struct CountingAllocator;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+//  #   Safety
+//
+//  -   As per `System`.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        //  `try_with` as the thread-local may already be destroyed, on thread exit.
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+
+        //  Safety:
+        //  -   As per pre-conditions.
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        //  Safety:
+        //  -   As per pre-conditions.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+
+        //  Safety:
+        //  -   As per pre-conditions.
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
@@ -0,0 +1,301 @@
+//! Unit tests for comparison operations.
+
+mod index_set {
+    use std::collections::{BTreeSet, HashSet};
+
+    use crate::{
+        chunk::{ArrayChunk, UnsignedChunk},
+        set::IndexSet,
+        vault::DynamicChunkStore,
+    };
+
+    #[test]
+    fn eq_across_stores() {
+        const SOME: [u64; 5] = [1, 2, 3, 5, 11];
+
+        let hashed = IndexSet::<HashSet<u64>>::from_iter(SOME);
+        let ordered = IndexSet::<BTreeSet<u64>>::from_iter(SOME);
+        let chunked = IndexSet::<DynamicChunkStore<ArrayChunk<UnsignedChunk<u64>, 2>>>::from_iter(SOME);
+
+        //  `HashSet` is not iterable, hence only appears on the right-hand side.
+        assert_eq!(ordered, hashed);
+        assert_eq!(ordered, chunked);
+        assert_eq!(chunked, ordered);
+        assert_eq!(chunked, hashed);
+    }
+
+    #[test]
+    fn ne_across_stores() {
+        let hashed = IndexSet::<HashSet<u64>>::from_iter([1, 2, 3]);
+        let subset = IndexSet::<BTreeSet<u64>>::from_iter([1, 2]);
+        let other = IndexSet::<BTreeSet<u64>>::from_iter([1, 2, 4]);
+
+        assert_ne!(subset, hashed);
+        assert_ne!(other, hashed);
+        assert_ne!(other, subset);
+    }
+
+    #[test]
+    fn eq_non_canonical() {
+        type Victim = IndexSet<DynamicChunkStore<ArrayChunk<UnsignedChunk<u64>, 2>>>;
+
+        //  Same indexes, yet `left` holds many more chunks than `right`.
+        let mut left = Victim::from_iter([1, 2, 3, 5, 11, 1_000]);
+        left.remove(1_000);
+
+        let right = Victim::from_iter([1, 2, 3, 5, 11]);
+
+        assert_eq!(left, right);
+        assert_eq!(right, left);
+
+        //  Same indexes, yet the hashed store has a much larger capacity.
+        let mut hashed = IndexSet::<HashSet<u64>>::with_store(HashSet::with_capacity(1_024));
+        hashed.extend([11, 5, 3, 2, 1]);
+
+        assert!(left == hashed);
+
+        left.remove(11);
+
+        assert_ne!(left, right);
+        assert!(left != hashed);
+    }
+} // mod index_set
+
+mod index_ord_set {
+    use core::{
+        cmp::Ordering,
+        hash::{BuildHasher, Hash},
+    };
+
+    use std::{collections::BTreeSet, hash::RandomState};
+
+    use crate::{
+        chunk::{ArrayChunk, UnsignedChunk},
+        set::IndexOrdSet,
+        vault::{BitmapStore, DynamicChunkStore},
+    };
+
+    fn hash_of<T: Hash>(state: &RandomState, value: &T) -> u64 {
+        state.hash_one(value)
+    }
+
+    #[test]
+    fn eq_across_stores() {
+        const SOME: [u64; 5] = [1, 2, 3, 5, 11];
+
+        let ordered = IndexOrdSet::<BTreeSet<u64>>::from_iter(SOME);
+        let chunked = IndexOrdSet::<DynamicChunkStore<ArrayChunk<UnsignedChunk<u64>, 2>>>::from_iter(SOME);
+
+        assert_eq!(ordered, chunked);
+        assert_eq!(chunked, ordered);
+
+        let other = IndexOrdSet::<BTreeSet<u64>>::from_iter([1, 2, 3, 5, 12]);
+
+        assert_ne!(other, chunked);
+        assert_ne!(chunked, other);
+    }
+
+    #[test]
+    fn hash_across_stores() {
+        const SOME: [u64; 5] = [1, 2, 3, 5, 11];
+
+        let state = RandomState::new();
+
+        let ordered = IndexOrdSet::<BTreeSet<u64>>::from_iter(SOME);
+        let mut bitmap = IndexOrdSet::<BitmapStore<u64>>::with_store(BitmapStore::with_capacity(1_000));
+
+        bitmap.try_extend(SOME).unwrap();
+
+        assert_eq!(ordered, bitmap);
+        assert_eq!(hash_of(&state, &ordered), hash_of(&state, &bitmap));
+
+        let other = IndexOrdSet::<BTreeSet<u64>>::from_iter([1, 2, 3, 5]);
+
+        assert_ne!(hash_of(&state, &ordered), hash_of(&state, &other));
+    }
+
+    #[test]
+    fn ord_lexicographic() {
+        type Victim = IndexOrdSet<BTreeSet<u64>>;
+
+        let empty = Victim::new();
+        let one = Victim::from([1]);
+        let one_two = Victim::from([1, 2]);
+        let one_three = Victim::from([1, 3]);
+        let two = Victim::from([2]);
+
+        assert!(one_two < one_three);
+        assert!(one < one_two);
+        assert!(empty < one);
+        assert!(one_three < two);
+        assert_eq!(Ordering::Equal, one_two.cmp(&Victim::from([2, 1])));
+
+        let mut sets = [
+            two.clone(),
+            one_three.clone(),
+            empty.clone(),
+            one_two.clone(),
+            one.clone(),
+        ];
+
+        sets.sort();
+
+        assert_eq!([empty, one, one_two, one_three, two], sets);
+    }
+
+    #[test]
+    fn partial_ord_across_stores() {
+        let ordered = IndexOrdSet::<BTreeSet<u64>>::from([1, 2]);
+        let chunked = IndexOrdSet::<DynamicChunkStore<ArrayChunk<UnsignedChunk<u64>, 2>>>::from([1, 3]);
+
+        assert!(ordered < chunked);
+        assert!(chunked > ordered);
+
+        let mut bitmap = IndexOrdSet::<BitmapStore<u64>>::with_store(BitmapStore::with_capacity(64));
+
+        bitmap.try_extend([2, 1]).unwrap();
+
+        assert_eq!(Some(Ordering::Equal), ordered.partial_cmp(&bitmap));
+    }
+} // mod index_ord_set
+
+mod index_chunked_set {
+    use core::{
+        cmp::Ordering,
+        hash::{BuildHasher, Hash},
+    };
+
+    use std::hash::RandomState;
+
+    use crate::{
+        chunk::{ArrayChunk, UnsignedChunk},
+        set::IndexChunkedSet,
+        vault::DynamicChunkStore,
+    };
+
+    type Victim = IndexChunkedSet<ArrayChunk<UnsignedChunk<u8>, 2>>;
+
+    type DynamicVictim = IndexChunkedSet<DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>>>;
+
+    #[test]
+    fn eq_canonical() {
+        let empty = Victim::new();
+        let primes = Victim::from_iter([1, 2, 3, 5, 11]);
+
+        assert_eq!(empty, Victim::new());
+        assert_eq!(primes, Victim::from_iter([11, 5, 3, 2, 1]));
+        assert_ne!(empty, primes);
+
+        let mut other = primes;
+        other.remove(11);
+
+        assert_ne!(primes, other);
+
+        other.insert(11).unwrap();
+
+        assert_eq!(primes, other);
+    }
+
+    #[test]
+    fn eq_across_capacities() {
+        const SOME: [u64; 5] = [1, 2, 3, 5, 40];
+
+        let small = DynamicVictim::from_iter(SOME);
+
+        let mut large = DynamicVictim::with_capacity(100);
+        large.extend(SOME);
+
+        assert_eq!(small, large);
+        assert_eq!(large, small);
+
+        large.remove(40);
+
+        assert_ne!(small, large);
+        assert_ne!(large, small);
+    }
+
+    #[test]
+    fn hash_across_capacities() {
+        fn hash_of<T: Hash>(state: &RandomState, value: &T) -> u64 {
+            state.hash_one(value)
+        }
+
+        const SOME: [u64; 5] = [1, 2, 3, 5, 40];
+
+        let state = RandomState::new();
+
+        let small = DynamicVictim::from_iter(SOME);
+
+        let mut large = DynamicVictim::with_capacity(100);
+        large.extend(SOME);
+
+        //  A populated chunk, emptied.
+        let mut emptied = DynamicVictim::with_capacity(4);
+        emptied.extend(SOME);
+        emptied.insert(1_000).unwrap();
+        emptied.remove(1_000);
+
+        assert_eq!(small, large);
+        assert_eq!(hash_of(&state, &small), hash_of(&state, &large));
+
+        assert_eq!(small, emptied);
+        assert_eq!(hash_of(&state, &small), hash_of(&state, &emptied));
+
+        let other = DynamicVictim::from_iter([1, 2, 3, 5]);
+
+        assert_ne!(hash_of(&state, &small), hash_of(&state, &other));
+
+        let canonical = Victim::from_iter([1, 2, 3, 5, 11]);
+
+        assert_eq!(
+            hash_of(&state, &canonical),
+            hash_of(&state, &Victim::from_iter([11, 5, 3, 2, 1]))
+        );
+    }
+
+    #[test]
+    fn cmp_by_words_equal() {
+        let empty = Victim::new();
+        let primes = Victim::from_iter([1, 2, 3, 5, 11]);
+
+        assert_eq!(Ordering::Equal, empty.cmp_by_words(&empty));
+        assert_eq!(Ordering::Equal, primes.cmp_by_words(&primes));
+    }
+
+    #[test]
+    fn cmp_by_words_significance() {
+        let low = Victim::from_iter([0, 1, 2, 3, 4, 5, 6, 7]);
+        let high = Victim::from_iter([8]);
+
+        assert_eq!(Ordering::Less, low.cmp_by_words(&high));
+        assert_eq!(Ordering::Greater, high.cmp_by_words(&low));
+
+        //  Differ only in the least significant bit.
+        let left = Victim::from_iter([0, 9]);
+        let right = Victim::from_iter([9]);
+
+        assert_eq!(Ordering::Greater, left.cmp_by_words(&right));
+        assert_eq!(Ordering::Less, right.cmp_by_words(&left));
+
+        //  Not the lexicographical order: [0, 5] < [1] lexicographically, yet 0b100001 > 0b10.
+        let left = Victim::from_iter([0, 5]);
+        let right = Victim::from_iter([1]);
+
+        assert_eq!(Ordering::Greater, left.cmp_by_words(&right));
+    }
+
+    #[test]
+    fn cmp_by_words_missing_chunks() {
+        let short = DynamicVictim::from_iter([3]);
+        let long = DynamicVictim::from_iter([3, 40]);
+
+        assert_eq!(Ordering::Less, short.cmp_by_words(&long));
+        assert_eq!(Ordering::Greater, long.cmp_by_words(&short));
+
+        let mut cleared = DynamicVictim::from_iter([3, 40]);
+        cleared.remove(40);
+
+        assert_eq!(Ordering::Equal, short.cmp_by_words(&cleared));
+        assert_eq!(Ordering::Equal, cleared.cmp_by_words(&short));
+    }
+} // mod index_chunked_set
@@ -1,9 +1,67 @@
 //! Unit tests for constructing sets.
 
+//  A buggy store, yielding the indexes in the given sequence and reporting the given length.
+struct Liar {
+    len: usize,
+    indexes: &'static [u8],
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: not upheld, on purpose.
+unsafe impl crate::index::IndexView for Liar {
+    type Index = u8;
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn contains(&self, index: Self::Index) -> bool {
+        self.indexes.contains(&index)
+    }
+}
+
+//  #   Safety
+//
+//  -   NoTheft: not upheld, on purpose.
+unsafe impl crate::index::IndexVault for Liar {}
+
+//  #   Safety
+//
+//  -   NoDuplicate, NoPhantom, NoTheft: not upheld, on purpose.
+unsafe impl crate::index::IndexForward for Liar {
+    fn first(&self) -> Option<Self::Index> {
+        self.indexes.first().copied()
+    }
+
+    fn next_after(&self, current: Self::Index) -> Option<Self::Index> {
+        let position = self.indexes.iter().position(|&index| index == current)?;
+
+        self.indexes.get(position + 1).copied()
+    }
+}
+
+//  #   Safety
+//
+//  -   Ordered: not upheld, on purpose.
+unsafe impl crate::index::IndexOrdered for Liar {}
+
 mod index_set {
+    use core::ops::Bound;
+
+    use alloc::collections::BTreeSet;
     use std::collections::HashSet;
 
-    use crate::set::IndexSet;
+    use crate::{
+        set::IndexSet,
+        vault::{BitmapStore, OutOfSpanError},
+    };
+
+    use super::Liar;
 
     type Victim = IndexSet<HashSet<u8>>;
 
@@ -15,6 +73,18 @@ mod index_set {
         assert_eq!(0, victim.len());
     }
 
+    #[test]
+    fn debug() {
+        let mut victim = IndexSet::<BitmapStore<u64>>::with_span((Bound::Included(0), Bound::Excluded(64)));
+
+        for index in [5, 1, 3, 2] {
+            victim.insert(index).unwrap();
+        }
+
+        assert_eq!("{1, 2, 3, 5}", format!("{victim:?}"));
+        assert_eq!("{}", format!("{:?}", IndexSet::<BitmapStore<u64>>::new()));
+    }
+
     #[test]
     fn default() {
         let victim = Victim::default();
@@ -42,12 +112,166 @@ mod index_set {
             assert_eq!(SOME.len(), victim.len());
         }
     }
+
+    #[test]
+    fn from_iter_copied() {
+        const SOME: [u8; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let victim = Victim::from_iter_copied(SOME.iter());
+
+        assert_eq!(SOME.len(), victim.len());
+        assert!(SOME.iter().all(|&index| victim.contains(index)));
+    }
+
+    #[test]
+    fn try_from_iter() {
+        const SOME: [u8; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let victim = Victim::try_from_iter(SOME).unwrap();
+
+        assert_eq!(SOME.len(), victim.len());
+
+        let empty = IndexSet::<BitmapStore<u64>>::try_from_iter([]).unwrap();
+
+        assert!(empty.is_empty());
+
+        //  A new `BitmapStore` has no capacity.
+        let result = IndexSet::<BitmapStore<u64>>::try_from_iter([1]);
+
+        assert_eq!(Some(OutOfSpanError), result.err());
+    }
+
+    #[test]
+    fn from_array() {
+        let victim = Victim::from([5, 1, 3, 1]);
+
+        assert_eq!(3, victim.len());
+        assert!([1, 3, 5].into_iter().all(|i| victim.contains(i)));
+
+        let empty = Victim::from([]);
+
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn try_from_slice() {
+        const SOME: [u8; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let victim = Victim::try_from(&SOME[..]).unwrap();
+
+        assert_eq!(SOME.len(), victim.len());
+
+        let result = IndexSet::<BitmapStore<u64>>::try_from(&[1, 2][..]);
+
+        assert_eq!(Some(OutOfSpanError), result.err());
+    }
+
+    #[test]
+    fn reserve_unsupported() {
+        let mut victim = Victim::new();
+
+        victim.reserve((Bound::Included(0), Bound::Excluded(100)));
+
+        assert!(victim.is_empty());
+        assert_eq!(Victim::span(), victim.capacity_span());
+    }
+
+    #[test]
+    fn capacity_span_fixed() {
+        let mut victim = IndexSet::<BitmapStore<u64>>::with_span((Bound::Included(0), Bound::Excluded(100)));
+
+        assert_eq!((Bound::Included(0), Bound::Included(127)), victim.capacity_span());
+
+        //  A `BitmapStore` never grows.
+        victim.reserve((Bound::Included(0), Bound::Excluded(1_000)));
+
+        assert_eq!((Bound::Included(0), Bound::Included(127)), victim.capacity_span());
+
+        let empty = IndexSet::<BitmapStore<u64>>::new();
+
+        assert_eq!((Bound::Included(0), Bound::Excluded(0)), empty.capacity_span());
+    }
+
+    #[test]
+    fn from_store_checked() {
+        let victim = IndexSet::from_store_checked(Liar {
+            len: 3,
+            indexes: &[3, 1, 2],
+        });
+
+        assert_eq!(3, victim.len());
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "duplicate index")]
+    fn from_store_checked_duplicate() {
+        IndexSet::from_store_checked(Liar {
+            len: 3,
+            indexes: &[1, 2, 1],
+        });
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "duplicate index")]
+    fn from_store_checked_duplicate_fewer() {
+        //  Fewer distinct indexes than `len()`, as the walk yields 1, 2, 2, 2, ...
+        IndexSet::from_store_checked(Liar {
+            len: 3,
+            indexes: &[1, 2, 2],
+        });
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "more indexes yielded than len()")]
+    fn from_store_checked_too_many() {
+        IndexSet::from_store_checked(Liar {
+            len: 2,
+            indexes: &[1, 2, 3],
+        });
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "fewer indexes yielded than len()")]
+    fn from_store_checked_too_few() {
+        IndexSet::from_store_checked(Liar {
+            len: 3,
+            indexes: &[1, 2],
+        });
+    }
+
+    #[test]
+    fn map_indexes() {
+        let victim: IndexSet<BTreeSet<u8>> = [1, 2, 3, 5, 7].into_iter().collect();
+
+        let shifted: IndexSet<HashSet<u32>> = victim.map_indexes(|i| u32::from(i) + 1_000);
+
+        assert_eq!(5, shifted.len());
+        assert!(shifted.contains(1_005));
+        assert!(!shifted.contains(5));
+
+        //  Indexes transformed into the same index are only inserted once.
+        let halved: IndexSet<BTreeSet<u8>> = victim.map_indexes(|i| i / 2);
+
+        assert!(halved.iter().eq([0, 1, 2, 3]));
+    }
 } // index_set
 
 mod index_ord_set {
+    use core::ops::Bound;
+
     use alloc::collections::BTreeSet;
 
-    use crate::set::IndexOrdSet;
+    use crate::{
+        chunk::{ArrayChunk, UnsignedChunk},
+        set::IndexOrdSet,
+        vault::{BitmapStore, DynamicChunkStore, OutOfSpanError},
+    };
+
+    use super::Liar;
 
     type Victim = IndexOrdSet<BTreeSet<u8>>;
 
@@ -59,6 +283,14 @@ mod index_ord_set {
         assert_eq!(0, victim.len());
     }
 
+    #[test]
+    fn debug() {
+        let victim: Victim = [5, 1, 3, 2].into_iter().collect();
+
+        assert_eq!("{1, 2, 3, 5}", format!("{victim:?}"));
+        assert_eq!("{}", format!("{:?}", Victim::new()));
+    }
+
     #[test]
     fn default() {
         let victim = Victim::new();
@@ -86,12 +318,168 @@ mod index_ord_set {
             assert_eq!(SOME.len(), victim.len());
         }
     }
+
+    #[test]
+    fn from_iter_copied() {
+        const SOME: [u8; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let victim = Victim::from_iter_copied(SOME.iter());
+
+        assert_eq!(SOME.len(), victim.len());
+        assert_eq!(Victim::from_iter(SOME), victim);
+    }
+
+    #[test]
+    fn from_sorted_iter_checked() {
+        const SOME: [u8; 8] = [1, 2, 3, 3, 5, 7, 11, 13];
+
+        let victim = Victim::from_sorted_iter_checked(SOME);
+
+        assert_eq!(SOME.len() - 1, victim.len());
+
+        for index in SOME {
+            assert!(victim.contains(index));
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "unsorted input")]
+    fn from_sorted_iter_checked_unsorted() {
+        const SOME: [u8; 4] = [1, 2, 5, 3];
+
+        Victim::from_sorted_iter_checked(SOME);
+    }
+
+    #[test]
+    fn try_from_iter() {
+        const SOME: [u8; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let victim = Victim::try_from_iter(SOME).unwrap();
+
+        assert_eq!(SOME.len(), victim.len());
+
+        let empty = IndexOrdSet::<BitmapStore<u64>>::try_from_iter([]).unwrap();
+
+        assert!(empty.is_empty());
+
+        //  A new `BitmapStore` has no capacity.
+        let result = IndexOrdSet::<BitmapStore<u64>>::try_from_iter([1]);
+
+        assert_eq!(Some(OutOfSpanError), result.err());
+    }
+
+    #[test]
+    fn from_array() {
+        let victim = Victim::from([5, 1, 3, 1]);
+
+        assert_eq!(3, victim.len());
+        assert!([1, 3, 5].into_iter().all(|i| victim.contains(i)));
+
+        let empty = Victim::from([]);
+
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn try_from_slice() {
+        const SOME: [u8; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let victim = Victim::try_from(&SOME[..]).unwrap();
+
+        assert_eq!(SOME.len(), victim.len());
+
+        let result = IndexOrdSet::<BitmapStore<u64>>::try_from(&[1, 2][..]);
+
+        assert_eq!(Some(OutOfSpanError), result.err());
+    }
+
+    #[test]
+    fn reserve() {
+        let mut victim = IndexOrdSet::<DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>>>::new();
+
+        assert_eq!((Bound::Included(0), Bound::Excluded(0)), victim.capacity_span());
+
+        victim.reserve((Bound::Included(0), Bound::Excluded(100)));
+
+        let Bound::Included(end) = victim.capacity_span().1 else {
+            panic!("{:?}", victim.capacity_span());
+        };
+
+        assert!(end >= 99, "{end}");
+        assert!(victim.is_empty());
+
+        //  Reserving a smaller span is a no-op.
+        victim.reserve((Bound::Included(0), Bound::Included(3)));
+
+        assert_eq!(Bound::Included(end), victim.capacity_span().1);
+
+        //  Reserving an unbounded span is a no-op.
+        victim.reserve((Bound::Included(0), Bound::Unbounded));
+
+        assert_eq!(Bound::Included(end), victim.capacity_span().1);
+    }
+
+    #[test]
+    fn from_store_checked() {
+        let victim = IndexOrdSet::from_store_checked(Liar {
+            len: 3,
+            indexes: &[1, 2, 3],
+        });
+
+        assert_eq!(3, victim.len());
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "unordered or duplicate index")]
+    fn from_store_checked_unordered() {
+        IndexOrdSet::from_store_checked(Liar {
+            len: 3,
+            indexes: &[1, 3, 2],
+        });
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "fewer indexes yielded than len()")]
+    fn from_store_checked_too_few() {
+        IndexOrdSet::from_store_checked(Liar {
+            len: 3,
+            indexes: &[1, 2],
+        });
+    }
+
+    #[test]
+    fn map_indexes() {
+        let victim: Victim = [1, 2, 3, 5, 7].into_iter().collect();
+
+        let shifted: IndexOrdSet<BTreeSet<u32>> = victim.map_indexes(|i| u32::from(i) + 1_000);
+
+        assert!(shifted.iter().eq([1_001, 1_002, 1_003, 1_005, 1_007]));
+
+        let halved: Victim = victim.map_indexes(|i| i / 2);
+
+        assert!(halved.iter().eq([0, 1, 2, 3]));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "order-breaking transform")]
+    fn map_indexes_unordered() {
+        let victim: Victim = [1, 2, 3].into_iter().collect();
+
+        let _: Victim = victim.map_indexes(|i| 10 - i);
+    }
 } // index_ord_set
 
 mod index_chunked_set {
+    use core::ops::Bound;
+
     use crate::{
         chunk::{ArrayChunk, UnsignedChunk},
         set::IndexChunkedSet,
+        vault::DynamicChunkStore,
     };
 
     type Victim = IndexChunkedSet<ArrayChunk<UnsignedChunk<u8>, 2>>;
@@ -104,6 +492,27 @@ mod index_chunked_set {
         assert_eq!(0, victim.len());
     }
 
+    #[test]
+    fn debug() {
+        let victim: IndexChunkedSet<DynamicChunkStore<ArrayChunk<UnsignedChunk<u64>, 2>>> =
+            [130, 1, 3, 2].into_iter().collect();
+
+        assert_eq!("{1, 2, 3, 130}", format!("{victim:?}"));
+        assert_eq!("{}", format!("{:?}", Victim::new()));
+    }
+
+    #[test]
+    fn with_capacity() {
+        let victim = IndexChunkedSet::<DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>>>::with_capacity(4);
+
+        assert!(victim.is_empty());
+        assert!(matches!(victim.capacity_span().1, Bound::Included(end) if end >= 63));
+
+        let victim = IndexChunkedSet::<DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>>>::with_capacity(0);
+
+        assert_eq!((Bound::Included(0), Bound::Excluded(0)), victim.capacity_span());
+    }
+
     #[test]
     fn default() {
         let victim = Victim::new();
@@ -131,4 +540,56 @@ mod index_chunked_set {
             assert_eq!(SOME.len(), victim.len());
         }
     }
+
+    #[test]
+    fn from_iter_copied() {
+        const SOME: [u16; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let victim = Victim::from_iter_copied(SOME.iter());
+
+        assert_eq!(SOME.len(), victim.len());
+        assert_eq!(Victim::from_iter(SOME), victim);
+    }
+
+    #[test]
+    fn reserve() {
+        let mut victim = IndexChunkedSet::<DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>>>::new();
+
+        victim.reserve((Bound::Included(0), Bound::Included(16)));
+
+        let Bound::Included(end) = victim.capacity_span().1 else {
+            panic!("{:?}", victim.capacity_span());
+        };
+
+        //  At least 2 chunks of 16 bits.
+        assert!(end >= 31 && end % 16 == 15, "{end}");
+
+        //  The fixed-size chunk cannot reserve, and reports its full span.
+        let fixed = Victim::new();
+
+        assert_eq!(Victim::span(), fixed.capacity_span());
+    }
+
+    #[test]
+    fn map_indexes() {
+        let victim: Victim = [1, 2, 3, 5, 7].into_iter().collect();
+
+        let shifted: IndexChunkedSet<DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>>> =
+            victim.map_indexes(|i| u64::from(i) + 1_000);
+
+        assert!(shifted.iter().eq([1_001, 1_002, 1_003, 1_005, 1_007]));
+
+        let halved: Victim = victim.map_indexes(|i| i / 2);
+
+        assert!(halved.iter().eq([0, 1, 2, 3]));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "order-breaking transform")]
+    fn map_indexes_unordered() {
+        let victim: Victim = [1, 2, 3].into_iter().collect();
+
+        let _: Victim = victim.map_indexes(|i| 10 - i);
+    }
 } // index_chunked_set
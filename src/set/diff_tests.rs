@@ -0,0 +1,134 @@
+//! Unit tests for diff operations.
+
+mod index_set {
+    use alloc::collections::BTreeSet;
+
+    use crate::set::IndexSet;
+
+    type Victim = IndexSet<BTreeSet<u8>>;
+
+    #[test]
+    fn diff_empty() {
+        let empty = Victim::new();
+        let primes = Victim::from_iter([1, 2, 3, 5]);
+
+        let changes = empty.diff(&empty);
+
+        assert!(changes.to_insert.is_empty());
+        assert!(changes.to_remove.is_empty());
+
+        let changes = empty.diff(&primes);
+
+        assert_eq!(vec![1, 2, 3, 5], changes.to_insert);
+        assert!(changes.to_remove.is_empty());
+
+        let changes = primes.diff(&empty);
+
+        assert!(changes.to_insert.is_empty());
+        assert_eq!(vec![1, 2, 3, 5], changes.to_remove);
+    }
+
+    #[test]
+    fn diff_apply() {
+        let mut victim = Victim::from_iter([1, 2, 3, 5]);
+        let target = Victim::from_iter([0, 2, 4, 5]);
+
+        let changes = victim.diff(&target);
+
+        assert_eq!(vec![0, 4], changes.to_insert);
+        assert_eq!(vec![1, 3], changes.to_remove);
+
+        victim.apply_changeset(&changes).unwrap();
+
+        assert_eq!(target.iter().collect::<Vec<_>>(), victim.iter().collect::<Vec<_>>());
+    }
+} // mod index_set
+
+mod index_ord_set {
+    use alloc::collections::BTreeSet;
+
+    use crate::set::IndexOrdSet;
+
+    type Victim = IndexOrdSet<BTreeSet<u8>>;
+
+    #[test]
+    fn diff_empty() {
+        let empty = Victim::new();
+        let primes = Victim::from_iter([1, 2, 3, 5]);
+
+        let changes = empty.diff(&empty);
+
+        assert!(changes.to_insert.is_empty());
+        assert!(changes.to_remove.is_empty());
+
+        let changes = empty.diff(&primes);
+
+        assert_eq!(vec![1, 2, 3, 5], changes.to_insert);
+        assert!(changes.to_remove.is_empty());
+
+        let changes = primes.diff(&empty);
+
+        assert!(changes.to_insert.is_empty());
+        assert_eq!(vec![1, 2, 3, 5], changes.to_remove);
+    }
+
+    #[test]
+    fn diff_apply() {
+        let mut victim = Victim::from_iter([1, 2, 3, 5]);
+        let target = Victim::from_iter([0, 2, 4, 5]);
+
+        let changes = victim.diff(&target);
+
+        assert_eq!(vec![0, 4], changes.to_insert);
+        assert_eq!(vec![1, 3], changes.to_remove);
+
+        victim.apply_changeset(&changes).unwrap();
+
+        assert_eq!(target.iter().collect::<Vec<_>>(), victim.iter().collect::<Vec<_>>());
+    }
+} // mod index_ord_set
+
+mod index_chunked_set {
+    use crate::{
+        chunk::{ArrayChunk, UnsignedChunk},
+        set::IndexChunkedSet,
+    };
+
+    type Victim = IndexChunkedSet<ArrayChunk<UnsignedChunk<u8>, 2>>;
+
+    #[test]
+    fn diff_empty() {
+        let empty = Victim::new();
+        let primes = Victim::from_iter([1, 2, 3, 5]);
+
+        let changes = empty.diff(&empty);
+
+        assert!(changes.to_insert.is_empty());
+        assert!(changes.to_remove.is_empty());
+
+        let changes = empty.diff(&primes);
+
+        assert_eq!(vec![1, 2, 3, 5], changes.to_insert);
+        assert!(changes.to_remove.is_empty());
+
+        let changes = primes.diff(&empty);
+
+        assert!(changes.to_insert.is_empty());
+        assert_eq!(vec![1, 2, 3, 5], changes.to_remove);
+    }
+
+    #[test]
+    fn diff_apply() {
+        let mut victim = Victim::from_iter([1, 2, 3, 5, 11]);
+        let target = Victim::from_iter([0, 2, 4, 5, 13]);
+
+        let changes = victim.diff(&target);
+
+        assert_eq!(vec![0, 4, 13], changes.to_insert);
+        assert_eq!(vec![1, 3, 11], changes.to_remove);
+
+        victim.apply_changeset(&changes).unwrap();
+
+        assert_eq!(target.iter().collect::<Vec<_>>(), victim.iter().collect::<Vec<_>>());
+    }
+} // mod index_chunked_set
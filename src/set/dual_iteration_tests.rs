@@ -3,7 +3,11 @@
 mod index_set {
     use std::collections::BTreeSet;
 
-    use crate::set::IndexSet;
+    use crate::{
+        chunk::{ArrayChunk, UnsignedChunk},
+        set::IndexSet,
+        vault::{BitmapStore, DynamicChunkStore, OutOfSpanError},
+    };
 
     use super::helper;
 
@@ -17,6 +21,10 @@ mod index_set {
     const EVEN_PRIMES: [u8; 1] = [2];
     const ODD_PRIMES: [u8; 3] = [1, 3, 5];
 
+    const DENSE: [u8; 11] = [1, 2, 3, 4, 5, 6, 7, 8, 40, 41, 42];
+
+    const FIXTURES: [&[u8]; 5] = [&EMPTY, &PRIMES, &EVENS, &ODDS, &DENSE];
+
     #[test]
     fn difference() {
         let empty = Victim::from_iter(EMPTY);
@@ -61,6 +69,44 @@ mod index_set {
         helper::assert_iterator(odds.symmetric_difference(&odds), EMPTY);
     }
 
+    #[test]
+    fn symmetric_difference_size_hint() {
+        let empty = Victim::from_iter(EMPTY);
+        let primes = Victim::from_iter(PRIMES);
+        let evens = Victim::from_iter(EVENS);
+
+        let mut victim = primes.symmetric_difference(&empty);
+
+        assert_eq!((4, Some(4)), victim.size_hint());
+
+        victim.next();
+
+        assert_eq!((3, Some(3)), victim.size_hint());
+
+        assert_eq!((4, Some(4)), empty.symmetric_difference(&evens).size_hint());
+        assert_eq!((0, Some(8)), primes.symmetric_difference(&evens).size_hint());
+    }
+
+    #[test]
+    fn symmetric_difference_into() {
+        let empty = Victim::from_iter(EMPTY);
+        let primes = Victim::from_iter(PRIMES);
+        let evens = Victim::from_iter(EVENS);
+        let odds = Victim::from_iter(ODDS);
+
+        helper::assert_iterator(empty.clone().symmetric_difference_into(&primes).iter(), PRIMES);
+        helper::assert_iterator(primes.clone().symmetric_difference_into(&empty).iter(), PRIMES);
+
+        helper::assert_iterator(
+            primes.clone().symmetric_difference_into(&evens).iter(),
+            [1, 3, 4, 5, 6, 8],
+        );
+        helper::assert_iterator(evens.clone().symmetric_difference_into(&odds).iter(), 1..=8);
+
+        helper::assert_iterator(primes.clone().symmetric_difference_into(&primes).iter(), EMPTY);
+        helper::assert_iterator(odds.clone().symmetric_difference_into(&odds).iter(), EMPTY);
+    }
+
     #[test]
     fn intersection() {
         let empty = Victim::from_iter(EMPTY);
@@ -118,12 +164,143 @@ mod index_set {
         helper::assert_iterator(evens.union(&odds), [2, 4, 6, 8, 1, 3, 5, 7]);
         helper::assert_iterator(odds.union(&evens), [1, 3, 5, 7, 2, 4, 6, 8]);
     }
+
+    #[test]
+    fn overlap_len() {
+        helper::for_each_pair(&FIXTURES, |left: Victim, right: Victim| {
+            let expected = left.intersection(&right).fold(0, |acc, _| acc + 1);
+
+            assert_eq!(expected, left.overlap_len(&right), "{left:?} {right:?}");
+            assert_eq!(expected, right.overlap_len(&left), "{left:?} {right:?}");
+        });
+    }
+
+    #[test]
+    fn symmetric_difference_len() {
+        helper::for_each_pair(&FIXTURES, |left: Victim, right: Victim| {
+            let expected = left.symmetric_difference(&right).fold(0, |acc, _| acc + 1);
+
+            assert_eq!(expected, left.symmetric_difference_len(&right), "{left:?} {right:?}");
+            assert_eq!(expected, right.symmetric_difference_len(&left), "{left:?} {right:?}");
+        });
+    }
+
+    #[test]
+    fn similarity() {
+        let empty = Victim::from_iter(EMPTY);
+        let primes = Victim::from_iter(PRIMES);
+        let evens = Victim::from_iter(EVENS);
+        let odds = Victim::from_iter(ODDS);
+
+        //  Identical.
+        assert_eq!(1.0, empty.jaccard(&empty));
+        assert_eq!(1.0, empty.dice(&empty));
+        assert_eq!(1.0, primes.jaccard(&primes));
+        assert_eq!(1.0, primes.dice(&primes));
+
+        //  Disjoint.
+        assert_eq!(0.0, evens.jaccard(&odds));
+        assert_eq!(0.0, evens.dice(&odds));
+        assert_eq!(0.0, empty.jaccard(&primes));
+        assert_eq!(0.0, primes.dice(&empty));
+
+        //  Partially overlapping: {1, 3, 5} in common, out of {1, 2, 3, 5, 7}.
+        assert_eq!(3.0 / 5.0, primes.jaccard(&odds));
+        assert_eq!(3.0 / 5.0, odds.jaccard(&primes));
+        assert_eq!(6.0 / 8.0, primes.dice(&odds));
+        assert_eq!(6.0 / 8.0, odds.dice(&primes));
+    }
+
+    #[test]
+    fn count() {
+        //  Compares `count` to the number of indexes yielded, after advancing `skip` times.
+        #[track_caller]
+        fn assert_count<I>(mut victim: I, mut expected: I, skip: usize)
+        where
+            I: Iterator<Item = u8>,
+        {
+            for _ in 0..skip {
+                victim.next();
+                expected.next();
+            }
+
+            assert_eq!(expected.fold(0, |acc, _| acc + 1), victim.count(), "{skip}");
+        }
+
+        helper::for_each_pair(&FIXTURES, |left: Victim, right: Victim| {
+            for skip in 0..4 {
+                assert_count(left.difference(&right), left.difference(&right), skip);
+                assert_count(
+                    left.symmetric_difference(&right),
+                    left.symmetric_difference(&right),
+                    skip,
+                );
+                assert_count(left.intersection(&right), left.intersection(&right), skip);
+                assert_count(left.union(&right), left.union(&right), skip);
+            }
+        });
+    }
+
+    #[test]
+    fn collect() {
+        type Collected = IndexSet<UnsignedChunk<u64>>;
+
+        let primes = Victim::from_iter(PRIMES);
+        let evens = Victim::from_iter(EVENS);
+
+        let difference: Collected = primes.difference_collect(&evens);
+        let intersection: Collected = primes.intersection_collect(&evens);
+        let union: Collected = primes.union_collect(&evens);
+        let symmetric_difference: Collected = primes.symmetric_difference_collect(&evens);
+
+        helper::assert_iterator(difference.iter(), ODD_PRIMES);
+        helper::assert_iterator(intersection.iter(), EVEN_PRIMES);
+        helper::assert_iterator(union.iter(), [1, 2, 3, 4, 5, 6, 8]);
+        helper::assert_iterator(symmetric_difference.iter(), [1, 3, 4, 5, 6, 8]);
+    }
+
+    #[test]
+    fn try_collect() {
+        let primes = Victim::from_iter(PRIMES);
+        let evens = Victim::from_iter(EVENS);
+
+        let union: IndexSet<UnsignedChunk<u64>> = primes.try_union_collect(&evens).unwrap();
+
+        helper::assert_iterator(union.iter(), [1, 2, 3, 4, 5, 6, 8]);
+
+        //  A default `BitmapStore` has an empty span, hence any insertion fails.
+        let primes = IndexSet::<BTreeSet<u64>>::from_iter([1, 2, 3, 5]);
+        let evens = IndexSet::<BTreeSet<u64>>::from_iter([2, 4, 6, 8]);
+        let odds = IndexSet::<BTreeSet<u64>>::from_iter([1, 3, 5, 7]);
+
+        let union = primes.try_union_collect::<_, BitmapStore<u64>>(&evens);
+        let intersection = evens.try_intersection_collect::<_, BitmapStore<u64>>(&odds);
+
+        assert_eq!(Some(OutOfSpanError), union.err());
+        assert!(intersection.is_ok_and(|intersection| intersection.is_empty()));
+    }
+
+    #[test]
+    fn collect_into_chunked() {
+        type Collected = IndexSet<DynamicChunkStore<ArrayChunk<UnsignedChunk<u64>, 2>, u32>>;
+
+        let left = IndexSet::<BTreeSet<u32>>::from_iter([1, 2, 3, 500, 70_000]);
+        let right = IndexSet::<BTreeSet<u32>>::from_iter([2, 4, 500, 80_000]);
+
+        let intersection: Collected = left.intersection_collect(&right);
+        let symmetric_difference: Collected = left.symmetric_difference_collect(&right);
+
+        assert!(intersection.iter().eq([2, 500]));
+        assert!(symmetric_difference.iter().eq([1, 3, 4, 70_000, 80_000]));
+    }
 } // mod index_set
 
 mod index_ord_set {
+    use core::iter::FusedIterator;
+
     use alloc::collections::BTreeSet;
 
-    use crate::set::IndexOrdSet;
+    use crate::{chunk::UnsignedChunk, set::IndexOrdSet};
 
     use super::helper;
 
@@ -137,6 +314,10 @@ mod index_ord_set {
     const EVEN_PRIMES: [u8; 1] = [2];
     const ODD_PRIMES: [u8; 3] = [1, 3, 5];
 
+    const DENSE: [u8; 11] = [1, 2, 3, 4, 5, 6, 7, 8, 40, 41, 42];
+
+    const FIXTURES: [&[u8]; 5] = [&EMPTY, &PRIMES, &EVENS, &ODDS, &DENSE];
+
     #[test]
     fn difference() {
         let empty = Victim::from_iter(EMPTY);
@@ -238,12 +419,144 @@ mod index_ord_set {
         helper::assert_iterator(evens.union(&odds), [1, 2, 3, 4, 5, 6, 7, 8]);
         helper::assert_iterator(odds.union(&evens), [1, 2, 3, 4, 5, 6, 7, 8]);
     }
+
+    #[test]
+    fn nth() {
+        //  The default implementation, advancing one index at a time.
+        fn nth_default<I>(iter: &mut I, n: usize) -> Option<I::Item>
+        where
+            I: Iterator,
+        {
+            for _ in 0..n {
+                iter.next()?;
+            }
+
+            iter.next()
+        }
+
+        #[track_caller]
+        fn assert_nth<I>(mut victim: I, mut expected: I, n: usize)
+        where
+            I: Iterator<Item = u8> + FusedIterator,
+        {
+            assert_eq!(nth_default(&mut expected, n), victim.nth(n), "{n}");
+            assert_eq!(nth_default(&mut expected, n), victim.nth(n), "{n}");
+            assert!(expected.eq(victim), "{n}");
+        }
+
+        helper::for_each_pair(&FIXTURES, |left: Victim, right: Victim| {
+            for n in 0..12 {
+                assert_nth(left.symmetric_difference(&right), left.symmetric_difference(&right), n);
+                assert_nth(left.intersection(&right), left.intersection(&right), n);
+                assert_nth(left.union(&right), left.union(&right), n);
+            }
+        });
+    }
+
+    #[test]
+    fn overlap_len() {
+        helper::for_each_pair(&FIXTURES, |left: Victim, right: Victim| {
+            let expected = left.intersection(&right).fold(0, |acc, _| acc + 1);
+
+            assert_eq!(expected, left.overlap_len(&right), "{left:?} {right:?}");
+            assert_eq!(expected, right.overlap_len(&left), "{left:?} {right:?}");
+        });
+    }
+
+    #[test]
+    fn symmetric_difference_len() {
+        helper::for_each_pair(&FIXTURES, |left: Victim, right: Victim| {
+            let expected = left.symmetric_difference(&right).fold(0, |acc, _| acc + 1);
+
+            assert_eq!(expected, left.symmetric_difference_len(&right), "{left:?} {right:?}");
+            assert_eq!(expected, right.symmetric_difference_len(&left), "{left:?} {right:?}");
+        });
+    }
+
+    #[test]
+    fn similarity() {
+        let empty = Victim::from_iter(EMPTY);
+        let primes = Victim::from_iter(PRIMES);
+        let evens = Victim::from_iter(EVENS);
+        let odds = Victim::from_iter(ODDS);
+
+        //  Identical.
+        assert_eq!(1.0, empty.jaccard(&empty));
+        assert_eq!(1.0, empty.dice(&empty));
+        assert_eq!(1.0, primes.jaccard(&primes));
+        assert_eq!(1.0, primes.dice(&primes));
+
+        //  Disjoint.
+        assert_eq!(0.0, evens.jaccard(&odds));
+        assert_eq!(0.0, evens.dice(&odds));
+        assert_eq!(0.0, empty.jaccard(&primes));
+        assert_eq!(0.0, primes.dice(&empty));
+
+        //  Partially overlapping: {1, 3, 5} in common, out of {1, 2, 3, 5, 7}.
+        assert_eq!(3.0 / 5.0, primes.jaccard(&odds));
+        assert_eq!(3.0 / 5.0, odds.jaccard(&primes));
+        assert_eq!(6.0 / 8.0, primes.dice(&odds));
+        assert_eq!(6.0 / 8.0, odds.dice(&primes));
+    }
+
+    #[test]
+    fn count() {
+        //  Compares `count` to the number of indexes yielded, after advancing `skip` times.
+        #[track_caller]
+        fn assert_count<I>(mut victim: I, mut expected: I, skip: usize)
+        where
+            I: Iterator<Item = u8>,
+        {
+            for _ in 0..skip {
+                victim.next();
+                expected.next();
+            }
+
+            assert_eq!(expected.fold(0, |acc, _| acc + 1), victim.count(), "{skip}");
+        }
+
+        helper::for_each_pair(&FIXTURES, |left: Victim, right: Victim| {
+            for skip in 0..4 {
+                assert_count(left.difference(&right), left.difference(&right), skip);
+                assert_count(
+                    left.symmetric_difference(&right),
+                    left.symmetric_difference(&right),
+                    skip,
+                );
+                assert_count(left.intersection(&right), left.intersection(&right), skip);
+                assert_count(left.union(&right), left.union(&right), skip);
+            }
+        });
+    }
+
+    #[test]
+    fn collect() {
+        type Collected = IndexOrdSet<UnsignedChunk<u64>>;
+
+        let primes = Victim::from_iter(PRIMES);
+        let evens = Victim::from_iter(EVENS);
+
+        let difference: Collected = primes.difference_collect(&evens);
+        let intersection: Collected = primes.intersection_collect(&evens);
+        let union: Collected = primes.union_collect(&evens);
+        let symmetric_difference: Collected = primes.symmetric_difference_collect(&evens);
+
+        helper::assert_iterator(difference.iter(), ODD_PRIMES);
+        helper::assert_iterator(intersection.iter(), EVEN_PRIMES);
+        helper::assert_iterator(union.iter(), [1, 2, 3, 4, 5, 6, 8]);
+        helper::assert_iterator(symmetric_difference.iter(), [1, 3, 4, 5, 6, 8]);
+
+        let union: IndexOrdSet<UnsignedChunk<u16>> = primes.try_union_collect(&evens).unwrap();
+
+        helper::assert_iterator(union.iter(), [1, 2, 3, 4, 5, 6, 8]);
+    }
 } // mod index_ord_set
 
 mod index_chunked_set {
     use crate::{
         chunk::{ArrayChunk, UnsignedChunk},
         set::IndexChunkedSet,
+        vault::DynamicChunkStore,
     };
 
     use super::helper;
@@ -258,6 +571,10 @@ mod index_chunked_set {
     const EVEN_PRIMES: [u16; 1] = [2];
     const ODD_PRIMES: [u16; 3] = [1, 3, 5];
 
+    const DENSE: [u16; 11] = [1, 2, 3, 4, 5, 6, 7, 8, 10, 11, 15];
+
+    const FIXTURES: [&[u16]; 5] = [&EMPTY, &PRIMES, &EVENS, &ODDS, &DENSE];
+
     #[test]
     fn difference() {
         let empty = Victim::from_iter(EMPTY);
@@ -330,6 +647,24 @@ mod index_chunked_set {
         helper::assert_iterator(odds.intersection(&evens), EMPTY);
     }
 
+    #[test]
+    fn clone_union() {
+        let primes = Victim::from_iter(PRIMES);
+        let evens = Victim::from_iter(EVENS);
+
+        let mut union = primes.union(&evens);
+
+        assert_eq!(Some(1), union.next());
+        assert_eq!(Some(2), union.next());
+
+        let clone = union.clone();
+
+        assert_eq!(Some(3), union.next());
+
+        helper::assert_iterator(clone, [3, 4, 5, 6, 8]);
+        helper::assert_iterator(union, [4, 5, 6, 8]);
+    }
+
     #[test]
     fn union() {
         let empty = Victim::from_iter(EMPTY);
@@ -359,11 +694,170 @@ mod index_chunked_set {
         helper::assert_iterator(evens.union(&odds), [1, 2, 3, 4, 5, 6, 7, 8]);
         helper::assert_iterator(odds.union(&evens), [1, 2, 3, 4, 5, 6, 7, 8]);
     }
+    #[test]
+    fn mismatched_chunks() {
+        type DynamicVictim = IndexChunkedSet<DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>>>;
+
+        let short = DynamicVictim::from_iter([1, 2, 3, 5]);
+        let long = DynamicVictim::from_iter([2, 4, 40, 70]);
+
+        helper::assert_iterator(short.difference(&long), [1, 3, 5]);
+        helper::assert_iterator(long.difference(&short), [4, 40, 70]);
+
+        helper::assert_iterator(short.symmetric_difference(&long), [1, 3, 4, 5, 40, 70]);
+        helper::assert_iterator(long.symmetric_difference(&short), [1, 3, 4, 5, 40, 70]);
+
+        helper::assert_iterator(short.intersection(&long), [2]);
+        helper::assert_iterator(long.intersection(&short), [2]);
+
+        helper::assert_iterator(short.union(&long), [1, 2, 3, 4, 5, 40, 70]);
+        helper::assert_iterator(long.union(&short), [1, 2, 3, 4, 5, 40, 70]);
+    }
+
+    #[test]
+    fn overlap_len() {
+        helper::for_each_pair(&FIXTURES, |left: Victim, right: Victim| {
+            let expected = left.intersection(&right).fold(0, |acc, _| acc + 1);
+
+            assert_eq!(expected, left.overlap_len(&right), "{left:?} {right:?}");
+            assert_eq!(expected, right.overlap_len(&left), "{left:?} {right:?}");
+        });
+    }
+
+    #[test]
+    fn symmetric_difference_len() {
+        helper::for_each_pair(&FIXTURES, |left: Victim, right: Victim| {
+            let expected = left.symmetric_difference(&right).fold(0, |acc, _| acc + 1);
+
+            assert_eq!(expected, left.symmetric_difference_len(&right), "{left:?} {right:?}");
+            assert_eq!(expected, right.symmetric_difference_len(&left), "{left:?} {right:?}");
+        });
+    }
+
+    #[test]
+    fn similarity() {
+        let empty = Victim::from_iter(EMPTY);
+        let primes = Victim::from_iter(PRIMES);
+        let evens = Victim::from_iter(EVENS);
+        let odds = Victim::from_iter(ODDS);
+
+        //  Identical.
+        assert_eq!(1.0, empty.jaccard(&empty));
+        assert_eq!(1.0, empty.dice(&empty));
+        assert_eq!(1.0, primes.jaccard(&primes));
+        assert_eq!(1.0, primes.dice(&primes));
+
+        //  Disjoint.
+        assert_eq!(0.0, evens.jaccard(&odds));
+        assert_eq!(0.0, evens.dice(&odds));
+        assert_eq!(0.0, empty.jaccard(&primes));
+        assert_eq!(0.0, primes.dice(&empty));
+
+        //  Partially overlapping: {1, 3, 5} in common, out of {1, 2, 3, 5, 7}.
+        assert_eq!(3.0 / 5.0, primes.jaccard(&odds));
+        assert_eq!(3.0 / 5.0, odds.jaccard(&primes));
+        assert_eq!(6.0 / 8.0, primes.dice(&odds));
+        assert_eq!(6.0 / 8.0, odds.dice(&primes));
+    }
+
+    #[test]
+    fn count() {
+        //  Compares `count` to the number of indexes yielded, after advancing `skip` times.
+        #[track_caller]
+        fn assert_count<I>(mut victim: I, mut expected: I, skip: usize)
+        where
+            I: Iterator<Item = u16>,
+        {
+            for _ in 0..skip {
+                victim.next();
+                expected.next();
+            }
+
+            assert_eq!(expected.fold(0, |acc, _| acc + 1), victim.count(), "{skip}");
+        }
+
+        helper::for_each_pair(&FIXTURES, |left: Victim, right: Victim| {
+            for skip in 0..4 {
+                assert_count(left.difference(&right), left.difference(&right), skip);
+                assert_count(
+                    left.symmetric_difference(&right),
+                    left.symmetric_difference(&right),
+                    skip,
+                );
+                assert_count(left.intersection(&right), left.intersection(&right), skip);
+                assert_count(left.union(&right), left.union(&right), skip);
+            }
+        });
+    }
+
+    #[test]
+    fn collect() {
+        type DynamicVictim = IndexChunkedSet<DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>>>;
+
+        let short = DynamicVictim::from_iter([1, 2, 3, 5, 40]);
+        let long = DynamicVictim::from_iter([2, 4, 40, 70]);
+
+        let difference: DynamicVictim = short.difference_collect(&long);
+        let intersection: DynamicVictim = short.intersection_collect(&long);
+        let union: DynamicVictim = short.union_collect(&long);
+        let symmetric_difference: DynamicVictim = short.symmetric_difference_collect(&long);
+
+        helper::assert_iterator(difference.iter(), [1, 3, 5]);
+        helper::assert_iterator(intersection.iter(), [2, 40]);
+        helper::assert_iterator(union.iter(), [1, 2, 3, 4, 5, 40, 70]);
+        helper::assert_iterator(symmetric_difference.iter(), [1, 3, 4, 5, 70]);
+
+        assert_eq!(3, difference.len());
+        assert_eq!(7, union.len());
+        assert_eq!(5, symmetric_difference.len());
+
+        let union: Victim = Victim::from_iter(PRIMES)
+            .try_union_collect(&Victim::from_iter(EVENS))
+            .unwrap();
+
+        helper::assert_iterator(union.iter(), [1, 2, 3, 4, 5, 6, 8]);
+    }
+
+    #[test]
+    fn difference_collect() {
+        type DynamicVictim = IndexChunkedSet<DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>>>;
+
+        helper::for_each_pair(&FIXTURES, |left: Victim, right: Victim| {
+            let expected: Victim = left.difference(&right).collect();
+            let victim: Victim = left.difference_collect(&right);
+
+            assert_eq!(expected, victim, "{left:?} - {right:?}");
+
+            let (left, right) = (
+                DynamicVictim::from_iter(left.iter().map(u64::from)),
+                DynamicVictim::from_iter(right.iter().map(|i| u64::from(i) * 20)),
+            );
+
+            let expected: DynamicVictim = left.difference(&right).collect();
+            let victim: DynamicVictim = left.difference_collect(&right);
+
+            assert!(expected.iter().eq(victim.iter()), "{left:?} - {right:?}");
+        });
+    }
 } // mod index_chunked_set
 
 mod helper {
     use core::fmt;
 
+    //  Calls `f` with every pair of sets built from `fixtures`, including each set paired with itself.
+    pub(super) fn for_each_pair<V, I, F>(fixtures: &[&[I]], mut f: F)
+    where
+        V: FromIterator<I>,
+        I: Copy,
+        F: FnMut(V, V),
+    {
+        for left in fixtures {
+            for right in fixtures {
+                f(left.iter().copied().collect(), right.iter().copied().collect());
+            }
+        }
+    }
+
     #[track_caller]
     pub(super) fn assert_iterator<I, E>(mut victim: I, expected: E)
     where
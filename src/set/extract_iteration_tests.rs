@@ -1,5 +1,94 @@
 //! Unit tests for drain/extract_if/retain operations.
 
+use std::vec::Vec;
+
+//  A store which only knows the index after an index it contains, locking in that `retain` and co. fetch the next index
+//  prior to removing the current one.
+//
+//  Not `IndexOrdered`, as it does not uphold Seek.
+#[derive(Default)]
+struct Forgetful(Vec<u8>);
+
+//  #   Safety
+//
+//  -   NoPhantom: only indexes inserted, and not removed since, are in the vector.
+unsafe impl crate::index::IndexView for Forgetful {
+    type Index = u8;
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn contains(&self, index: Self::Index) -> bool {
+        self.0.binary_search(&index).is_ok()
+    }
+}
+
+impl crate::index::IndexCollection for Forgetful {
+    fn span() -> (core::ops::Bound<Self::Index>, core::ops::Bound<Self::Index>) {
+        (core::ops::Bound::Unbounded, core::ops::Bound::Unbounded)
+    }
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_span(_range: (core::ops::Bound<Self::Index>, core::ops::Bound<Self::Index>)) -> Self {
+        Self::default()
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: only indexes inserted, and not removed since, are in the vector.
+unsafe impl crate::index::IndexStore for Forgetful {
+    type InsertionError = crate::Never;
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn insert(&mut self, index: Self::Index) -> Result<bool, Self::InsertionError> {
+        let Err(position) = self.0.binary_search(&index) else {
+            return Ok(false);
+        };
+
+        self.0.insert(position, index);
+
+        Ok(true)
+    }
+
+    fn remove(&mut self, index: Self::Index) -> bool {
+        let Ok(position) = self.0.binary_search(&index) else {
+            return false;
+        };
+
+        self.0.remove(position);
+
+        true
+    }
+}
+
+//  #   Safety
+//
+//  -   NoDuplicate, NoPhantom, NoTheft: the vector is sorted, and free of duplicates.
+unsafe impl crate::index::IndexForward for Forgetful {
+    fn first(&self) -> Option<Self::Index> {
+        self.0.first().copied()
+    }
+
+    //  Returns `None` if `current` is not contained.
+    fn next_after(&self, current: Self::Index) -> Option<Self::Index> {
+        let position = self.0.binary_search(&current).ok()?;
+
+        self.0.get(position + 1).copied()
+    }
+}
+
 mod index_set {
     use std::collections::BTreeSet;
 
@@ -22,6 +111,45 @@ mod index_set {
         helper::assert_exact_iterator(victim.iter(), EMPTY);
     }
 
+    #[test]
+    fn drain_dropped() {
+        let mut victim = Victim::from_iter(PRIMES);
+
+        let _ = victim.drain();
+
+        helper::assert_exact_iterator(victim.iter(), EMPTY);
+    }
+
+    #[test]
+    fn extract_if_dropped() {
+        let mut victim = Victim::from_iter(PRIMES);
+
+        let _ = victim.extract_if(|_| true);
+
+        helper::assert_exact_iterator(victim.iter(), PRIMES);
+    }
+
+    #[test]
+    fn extract_if_size_hint() {
+        let mut victim = Victim::from_iter(PRIMES);
+        let mut extract = victim.extract_if(|i: u8| i != 2);
+
+        assert_eq!((0, Some(4)), extract.size_hint());
+
+        assert_eq!(Some(1), extract.next());
+        assert_eq!((0, Some(3)), extract.size_hint());
+
+        //  Passes over 2, then extracts 3.
+        assert_eq!(Some(3), extract.next());
+        assert_eq!((0, Some(1)), extract.size_hint());
+
+        assert_eq!(Some(5), extract.next());
+        assert_eq!((0, Some(0)), extract.size_hint());
+
+        assert_eq!(None, extract.next());
+        assert_eq!((0, Some(0)), extract.size_hint());
+    }
+
     #[test]
     fn extract_if() {
         {
@@ -87,6 +215,109 @@ mod index_set {
             helper::assert_exact_iterator(victim.iter(), EVEN_PRIMES);
         }
     }
+
+    #[test]
+    fn cursor_front() {
+        {
+            let mut victim = Victim::from_iter(EMPTY);
+
+            let mut cursor = victim.cursor_front();
+
+            assert_eq!(None, cursor.current());
+            assert_eq!(None, cursor.remove_current());
+        }
+
+        {
+            let mut victim = Victim::from_iter(PRIMES);
+
+            let mut cursor = victim.cursor_front();
+
+            assert_eq!(Some(1), cursor.current());
+
+            cursor.move_next();
+
+            assert_eq!(Some(2), cursor.remove_current());
+            assert_eq!(Some(3), cursor.current());
+
+            cursor.move_prev();
+
+            assert_eq!(Some(1), cursor.current());
+
+            cursor.move_next();
+            cursor.move_next();
+
+            assert_eq!(Some(5), cursor.remove_current());
+            assert_eq!(None, cursor.current());
+
+            helper::assert_exact_iterator(victim.iter(), [1, 3]);
+        }
+
+        {
+            let mut victim = Victim::from_iter(PRIMES);
+
+            let mut cursor = victim.cursor_front();
+            let mut sum = 0;
+
+            while let Some(index) = cursor.current() {
+                sum += index;
+
+                if sum > 3 {
+                    cursor.remove_current();
+                } else {
+                    cursor.move_next();
+                }
+            }
+
+            helper::assert_exact_iterator(victim.iter(), [1, 2]);
+        }
+    }
+
+    #[test]
+    fn drain_partition() {
+        const INDEXES: [u8; 6] = [1, 2, 9, 11, 12, 15];
+
+        let original = Victim::from_iter(INDEXES);
+
+        let mut victim = original.clone();
+        let removed = victim.drain_partition(|i| i % 3 == 0);
+
+        helper::assert_exact_iterator(victim.iter(), [1, 2, 11]);
+        helper::assert_exact_iterator(removed.iter(), [9, 12, 15]);
+
+        let union: Victim = removed.union(&victim).collect();
+
+        assert_eq!(original, union);
+
+        let mut victim = original.clone();
+
+        assert!(victim.drain_partition(|_| false).is_empty());
+        assert_eq!(original, victim);
+
+        let removed = victim.drain_partition(|_| true);
+
+        assert!(victim.is_empty());
+        assert_eq!(original, removed);
+    }
+
+    #[test]
+    fn forgetful() {
+        let mut victim = IndexSet::<super::Forgetful>::from_iter(PRIMES);
+
+        victim.retain(|i| !i.is_multiple_of(2));
+
+        helper::assert_exact_iterator(victim.iter(), ODD_PRIMES);
+
+        let mut victim = IndexSet::<super::Forgetful>::from_iter(PRIMES);
+
+        helper::assert_iterator(victim.extract_if(|_| true), PRIMES);
+        helper::assert_exact_iterator(victim.iter(), EMPTY);
+
+        let mut victim = IndexSet::<super::Forgetful>::from_iter(PRIMES);
+        let mut cursor = victim.cursor_front();
+
+        assert_eq!(Some(1), cursor.remove_current());
+        assert_eq!(Some(2), cursor.remove_current());
+    }
 } // mod index_set
 
 mod index_ord_set {
@@ -111,6 +342,45 @@ mod index_ord_set {
         helper::assert_exact_iterator(victim.iter(), EMPTY);
     }
 
+    #[test]
+    fn drain_dropped() {
+        let mut victim = Victim::from_iter(PRIMES);
+
+        let _ = victim.drain();
+
+        helper::assert_exact_iterator(victim.iter(), EMPTY);
+    }
+
+    #[test]
+    fn extract_if_dropped() {
+        let mut victim = Victim::from_iter(PRIMES);
+
+        let _ = victim.extract_if(|_| true);
+
+        helper::assert_exact_iterator(victim.iter(), PRIMES);
+    }
+
+    #[test]
+    fn extract_if_size_hint() {
+        let mut victim = Victim::from_iter(PRIMES);
+        let mut extract = victim.extract_if(|i: u8| i != 2);
+
+        assert_eq!((0, Some(4)), extract.size_hint());
+
+        assert_eq!(Some(1), extract.next());
+        assert_eq!((0, Some(3)), extract.size_hint());
+
+        //  Passes over 2, then extracts 3.
+        assert_eq!(Some(3), extract.next());
+        assert_eq!((0, Some(1)), extract.size_hint());
+
+        assert_eq!(Some(5), extract.next());
+        assert_eq!((0, Some(0)), extract.size_hint());
+
+        assert_eq!(None, extract.next());
+        assert_eq!((0, Some(0)), extract.size_hint());
+    }
+
     #[test]
     fn extract_if() {
         {
@@ -176,6 +446,89 @@ mod index_ord_set {
             helper::assert_exact_iterator(victim.iter(), EVEN_PRIMES);
         }
     }
+
+    #[test]
+    fn cursor_front() {
+        {
+            let mut victim = Victim::from_iter(EMPTY);
+
+            let mut cursor = victim.cursor_front();
+
+            assert_eq!(None, cursor.current());
+            assert_eq!(None, cursor.remove_current());
+        }
+
+        {
+            let mut victim = Victim::from_iter(PRIMES);
+
+            let mut cursor = victim.cursor_front();
+
+            assert_eq!(Some(1), cursor.current());
+
+            cursor.move_next();
+
+            assert_eq!(Some(2), cursor.remove_current());
+            assert_eq!(Some(3), cursor.current());
+
+            cursor.move_prev();
+
+            assert_eq!(Some(1), cursor.current());
+
+            cursor.move_next();
+            cursor.move_next();
+
+            assert_eq!(Some(5), cursor.remove_current());
+            assert_eq!(None, cursor.current());
+
+            helper::assert_exact_iterator(victim.iter(), [1, 3]);
+        }
+
+        {
+            let mut victim = Victim::from_iter(PRIMES);
+
+            let mut cursor = victim.cursor_front();
+            let mut sum = 0;
+
+            while let Some(index) = cursor.current() {
+                sum += index;
+
+                if sum > 3 {
+                    cursor.remove_current();
+                } else {
+                    cursor.move_next();
+                }
+            }
+
+            helper::assert_exact_iterator(victim.iter(), [1, 2]);
+        }
+    }
+
+    #[test]
+    fn drain_partition() {
+        const INDEXES: [u8; 6] = [1, 2, 9, 11, 12, 15];
+
+        let original = Victim::from_iter(INDEXES);
+
+        let mut victim = original.clone();
+        let removed = victim.drain_partition(|i| i % 3 == 0);
+
+        helper::assert_exact_iterator(victim.iter(), [1, 2, 11]);
+        helper::assert_exact_iterator(removed.iter(), [9, 12, 15]);
+
+        let union: Victim = removed.union(&victim).collect();
+
+        assert_eq!(original, union);
+
+        let mut victim = original.clone();
+
+        assert!(victim.drain_partition(|_| false).is_empty());
+        assert_eq!(original, victim);
+
+        let removed = victim.drain_partition(|_| true);
+
+        assert!(victim.is_empty());
+        assert_eq!(original, removed);
+    }
 } // mod index_ord_set
 
 mod index_chunked_set {
@@ -201,6 +554,102 @@ mod index_chunked_set {
         helper::assert_exact_iterator(victim.iter(), EMPTY);
     }
 
+    #[test]
+    fn drain_dropped() {
+        let mut victim = Victim::from_iter(PRIMES);
+
+        let _ = victim.drain();
+
+        helper::assert_exact_iterator(victim.iter(), EMPTY);
+    }
+
+    #[test]
+    fn extract_if_dropped() {
+        let mut victim = Victim::from_iter(PRIMES);
+
+        let _ = victim.extract_if(|_| true);
+
+        helper::assert_exact_iterator(victim.iter(), PRIMES);
+    }
+
+    #[test]
+    fn drain_chunks() {
+        const INDEXES: [u16; 6] = [1, 2, 9, 11, 12, 15];
+
+        let mut victim = Victim::from_iter(INDEXES);
+
+        helper::assert_exact_iterator(victim.drain(), INDEXES);
+        helper::assert_exact_iterator(victim.iter(), EMPTY);
+    }
+
+    #[test]
+    fn extract_if_chunks() {
+        const INDEXES: [u16; 6] = [1, 2, 9, 11, 12, 15];
+
+        let mut victim = Victim::from_iter(INDEXES);
+        let mut visited = Vec::new();
+
+        helper::assert_iterator(
+            victim.extract_if(|index| {
+                visited.push(index);
+                index % 3 == 0
+            }),
+            [9, 12, 15],
+        );
+
+        assert_eq!(&INDEXES[..], &visited[..]);
+        helper::assert_exact_iterator(victim.iter(), [1, 2, 11]);
+    }
+
+    #[test]
+    fn extract_if_abandoned() {
+        const INDEXES: [u16; 6] = [1, 2, 9, 11, 12, 15];
+
+        let mut victim = Victim::from_iter(INDEXES);
+
+        {
+            let mut extract = victim.extract_if(|index| index % 3 == 0);
+
+            assert_eq!(Some(9), extract.next());
+        }
+
+        helper::assert_exact_iterator(victim.iter(), [1, 2, 11, 12, 15]);
+    }
+
+    #[test]
+    fn retain_chunks() {
+        const INDEXES: [u16; 6] = [1, 2, 9, 11, 12, 15];
+
+        let mut victim = Victim::from_iter(INDEXES);
+        let mut visited = Vec::new();
+
+        victim.retain(|index| {
+            visited.push(index);
+            index % 3 != 0
+        });
+
+        assert_eq!(&INDEXES[..], &visited[..]);
+        helper::assert_exact_iterator(victim.iter(), [1, 2, 11]);
+    }
+
+    #[test]
+    fn drain_abandoned() {
+        const INDEXES: [u16; 6] = [1, 2, 9, 11, 12, 15];
+
+        let mut victim = Victim::from_iter(INDEXES);
+
+        {
+            let mut drain = victim.drain();
+
+            assert_eq!(Some(1), drain.next());
+            assert_eq!(Some(2), drain.next());
+            assert_eq!(Some(9), drain.next());
+            assert_eq!(3, drain.len());
+        }
+
+        helper::assert_exact_iterator(victim.iter(), EMPTY);
+    }
+
     #[test]
     fn extract_if() {
         {
@@ -266,6 +715,89 @@ mod index_chunked_set {
             helper::assert_exact_iterator(victim.iter(), EVEN_PRIMES);
         }
     }
+
+    #[test]
+    fn cursor_front() {
+        {
+            let mut victim = Victim::from_iter(EMPTY);
+
+            let mut cursor = victim.cursor_front();
+
+            assert_eq!(None, cursor.current());
+            assert_eq!(None, cursor.remove_current());
+        }
+
+        {
+            let mut victim = Victim::from_iter(PRIMES);
+
+            let mut cursor = victim.cursor_front();
+
+            assert_eq!(Some(1), cursor.current());
+
+            cursor.move_next();
+
+            assert_eq!(Some(2), cursor.remove_current());
+            assert_eq!(Some(3), cursor.current());
+
+            cursor.move_prev();
+
+            assert_eq!(Some(1), cursor.current());
+
+            cursor.move_next();
+            cursor.move_next();
+
+            assert_eq!(Some(5), cursor.remove_current());
+            assert_eq!(None, cursor.current());
+
+            helper::assert_exact_iterator(victim.iter(), [1, 3]);
+        }
+
+        {
+            let mut victim = Victim::from_iter(PRIMES);
+
+            let mut cursor = victim.cursor_front();
+            let mut sum = 0;
+
+            while let Some(index) = cursor.current() {
+                sum += index;
+
+                if sum > 3 {
+                    cursor.remove_current();
+                } else {
+                    cursor.move_next();
+                }
+            }
+
+            helper::assert_exact_iterator(victim.iter(), [1, 2]);
+        }
+    }
+
+    #[test]
+    fn drain_partition() {
+        const INDEXES: [u16; 6] = [1, 2, 9, 11, 12, 15];
+
+        let original = Victim::from_iter(INDEXES);
+
+        let mut victim = original;
+        let removed = victim.drain_partition(|i| i % 3 == 0);
+
+        helper::assert_exact_iterator(victim.iter(), [1, 2, 11]);
+        helper::assert_exact_iterator(removed.iter(), [9, 12, 15]);
+
+        let union: Victim = removed.union(&victim).collect();
+
+        assert!(original.iter().eq(union.iter()));
+
+        let mut victim = original;
+
+        assert!(victim.drain_partition(|_| false).is_empty());
+        assert!(original.iter().eq(victim.iter()));
+
+        let removed = victim.drain_partition(|_| true);
+
+        assert!(victim.is_empty());
+        assert!(original.iter().eq(removed.iter()));
+    }
 } // mod index_chunked_set
 
 mod helper {
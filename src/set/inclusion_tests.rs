@@ -82,6 +82,82 @@ mod index_ord_set {
         assert!(!evens.is_subset(&primes));
         assert!(!evens.is_superset(&primes));
     }
+
+    #[test]
+    fn is_sorted_disjoint() {
+        let primes = Victim::from_iter([1, 2, 3, 5]);
+        let evens = Victim::from_iter([2, 4, 6, 8]);
+        let perfects = Victim::from_iter([36]);
+        let empty = Victim::new();
+
+        assert!(!primes.is_sorted_disjoint(&evens));
+        assert!(!evens.is_sorted_disjoint(&primes));
+
+        assert!(primes.is_sorted_disjoint(&perfects));
+        assert!(perfects.is_sorted_disjoint(&primes));
+
+        assert!(empty.is_sorted_disjoint(&empty));
+        assert!(empty.is_sorted_disjoint(&primes));
+        assert!(primes.is_sorted_disjoint(&empty));
+    }
+
+    #[test]
+    fn is_sorted_subset() {
+        let primes = Victim::from_iter([1, 2, 3, 5, 7]);
+        let odds = Victim::from_iter([1, 3, 5, 7]);
+        let evens = Victim::from_iter([2, 4, 6, 8]);
+        let empty = Victim::new();
+
+        assert!(odds.is_sorted_subset(&odds));
+        assert!(odds.is_sorted_subset(&primes));
+        assert!(!primes.is_sorted_subset(&odds));
+        assert!(!primes.is_sorted_subset(&evens));
+        assert!(!evens.is_sorted_subset(&primes));
+
+        assert!(empty.is_sorted_subset(&empty));
+        assert!(empty.is_sorted_subset(&primes));
+        assert!(!primes.is_sorted_subset(&empty));
+    }
+
+    #[test]
+    fn is_sorted_random() {
+        //  xorshift64, for reproducibility.
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+
+        let mut random = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        let to_set = |mask: u64| Victim::from_iter((0..64).filter(|i| mask & (1 << i) != 0));
+
+        for _ in 0..1_000 {
+            let (left, right) = (random(), random());
+
+            //  Random pairs are rarely subsets, nor disjoint, hence also try their intersection and difference.
+            for (left, right) in [
+                (left, right),
+                (left & right, right),
+                (left & right & random(), left),
+                (left & !right, right),
+            ] {
+                let (left, right) = (to_set(left), to_set(right));
+
+                assert_eq!(
+                    left.is_subset(&right),
+                    left.is_sorted_subset(&right),
+                    "{left:?} <= {right:?}"
+                );
+                assert_eq!(
+                    right.is_subset(&left),
+                    right.is_sorted_subset(&left),
+                    "{right:?} <= {left:?}"
+                );
+            }
+        }
+    }
 } // mod index_ord_set
 
 mod index_chunked_set {
@@ -1,6 +1,8 @@
 //! Unit tests for negation operations.
 
 mod index_set {
+    use alloc::vec::Vec;
+
     use crate::{chunk::UnsignedChunk, index::IndexView, set::IndexSet};
 
     type Victim = IndexSet<UnsignedChunk<u16>>;
@@ -24,10 +26,48 @@ mod index_set {
             assert!(!victim.as_not().contains(1));
         }
     }
+
+    #[test]
+    fn complement() {
+        const SOME: [u8; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let victim: Victim = SOME.into_iter().collect();
+        let complement = victim.complement();
+
+        assert!(complement.contains(0));
+        assert!(!complement.contains(1));
+        assert_eq!(16 - SOME.len(), complement.len());
+
+        let absent: Vec<_> = complement.iter().collect();
+
+        assert_eq!(&[0, 4, 6, 8, 9, 10, 12, 14, 15][..], &absent[..]);
+    }
+
+    #[test]
+    fn complement_is_empty() {
+        const SOME: [u8; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let empty = Victim::new();
+        let some: Victim = SOME.into_iter().collect();
+        let full: Victim = (0..16).collect();
+
+        assert!(!empty.complement().is_empty());
+        assert!(!some.complement().is_empty());
+        assert!(full.complement().is_empty());
+    }
 } // mod index_set
 
 mod index_ord_set {
-    use crate::{chunk::UnsignedChunk, index::IndexView, set::IndexOrdSet};
+    use alloc::vec::Vec;
+
+    use core::ops::Bound;
+
+    use crate::{
+        chunk::{ArrayChunk, UnsignedChunk},
+        index::IndexView,
+        set::IndexOrdSet,
+        vault::DynamicChunkStore,
+    };
 
     type Victim = IndexOrdSet<UnsignedChunk<u16>>;
 
@@ -50,9 +90,147 @@ mod index_ord_set {
             assert!(!victim.as_not().contains(1));
         }
     }
+
+    #[test]
+    fn complement() {
+        const SOME: [u8; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let victim: Victim = SOME.into_iter().collect();
+        let complement = victim.complement();
+
+        assert!(complement.contains(0));
+        assert!(!complement.contains(1));
+        assert_eq!(16 - SOME.len(), complement.len());
+
+        let absent: Vec<_> = complement.iter().collect();
+
+        assert_eq!(&[0, 4, 6, 8, 9, 10, 12, 14, 15][..], &absent[..]);
+    }
+
+    #[test]
+    fn complement_is_empty() {
+        const SOME: [u8; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let empty = Victim::new();
+        let some: Victim = SOME.into_iter().collect();
+        let full: Victim = (0..16).collect();
+
+        assert!(!empty.complement().is_empty());
+        assert!(!some.complement().is_empty());
+        assert!(full.complement().is_empty());
+    }
+
+    #[test]
+    fn within() {
+        type Store = DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>, u32>;
+
+        let victim: IndexOrdSet<Store> = [1, 3, 5, 40].into_iter().collect();
+
+        let below = victim.complement_within(..8);
+
+        assert_eq!(5, below.len());
+        assert!(below.iter().eq([0, 2, 4, 6, 7]));
+
+        let inclusive = victim.complement_within(2..=6);
+
+        assert_eq!(3, inclusive.len());
+        assert!(inclusive.contains(6));
+        assert!(!inclusive.contains(7));
+        assert!(inclusive.iter().eq([2, 4, 6]));
+
+        let excluded = victim.complement_within((Bound::Excluded(38), Bound::Included(41)));
+
+        assert!(excluded.iter().eq([39, 41]));
+
+        let empty = victim.complement_within(3..4);
+
+        assert!(empty.is_empty());
+        assert_eq!(0, empty.len());
+    }
+
+    #[test]
+    fn within_past_capacity() {
+        type Store = DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>, u64>;
+
+        let victim: IndexOrdSet<Store> = [3].into_iter().collect();
+
+        //  The store only allocates 0..16, yet all indexes of the span absent from the store are iterated.
+        let beyond = victim.complement_within(0..100);
+
+        assert!(beyond.contains(50));
+        assert_eq!(99, beyond.len());
+        assert_eq!(99, beyond.iter().count());
+        assert_eq!(Some(99), beyond.last());
+        assert_eq!(Some(99), beyond.iter().next_back());
+        assert!(beyond.iter().rev().eq((0..100).rev().filter(|&i| i != 3)));
+
+        //  Full up to its capacity, the first index past it is the first of the complement.
+        let mut full: IndexOrdSet<Store> = (0..16).collect();
+
+        assert!(full.complement_within(4..20).iter().eq(16..20));
+        assert!(full.complement_within(4..20).iter().rev().eq((16..20).rev()));
+        assert_eq!(Some(16), full.complement_within(..).first());
+        assert_eq!(Some(u64::MAX), full.complement_within(..).last());
+
+        full.clear();
+
+        assert!(full.complement_within(..3).iter().eq(0..3));
+    }
+
+    #[test]
+    fn within_len_large() {
+        type Store = DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>, u64>;
+
+        let victim: IndexOrdSet<Store> = [3, 1 << 20].into_iter().collect();
+
+        //  Only the indexes of the store within the span are walked, not the absent ones.
+        let start = std::time::Instant::now();
+
+        assert_eq!((1 << 48) - 2, victim.complement_within(0..1 << 48).len());
+        assert_eq!((1 << 40) - 4, victim.complement_within(4..=1 << 40).len());
+        assert_eq!(usize::MAX - 1, victim.complement_within(..).len());
+        assert_eq!(0, victim.complement_within(3..=3).len());
+
+        assert!(start.elapsed() < std::time::Duration::from_secs(1));
+    }
+
+    #[test]
+    fn first_gap_from() {
+        let victim: Victim = [0, 1, 2, 4].into_iter().collect();
+
+        assert_eq!(Some(3), victim.first_gap_from(0));
+        assert_eq!(Some(3), victim.first_gap_from(3));
+        assert_eq!(Some(5), victim.first_gap_from(4));
+        assert_eq!(Some(5), victim.first_gap_from(5));
+
+        //  Full up to the end of its span, hence no gap.
+        let full: Victim = (0..16).collect();
+
+        assert_eq!(None, full.first_gap_from(0));
+        assert_eq!(None, full.first_gap_from(15));
+    }
+
+    #[test]
+    fn first_gap_from_dynamic() {
+        type Store = DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>, u32>;
+
+        let mut victim: IndexOrdSet<Store> = [0, 1, 2, 4].into_iter().collect();
+
+        assert_eq!(Some(3), victim.first_gap_from(0));
+        assert_eq!(Some(5), victim.first_gap_from(4));
+
+        //  Full chunks are skipped over, up to and including the last allocated one, past which all indexes are gaps.
+        victim.insert_range(0..64).unwrap();
+
+        assert_eq!(Some(64), victim.first_gap_from(0));
+        assert_eq!(Some(64), victim.first_gap_from(17));
+        assert_eq!(Some(1_000), victim.first_gap_from(1_000));
+    }
 } // mod index_ord_set
 
 mod index_chunked_set {
+    use alloc::vec::Vec;
+
     use crate::{
         chunk::{ArrayChunk, UnsignedChunk},
         index::IndexView,
@@ -80,4 +258,33 @@ mod index_chunked_set {
             assert!(!victim.as_not().contains(1));
         }
     }
+
+    #[test]
+    fn complement() {
+        const SOME: [u16; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let victim: Victim = SOME.into_iter().collect();
+        let complement = victim.complement();
+
+        assert!(complement.contains(0));
+        assert!(!complement.contains(1));
+        assert_eq!(16 - SOME.len(), complement.len());
+
+        let absent: Vec<_> = complement.iter().collect();
+
+        assert_eq!(&[0, 4, 6, 8, 9, 10, 12, 14, 15][..], &absent[..]);
+    }
+
+    #[test]
+    fn complement_is_empty() {
+        const SOME: [u16; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let empty = Victim::new();
+        let some: Victim = SOME.into_iter().collect();
+        let full: Victim = (0..16).collect();
+
+        assert!(!empty.complement().is_empty());
+        assert!(!some.complement().is_empty());
+        assert!(full.complement().is_empty());
+    }
 } // mod index_chunked_set
@@ -0,0 +1,400 @@
+//! Unit tests for the bitwise operators.
+
+mod index_set {
+    use alloc::{collections::BTreeSet, vec::Vec};
+
+    use crate::set::IndexSet;
+
+    type Victim = IndexSet<BTreeSet<u8>>;
+
+    const FIXTURES: [&[u8]; 4] = [&[], &[1, 2, 3, 5], &[2, 4, 6, 8], &[1, 3, 5, 7]];
+
+    #[track_caller]
+    fn assert_operator<I, O, OR, A, AR>(inherent: I, owned: O, by_ref: OR, assign: A, assign_by_ref: AR)
+    where
+        I: Fn(&mut Victim, &Victim),
+        O: Fn(Victim, Victim) -> Victim,
+        OR: Fn(Victim, &Victim) -> Victim,
+        A: Fn(&mut Victim, Victim),
+        AR: Fn(&mut Victim, &Victim),
+    {
+        for left in FIXTURES {
+            for right in FIXTURES {
+                let victim = || Victim::from_iter(left.iter().copied());
+                let other = || Victim::from_iter(right.iter().copied());
+
+                let mut expected = victim();
+                inherent(&mut expected, &other());
+
+                let expected = collect(&expected);
+
+                assert_eq!(expected, collect(&owned(victim(), other())), "{left:?} {right:?}");
+                assert_eq!(expected, collect(&by_ref(victim(), &other())), "{left:?} {right:?}");
+
+                let mut assigned = victim();
+                assign(&mut assigned, other());
+
+                assert_eq!(expected, collect(&assigned), "{left:?} {right:?}");
+
+                let mut assigned = victim();
+                assign_by_ref(&mut assigned, &other());
+
+                assert_eq!(expected, collect(&assigned), "{left:?} {right:?}");
+            }
+        }
+    }
+
+    fn collect(victim: &Victim) -> Vec<u8> {
+        victim.iter().collect()
+    }
+
+    fn fixture(indexes: &[u8]) -> Victim {
+        Victim::from_iter(indexes.iter().copied())
+    }
+
+    #[test]
+    fn bitand() {
+        assert_operator(
+            |left, right| left.bitand_assign(right),
+            |left, right| left & right,
+            |left, right| left & right,
+            |left, right| *left &= right,
+            |left, right| *left &= right,
+        );
+    }
+
+    #[test]
+    fn bitor() {
+        assert_operator(
+            |left, right| left.bitor_assign(right),
+            |left, right| left | right,
+            |left, right| left | right,
+            |left, right| *left |= right,
+            |left, right| *left |= right,
+        );
+    }
+
+    #[test]
+    fn sub() {
+        assert_operator(
+            |left, right| left.sub_assign(right),
+            |left, right| left - right,
+            |left, right| left - right,
+            |left, right| *left -= right,
+            |left, right| *left -= right,
+        );
+    }
+
+    #[test]
+    fn bitxor() {
+        assert_operator(
+            |left, right| left.bitxor_assign(right),
+            |left, right| left ^ right,
+            |left, right| left ^ right,
+            |left, right| *left ^= right,
+            |left, right| *left ^= right,
+        );
+    }
+
+    #[test]
+    fn commutativity() {
+        for left in FIXTURES {
+            for right in FIXTURES {
+                assert_eq!(
+                    collect(&(fixture(left) & fixture(right))),
+                    collect(&(fixture(right) & fixture(left)))
+                );
+                assert_eq!(
+                    collect(&(fixture(left) | fixture(right))),
+                    collect(&(fixture(right) | fixture(left)))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn associativity() {
+        for a in FIXTURES {
+            for b in FIXTURES {
+                for c in FIXTURES {
+                    let left = fixture(a) | (fixture(b) | fixture(c));
+                    let right = (fixture(a) | fixture(b)) | fixture(c);
+
+                    assert_eq!(collect(&left), collect(&right));
+
+                    let left = fixture(a) & (fixture(b) & fixture(c));
+                    let right = (fixture(a) & fixture(b)) & fixture(c);
+
+                    assert_eq!(collect(&left), collect(&right));
+                }
+            }
+        }
+    }
+} // mod index_set
+
+mod index_ord_set {
+    use alloc::{collections::BTreeSet, vec::Vec};
+
+    use crate::set::IndexOrdSet;
+
+    type Victim = IndexOrdSet<BTreeSet<u8>>;
+
+    const FIXTURES: [&[u8]; 4] = [&[], &[1, 2, 3, 5], &[2, 4, 6, 8], &[1, 3, 5, 7]];
+
+    #[track_caller]
+    fn assert_operator<I, O, OR, A, AR>(inherent: I, owned: O, by_ref: OR, assign: A, assign_by_ref: AR)
+    where
+        I: Fn(&mut Victim, &Victim),
+        O: Fn(Victim, Victim) -> Victim,
+        OR: Fn(Victim, &Victim) -> Victim,
+        A: Fn(&mut Victim, Victim),
+        AR: Fn(&mut Victim, &Victim),
+    {
+        for left in FIXTURES {
+            for right in FIXTURES {
+                let victim = || Victim::from_iter(left.iter().copied());
+                let other = || Victim::from_iter(right.iter().copied());
+
+                let mut expected = victim();
+                inherent(&mut expected, &other());
+
+                let expected = collect(&expected);
+
+                assert_eq!(expected, collect(&owned(victim(), other())), "{left:?} {right:?}");
+                assert_eq!(expected, collect(&by_ref(victim(), &other())), "{left:?} {right:?}");
+
+                let mut assigned = victim();
+                assign(&mut assigned, other());
+
+                assert_eq!(expected, collect(&assigned), "{left:?} {right:?}");
+
+                let mut assigned = victim();
+                assign_by_ref(&mut assigned, &other());
+
+                assert_eq!(expected, collect(&assigned), "{left:?} {right:?}");
+            }
+        }
+    }
+
+    fn collect(victim: &Victim) -> Vec<u8> {
+        victim.iter().collect()
+    }
+
+    fn fixture(indexes: &[u8]) -> Victim {
+        Victim::from_iter(indexes.iter().copied())
+    }
+
+    #[test]
+    fn bitand() {
+        assert_operator(
+            |left, right| left.bitand_assign(right),
+            |left, right| left & right,
+            |left, right| left & right,
+            |left, right| *left &= right,
+            |left, right| *left &= right,
+        );
+    }
+
+    #[test]
+    fn bitor() {
+        assert_operator(
+            |left, right| left.bitor_assign(right),
+            |left, right| left | right,
+            |left, right| left | right,
+            |left, right| *left |= right,
+            |left, right| *left |= right,
+        );
+    }
+
+    #[test]
+    fn sub() {
+        assert_operator(
+            |left, right| left.sub_assign(right),
+            |left, right| left - right,
+            |left, right| left - right,
+            |left, right| *left -= right,
+            |left, right| *left -= right,
+        );
+    }
+
+    #[test]
+    fn bitxor() {
+        assert_operator(
+            |left, right| left.bitxor_assign(right),
+            |left, right| left ^ right,
+            |left, right| left ^ right,
+            |left, right| *left ^= right,
+            |left, right| *left ^= right,
+        );
+    }
+
+    #[test]
+    fn commutativity() {
+        for left in FIXTURES {
+            for right in FIXTURES {
+                assert_eq!(
+                    collect(&(fixture(left) & fixture(right))),
+                    collect(&(fixture(right) & fixture(left)))
+                );
+                assert_eq!(
+                    collect(&(fixture(left) | fixture(right))),
+                    collect(&(fixture(right) | fixture(left)))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn associativity() {
+        for a in FIXTURES {
+            for b in FIXTURES {
+                for c in FIXTURES {
+                    let left = fixture(a) | (fixture(b) | fixture(c));
+                    let right = (fixture(a) | fixture(b)) | fixture(c);
+
+                    assert_eq!(collect(&left), collect(&right));
+
+                    let left = fixture(a) & (fixture(b) & fixture(c));
+                    let right = (fixture(a) & fixture(b)) & fixture(c);
+
+                    assert_eq!(collect(&left), collect(&right));
+                }
+            }
+        }
+    }
+} // mod index_ord_set
+
+mod index_chunked_set {
+    use alloc::vec::Vec;
+
+    use crate::{
+        chunk::{ArrayChunk, UnsignedChunk},
+        set::IndexChunkedSet,
+    };
+
+    type Victim = IndexChunkedSet<ArrayChunk<UnsignedChunk<u8>, 2>>;
+
+    const FIXTURES: [&[u16]; 4] = [&[], &[1, 2, 3, 5], &[2, 4, 6, 8], &[1, 3, 5, 7]];
+
+    #[track_caller]
+    fn assert_operator<I, O, OR, A, AR>(inherent: I, owned: O, by_ref: OR, assign: A, assign_by_ref: AR)
+    where
+        I: Fn(&mut Victim, &Victim),
+        O: Fn(Victim, Victim) -> Victim,
+        OR: Fn(Victim, &Victim) -> Victim,
+        A: Fn(&mut Victim, Victim),
+        AR: Fn(&mut Victim, &Victim),
+    {
+        for left in FIXTURES {
+            for right in FIXTURES {
+                let victim = || Victim::from_iter(left.iter().copied());
+                let other = || Victim::from_iter(right.iter().copied());
+
+                let mut expected = victim();
+                inherent(&mut expected, &other());
+
+                let expected = collect(&expected);
+
+                assert_eq!(expected, collect(&owned(victim(), other())), "{left:?} {right:?}");
+                assert_eq!(expected, collect(&by_ref(victim(), &other())), "{left:?} {right:?}");
+
+                let mut assigned = victim();
+                assign(&mut assigned, other());
+
+                assert_eq!(expected, collect(&assigned), "{left:?} {right:?}");
+
+                let mut assigned = victim();
+                assign_by_ref(&mut assigned, &other());
+
+                assert_eq!(expected, collect(&assigned), "{left:?} {right:?}");
+            }
+        }
+    }
+
+    fn collect(victim: &Victim) -> Vec<u16> {
+        victim.iter().collect()
+    }
+
+    fn fixture(indexes: &[u16]) -> Victim {
+        Victim::from_iter(indexes.iter().copied())
+    }
+
+    #[test]
+    fn bitand() {
+        assert_operator(
+            |left, right| left.bitand_assign(right),
+            |left, right| left & right,
+            |left, right| left & right,
+            |left, right| *left &= right,
+            |left, right| *left &= right,
+        );
+    }
+
+    #[test]
+    fn bitor() {
+        assert_operator(
+            |left, right| left.bitor_assign(right),
+            |left, right| left | right,
+            |left, right| left | right,
+            |left, right| *left |= right,
+            |left, right| *left |= right,
+        );
+    }
+
+    #[test]
+    fn sub() {
+        assert_operator(
+            |left, right| left.sub_assign(right),
+            |left, right| left - right,
+            |left, right| left - right,
+            |left, right| *left -= right,
+            |left, right| *left -= right,
+        );
+    }
+
+    #[test]
+    fn bitxor() {
+        assert_operator(
+            |left, right| left.bitxor_assign(right),
+            |left, right| left ^ right,
+            |left, right| left ^ right,
+            |left, right| *left ^= right,
+            |left, right| *left ^= right,
+        );
+    }
+
+    #[test]
+    fn commutativity() {
+        for left in FIXTURES {
+            for right in FIXTURES {
+                assert_eq!(
+                    collect(&(fixture(left) & fixture(right))),
+                    collect(&(fixture(right) & fixture(left)))
+                );
+                assert_eq!(
+                    collect(&(fixture(left) | fixture(right))),
+                    collect(&(fixture(right) | fixture(left)))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn associativity() {
+        for a in FIXTURES {
+            for b in FIXTURES {
+                for c in FIXTURES {
+                    let left = fixture(a) | (fixture(b) | fixture(c));
+                    let right = (fixture(a) | fixture(b)) | fixture(c);
+
+                    assert_eq!(collect(&left), collect(&right));
+
+                    let left = fixture(a) & (fixture(b) & fixture(c));
+                    let right = (fixture(a) & fixture(b)) & fixture(c);
+
+                    assert_eq!(collect(&left), collect(&right));
+                }
+            }
+        }
+    }
+} // mod index_chunked_set
@@ -0,0 +1,244 @@
+//! Property-based tests for difference/symmetric_difference/intersection/union, and their assigning counterparts.
+//!
+//! Each property builds a pair of sets from random indexes, and checks the results against those of `BTreeSet`.
+
+use std::collections::BTreeSet;
+
+use alloc::vec::Vec;
+
+use quickcheck::{QuickCheck, TestResult};
+
+//  The indexes are generated as `u16`, so as to keep the chunked stores reasonably small.
+type Reference = BTreeSet<u32>;
+
+fn reference(indexes: &[u16]) -> Reference {
+    indexes.iter().map(|&i| u32::from(i)).collect()
+}
+
+fn quickcheck(property: fn(Vec<u16>, Vec<u16>) -> TestResult) {
+    QuickCheck::new().tests(500).quickcheck(property);
+}
+
+mod index_set {
+    use std::collections::BTreeSet;
+
+    use alloc::vec::Vec;
+
+    use quickcheck::TestResult;
+
+    use crate::set::IndexSet;
+
+    use super::{Reference, quickcheck, reference};
+
+    type Victim = IndexSet<BTreeSet<u32>>;
+
+    fn victim(indexes: &[u16]) -> Victim {
+        indexes.iter().map(|&i| u32::from(i)).collect()
+    }
+
+    //  `IndexSet` iterates in the order of its store, hence the results are sorted prior to comparison.
+    fn sorted<I>(iter: I) -> Vec<u32>
+    where
+        I: IntoIterator<Item = u32>,
+    {
+        let mut result: Vec<_> = iter.into_iter().collect();
+
+        result.sort_unstable();
+
+        result
+    }
+
+    fn expected<'a, I>(iter: I) -> Vec<u32>
+    where
+        I: IntoIterator<Item = &'a u32>,
+    {
+        iter.into_iter().copied().collect()
+    }
+
+    #[test]
+    fn dual_iteration() {
+        fn property(left: Vec<u16>, right: Vec<u16>) -> TestResult {
+            let (victim_left, victim_right) = (victim(&left), victim(&right));
+            let (left, right): (Reference, Reference) = (reference(&left), reference(&right));
+
+            let difference = sorted(victim_left.difference(&victim_right));
+            let symmetric_difference = sorted(victim_left.symmetric_difference(&victim_right));
+            let intersection = sorted(victim_left.intersection(&victim_right));
+            let union = sorted(victim_left.union(&victim_right));
+
+            TestResult::from_bool(
+                difference == expected(left.difference(&right))
+                    && symmetric_difference == expected(left.symmetric_difference(&right))
+                    && intersection == expected(left.intersection(&right))
+                    && union == expected(left.union(&right)),
+            )
+        }
+
+        quickcheck(property);
+    }
+
+    #[test]
+    fn bitwise_assign() {
+        fn property(left: Vec<u16>, right: Vec<u16>) -> TestResult {
+            let (victim_left, victim_right) = (victim(&left), victim(&right));
+            let (left, right): (Reference, Reference) = (reference(&left), reference(&right));
+
+            let mut and = victim_left.clone();
+            let mut or = victim_left.clone();
+            let mut sub = victim_left.clone();
+            let mut xor = victim_left;
+
+            and.bitand_assign(&victim_right);
+            or.bitor_assign(&victim_right);
+            sub.sub_assign(&victim_right);
+            xor.bitxor_assign(&victim_right);
+
+            TestResult::from_bool(
+                sorted(and.iter()) == expected(&(&left & &right))
+                    && sorted(or.iter()) == expected(&(&left | &right))
+                    && sorted(sub.iter()) == expected(&(&left - &right))
+                    && sorted(xor.iter()) == expected(&(&left ^ &right)),
+            )
+        }
+
+        quickcheck(property);
+    }
+} // mod index_set
+
+mod index_ord_set {
+    use std::collections::BTreeSet;
+
+    use alloc::vec::Vec;
+
+    use quickcheck::TestResult;
+
+    use crate::set::IndexOrdSet;
+
+    use super::{Reference, quickcheck, reference};
+
+    type Victim = IndexOrdSet<BTreeSet<u32>>;
+
+    fn victim(indexes: &[u16]) -> Victim {
+        indexes.iter().map(|&i| u32::from(i)).collect()
+    }
+
+    #[test]
+    fn dual_iteration() {
+        fn property(left: Vec<u16>, right: Vec<u16>) -> TestResult {
+            let (victim_left, victim_right) = (victim(&left), victim(&right));
+            let (left, right): (Reference, Reference) = (reference(&left), reference(&right));
+
+            TestResult::from_bool(
+                victim_left
+                    .difference(&victim_right)
+                    .eq(left.difference(&right).copied())
+                    && victim_left
+                        .symmetric_difference(&victim_right)
+                        .eq(left.symmetric_difference(&right).copied())
+                    && victim_left
+                        .intersection(&victim_right)
+                        .eq(left.intersection(&right).copied())
+                    && victim_left.union(&victim_right).eq(left.union(&right).copied()),
+            )
+        }
+
+        quickcheck(property);
+    }
+
+    #[test]
+    fn bitwise_assign() {
+        fn property(left: Vec<u16>, right: Vec<u16>) -> TestResult {
+            let (victim_left, victim_right) = (victim(&left), victim(&right));
+            let (left, right): (Reference, Reference) = (reference(&left), reference(&right));
+
+            let mut and = victim_left.clone();
+            let mut or = victim_left.clone();
+            let mut sub = victim_left.clone();
+            let mut xor = victim_left;
+
+            and.bitand_assign(&victim_right);
+            or.bitor_assign(&victim_right);
+            sub.sub_assign(&victim_right);
+            xor.bitxor_assign(&victim_right);
+
+            TestResult::from_bool(
+                and.iter().eq(&left & &right)
+                    && or.iter().eq(&left | &right)
+                    && sub.iter().eq(&left - &right)
+                    && xor.iter().eq(&left ^ &right),
+            )
+        }
+
+        quickcheck(property);
+    }
+} // mod index_ord_set
+
+mod index_chunked_set {
+    use alloc::vec::Vec;
+
+    use quickcheck::TestResult;
+
+    use crate::{
+        chunk::{ArrayChunk, UnsignedChunk},
+        set::IndexChunkedSet,
+        vault::DynamicChunkStore,
+    };
+
+    use super::{Reference, quickcheck, reference};
+
+    type Victim = IndexChunkedSet<DynamicChunkStore<ArrayChunk<UnsignedChunk<u64>, 4>, u32>>;
+
+    fn victim(indexes: &[u16]) -> Victim {
+        indexes.iter().map(|&i| u32::from(i)).collect()
+    }
+
+    #[test]
+    fn dual_iteration() {
+        fn property(left: Vec<u16>, right: Vec<u16>) -> TestResult {
+            let (victim_left, victim_right) = (victim(&left), victim(&right));
+            let (left, right): (Reference, Reference) = (reference(&left), reference(&right));
+
+            TestResult::from_bool(
+                victim_left
+                    .difference(&victim_right)
+                    .eq(left.difference(&right).copied())
+                    && victim_left
+                        .symmetric_difference(&victim_right)
+                        .eq(left.symmetric_difference(&right).copied())
+                    && victim_left
+                        .intersection(&victim_right)
+                        .eq(left.intersection(&right).copied())
+                    && victim_left.union(&victim_right).eq(left.union(&right).copied()),
+            )
+        }
+
+        quickcheck(property);
+    }
+
+    #[test]
+    fn bitwise_assign() {
+        fn property(left: Vec<u16>, right: Vec<u16>) -> TestResult {
+            let (victim_left, victim_right) = (victim(&left), victim(&right));
+            let (left, right): (Reference, Reference) = (reference(&left), reference(&right));
+
+            let mut and = victim_left.clone();
+            let mut or = victim_left.clone();
+            let mut sub = victim_left.clone();
+            let mut xor = victim_left;
+
+            and.bitand_assign(&victim_right);
+            or.bitor_assign(&victim_right);
+            sub.sub_assign(&victim_right);
+            xor.bitxor_assign(&victim_right);
+
+            TestResult::from_bool(
+                and.iter().eq(&left & &right)
+                    && or.iter().eq(&left | &right)
+                    && sub.iter().eq(&left - &right)
+                    && xor.iter().eq(&left ^ &right),
+            )
+        }
+
+        quickcheck(property);
+    }
+} // mod index_chunked_set
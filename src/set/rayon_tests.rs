@@ -0,0 +1,43 @@
+//! Unit tests for parallel iteration.
+
+mod index_chunked_set {
+    use alloc::vec::Vec;
+
+    use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+    use crate::{
+        chunk::{ArrayChunk, UnsignedChunk},
+        set::IndexChunkedSet,
+        vault::DynamicChunkStore,
+    };
+
+    type Victim = IndexChunkedSet<DynamicChunkStore<ArrayChunk<UnsignedChunk<u64>, 4>>>;
+
+    #[test]
+    fn par_iter_empty() {
+        let victim = Victim::new();
+
+        assert_eq!(0, victim.par_iter().count());
+    }
+
+    #[test]
+    fn par_iter_sum() {
+        //  Sparse, yet spread over many chunks.
+        let victim: Victim = (0..1_000_000u64).filter(|i| i % 7 == 0 || i % 11 == 3).collect();
+
+        let sequential: u64 = victim.iter().sum();
+        let parallel: u64 = victim.par_iter().sum();
+
+        assert_eq!(sequential, parallel);
+    }
+
+    #[test]
+    fn into_par_iter_order() {
+        let victim: Victim = (0..100_000u64).filter(|i| i % 3 == 0).collect();
+
+        let sequential: Vec<_> = victim.iter().collect();
+        let parallel: Vec<_> = (&victim).into_par_iter().collect();
+
+        assert_eq!(sequential, parallel);
+    }
+} // mod index_chunked_set
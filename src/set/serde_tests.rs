@@ -0,0 +1,77 @@
+//! Unit tests for serialization.
+
+mod index_set {
+    use alloc::{collections::BTreeSet, vec::Vec};
+
+    use crate::set::IndexSet;
+
+    #[test]
+    fn round_trip() {
+        const SOME: [u32; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let victim: IndexSet<BTreeSet<u32>> = SOME.into_iter().collect();
+
+        let json = serde_json::to_string(&victim).unwrap();
+
+        assert_eq!("[1,2,3,5,7,11,13]", json);
+
+        let victim: IndexSet<BTreeSet<u32>> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(&SOME[..], &victim.iter().collect::<Vec<_>>()[..]);
+    }
+
+    #[test]
+    fn round_trip_empty() {
+        let victim: IndexSet<BTreeSet<u32>> = IndexSet::new();
+
+        let json = serde_json::to_string(&victim).unwrap();
+
+        assert_eq!("[]", json);
+
+        let victim: IndexSet<BTreeSet<u32>> = serde_json::from_str(&json).unwrap();
+
+        assert!(victim.is_empty());
+    }
+
+    #[test]
+    fn deserialize_unordered() {
+        let victim: IndexSet<BTreeSet<u32>> = serde_json::from_str("[5,1,3,1]").unwrap();
+
+        assert_eq!(&[1, 3, 5][..], &victim.iter().collect::<Vec<_>>()[..]);
+    }
+
+    #[test]
+    fn deserialize_invalid() {
+        let result: Result<IndexSet<BTreeSet<u32>>, _> = serde_json::from_str("{\"index\":1}");
+
+        assert!(result.is_err());
+    }
+} // mod index_set
+
+mod index_ord_set {
+    use alloc::{collections::BTreeSet, vec::Vec};
+
+    use crate::set::IndexOrdSet;
+
+    #[test]
+    fn round_trip() {
+        const SOME: [u32; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let victim: IndexOrdSet<BTreeSet<u32>> = SOME.into_iter().collect();
+
+        let json = serde_json::to_string(&victim).unwrap();
+
+        assert_eq!("[1,2,3,5,7,11,13]", json);
+
+        let victim: IndexOrdSet<BTreeSet<u32>> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(&SOME[..], &victim.iter().collect::<Vec<_>>()[..]);
+    }
+
+    #[test]
+    fn deserialize_unordered() {
+        let victim: IndexOrdSet<BTreeSet<u32>> = serde_json::from_str("[5,1,3]").unwrap();
+
+        assert_eq!(&[1, 3, 5][..], &victim.iter().collect::<Vec<_>>()[..]);
+    }
+} // mod index_ord_set
@@ -3,7 +3,11 @@
 mod index_set {
     use std::collections::HashSet;
 
-    use crate::set::IndexSet;
+    use crate::{
+        chunk::{ArrayChunk, UnsignedChunk},
+        set::IndexSet,
+        vault::{BitmapStore, DynamicChunkStore, OutOfSpanError},
+    };
 
     type Victim = IndexSet<HashSet<u8>>;
 
@@ -38,6 +42,37 @@ mod index_set {
         }
     }
 
+    #[test]
+    fn toggle() {
+        let mut victim: Victim = [1, 2, 3].into_iter().collect();
+
+        assert_eq!(Ok(false), victim.toggle(2));
+        assert_eq!(Ok(true), victim.toggle(42));
+        assert_eq!(Ok(true), victim.toggle(2));
+
+        assert_eq!(4, victim.len());
+        assert!([1, 2, 3, 42].into_iter().all(|i| victim.contains(i)));
+
+        //  Insertion errors are surfaced, leaving the set unmodified.
+        let mut bitmap = IndexSet::<BitmapStore<u64>>::new();
+
+        assert_eq!(Err(OutOfSpanError), bitmap.toggle(3));
+        assert!(bitmap.is_empty());
+    }
+
+    #[test]
+    fn insert_then() {
+        const INDEX: u8 = 42;
+
+        let mut victim = Victim::new();
+
+        assert_eq!(Ok(true), victim.insert_then(INDEX, |new| new));
+        assert_eq!(Ok(false), victim.insert_then(INDEX, |new| new));
+
+        assert!(victim.contains(INDEX));
+        assert_eq!(1, victim.len());
+    }
+
     #[test]
     fn extend() {
         const EMPTY: [u8; 0] = [];
@@ -68,12 +103,107 @@ mod index_set {
             }
         }
     }
+
+    #[test]
+    fn get_or_insert() {
+        let mut victim = Victim::new();
+
+        assert_eq!(Ok(3), victim.get_or_insert(3));
+        assert_eq!(1, victim.len());
+
+        assert_eq!(Ok(3), victim.get_or_insert(3));
+        assert_eq!(1, victim.len());
+
+        assert_eq!(Ok(5), victim.get_or_insert(5));
+        assert_eq!(2, victim.len());
+
+        assert!(victim.contains(3));
+        assert!(victim.contains(5));
+    }
+
+    #[test]
+    fn extend_copied() {
+        const SOME: [u8; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let mut victim = Victim::new();
+
+        victim.extend_copied(SOME.iter());
+
+        assert_eq!(SOME.len(), victim.len());
+
+        for index in SOME {
+            assert!(victim.contains(index));
+        }
+    }
+
+    #[test]
+    fn try_extend() {
+        let mut victim = IndexSet::with_store(BitmapStore::<u64>::with_capacity(64));
+
+        assert_eq!(Ok(()), victim.try_extend([1, 2, 3]));
+        assert_eq!(3, victim.len());
+
+        assert_eq!(Err(OutOfSpanError), victim.try_extend([5, 64, 7]));
+
+        //  Indexes inserted prior to the error remain, those after are not inserted.
+        assert_eq!(4, victim.len());
+        assert!(victim.contains(5));
+        assert!(!victim.contains(7));
+    }
+
+    #[test]
+    fn insert_range() {
+        type DynamicVictim = IndexSet<DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>>>;
+
+        let mut victim = DynamicVictim::new();
+
+        assert_eq!(Ok(4), victim.insert_range(2..=5));
+        assert_eq!(4, victim.len());
+        assert!((0..8).all(|i| victim.contains(i) == (2..=5).contains(&i)));
+
+        //  Only newly inserted indexes are counted.
+        assert_eq!(Ok(3), victim.insert_range(4..9));
+        assert_eq!(7, victim.len());
+
+        //  Empty ranges.
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 9..4;
+
+        assert_eq!(Ok(0), victim.insert_range(reversed));
+        assert_eq!(Ok(0), victim.insert_range(100..100));
+        assert_eq!(7, victim.len());
+    }
+
+    #[test]
+    fn remove_range() {
+        type DynamicVictim = IndexSet<DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>>>;
+
+        let mut victim = DynamicVictim::new();
+
+        victim.insert_range(0..64).unwrap();
+
+        //  Spanning multiple chunks of 16 indexes, including a full one.
+        assert_eq!(30, victim.remove_range(6..36));
+        assert_eq!(34, victim.len());
+        assert!((0..64).all(|i| victim.contains(i) != (6..36).contains(&i)));
+
+        //  Only removed indexes are counted.
+        assert_eq!(4, victim.remove_range(2..=9));
+        assert_eq!(30, victim.remove_range(..));
+        assert!(victim.is_empty());
+    }
 } // mod index_set
 
 mod index_ord_set {
+    use core::ops::{Bound, RangeBounds};
+
     use alloc::collections::BTreeSet;
 
-    use crate::set::IndexOrdSet;
+    use crate::{
+        chunk::{ArrayChunk, UnsignedChunk},
+        set::IndexOrdSet,
+        vault::{BitmapStore, DynamicChunkStore, OutOfSpanError},
+    };
 
     type Victim = IndexOrdSet<BTreeSet<u8>>;
 
@@ -108,6 +238,49 @@ mod index_ord_set {
         }
     }
 
+    #[test]
+    fn toggle() {
+        let mut victim = IndexOrdSet::<DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>>>::new();
+
+        assert_eq!(Ok(true), victim.toggle(3));
+        assert_eq!(Ok(true), victim.toggle(100));
+        assert_eq!(Ok(false), victim.toggle(3));
+        assert_eq!(Ok(true), victim.toggle(4));
+
+        assert_eq!(2, victim.len());
+        assert!(victim.iter().eq([4, 100]));
+
+        let mut victim: Victim = [1, 2, 3].into_iter().collect();
+
+        assert_eq!(Ok(false), victim.toggle(1));
+        assert_eq!(Ok(true), victim.toggle(0));
+        assert!(victim.iter().eq([0, 2, 3]));
+    }
+
+    #[test]
+    fn insert_then() {
+        const INDEX: u8 = 42;
+
+        let mut victim = Victim::new();
+
+        assert_eq!(Ok(true), victim.insert_then(INDEX, |new| new));
+        assert_eq!(Ok(false), victim.insert_then(INDEX, |new| new));
+
+        assert!(victim.contains(INDEX));
+        assert_eq!(1, victim.len());
+    }
+
+    #[test]
+    fn insert_ranked() {
+        let mut victim: Victim = [1, 2, 3, 5, 7].into_iter().collect();
+
+        assert_eq!(Ok((true, 3)), victim.insert_ranked(4));
+        assert_eq!(Ok((false, 5)), victim.insert_ranked(7));
+        assert_eq!(Ok((true, 0)), victim.insert_ranked(0));
+
+        assert!(victim.iter().eq([0, 1, 2, 3, 4, 5, 7]));
+    }
+
     #[test]
     fn extend() {
         const EMPTY: [u8; 0] = [];
@@ -138,12 +311,176 @@ mod index_ord_set {
             }
         }
     }
+
+    #[test]
+    fn remove_range() {
+        const INDEXES: [u8; 10] = [1, 2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+        #[track_caller]
+        fn assert_remove_range<R>(range: R, expected: &[u8])
+        where
+            R: RangeBounds<u8> + Clone,
+        {
+            let mut victim: Victim = INDEXES.into_iter().collect();
+
+            let before = victim.len();
+            let removed = victim.remove_range(range.clone());
+
+            assert_eq!(before - victim.len(), removed);
+            assert_eq!(expected.len(), removed);
+
+            for index in INDEXES {
+                assert_eq!(!expected.contains(&index), victim.contains(index), "{index}");
+            }
+        }
+
+        assert_remove_range(.., &INDEXES);
+        assert_remove_range(0..0, &[]);
+        assert_remove_range(8..11, &[]);
+        assert_remove_range(24.., &[]);
+
+        assert_remove_range(..3, &[1, 2]);
+        assert_remove_range(..=3, &[1, 2, 3]);
+        assert_remove_range(3..13, &[3, 5, 7, 11]);
+        assert_remove_range(3..=13, &[3, 5, 7, 11, 13]);
+        assert_remove_range((Bound::Excluded(3), Bound::Excluded(13)), &[5, 7, 11]);
+        assert_remove_range(12.., &[13, 17, 19, 23]);
+    }
+
+    #[test]
+    fn shrink_bounds() {
+        const INDEXES: [u8; 10] = [1, 2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+        #[track_caller]
+        fn assert_shrink_bounds(lo: u8, hi: u8, expected: &[u8]) {
+            let mut victim: Victim = INDEXES.into_iter().collect();
+
+            let before = victim.len();
+            let removed = victim.shrink_bounds(lo, hi);
+
+            assert_eq!(before - victim.len(), removed);
+            assert_eq!(INDEXES.len() - expected.len(), removed);
+
+            for index in INDEXES {
+                assert_eq!(expected.contains(&index), victim.contains(index), "{index}");
+            }
+        }
+
+        assert_shrink_bounds(0, 30, &INDEXES);
+        assert_shrink_bounds(1, 23, &INDEXES);
+        assert_shrink_bounds(8, 10, &[]);
+        assert_shrink_bounds(24, 30, &[]);
+
+        assert_shrink_bounds(3, 13, &[3, 5, 7, 11, 13]);
+        assert_shrink_bounds(4, 12, &[5, 7, 11]);
+        assert_shrink_bounds(0, 2, &[1, 2]);
+        assert_shrink_bounds(19, 19, &[19]);
+    }
+
+    #[test]
+    fn pop_first() {
+        const PRIMES: [u8; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let mut victim = Victim::from_iter(PRIMES);
+
+        for (i, prime) in PRIMES.into_iter().enumerate() {
+            assert_eq!(PRIMES.len() - i, victim.len());
+            assert_eq!(victim.iter().next(), Some(prime));
+
+            assert_eq!(Some(prime), victim.pop_first());
+        }
+
+        assert!(victim.is_empty());
+        assert_eq!(None, victim.pop_first());
+    }
+
+    #[test]
+    fn pop_last() {
+        const PRIMES: [u8; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let mut victim = Victim::from_iter(PRIMES);
+
+        for (i, prime) in PRIMES.into_iter().rev().enumerate() {
+            assert_eq!(PRIMES.len() - i, victim.len());
+            assert_eq!(victim.iter_rev().next(), Some(prime));
+
+            assert_eq!(Some(prime), victim.pop_last());
+        }
+
+        assert!(victim.is_empty());
+        assert_eq!(None, victim.pop_last());
+    }
+
+    #[test]
+    fn get_or_insert() {
+        let mut victim = Victim::new();
+
+        assert_eq!(Ok(3), victim.get_or_insert(3));
+        assert_eq!(1, victim.len());
+
+        assert_eq!(Ok(3), victim.get_or_insert(3));
+        assert_eq!(1, victim.len());
+
+        assert_eq!(Ok(5), victim.get_or_insert(5));
+        assert_eq!(2, victim.len());
+
+        assert!(victim.contains(3));
+        assert!(victim.contains(5));
+    }
+
+    #[test]
+    fn extend_copied() {
+        const SOME: [u8; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let mut victim = Victim::new();
+
+        victim.extend_copied(SOME.iter());
+
+        assert_eq!(SOME.len(), victim.len());
+
+        for index in SOME {
+            assert!(victim.contains(index));
+        }
+    }
+
+    #[test]
+    fn try_extend() {
+        let mut victim = IndexOrdSet::with_store(BitmapStore::<u64>::with_capacity(64));
+
+        assert_eq!(Ok(()), victim.try_extend([1, 2, 3]));
+        assert_eq!(3, victim.len());
+
+        assert_eq!(Err(OutOfSpanError), victim.try_extend([5, 64, 7]));
+
+        //  Indexes inserted prior to the error remain, those after are not inserted.
+        assert_eq!(4, victim.len());
+        assert!(victim.contains(5));
+        assert!(!victim.contains(7));
+    }
+
+    #[test]
+    fn insert_range() {
+        type DynamicVictim = IndexOrdSet<DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>>>;
+
+        let mut victim = DynamicVictim::new();
+
+        //  Spanning multiple chunks of 16 indexes, including a full one.
+        assert_eq!(Ok(40), victim.insert_range((Bound::Excluded(9), Bound::Included(49))));
+        assert_eq!(40, victim.len());
+
+        assert_eq!(Some(10), victim.first());
+        assert_eq!(Some(49), victim.last());
+        assert!(victim.iter().eq(10..50));
+    }
 } // mod index_ord_set
 
 mod index_chunked_set {
+    use core::ops::{Bound, RangeBounds};
+
     use crate::{
         chunk::{ArrayChunk, UnsignedChunk},
         set::IndexChunkedSet,
+        vault::DynamicChunkStore,
     };
 
     type Victim = IndexChunkedSet<ArrayChunk<UnsignedChunk<u8>, 8>>;
@@ -160,6 +497,28 @@ mod index_chunked_set {
         assert_eq!(0, victim.len());
     }
 
+    #[test]
+    fn is_empty_large_capacity() {
+        let mut victim = IndexChunkedSet::<DynamicChunkStore<ArrayChunk<UnsignedChunk<u64>, 64>>>::with_capacity(256);
+
+        assert!(victim.is_empty());
+
+        //  Leaves a trail of allocated, yet empty, chunks behind.
+        victim.insert(1 << 20).unwrap();
+        victim.remove(1 << 20);
+
+        assert!(victim.is_empty());
+        assert_eq!(0, victim.len());
+
+        let mut victim = IndexChunkedSet::<ArrayChunk<UnsignedChunk<u64>, 64>>::new();
+
+        assert!(victim.is_empty());
+
+        victim.insert(4_095).unwrap();
+
+        assert!(!victim.is_empty());
+    }
+
     #[test]
     fn insert_remove() {
         const INDEX: u16 = 42;
@@ -179,6 +538,48 @@ mod index_chunked_set {
         }
     }
 
+    #[test]
+    fn toggle() {
+        let mut victim: Victim = [1, 2, 3, 60].into_iter().collect();
+
+        assert_eq!(Ok(false), victim.toggle(60));
+        assert_eq!(Ok(true), victim.toggle(63));
+        assert_eq!(Ok(false), victim.toggle(1));
+
+        assert_eq!(3, victim.len());
+        assert!(victim.iter().eq([2, 3, 63]));
+    }
+
+    #[test]
+    fn insert_then() {
+        const INDEX: u16 = 42;
+
+        let mut victim = Victim::new();
+
+        assert_eq!(Ok(true), victim.insert_then(INDEX, |new| new));
+        assert_eq!(Ok(false), victim.insert_then(INDEX, |new| new));
+
+        assert!(victim.contains(INDEX));
+        assert_eq!(1, victim.len());
+    }
+
+    #[test]
+    fn insert_ranked() {
+        let mut victim: Victim = [1, 2, 3, 20, 60].into_iter().collect();
+
+        assert_eq!(Ok((true, 4)), victim.insert_ranked(40));
+        assert_eq!(Ok((false, 3)), victim.insert_ranked(20));
+        assert_eq!(Ok((true, 6)), victim.insert_ranked(63));
+
+        let mut victim = IndexChunkedSet::<DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>, u32>>::new();
+
+        assert_eq!(Ok((true, 0)), victim.insert_ranked(1_000));
+        assert_eq!(Ok((true, 0)), victim.insert_ranked(17));
+        assert_eq!(Ok((true, 2)), victim.insert_ranked(40_000));
+        assert_eq!(Ok((true, 1)), victim.insert_ranked(999));
+        assert_eq!(Ok((false, 2)), victim.insert_ranked(1_000));
+    }
+
     #[test]
     fn extend() {
         const EMPTY: [u16; 0] = [];
@@ -209,4 +610,116 @@ mod index_chunked_set {
             }
         }
     }
+
+    #[test]
+    fn remove_range() {
+        const INDEXES: [u16; 10] = [1, 2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+        #[track_caller]
+        fn assert_remove_range<R>(range: R, expected: &[u16])
+        where
+            R: RangeBounds<u16> + Clone,
+        {
+            let mut victim: Victim = INDEXES.into_iter().collect();
+
+            let before = victim.len();
+            let removed = victim.remove_range(range.clone());
+
+            assert_eq!(before - victim.len(), removed);
+            assert_eq!(expected.len(), removed);
+
+            for index in INDEXES {
+                assert_eq!(!expected.contains(&index), victim.contains(index), "{index}");
+            }
+        }
+
+        assert_remove_range(.., &INDEXES);
+        assert_remove_range(0..0, &[]);
+        assert_remove_range(8..11, &[]);
+        assert_remove_range(24.., &[]);
+
+        assert_remove_range(..3, &[1, 2]);
+        assert_remove_range(..=3, &[1, 2, 3]);
+        assert_remove_range(3..13, &[3, 5, 7, 11]);
+        assert_remove_range(3..=13, &[3, 5, 7, 11, 13]);
+        assert_remove_range((Bound::Excluded(3), Bound::Excluded(13)), &[5, 7, 11]);
+        assert_remove_range(12.., &[13, 17, 19, 23]);
+    }
+
+    #[test]
+    fn shrink_bounds() {
+        const INDEXES: [u16; 10] = [1, 2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+        #[track_caller]
+        fn assert_shrink_bounds(lo: u16, hi: u16, expected: &[u16]) {
+            let mut victim: Victim = INDEXES.into_iter().collect();
+
+            let before = victim.len();
+            let removed = victim.shrink_bounds(lo, hi);
+
+            assert_eq!(before - victim.len(), removed);
+            assert_eq!(INDEXES.len() - expected.len(), removed);
+
+            for index in INDEXES {
+                assert_eq!(expected.contains(&index), victim.contains(index), "{index}");
+            }
+        }
+
+        assert_shrink_bounds(0, 30, &INDEXES);
+        assert_shrink_bounds(1, 23, &INDEXES);
+        assert_shrink_bounds(8, 10, &[]);
+        assert_shrink_bounds(24, 30, &[]);
+
+        assert_shrink_bounds(3, 13, &[3, 5, 7, 11, 13]);
+        assert_shrink_bounds(4, 12, &[5, 7, 11]);
+        assert_shrink_bounds(0, 2, &[1, 2]);
+        assert_shrink_bounds(19, 19, &[19]);
+    }
+
+    #[test]
+    fn get_or_insert() {
+        let mut victim = Victim::new();
+
+        assert_eq!(Ok(3), victim.get_or_insert(3));
+        assert_eq!(1, victim.len());
+
+        assert_eq!(Ok(3), victim.get_or_insert(3));
+        assert_eq!(1, victim.len());
+
+        assert_eq!(Ok(5), victim.get_or_insert(5));
+        assert_eq!(2, victim.len());
+
+        assert!(victim.contains(3));
+        assert!(victim.contains(5));
+    }
+
+    #[test]
+    fn extend_copied() {
+        const SOME: [u16; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let mut victim = Victim::new();
+
+        victim.extend_copied(SOME.iter());
+
+        assert_eq!(SOME.len(), victim.len());
+
+        for index in SOME {
+            assert!(victim.contains(index));
+        }
+    }
+
+    #[test]
+    fn insert_range() {
+        let mut victim = Victim::new();
+
+        assert_eq!(Ok(4), victim.insert_range(2..=5));
+        assert!(victim.iter().eq(2..=5));
+
+        //  Spanning multiple sub-chunks, including a full one.
+        assert_eq!(Ok(20), victim.insert_range(6..26));
+        assert!(victim.iter().eq(2..26));
+
+        assert_eq!(Ok(2 + 64 - 26), victim.insert_range(..));
+        assert_eq!(64, victim.len());
+    }
 } // mod index_chunked_set
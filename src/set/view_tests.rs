@@ -54,12 +54,61 @@ mod index_ord_set {
             assert!(victim.contains(1));
         }
     }
+
+    #[test]
+    fn first_last() {
+        const EMPTY: [u8; 0] = [];
+        const SOME: [u8; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        {
+            let victim: Victim = EMPTY.into_iter().collect();
+
+            assert_eq!(None, victim.first());
+            assert_eq!(None, victim.last());
+            assert_eq!(None, victim.bounds());
+        }
+
+        {
+            let victim: Victim = SOME.into_iter().collect();
+
+            assert_eq!(Some(1), victim.first());
+            assert_eq!(Some(13), victim.last());
+
+            assert_eq!(victim.iter().next(), victim.first());
+            assert_eq!(victim.iter_rev().next(), victim.last());
+
+            assert_eq!(Some((1, 13)), victim.bounds());
+        }
+    }
+
+    #[test]
+    fn rank_select() {
+        const SOME: [u8; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let victim: Victim = SOME.into_iter().collect();
+
+        for index in 0..=u8::MAX {
+            let expected = SOME.iter().filter(|&&i| i < index).count();
+
+            assert_eq!(expected, victim.rank(index), "{index}");
+        }
+
+        for n in 0..10 {
+            assert_eq!(SOME.get(n).copied(), victim.select(n), "{n}");
+        }
+
+        let empty = Victim::new();
+
+        assert_eq!(0, empty.rank(u8::MAX));
+        assert_eq!(None, empty.select(0));
+    }
 } // mod index_ord_set
 
 mod index_chunked_set {
     use crate::{
         chunk::{ArrayChunk, UnsignedChunk},
         set::IndexChunkedSet,
+        vault::DynamicChunkStore,
     };
 
     type Victim = IndexChunkedSet<ArrayChunk<UnsignedChunk<u8>, 2>>;
@@ -83,4 +132,64 @@ mod index_chunked_set {
             assert!(victim.contains(1));
         }
     }
+
+    #[test]
+    fn populated_chunks() {
+        let victim = Victim::new();
+
+        assert_eq!(0, victim.populated_chunks());
+
+        let victim: Victim = [1, 2, 13].into_iter().collect();
+
+        assert_eq!(2, victim.populated_chunks());
+
+        //  Many chunks of capacity, only 2 of which are populated.
+        let mut victim = IndexChunkedSet::<DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>>>::with_capacity(100);
+
+        victim.insert(70_000).unwrap();
+        victim.insert(3).unwrap();
+        victim.insert(4).unwrap();
+        victim.insert(1_000).unwrap();
+        victim.remove(70_000);
+
+        assert_eq!(3, victim.len());
+        assert_eq!(2, victim.populated_chunks());
+    }
+
+    #[test]
+    fn rank_select() {
+        fn check<S>(victim: &IndexChunkedSet<S>, indexes: &[u16])
+        where
+            S: crate::index::IndexOrderedChunked<Index: From<u16> + core::fmt::Debug> + crate::index::IndexOrderedStore,
+            S::Chunk: crate::index::IndexForward,
+        {
+            for index in 0..1_024 {
+                let expected = indexes.iter().filter(|&&i| i < index).count();
+
+                assert_eq!(expected, victim.rank(index.into()), "{index}");
+            }
+
+            for n in 0..indexes.len() + 4 {
+                assert_eq!(indexes.get(n).map(|&i| i.into()), victim.select(n), "{n}");
+            }
+        }
+
+        const SOME: [u16; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        check(&Victim::new(), &[]);
+        check(&SOME.into_iter().collect::<Victim>(), &SOME);
+
+        //  Spanning multiple chunks, with empty chunks both in-between and trailing.
+        const SPREAD: [u16; 11] = [1, 2, 3, 5, 7, 11, 13, 255, 256, 300, 511];
+
+        let mut victim = IndexChunkedSet::<DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>>>::with_capacity(8);
+
+        check(&victim, &[]);
+
+        victim.insert(900).unwrap();
+        victim.remove(900);
+        victim.extend(SPREAD.map(u64::from));
+
+        check(&victim, &SPREAD);
+    }
 } // mod index_chunked_set
@@ -4,7 +4,11 @@
 //!
 //! #   Limitation
 //!
-//! For ease of implementation, only _vaults_, ie victims implementing `IndexVault`, can be tested with this test-suite.
+//! For ease of implementation, only _vaults_, ie victims implementing `IndexVault`, can be tested with most of this
+//! test-suite.
+//!
+//! Stores which are not vaults, such as fixed-capacity stores silently dropping indexes outside their span, can still be
+//! tested for their `IndexStore` semantics with `test_index_store_lossy!`, see `IndexTesterLossy`.
 
 mod index_backward;
 mod index_backward_chunked;
@@ -15,12 +19,14 @@ mod index_forward;
 mod index_forward_chunked;
 mod index_forward_chunked_not;
 mod index_forward_not;
+mod index_ordered_store;
 mod index_store;
+mod index_store_lossy;
 mod index_view;
 mod index_view_chunked;
 mod index_view_not;
 
-use crate::index::IndexVault;
+use crate::index::{IndexStore, IndexVault};
 
 pub use index_backward::TestIndexBackward;
 pub use index_backward_chunked::TestIndexBackwardChunked;
@@ -31,7 +37,9 @@ pub use index_forward::TestIndexForward;
 pub use index_forward_chunked::TestIndexForwardChunked;
 pub use index_forward_chunked_not::TestIndexForwardChunkedNot;
 pub use index_forward_not::TestIndexForwardNot;
+pub use index_ordered_store::TestIndexOrderedStore;
 pub use index_store::TestIndexStore;
+pub use index_store_lossy::TestIndexStoreLossy;
 pub use index_view::TestIndexView;
 pub use index_view_chunked::TestIndexViewChunked;
 pub use index_view_not::TestIndexViewNot;
@@ -75,3 +83,34 @@ pub trait IndexTesterNot: IndexTester {
     /// Equivalent to `let victim: Victim = indexes.iter().map(|i| Self::map(*i)).collect()`.
     fn victim_not(indexes: &[u8]) -> Self::Victim;
 }
+
+/// A trait to generate a store, which need not be a vault.
+///
+/// The victim may silently drop indexes outside its span, but SHALL retain all indexes in `0..=upper_bound()`.
+pub trait IndexTesterLossy {
+    /// Index of the victim.
+    type Index: Copy + Eq + Ord;
+
+    /// Actual victim.
+    type Victim: IndexStore<Index = Self::Index>;
+
+    /// Returns the maximum value of `u8` which is guaranteed to be retained.
+    ///
+    /// This value should be at least 7.
+    fn upper_bound() -> u8;
+
+    /// Creates a victim containing the given indexes.
+    fn victim(indexes: &[u8]) -> Self::Victim;
+
+    /// Maps a number to an index, arbitrarily.
+    ///
+    /// This operation should succeed for all `i` in `0..=Self::upper_bound()`, and may panic otherwise.
+    ///
+    /// This operation should be a bijection between input and output.
+    fn index(i: u8) -> Self::Index;
+
+    /// Returns an index which the victim may silently drop on insertion, if any.
+    ///
+    /// The index should be distinct from all indexes in `0..=Self::upper_bound()`.
+    fn lossy_index() -> Option<Self::Index>;
+}
@@ -40,6 +40,16 @@ macro_rules! test_index_backward {
                 TestSuite::nth_before();
             }
 
+            #[test]
+            fn last_at() {
+                TestSuite::last_at();
+            }
+
+            #[test]
+            fn bounds() {
+                TestSuite::bounds();
+            }
+
             #[cfg(feature = "nightly")]
             #[test]
             fn try_fold_before_all() {
@@ -146,6 +156,30 @@ where
         assert!(Err(non_zero(1)) == victim.nth_before(0, indexes[0]));
     }
 
+    /// Checks that a non-empty victim seeks the last index not after the given one.
+    pub fn last_at() {
+        const INDEXES: [u8; 5] = [1, 2, 3, 5, 7];
+
+        let victim = T::victim(&INDEXES);
+
+        for to in 0..=T::upper_bound() {
+            let expected = INDEXES.iter().rev().find(|&&i| i <= to).map(|&i| T::index(i));
+
+            assert!(expected == victim.last_at(T::index(to)), "{to}");
+        }
+
+        assert!(T::victim(&[]).last_at(T::index(0)).is_none());
+    }
+
+    /// Checks that `bounds` returns the first and last indexes, if any.
+    pub fn bounds() {
+        const PRIMES: [u8; 4] = [1, 2, 3, 5];
+
+        assert!(T::victim(&PRIMES).bounds() == Some((T::index(1), T::index(5))));
+        assert!(T::victim(&[3]).bounds() == Some((T::index(3), T::index(3))));
+        assert!(T::victim(&[]).bounds().is_none());
+    }
+
     /// Checks that a non-empty victim folds all the items in order.
     #[cfg(feature = "nightly")]
     pub fn try_fold_before_all() {
@@ -35,11 +35,21 @@ macro_rules! test_index_forward {
                 TestSuite::next_after();
             }
 
+            #[test]
+            fn next_after_not_contained() {
+                TestSuite::next_after_not_contained();
+            }
+
             #[test]
             fn nth_after() {
                 TestSuite::nth_after();
             }
 
+            #[test]
+            fn first_at() {
+                TestSuite::first_at();
+            }
+
             #[cfg(feature = "nightly")]
             #[test]
             fn try_fold_after_all() {
@@ -113,6 +123,21 @@ where
         assert!(victim.next_after(indexes[indexes.len() - 1]).is_none());
     }
 
+    /// Checks that `next_after` returns the smallest index greater than a non-contained index, as per `IndexOrdered`.
+    pub fn next_after_not_contained() {
+        const INDEXES: [u8; 3] = [1, 3, 5];
+
+        let victim = T::victim(&INDEXES);
+
+        assert!(Some(T::index(3)) == victim.next_after(T::index(2)));
+
+        for i in 0..=T::upper_bound() {
+            let expected = INDEXES.iter().find(|&&index| index > i).map(|&index| T::index(index));
+
+            assert!(expected == victim.next_after(T::index(i)), "{i}");
+        }
+    }
+
     /// Checks that a non-empty victim returns the n-th index as appropriate.
     pub fn nth_after() {
         const INDEXES: [u8; 5] = [1, 2, 3, 5, 7];
@@ -146,6 +171,21 @@ where
         assert!(Err(non_zero(1)) == victim.nth_after(0, indexes[4]));
     }
 
+    /// Checks that a non-empty victim seeks the first index not before the given one.
+    pub fn first_at() {
+        const INDEXES: [u8; 5] = [1, 2, 3, 5, 7];
+
+        let victim = T::victim(&INDEXES);
+
+        for from in 0..=T::upper_bound() {
+            let expected = INDEXES.iter().find(|&&i| i >= from).map(|&i| T::index(i));
+
+            assert!(expected == victim.first_at(T::index(from)), "{from}");
+        }
+
+        assert!(T::victim(&[]).first_at(T::index(0)).is_none());
+    }
+
     /// Checks that a non-empty victim folds all the items in order.
     #[cfg(feature = "nightly")]
     pub fn try_fold_after_all() {
@@ -0,0 +1,101 @@
+//! Test suite for the `IndexOrderedStore` trait.
+
+use core::marker::PhantomData;
+
+use alloc::collections::BTreeSet;
+
+use crate::index::{IndexOrderedStore, IndexView};
+
+use super::IndexTester;
+
+/// Tests that the `$victim` correctly implements the `IndexOrderedStore` trait.
+#[macro_export]
+macro_rules! test_index_ordered_store {
+    ($tester:ident) => {
+        mod test_index_ordered_store {
+            use super::$tester;
+
+            type TestSuite = $crate::test::TestIndexOrderedStore<$tester>;
+
+            #[test]
+            fn validate() {
+                TestSuite::validate();
+            }
+
+            #[test]
+            fn rank_empty() {
+                TestSuite::rank_empty();
+            }
+
+            #[test]
+            fn rank_non_empty() {
+                TestSuite::rank_non_empty();
+            }
+
+            #[test]
+            fn insert_ranked() {
+                TestSuite::insert_ranked();
+            }
+        } // mod test_index_ordered_store
+    };
+}
+
+/// Test suite for the `IndexOrderedStore` trait.
+pub struct TestIndexOrderedStore<T>(PhantomData<T>);
+
+impl<T> TestIndexOrderedStore<T>
+where
+    T: IndexTester<Victim: IndexOrderedStore>,
+{
+    const MINIMUM_UPPER_BOUND: u8 = 7;
+
+    /// Validates `T` itself.
+    pub fn validate() {
+        assert!(
+            T::upper_bound() >= Self::MINIMUM_UPPER_BOUND,
+            "{} < {}",
+            T::upper_bound(),
+            Self::MINIMUM_UPPER_BOUND
+        );
+    }
+
+    /// Checks that the rank of any index in an empty victim is 0.
+    pub fn rank_empty() {
+        let victim = T::victim(&[]);
+
+        for i in 0..=T::upper_bound() {
+            assert_eq!(0, victim.rank(T::index(i)), "{i}");
+        }
+    }
+
+    /// Checks that the rank of any index, contained or not, is the number of indexes strictly less than it.
+    pub fn rank_non_empty() {
+        const INDEXES: [u8; 5] = [1, 2, 3, 5, 7];
+
+        let victim = T::victim(&INDEXES);
+
+        for i in 0..=T::upper_bound() {
+            let expected = INDEXES.iter().filter(|&&index| index < i).count();
+
+            assert_eq!(expected, victim.rank(T::index(i)), "{i}");
+        }
+    }
+
+    /// Checks that `insert_ranked` inserts, and returns the rank of the index after insertion.
+    pub fn insert_ranked() {
+        const INDEXES: [u8; 7] = [5, 1, 7, 3, 1, 0, 5];
+
+        let mut victim = T::victim(&[]);
+        let mut reference = BTreeSet::new();
+
+        for i in INDEXES {
+            let inserted = reference.insert(i);
+            let rank = reference.range(..i).count();
+
+            assert_eq!((inserted, rank), victim.insert_ranked(T::index(i)).unwrap(), "{i}");
+            assert!(victim.contains(T::index(i)), "{i}");
+        }
+
+        assert_eq!(reference.len(), victim.len());
+    }
+}
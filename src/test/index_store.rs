@@ -2,6 +2,8 @@
 
 use core::marker::PhantomData;
 
+use alloc::collections::BTreeSet;
+
 use crate::index::{IndexStore, IndexView};
 
 use super::IndexTester;
@@ -29,6 +31,21 @@ macro_rules! test_index_store {
             fn insert_remove() {
                 TestSuite::insert_remove();
             }
+
+            #[test]
+            fn toggle() {
+                TestSuite::toggle();
+            }
+
+            #[test]
+            fn replace_with() {
+                TestSuite::replace_with();
+            }
+
+            #[test]
+            fn insert_all() {
+                TestSuite::insert_all();
+            }
         } // test_index_store
     };
 }
@@ -105,4 +122,91 @@ where
             assert!(!victim.remove(index), "{i}");
         }
     }
+
+    /// Checks that toggling an index flips whether it is contained, without affecting any other index.
+    pub fn toggle() {
+        const INDEXES: [u8; 5] = [1, 2, 3, 5, 7];
+
+        let mut victim = T::victim(&INDEXES);
+
+        for i in 0..=T::upper_bound() {
+            let index = T::index(i);
+            let contained = INDEXES.contains(&i);
+
+            assert_eq!(!contained, victim.toggle(index).unwrap(), "{i}");
+            assert_eq!(!contained, victim.contains(index), "{i}");
+
+            let expected = if contained {
+                INDEXES.len() - 1
+            } else {
+                INDEXES.len() + 1
+            };
+
+            assert_eq!(expected, victim.len(), "{i}");
+
+            assert_eq!(contained, victim.toggle(index).unwrap(), "{i}");
+            assert_eq!(contained, victim.contains(index), "{i}");
+            assert_eq!(INDEXES.len(), victim.len(), "{i}");
+        }
+    }
+
+    /// Checks that `replace_with` passes the current membership, and applies the returned one.
+    pub fn replace_with() {
+        const INDEXES: [u8; 5] = [1, 2, 3, 5, 7];
+
+        let mut victim = T::victim(&INDEXES);
+
+        for i in 0..=T::upper_bound() {
+            let index = T::index(i);
+            let contained = INDEXES.contains(&i);
+
+            //  Keep as is.
+            let mut seen = None;
+
+            assert!(!victim.replace_with(index, |c| *seen.insert(c)).unwrap(), "{i}");
+            assert_eq!(Some(contained), seen, "{i}");
+            assert_eq!(contained, victim.contains(index), "{i}");
+            assert_eq!(INDEXES.len(), victim.len(), "{i}");
+
+            //  Flip.
+            assert!(victim.replace_with(index, |c| !c).unwrap(), "{i}");
+            assert_eq!(!contained, victim.contains(index), "{i}");
+
+            let expected = if contained {
+                INDEXES.len() - 1
+            } else {
+                INDEXES.len() + 1
+            };
+
+            assert_eq!(expected, victim.len(), "{i}");
+
+            //  Flip back, unconditionally.
+            assert!(victim.replace_with(index, |_| contained).unwrap(), "{i}");
+            assert_eq!(contained, victim.contains(index), "{i}");
+            assert_eq!(INDEXES.len(), victim.len(), "{i}");
+        }
+    }
+
+    /// Checks that `insert_all` inserts all indexes of the other store, and counts only the newly inserted ones.
+    pub fn insert_all() {
+        const PRIMES: [u8; 3] = [2, 3, 5];
+        const EVENS: [u8; 4] = [0, 2, 4, 6];
+
+        let mut victim = T::victim(&PRIMES);
+
+        let evens: BTreeSet<_> = EVENS.into_iter().map(T::index).collect();
+
+        assert_eq!(3, victim.insert_all(&evens).unwrap());
+        assert_eq!(6, victim.len());
+
+        for i in 0..=T::upper_bound() {
+            let expected = PRIMES.contains(&i) || EVENS.contains(&i);
+
+            assert_eq!(expected, victim.contains(T::index(i)), "{i}");
+        }
+
+        assert_eq!(0, victim.insert_all(&evens).unwrap());
+        assert_eq!(0, victim.insert_all(&BTreeSet::new()).unwrap());
+        assert_eq!(6, victim.len());
+    }
 }
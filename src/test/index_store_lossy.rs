@@ -0,0 +1,153 @@
+//! Test suite for the `IndexStore` trait, for stores which need not be vaults.
+
+use core::marker::PhantomData;
+
+use crate::index::{IndexStore, IndexView};
+
+use super::IndexTesterLossy;
+
+/// Tests that the `$victim` correctly implements the `IndexStore` trait, without requiring `IndexVault`.
+#[macro_export]
+macro_rules! test_index_store_lossy {
+    ($tester:ident) => {
+        mod test_index_store_lossy {
+            use super::$tester;
+
+            type TestSuite = $crate::test::TestIndexStoreLossy<$tester>;
+
+            #[test]
+            fn validate() {
+                TestSuite::validate();
+            }
+
+            #[test]
+            fn clear() {
+                TestSuite::clear();
+            }
+
+            #[test]
+            fn insert_remove() {
+                TestSuite::insert_remove();
+            }
+
+            #[test]
+            fn insert_all() {
+                TestSuite::insert_all();
+            }
+
+            #[test]
+            fn insert_lossy() {
+                TestSuite::insert_lossy();
+            }
+        } // test_index_store_lossy
+    };
+}
+
+/// Test suite for the `IndexStore` traits, for stores which need not be vaults.
+pub struct TestIndexStoreLossy<T>(PhantomData<T>);
+
+impl<T> TestIndexStoreLossy<T>
+where
+    T: IndexTesterLossy,
+{
+    const MINIMUM_UPPER_BOUND: u8 = 7;
+
+    /// Validates `T` itself.
+    pub fn validate() {
+        assert!(
+            T::upper_bound() >= Self::MINIMUM_UPPER_BOUND,
+            "{} < {}",
+            T::upper_bound(),
+            Self::MINIMUM_UPPER_BOUND
+        );
+
+        if let Some(lossy) = T::lossy_index() {
+            assert!((0..=T::upper_bound()).all(|i| T::index(i) != lossy));
+        }
+    }
+
+    /// Checks that `clear` makes an instance empty, whether it was empty or not.
+    pub fn clear() {
+        const INDEXES: [u8; 5] = [1, 2, 3, 5, 7];
+
+        for indexes in [&[][..], &INDEXES[..]] {
+            let mut victim = T::victim(indexes);
+
+            victim.clear();
+
+            assert!(victim.is_empty(), "{indexes:?}");
+            assert_eq!(0, victim.len(), "{indexes:?}");
+
+            for i in 0..=T::upper_bound() {
+                assert!(!victim.contains(T::index(i)), "{indexes:?}: {i}");
+            }
+        }
+    }
+
+    /// Checks that an index within span is not contained by default, is contained after being inserted, and is no
+    /// longer contained after being removed.
+    pub fn insert_remove() {
+        const INDEX: u8 = 5;
+
+        let mut victim = T::victim(&[]);
+
+        for i in 0..3 {
+            let index = T::index(INDEX);
+
+            assert!(!victim.contains(index), "{i}");
+
+            assert!(victim.insert(index).unwrap(), "{i}");
+            assert!(!victim.insert(index).unwrap(), "{i}");
+
+            assert!(victim.contains(index), "{i}");
+            assert_eq!(1, victim.len(), "{i}");
+
+            assert!(victim.remove(index), "{i}");
+            assert!(!victim.remove(index), "{i}");
+
+            assert!(!victim.contains(index), "{i}");
+            assert_eq!(0, victim.len(), "{i}");
+        }
+    }
+
+    /// Checks that all indexes within span are retained, without affecting any other index.
+    pub fn insert_all() {
+        let mut victim = T::victim(&[]);
+
+        for i in 0..=T::upper_bound() {
+            assert!(victim.insert(T::index(i)).unwrap(), "{i}");
+
+            assert_eq!(usize::from(i) + 1, victim.len(), "{i}");
+
+            for j in 0..=T::upper_bound() {
+                assert_eq!(j <= i, victim.contains(T::index(j)), "{i}: {j}");
+            }
+        }
+    }
+
+    /// Checks that inserting an index which may be dropped is consistent, and does not affect any other index.
+    pub fn insert_lossy() {
+        const INDEXES: [u8; 5] = [1, 2, 3, 5, 7];
+
+        let Some(lossy) = T::lossy_index() else { return };
+
+        let mut victim = T::victim(&INDEXES);
+
+        //  Whether dropped, or not, the store shall report it consistently.
+        if let Ok(inserted) = victim.insert(lossy) {
+            assert_eq!(inserted, victim.contains(lossy));
+        }
+
+        let retained = usize::from(victim.contains(lossy));
+
+        assert_eq!(INDEXES.len() + retained, victim.len());
+
+        for i in 0..=T::upper_bound() {
+            assert_eq!(INDEXES.contains(&i), victim.contains(T::index(i)), "{i}");
+        }
+
+        assert_eq!(retained == 1, victim.remove(lossy));
+        assert!(!victim.contains(lossy));
+        assert_eq!(INDEXES.len(), victim.len());
+    }
+}
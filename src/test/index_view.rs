@@ -29,6 +29,16 @@ macro_rules! test_index_view {
             fn non_empty() {
                 TestSuite::non_empty();
             }
+
+            #[test]
+            fn contains_all() {
+                TestSuite::contains_all();
+            }
+
+            #[test]
+            fn contains_any() {
+                TestSuite::contains_any();
+            }
         } // mod test_index_view
     };
 }
@@ -82,4 +92,50 @@ where
             assert!(!victim.contains(T::index(i)), "{i}");
         }
     }
+
+    /// Checks that `contains_all` returns whether all indexes are contained, even when revisiting indexes.
+    pub fn contains_all() {
+        const INDEXES: [u8; 3] = [0, 3, 6];
+
+        let empty = T::victim(&[]);
+
+        assert!(empty.contains_all([]));
+        assert!(!empty.contains_all([T::index(0)]));
+
+        let victim = T::victim(&INDEXES);
+
+        assert!(victim.contains_all([]));
+        assert!(victim.contains_all(INDEXES.map(T::index)));
+        assert!(victim.contains_all([6, 0, 3, 0, 6].map(T::index)));
+
+        assert!(!victim.contains_all([0, 1].map(T::index)));
+        assert!(!victim.contains_all([6, 0, 5].map(T::index)));
+
+        let upper = T::upper_bound();
+
+        assert_eq!(INDEXES.contains(&upper), victim.contains_all([0, upper].map(T::index)));
+    }
+
+    /// Checks that `contains_any` returns whether any index is contained, even when revisiting indexes.
+    pub fn contains_any() {
+        const INDEXES: [u8; 3] = [0, 3, 6];
+
+        let empty = T::victim(&[]);
+
+        assert!(!empty.contains_any([]));
+        assert!(!empty.contains_any([T::index(0)]));
+
+        let victim = T::victim(&INDEXES);
+
+        assert!(!victim.contains_any([]));
+        assert!(victim.contains_any([6].map(T::index)));
+        assert!(victim.contains_any([1, 2, 4, 5, 3].map(T::index)));
+
+        assert!(!victim.contains_any([1, 2, 4, 5].map(T::index)));
+        assert!(!victim.contains_any([5, 1, 5].map(T::index)));
+
+        let upper = T::upper_bound();
+
+        assert_eq!(INDEXES.contains(&upper), victim.contains_any([upper].map(T::index)));
+    }
 }
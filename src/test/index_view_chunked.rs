@@ -29,6 +29,11 @@ macro_rules! test_index_view_chunked {
             fn get_chunk() {
                 TestSuite::get_chunk();
             }
+
+            #[test]
+            fn contains_with_chunk() {
+                TestSuite::contains_with_chunk();
+            }
         } // mod test_index_view_chunked
     };
 }
@@ -85,4 +90,24 @@ where
             );
         }
     }
+
+    /// Checks that `contains_with_chunk` agrees with `contains`, and returns the chunk of the index.
+    pub fn contains_with_chunk() {
+        const INDEXES: [u8; 7] = [1, 2, 3, 5, 7, 11, 13];
+
+        let victim = T::victim(&INDEXES);
+
+        for i in 0..=T::upper_bound() {
+            let index = T::index(i);
+            let (outer, _) = T::Victim::split(index);
+
+            let chunk = victim.contains_with_chunk(index);
+
+            assert_eq!(victim.contains(index), chunk.is_some(), "{i}");
+
+            if let Some(chunk) = chunk {
+                assert!(Some(chunk) == victim.get_chunk(outer), "{i}");
+            }
+        }
+    }
 }
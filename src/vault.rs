@@ -1,13 +1,64 @@
 //! A collection of index vaults for common needs.
+//!
+//! #   Standard collections
+//!
+//! The index traits are implemented directly on the standard collections, hence no wrapper is necessary:
+//!
+//! -   `BTreeSet<I>`, with the `alloc` feature, implements `IndexStore`, `IndexVault`, and `IndexOrdered` in both
+//!     directions, and is therefore usable as-is in `IndexSet<BTreeSet<u32>>` or `IndexOrdSet<BTreeSet<u32>>`.
+//! -   `HashSet<I, S>`, with the `std` feature, implements `IndexStore` and `IndexVault`, but not `IndexForward`, as
+//!     it cannot resume iteration after a given index. It is usable in `IndexSet<HashSet<u32>>`, but not in
+//!     `IndexOrdSet`, and only as the right-hand side of set operations, unless wrapped in `Sorted`.
+//! -   `HashSetStore<I>`, with the `std` feature, is a hash-based store which does implement `IndexForward`, in
+//!     arbitrary order, and is usable as either side of the set operations of `IndexSet`, but not in `IndexOrdSet`,
+//!     unless wrapped in `Sorted`.
+//! -   `Option<S>` implements the traits of `S`, `None` being a permanently empty store which rejects all insertions
+//!     with `OptionInsertionError::Absent`.
+
+#[cfg(any(feature = "alloc", test))]
+mod bitmap_store;
 
 #[cfg(any(feature = "alloc", test))]
 mod btree_set;
 
+#[cfg(any(feature = "alloc", test))]
+mod compact;
+
+mod concat_store;
+
 #[cfg(any(feature = "alloc", test))]
 mod dynamic_chunk_store;
 
 #[cfg(any(feature = "std", test))]
 mod hash_set;
 
+mod inline_store;
+
+mod option;
+
+#[cfg(any(feature = "alloc", test))]
+mod sorted;
+
+#[cfg(any(feature = "alloc", test))]
+pub use bitmap_store::{BitmapStore, OutOfSpanError};
+
+#[cfg(any(feature = "alloc", test))]
+pub use compact::{CompactChunk, CompactSet, collect_compact};
+
+pub use concat_store::{ConcatInsertionError, ConcatStore};
+
+#[cfg(any(feature = "alloc", test))]
+pub use dynamic_chunk_store::{
+    DynamicChunkAllocator, DynamicChunkIndex, DynamicChunkInnerIndex, DynamicChunkStore, DynamicChunkStore32,
+    DynamicChunkStore64, Global,
+};
+
+#[cfg(any(feature = "std", test))]
+pub use hash_set::HashSetStore;
+
+pub use inline_store::{InlineStore, OutOfCapacityError};
+
+pub use option::OptionInsertionError;
+
 #[cfg(any(feature = "alloc", test))]
-pub use dynamic_chunk_store::DynamicChunkStore;
+pub use sorted::Sorted;
@@ -0,0 +1,440 @@
+//! A fixed-capacity bitmap store.
+
+use core::{marker::PhantomData, ops::Bound};
+
+use alloc::{boxed::Box, vec};
+
+use crate::{
+    index::{
+        IndexBackward, IndexCollection, IndexForward, IndexOrdered, IndexOrderedStore, IndexStore, IndexVault,
+        IndexView, IndexViewCached,
+    },
+    vault::DynamicChunkIndex,
+};
+
+/// A fixed-capacity bitmap store.
+///
+/// The indexes are stored as bits in a flat array of words, sized at construction. Unlike `DynamicChunkStore`, the
+/// store never grows, and attempting to insert an index beyond its capacity fails with `OutOfSpanError`.
+///
+/// The `I` parameter is the type of the indexes, see `DynamicChunkIndex`.
+#[derive(Debug)]
+pub struct BitmapStore<I = u64> {
+    count: usize,
+    words: Box<[u64]>,
+    _index: PhantomData<I>,
+}
+
+impl<I> Clone for BitmapStore<I> {
+    fn clone(&self) -> Self {
+        let count = self.count;
+        let words = self.words.clone();
+        let _index = PhantomData;
+
+        Self { count, words, _index }
+    }
+
+    /// Reuses the words of `self` if they have the same capacity, rather than allocating anew.
+    fn clone_from(&mut self, source: &Self) {
+        self.count = source.count;
+        self.words.clone_from(&source.words);
+    }
+}
+
+/// The error returned when attempting to insert an index beyond the capacity of a `BitmapStore`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutOfSpanError;
+
+impl<I> BitmapStore<I>
+where
+    I: DynamicChunkIndex,
+{
+    /// Creates a new store, able to contain any index strictly less than `capacity`.
+    ///
+    /// The capacity is rounded up to the next multiple of 64.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_words(capacity.div_ceil(WORD_BITS as usize))
+    }
+
+    /// Returns the capacity of the store, that is any index strictly less than the capacity may be inserted.
+    pub fn capacity(&self) -> usize {
+        self.words.len() * WORD_BITS as usize
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: the store will only ever return indexes that have been inserted and have not been removed since.
+unsafe impl<I> IndexView for BitmapStore<I>
+where
+    I: DynamicChunkIndex,
+{
+    type Index = I;
+
+    fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    fn len(&self) -> usize {
+        self.count
+    }
+
+    fn contains(&self, index: Self::Index) -> bool {
+        let (outer, inner) = Self::split(index);
+
+        self.words.get(outer).is_some_and(|word| word & (1 << inner) != 0)
+    }
+}
+
+impl<I> IndexViewCached for BitmapStore<I> where I: DynamicChunkIndex {}
+
+impl<I> IndexCollection for BitmapStore<I>
+where
+    I: DynamicChunkIndex,
+{
+    fn span() -> (Bound<Self::Index>, Bound<Self::Index>) {
+        (Bound::Included(I::MIN), Bound::Unbounded)
+    }
+
+    /// Constructs a new store, with a capacity of 0.
+    ///
+    /// Since the store never grows, no index may ever be inserted in it: prefer `with_capacity` or `with_span`.
+    fn new() -> Self {
+        Self::with_words(0)
+    }
+
+    /// Constructs a new store, with the capacity to contain any index within the given span.
+    ///
+    /// If the span is unbounded, the store has a capacity of 0.
+    fn with_span(range: (Bound<Self::Index>, Bound<Self::Index>)) -> Self {
+        let words = match range.1 {
+            Bound::Included(n) => Self::split(n).0 + 1,
+            Bound::Excluded(n) => {
+                let (outer, inner) = Self::split(n);
+
+                outer + usize::from(inner > 0)
+            }
+            Bound::Unbounded => 0,
+        };
+
+        Self::with_words(words)
+    }
+
+    /// Returns the span of indexes which may be inserted, that is any index strictly less than the capacity.
+    ///
+    /// Since the store never grows, this is also the span of indexes which may ever be inserted.
+    fn capacity_span(&self) -> (Bound<Self::Index>, Bound<Self::Index>) {
+        let end = match self.words.len().checked_sub(1) {
+            Some(last) => Bound::Included(Self::fuse(last, WORD_BITS - 1)),
+            None => Bound::Excluded(I::MIN),
+        };
+
+        (Bound::Included(I::MIN), end)
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: the store will only ever return indexes that have been inserted and have not been removed since.
+unsafe impl<I> IndexStore for BitmapStore<I>
+where
+    I: DynamicChunkIndex,
+{
+    type InsertionError = OutOfSpanError;
+
+    fn clear(&mut self) {
+        if self.count == 0 {
+            return;
+        }
+
+        self.count = 0;
+        self.words.fill(0);
+    }
+
+    fn insert(&mut self, index: Self::Index) -> Result<bool, Self::InsertionError> {
+        let (outer, inner) = Self::split(index);
+
+        let Some(word) = self.words.get_mut(outer) else {
+            return Err(OutOfSpanError);
+        };
+
+        let mask = 1 << inner;
+        let inserted = *word & mask == 0;
+
+        if inserted {
+            *word |= mask;
+            self.count += 1;
+        }
+
+        Ok(inserted)
+    }
+
+    fn remove(&mut self, index: Self::Index) -> bool {
+        let (outer, inner) = Self::split(index);
+
+        //  If out-of-bounds, then there's nothing to remove.
+        let Some(word) = self.words.get_mut(outer) else {
+            return false;
+        };
+
+        let mask = 1 << inner;
+        let removed = *word & mask != 0;
+
+        if removed {
+            *word &= !mask;
+            self.count -= 1;
+        }
+
+        removed
+    }
+}
+
+//  #   Safety
+//
+//  -   NoTheft: the vault will never return that it does not contain an index if the index was inserted, and was
+//      not removed since.
+unsafe impl<I> IndexVault for BitmapStore<I> where I: DynamicChunkIndex {}
+
+//  #   Safety
+//
+//  -   NoDuplicate: the view SHALL never return the same index a second time.
+//  -   NoPhantom: the view SHALL only ever return that it contains an index if the index was inserted, and was not
+//      removed since.
+//  -   NoTheft: if `Self` implements `IndexVault`, the view shall return all indexes.
+unsafe impl<I> IndexForward for BitmapStore<I>
+where
+    I: DynamicChunkIndex,
+{
+    fn first(&self) -> Option<Self::Index> {
+        self.scan_forward(0, u64::MAX)
+    }
+
+    fn next_after(&self, current: Self::Index) -> Option<Self::Index> {
+        let (outer, inner) = Self::split(current);
+
+        if inner + 1 == WORD_BITS as u16 {
+            self.scan_forward(outer.checked_add(1)?, u64::MAX)
+        } else {
+            self.scan_forward(outer, u64::MAX << (inner + 1))
+        }
+    }
+}
+
+//  #   Safety
+//
+//  -   Reverse: the view WILL return indexes in the exact opposite sequence than `IndexForward` does.
+unsafe impl<I> IndexBackward for BitmapStore<I>
+where
+    I: DynamicChunkIndex,
+{
+    fn last(&self) -> Option<Self::Index> {
+        self.scan_backward(usize::MAX, u64::MAX)
+    }
+
+    fn next_before(&self, current: Self::Index) -> Option<Self::Index> {
+        let (outer, inner) = Self::split(current);
+
+        if inner == 0 {
+            self.scan_backward(outer.checked_sub(1)?, u64::MAX)
+        } else {
+            self.scan_backward(outer, (1 << inner) - 1)
+        }
+    }
+}
+
+//  #   Safety
+//
+//  -   Ordered: the `IndexForward` implementation WILL return indexes in strictly increasing order.
+unsafe impl<I> IndexOrdered for BitmapStore<I> where I: DynamicChunkIndex {}
+
+//  #   Safety
+//
+//  -   Rank: the store WILL count the bits of the words before the word of `index`, and the bits below within.
+unsafe impl<I> IndexOrderedStore for BitmapStore<I>
+where
+    I: DynamicChunkIndex,
+{
+    fn rank(&self, index: Self::Index) -> usize {
+        let (outer, inner) = Self::split(index);
+
+        let Some(word) = self.words.get(outer) else {
+            return self.count;
+        };
+
+        let before: usize = self.words[..outer].iter().map(|word| word.count_ones() as usize).sum();
+
+        before + (word & ((1 << inner) - 1)).count_ones() as usize
+    }
+}
+
+//
+//  Implementation
+//
+
+const WORD_BITS: u32 = u64::BITS;
+
+impl<I> BitmapStore<I>
+where
+    I: DynamicChunkIndex,
+{
+    fn with_words(words: usize) -> Self {
+        let count = 0;
+        let words = vec![0; words].into_boxed_slice();
+        let _index = PhantomData;
+
+        Self { count, words, _index }
+    }
+
+    fn fuse(outer: usize, inner: u32) -> I {
+        I::fuse(outer, inner as u16, WORD_BITS)
+    }
+
+    fn split(index: I) -> (usize, u16) {
+        index.split(WORD_BITS)
+    }
+
+    //  Returns the first index set in `self.words[outer] & mask`, or in any subsequent word.
+    fn scan_forward(&self, outer: usize, mask: u64) -> Option<I> {
+        let mut outer = outer;
+        let mut word = self.words.get(outer)? & mask;
+
+        while word == 0 {
+            outer += 1;
+            word = *self.words.get(outer)?;
+        }
+
+        Some(Self::fuse(outer, word.trailing_zeros()))
+    }
+
+    //  Returns the last index set in `self.words[outer] & mask`, or in any preceding word.
+    //
+    //  If `outer` is beyond the last word, starts from the last word, unmasked.
+    fn scan_backward(&self, outer: usize, mask: u64) -> Option<I> {
+        let (mut outer, mut word) = match self.words.get(outer) {
+            Some(word) => (outer, word & mask),
+            None => {
+                let outer = self.words.len().checked_sub(1)?;
+
+                (outer, self.words[outer])
+            }
+        };
+
+        while word == 0 {
+            outer = outer.checked_sub(1)?;
+            word = self.words[outer];
+        }
+
+        Some(Self::fuse(outer, WORD_BITS - 1 - word.leading_zeros()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    macro_rules! test_bitmap_store {
+        ($($i:ident)*) => { $(
+            mod $i {
+                use crate::{index::IndexStore, vault::BitmapStore};
+
+                struct Tester;
+
+                impl crate::test::IndexTester for Tester {
+                    type Index = $i;
+                    type Victim = BitmapStore<$i>;
+
+                    fn upper_bound() -> u8 { 64 * 3 - 1 }
+
+                    fn victim(indexes: &[u8]) -> Self::Victim {
+                        let mut victim = Self::Victim::with_capacity(Self::upper_bound() as usize + 1);
+
+                        for &index in indexes {
+                            victim.insert(index.into()).unwrap();
+                        }
+
+                        victim
+                    }
+
+                    fn index(i: u8) -> Self::Index { i.into() }
+                }
+
+                crate::test_index_view!(Tester);
+                crate::test_index_collection!(Tester);
+                crate::test_index_store!(Tester);
+                crate::test_index_forward!(Tester);
+                crate::test_index_backward!(Tester);
+                crate::test_index_ordered_store!(Tester);
+            }
+        )* };
+    }
+
+    test_bitmap_store!(u32 u64);
+
+    mod capacity {
+        use core::ops::Bound;
+
+        use crate::{
+            index::{IndexBackward, IndexCollection, IndexForward, IndexStore, IndexView},
+            vault::{BitmapStore, OutOfSpanError},
+        };
+
+        #[test]
+        fn with_capacity() {
+            assert_eq!(0, BitmapStore::<u64>::with_capacity(0).capacity());
+            assert_eq!(64, BitmapStore::<u64>::with_capacity(1).capacity());
+            assert_eq!(64, BitmapStore::<u64>::with_capacity(64).capacity());
+            assert_eq!(128, BitmapStore::<u64>::with_capacity(65).capacity());
+        }
+
+        #[test]
+        fn with_span() {
+            let victim = BitmapStore::<u64>::with_span((Bound::Included(0), Bound::Included(64)));
+
+            assert_eq!(128, victim.capacity());
+
+            let victim = BitmapStore::<u64>::with_span((Bound::Included(0), Bound::Excluded(64)));
+
+            assert_eq!(64, victim.capacity());
+
+            let victim = BitmapStore::<u64>::with_span((Bound::Included(0), Bound::Unbounded));
+
+            assert_eq!(0, victim.capacity());
+        }
+
+        #[test]
+        fn insert_out_of_span() {
+            let mut victim = BitmapStore::<u64>::with_capacity(64);
+
+            assert_eq!(Ok(true), victim.insert(63));
+            assert_eq!(Err(OutOfSpanError), victim.insert(64));
+
+            assert_eq!(64, victim.capacity());
+            assert_eq!(1, victim.len());
+            assert!(!victim.contains(64));
+            assert!(!victim.remove(64));
+        }
+
+        #[test]
+        fn scan_across_words() {
+            const INDEXES: [u64; 5] = [0, 63, 64, 130, 255];
+
+            let mut victim = BitmapStore::<u64>::with_capacity(256);
+
+            for index in INDEXES {
+                victim.insert(index).unwrap();
+            }
+
+            assert_eq!(Some(0), victim.first());
+            assert_eq!(Some(255), victim.last());
+
+            for window in INDEXES.windows(2) {
+                assert_eq!(Some(window[1]), victim.next_after(window[0]));
+                assert_eq!(Some(window[0]), victim.next_before(window[1]));
+            }
+
+            assert_eq!(None, victim.next_after(255));
+            assert_eq!(None, victim.next_before(0));
+
+            assert_eq!(None, victim.next_after(1_000));
+            assert_eq!(Some(255), victim.next_before(1_000));
+        }
+    } // mod capacity
+} // mod tests
@@ -1,6 +1,6 @@
 //! Implementation of IndexXxx traits for BTreeSet.
 
-use core::ops::Bound;
+use core::ops::{Add, Bound, RangeBounds, Sub};
 
 #[cfg(feature = "nightly")]
 use core::{num::NonZeroUsize, ops::Try};
@@ -9,7 +9,10 @@ use alloc::collections::BTreeSet;
 
 use crate::{
     Never,
-    index::{IndexBackward, IndexCollection, IndexForward, IndexOrdered, IndexStore, IndexVault, IndexView},
+    index::{
+        IndexBackward, IndexCollection, IndexForward, IndexOrdered, IndexOrderedStore, IndexStore, IndexStoreRange,
+        IndexVault, IndexView, IndexViewCached,
+    },
 };
 
 //  #   Safety
@@ -34,6 +37,8 @@ where
     }
 }
 
+impl<I> IndexViewCached for BTreeSet<I> where I: Copy + Eq + Ord {}
+
 impl<I> IndexCollection for BTreeSet<I>
 where
     I: Copy + Eq + Ord,
@@ -73,6 +78,81 @@ where
     }
 }
 
+//  #   Safety
+//
+//  -   NoPhantom: the store will only ever insert indexes within the given range.
+unsafe impl<I> IndexStoreRange for BTreeSet<I>
+where
+    I: Copy + Eq + Ord + Add<Output = I> + Sub<Output = I> + From<u8>,
+{
+    /// Inserts the indexes of `range` one at a time, stepping from the start of `range`, or 0 if unbounded.
+    ///
+    /// #   Panics
+    ///
+    /// If the end of `range` is unbounded.
+    fn insert_range<R>(&mut self, range: R) -> Result<usize, Self::InsertionError>
+    where
+        R: RangeBounds<Self::Index>,
+    {
+        let start = range.start_bound().cloned();
+        let end = range.end_bound().cloned();
+
+        assert!(!matches!(end, Bound::Unbounded), "cannot insert an unbounded range");
+
+        if is_empty_range(start, end) {
+            return Ok(0);
+        }
+
+        let mut index = match start {
+            Bound::Included(start) => start,
+            Bound::Excluded(start) => start + I::from(1),
+            Bound::Unbounded => I::from(0),
+        };
+
+        let last = match end {
+            Bound::Included(end) => end,
+            Bound::Excluded(end) if end > index => end - I::from(1),
+            _ => return Ok(0),
+        };
+
+        let mut inserted = 0;
+
+        loop {
+            inserted += usize::from(self.insert(index));
+
+            if index == last {
+                break;
+            }
+
+            index = index + I::from(1);
+        }
+
+        Ok(inserted)
+    }
+
+    /// Removes the indexes of `range` one at a time, skipping over the indexes not in the store.
+    fn remove_range<R>(&mut self, range: R) -> usize
+    where
+        R: RangeBounds<Self::Index>,
+    {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+
+        if is_empty_range(range.0, range.1) {
+            return 0;
+        }
+
+        let mut removed = 0;
+
+        while let Some(&index) = self.range(range).next() {
+            self.remove(&index);
+
+            removed += 1;
+        }
+
+        removed
+    }
+}
+
 //  #   Safety
 //
 //  -   NoTheft: the vault will never return that it does not contain an index if the index was inserted, and was
@@ -97,6 +177,10 @@ where
         self.range(forward_range(current)).next().copied()
     }
 
+    fn first_at(&self, from: Self::Index) -> Option<Self::Index> {
+        self.range(from..).next().copied()
+    }
+
     #[cfg(feature = "nightly")]
     fn nth_after(&self, n: usize, current: Self::Index) -> Result<Self::Index, NonZeroUsize> {
         let mut iterator = self.range(forward_range(current));
@@ -131,6 +215,10 @@ where
         self.range(backward_range(current)).next_back().copied()
     }
 
+    fn last_at(&self, to: Self::Index) -> Option<Self::Index> {
+        self.range(..=to).next_back().copied()
+    }
+
     #[cfg(feature = "nightly")]
     fn nth_before(&self, n: usize, current: Self::Index) -> Result<Self::Index, NonZeroUsize> {
         let mut iterator = self.range(backward_range(current));
@@ -155,6 +243,18 @@ where
 //  -   Ordered: the `IndexForward` implementation WILL return indexes in strictly increasing order.
 unsafe impl<I> IndexOrdered for BTreeSet<I> where I: Copy + Eq + Ord {}
 
+//  #   Safety
+//
+//  -   Rank: the store WILL count the indexes strictly less than `index`.
+unsafe impl<I> IndexOrderedStore for BTreeSet<I>
+where
+    I: Copy + Eq + Ord,
+{
+    fn rank(&self, index: Self::Index) -> usize {
+        self.range(..index).count()
+    }
+}
+
 fn backward_range<I>(current: I) -> (Bound<I>, Bound<I>) {
     (Bound::Unbounded, Bound::Excluded(current))
 }
@@ -163,6 +263,18 @@ fn forward_range<I>(current: I) -> (Bound<I>, Bound<I>) {
     (Bound::Excluded(current), Bound::Unbounded)
 }
 
+//  `BTreeSet::range` panics on a range whose start is after its end, or whose bounds are both excluded and equal.
+fn is_empty_range<I>(start: Bound<I>, end: Bound<I>) -> bool
+where
+    I: Ord,
+{
+    match (start, end) {
+        (Bound::Included(start), Bound::Included(end)) => start > end,
+        (Bound::Included(start) | Bound::Excluded(start), Bound::Included(end) | Bound::Excluded(end)) => start >= end,
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::test::IndexTester;
@@ -193,4 +305,75 @@ mod tests {
     crate::test_index_store!(Tester);
     crate::test_index_forward!(Tester);
     crate::test_index_backward!(Tester);
+    crate::test_index_ordered_store!(Tester);
+
+    #[test]
+    fn insert_range() {
+        fn assert_insert_range<R>(range: R, expected: &[u8])
+        where
+            R: RangeBounds<u8>,
+        {
+            let mut victim: BTreeSet<u8> = BTreeSet::from([3, 5]);
+
+            let inserted = victim.insert_range(range).unwrap();
+
+            let mut reference = BTreeSet::from([3, 5]);
+            reference.extend(expected);
+
+            assert_eq!(reference.len() - 2, inserted);
+            assert_eq!(reference, victim);
+        }
+
+        assert_insert_range(0..0, &[]);
+        assert_insert_range(4..4, &[]);
+        assert_insert_range((Bound::Included(4), Bound::Included(3)), &[]);
+        assert_insert_range((Bound::Excluded(4), Bound::Excluded(4)), &[]);
+        assert_insert_range((Bound::Excluded(4), Bound::Excluded(5)), &[]);
+        assert_insert_range((Bound::Excluded(u8::MAX), Bound::Included(u8::MAX)), &[]);
+        assert_insert_range(..0, &[]);
+
+        assert_insert_range(..3, &[0, 1, 2]);
+        assert_insert_range(2..=6, &[2, 3, 4, 5, 6]);
+        assert_insert_range((Bound::Excluded(2), Bound::Excluded(6)), &[3, 4, 5]);
+        assert_insert_range(253..=u8::MAX, &[253, 254, 255]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_range_unbounded() {
+        let mut victim: BTreeSet<u8> = BTreeSet::new();
+
+        let _ = victim.insert_range(3..);
+    }
+
+    #[test]
+    fn remove_range() {
+        const INDEXES: [u8; 6] = [1, 2, 3, 5, 7, 255];
+
+        fn assert_remove_range<R>(range: R, expected: &[u8])
+        where
+            R: RangeBounds<u8>,
+        {
+            let mut victim = BTreeSet::from(INDEXES);
+
+            let removed = victim.remove_range(range);
+
+            let mut reference = BTreeSet::from(INDEXES);
+            reference.retain(|index| !expected.contains(index));
+
+            assert_eq!(expected.len(), removed);
+            assert_eq!(reference, victim);
+        }
+
+        assert_remove_range(4..4, &[]);
+        assert_remove_range((Bound::Included(4), Bound::Included(3)), &[]);
+        assert_remove_range((Bound::Excluded(3), Bound::Excluded(3)), &[]);
+        assert_remove_range((Bound::Excluded(3), Bound::Included(3)), &[]);
+
+        assert_remove_range(.., &INDEXES);
+        assert_remove_range(..3, &[1, 2]);
+        assert_remove_range(3..=7, &[3, 5, 7]);
+        assert_remove_range((Bound::Excluded(3), Bound::Excluded(7)), &[5]);
+        assert_remove_range(6.., &[7, 255]);
+    }
 } // mod tests
@@ -0,0 +1,263 @@
+//! A store picking the most compact representation for a given set of indexes.
+
+use core::{mem, num::NonZeroUsize};
+
+use alloc::collections::BTreeSet;
+
+use crate::{
+    chunk::{ArrayChunk, IndexChunk, UnsignedChunk},
+    index::{IndexBackward, IndexForward, IndexOrdered, IndexStore, IndexVault, IndexView, IndexViewCached},
+    vault::{DynamicChunkIndex, DynamicChunkStore},
+};
+
+/// The chunk used by the chunked representation of `CompactSet`.
+pub type CompactChunk = ArrayChunk<UnsignedChunk<u64>, 4>;
+
+/// A read-only store of indexes, in the most compact representation available.
+///
+/// See `collect_compact`.
+#[derive(Debug)]
+pub enum CompactSet<I> {
+    /// Chunked representation, for dense sets.
+    Chunked(DynamicChunkStore<CompactChunk, I>),
+    /// Sparse representation, for sparse sets.
+    Sparse(BTreeSet<I>),
+}
+
+/// Collects the indexes of `view` into the most compact representation available.
+///
+/// #   Heuristic
+///
+/// A first pass over `view` computes the number of indexes and the maximum index, from which the memory footprint of
+/// each representation is estimated:
+///
+/// -   Chunked: one `CompactChunk` for each chunk up to the one containing the maximum index.
+/// -   Sparse: twice the size of an index per index, to account for the B-Tree node overhead.
+///
+/// The representation with the smallest estimated footprint is then picked, and filled by a second pass over `view`.
+/// The chunked representation is allocated upfront, with as many chunks as estimated.
+///
+/// Both passes are linear in the number of indexes in `view`.
+pub fn collect_compact<V>(view: &V) -> CompactSet<V::Index>
+where
+    V: IndexForward<Index: DynamicChunkIndex>,
+{
+    let mut count = 0;
+    let mut maximum = None;
+
+    let mut cursor = view.first();
+
+    while let Some(index) = cursor {
+        count += 1;
+        maximum = maximum.max(Some(index));
+
+        cursor = view.next_after(index);
+    }
+
+    //  The number of chunks up to, and including, the one containing the maximum index.
+    let chunks = maximum.map_or(0, |maximum| maximum.split(CompactChunk::BITS).0.saturating_add(1));
+
+    let chunked = chunks.saturating_mul(mem::size_of::<CompactChunk>());
+
+    let sparse = count * 2 * mem::size_of::<V::Index>();
+
+    let mut cursor = view.first();
+
+    if chunked <= sparse {
+        //  Pre-allocated, as growing one index at a time may allocate up to twice as many chunks.
+        let mut store = DynamicChunkStore::with_capacity(chunks);
+
+        while let Some(index) = cursor {
+            let _ = store.insert(index);
+
+            cursor = view.next_after(index);
+        }
+
+        CompactSet::Chunked(store)
+    } else {
+        let mut store = BTreeSet::new();
+
+        while let Some(index) = cursor {
+            store.insert(index);
+
+            cursor = view.next_after(index);
+        }
+
+        CompactSet::Sparse(store)
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: as per the variants.
+unsafe impl<I> IndexView for CompactSet<I>
+where
+    I: DynamicChunkIndex,
+{
+    type Index = I;
+
+    fn is_empty(&self) -> bool {
+        match self {
+            Self::Chunked(store) => store.is_empty(),
+            Self::Sparse(store) => store.is_empty(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Chunked(store) => store.len(),
+            Self::Sparse(store) => store.len(),
+        }
+    }
+
+    fn contains(&self, index: Self::Index) -> bool {
+        match self {
+            Self::Chunked(store) => IndexView::contains(store, index),
+            Self::Sparse(store) => IndexView::contains(store, index),
+        }
+    }
+}
+
+impl<I> IndexViewCached for CompactSet<I> where I: DynamicChunkIndex {}
+
+//  #   Safety
+//
+//  -   NoTheft: as per the variants.
+unsafe impl<I> IndexVault for CompactSet<I> where I: DynamicChunkIndex {}
+
+//  #   Safety
+//
+//  -   NoDuplicate: as per the variants.
+//  -   NoPhantom: as per the variants.
+//  -   NoTheft: as per the variants.
+unsafe impl<I> IndexForward for CompactSet<I>
+where
+    I: DynamicChunkIndex,
+{
+    fn first(&self) -> Option<Self::Index> {
+        match self {
+            Self::Chunked(store) => IndexForward::first(store),
+            Self::Sparse(store) => IndexForward::first(store),
+        }
+    }
+
+    fn next_after(&self, current: Self::Index) -> Option<Self::Index> {
+        match self {
+            Self::Chunked(store) => store.next_after(current),
+            Self::Sparse(store) => store.next_after(current),
+        }
+    }
+
+    fn nth_after(&self, n: usize, current: Self::Index) -> Result<Self::Index, NonZeroUsize> {
+        match self {
+            Self::Chunked(store) => store.nth_after(n, current),
+            Self::Sparse(store) => store.nth_after(n, current),
+        }
+    }
+}
+
+//  #   Safety
+//
+//  -   Reverse: as per the variants.
+unsafe impl<I> IndexBackward for CompactSet<I>
+where
+    I: DynamicChunkIndex,
+{
+    fn last(&self) -> Option<Self::Index> {
+        match self {
+            Self::Chunked(store) => IndexBackward::last(store),
+            Self::Sparse(store) => IndexBackward::last(store),
+        }
+    }
+
+    fn next_before(&self, current: Self::Index) -> Option<Self::Index> {
+        match self {
+            Self::Chunked(store) => store.next_before(current),
+            Self::Sparse(store) => store.next_before(current),
+        }
+    }
+
+    fn nth_before(&self, n: usize, current: Self::Index) -> Result<Self::Index, NonZeroUsize> {
+        match self {
+            Self::Chunked(store) => store.nth_before(n, current),
+            Self::Sparse(store) => store.nth_before(n, current),
+        }
+    }
+}
+
+//  #   Safety
+//
+//  -   Ordered: both variants return indexes in strictly increasing order.
+unsafe impl<I> IndexOrdered for CompactSet<I> where I: DynamicChunkIndex {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    fn collect(indexes: &[u64]) -> CompactSet<u64> {
+        let view: BTreeSet<u64> = indexes.iter().copied().collect();
+
+        collect_compact(&view)
+    }
+
+    fn indexes(set: &CompactSet<u64>) -> Vec<u64> {
+        let mut result = Vec::new();
+        let mut cursor = IndexForward::first(set);
+
+        while let Some(index) = cursor {
+            result.push(index);
+
+            cursor = set.next_after(index);
+        }
+
+        result
+    }
+
+    #[test]
+    fn empty() {
+        let victim = collect(&[]);
+
+        assert!(matches!(victim, CompactSet::Chunked(_)));
+        assert!(victim.is_empty());
+    }
+
+    #[test]
+    fn dense() {
+        let expected: Vec<u64> = (0..200).filter(|i| i % 3 != 0).collect();
+
+        let victim = collect(&expected);
+
+        assert!(matches!(victim, CompactSet::Chunked(_)));
+        assert_eq!(expected.len(), victim.len());
+        assert_eq!(expected, indexes(&victim));
+    }
+
+    #[test]
+    fn dense_capacity() {
+        use core::ops::Bound;
+
+        use crate::index::IndexCollection;
+
+        let expected: Vec<u64> = (0..25_600).step_by(2).collect();
+
+        let CompactSet::Chunked(victim) = collect(&expected) else {
+            panic!("expected a chunked representation");
+        };
+
+        //  100 chunks, rather than the 128 chunks growing one index at a time would allocate.
+        assert_eq!((Bound::Included(0), Bound::Included(25_599)), victim.capacity_span());
+    }
+
+    #[test]
+    fn sparse() {
+        let expected = [1, 1_000, 1_000_000, 1_000_000_000];
+
+        let victim = collect(&expected);
+
+        assert!(matches!(victim, CompactSet::Sparse(_)));
+        assert_eq!(expected.len(), victim.len());
+        assert_eq!(&expected[..], &indexes(&victim)[..]);
+    }
+} // mod tests
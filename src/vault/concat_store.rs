@@ -0,0 +1,522 @@
+//! A store composed of two stores, one after the other.
+
+use core::{
+    num::NonZeroUsize,
+    ops::{Add, Bound, Sub},
+};
+
+use crate::index::{
+    IndexBackward, IndexCollection, IndexForward, IndexOrdered, IndexOrderedStore, IndexStore, IndexVault, IndexView,
+    IndexViewCached,
+};
+
+/// A store laying out the indexes of `low` strictly before `split`, and those of `high` from `split` onwards.
+///
+/// The indexes of `high` are offset by `split`, that is the index `split + i` of the store is the index `i` of `high`.
+/// This allows building a wide domain out of two heterogeneous stores, such as an `ArrayChunk` for a hot low range
+/// and a `DynamicChunkStore` for a sparse high range.
+///
+/// The indexes of `high` SHOULD not exceed `MAX - split`, lest the offset overflows.
+///
+/// `new`, `default`, and the other methods of `IndexCollection`, split at the end of the span of `low`, which MUST thus
+/// be bounded, otherwise they panic. A `low` store with an unbounded span, such as an `InlineStore`, is only usable
+/// with an explicit split, see `with_split`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ConcatStore<A, B, I> {
+    low: A,
+    high: B,
+    split: I,
+}
+
+/// The error returned when attempting to insert an index in a `ConcatStore`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConcatInsertionError<L, H> {
+    /// The insertion in the low store failed.
+    Low(L),
+    /// The insertion in the high store failed.
+    High(H),
+}
+
+impl<A, B, I> ConcatStore<A, B, I> {
+    /// Creates a new instance, laying out `low` before `split`, and `high` from `split` onwards.
+    ///
+    /// See `with_split` to create an empty instance, safely.
+    ///
+    /// #   Safety
+    ///
+    /// -   Low: `low` SHALL not contain any index at or above `split`.
+    /// -   High: `high` SHALL not contain any index above `MAX - split`, lest the offset overflows.
+    pub const unsafe fn from_parts(low: A, high: B, split: I) -> Self {
+        Self { low, high, split }
+    }
+
+    /// Returns a reference to the low store.
+    pub fn as_low(&self) -> &A {
+        &self.low
+    }
+
+    /// Returns a reference to the high store.
+    pub fn as_high(&self) -> &B {
+        &self.high
+    }
+
+    /// Returns the split point, that is the first index routed to the high store.
+    pub fn split(&self) -> I
+    where
+        I: Copy,
+    {
+        self.split
+    }
+
+    /// Returns the low store, the high store, and the split point.
+    pub fn into_parts(self) -> (A, B, I) {
+        (self.low, self.high, self.split)
+    }
+}
+
+impl<A, B, I> ConcatStore<A, B, I>
+where
+    A: IndexCollection<Index = I>,
+    B: IndexCollection<Index = I>,
+{
+    /// Creates a new, empty, instance, laying out the indexes before `split` in `low`, and the others in `high`.
+    pub fn with_split(split: I) -> Self {
+        //  Safety:
+        //  -   Low, High: both stores are empty.
+        unsafe { Self::from_parts(A::new(), B::new(), split) }
+    }
+}
+
+impl<A, B, I> ConcatStore<A, B, I>
+where
+    I: Copy + Ord + Add<Output = I> + Sub<Output = I> + From<u8>,
+    A: IndexCollection<Index = I>,
+{
+    //  Returns the split point derived from the span of `A`, just past its end.
+    #[track_caller]
+    fn default_split() -> I {
+        match A::span().1 {
+            Bound::Included(end) => end + I::from(1),
+            Bound::Excluded(end) => end,
+            Bound::Unbounded => {
+                panic!("ConcatStore requires a low store with a bounded span, use `with_split` instead")
+            }
+        }
+    }
+}
+
+/// Splits at the end of the span of `A`, like `IndexCollection::new`, hence panics if the span is unbounded.
+impl<A, B, I> Default for ConcatStore<A, B, I>
+where
+    I: Copy + Ord + Add<Output = I> + Sub<Output = I> + From<u8>,
+    A: IndexCollection<Index = I>,
+    B: IndexCollection<Index = I>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: inherited, as each index is routed to a single store.
+unsafe impl<A, B, I> IndexView for ConcatStore<A, B, I>
+where
+    I: Copy + Ord + Add<Output = I> + Sub<Output = I>,
+    A: IndexView<Index = I>,
+    B: IndexView<Index = I>,
+{
+    type Index = I;
+
+    fn is_empty(&self) -> bool {
+        self.low.is_empty() && self.high.is_empty()
+    }
+
+    fn len(&self) -> usize {
+        self.low.len() + self.high.len()
+    }
+
+    fn contains(&self, index: Self::Index) -> bool {
+        if index < self.split {
+            self.low.contains(index)
+        } else {
+            self.high.contains(index - self.split)
+        }
+    }
+}
+
+impl<A, B, I> IndexViewCached for ConcatStore<A, B, I>
+where
+    I: Copy + Ord + Add<Output = I> + Sub<Output = I>,
+    A: IndexViewCached<Index = I>,
+    B: IndexViewCached<Index = I>,
+{
+}
+
+/// The split point is the end of the span of `A`, which MUST thus be bounded, otherwise the methods creating a store
+/// panic.
+impl<A, B, I> IndexCollection for ConcatStore<A, B, I>
+where
+    I: Copy + Ord + Add<Output = I> + Sub<Output = I> + From<u8>,
+    A: IndexCollection<Index = I>,
+    B: IndexCollection<Index = I>,
+{
+    fn span() -> (Bound<Self::Index>, Bound<Self::Index>) {
+        let split = Self::default_split();
+
+        (A::span().0, B::span().1.map(|end| end + split))
+    }
+
+    fn new() -> Self {
+        Self::with_split(Self::default_split())
+    }
+
+    fn with_span(range: (Bound<Self::Index>, Bound<Self::Index>)) -> Self {
+        let mut this = Self::with_split(Self::default_split());
+
+        this.reserve(range);
+
+        this
+    }
+
+    fn reserve(&mut self, (start, end): (Bound<Self::Index>, Bound<Self::Index>)) {
+        let split = self.split;
+
+        let below = |bound: Bound<I>| match bound {
+            Bound::Included(index) | Bound::Excluded(index) => index < split,
+            Bound::Unbounded => true,
+        };
+
+        if below(start) {
+            let end = match end {
+                Bound::Included(end) if end < split => Bound::Included(end),
+                Bound::Excluded(end) if end <= split => Bound::Excluded(end),
+                _ => Bound::Excluded(split),
+            };
+
+            self.low.reserve((start, end));
+        }
+
+        if !below(end) || end == Bound::Excluded(split) {
+            let start = match start {
+                Bound::Included(start) if start >= split => Bound::Included(start - split),
+                Bound::Excluded(start) if start >= split => Bound::Excluded(start - split),
+                _ => Bound::Included(I::from(0)),
+            };
+
+            self.high.reserve((start, end.map(|end| end - split)));
+        }
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: inherited, as each index is routed to a single store.
+unsafe impl<A, B, I> IndexStore for ConcatStore<A, B, I>
+where
+    I: Copy + Ord + Add<Output = I> + Sub<Output = I>,
+    A: IndexStore<Index = I>,
+    B: IndexStore<Index = I>,
+{
+    type InsertionError = ConcatInsertionError<A::InsertionError, B::InsertionError>;
+
+    fn clear(&mut self) {
+        self.low.clear();
+        self.high.clear();
+    }
+
+    fn insert(&mut self, index: Self::Index) -> Result<bool, Self::InsertionError> {
+        if index < self.split {
+            self.low.insert(index).map_err(ConcatInsertionError::Low)
+        } else {
+            self.high.insert(index - self.split).map_err(ConcatInsertionError::High)
+        }
+    }
+
+    fn remove(&mut self, index: Self::Index) -> bool {
+        if index < self.split {
+            self.low.remove(index)
+        } else {
+            self.high.remove(index - self.split)
+        }
+    }
+}
+
+//  #   Safety
+//
+//  -   NoTheft: inherited, as each index is routed to a single store.
+unsafe impl<A, B, I> IndexVault for ConcatStore<A, B, I>
+where
+    I: Copy + Ord + Add<Output = I> + Sub<Output = I>,
+    A: IndexVault<Index = I>,
+    B: IndexVault<Index = I>,
+{
+}
+
+//  #   Safety
+//
+//  -   NoDuplicate: inherited, as the indexes of `low` and `high` are disjoint.
+//  -   NoPhantom: inherited.
+//  -   NoTheft: inherited, as all indexes of `low` are returned, followed by all indexes of `high`.
+unsafe impl<A, B, I> IndexForward for ConcatStore<A, B, I>
+where
+    I: Copy + Ord + Add<Output = I> + Sub<Output = I>,
+    A: IndexForward<Index = I>,
+    B: IndexForward<Index = I>,
+{
+    fn first(&self) -> Option<Self::Index> {
+        self.low.first().or_else(|| self.first_high())
+    }
+
+    fn next_after(&self, current: Self::Index) -> Option<Self::Index> {
+        if current < self.split {
+            return self.low.next_after(current).or_else(|| self.first_high());
+        }
+
+        let next = self.high.next_after(current - self.split)?;
+
+        Some(next + self.split)
+    }
+
+    fn nth_after(&self, n: usize, current: Self::Index) -> Result<Self::Index, NonZeroUsize> {
+        if current >= self.split {
+            let next = self.high.nth_after(n, current - self.split)?;
+
+            return Ok(next + self.split);
+        }
+
+        //  The remainder counts the indexes still to skip, the first index of `high` included.
+        let remainder = match self.low.nth_after(n, current) {
+            Ok(next) => return Ok(next),
+            Err(remainder) => remainder.get() - 1,
+        };
+
+        let first = self.first_high().ok_or(NonZeroUsize::MIN.saturating_add(remainder))?;
+
+        match remainder.checked_sub(1) {
+            None => Ok(first),
+            Some(n) => self.nth_after(n, first),
+        }
+    }
+}
+
+impl<A, B, I> ConcatStore<A, B, I>
+where
+    I: Copy + Ord + Add<Output = I> + Sub<Output = I>,
+    B: IndexForward<Index = I>,
+{
+    //  Returns the first index of `high`, offset.
+    fn first_high(&self) -> Option<I> {
+        Some(self.high.first()? + self.split)
+    }
+}
+
+//  #   Safety
+//
+//  -   Reverse: inherited, as all indexes of `high` are returned in reverse, followed by all indexes of `low` in
+//      reverse.
+unsafe impl<A, B, I> IndexBackward for ConcatStore<A, B, I>
+where
+    I: Copy + Ord + Add<Output = I> + Sub<Output = I>,
+    A: IndexBackward<Index = I>,
+    B: IndexBackward<Index = I>,
+{
+    fn last(&self) -> Option<Self::Index> {
+        match self.high.last() {
+            Some(last) => Some(last + self.split),
+            None => self.low.last(),
+        }
+    }
+
+    fn next_before(&self, current: Self::Index) -> Option<Self::Index> {
+        if current < self.split {
+            return self.low.next_before(current);
+        }
+
+        match self.high.next_before(current - self.split) {
+            Some(previous) => Some(previous + self.split),
+            None => self.low.last(),
+        }
+    }
+}
+
+//  #   Safety
+//
+//  -   Ordered: inherited, as all indexes of `low` are before `split`, and all indexes of `high` are after.
+unsafe impl<A, B, I> IndexOrdered for ConcatStore<A, B, I>
+where
+    I: Copy + Ord + Add<Output = I> + Sub<Output = I>,
+    A: IndexOrdered<Index = I>,
+    B: IndexOrdered<Index = I>,
+{
+}
+
+//  #   Safety
+//
+//  -   Rank: inherited, as all indexes of `low` before `split` precede all indexes of `high`.
+unsafe impl<A, B, I> IndexOrderedStore for ConcatStore<A, B, I>
+where
+    I: Copy + Ord + Add<Output = I> + Sub<Output = I>,
+    A: IndexOrderedStore<Index = I>,
+    B: IndexOrderedStore<Index = I>,
+{
+    fn rank(&self, index: Self::Index) -> usize {
+        if index < self.split {
+            self.low.rank(index)
+        } else {
+            self.low.rank(self.split) + self.high.rank(index - self.split)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeSet;
+
+    use crate::{
+        chunk::UnsignedChunk,
+        index::{IndexCollection, IndexForward, IndexStore, IndexView},
+        set::IndexOrdSet,
+        test::IndexTester,
+        vault::{InlineStore, OutOfCapacityError},
+    };
+
+    use super::{ConcatInsertionError, ConcatStore};
+
+    //  Splits at 8, hence the primes and evens of the test-suites straddle the boundary.
+    type Victim = ConcatStore<UnsignedChunk<u8>, BTreeSet<u8>, u8>;
+
+    struct Tester;
+
+    impl IndexTester for Tester {
+        type Index = u8;
+        type Victim = Victim;
+
+        fn upper_bound() -> u8 {
+            u8::MAX - 8
+        }
+
+        fn victim(indexes: &[u8]) -> Self::Victim {
+            let mut victim = Victim::new();
+
+            for &index in indexes {
+                victim.insert(index).unwrap();
+            }
+
+            victim
+        }
+
+        fn index(i: u8) -> Self::Index {
+            i
+        }
+    }
+
+    crate::test_index_view!(Tester);
+    crate::test_index_collection!(Tester);
+    crate::test_index_store!(Tester);
+    crate::test_index_forward!(Tester);
+    crate::test_index_backward!(Tester);
+    crate::test_index_ordered_store!(Tester);
+
+    //  Splits at 3, hence most of the test-suites indexes are in the high store.
+    type InlineVictim = ConcatStore<InlineStore<u32, 4>, BTreeSet<u32>, u32>;
+
+    struct InlineTester;
+
+    impl IndexTester for InlineTester {
+        type Index = u32;
+        type Victim = InlineVictim;
+
+        fn upper_bound() -> u8 {
+            u8::MAX
+        }
+
+        fn victim(indexes: &[u8]) -> Self::Victim {
+            let mut victim = InlineVictim::with_split(3);
+
+            for &index in indexes {
+                victim.insert(index.into()).unwrap();
+            }
+
+            victim
+        }
+
+        fn index(i: u8) -> Self::Index {
+            i.into()
+        }
+    }
+
+    mod inline {
+        use super::InlineTester;
+
+        crate::test_index_view!(InlineTester);
+        crate::test_index_store!(InlineTester);
+        crate::test_index_forward!(InlineTester);
+        crate::test_index_backward!(InlineTester);
+        crate::test_index_ordered_store!(InlineTester);
+    }
+
+    #[test]
+    fn default() {
+        let victim = Victim::default();
+
+        assert!(victim.is_empty());
+        assert_eq!(Victim::new(), victim);
+        assert_eq!(8, victim.split());
+    }
+
+    #[test]
+    #[should_panic(expected = "ConcatStore requires a low store with a bounded span")]
+    fn default_unbounded() {
+        let _ = InlineVictim::default();
+    }
+
+    #[test]
+    fn routing() {
+        let victim = Tester::victim(&[1, 7, 8, 20]);
+
+        assert_eq!(8, victim.split());
+        assert_eq!(0b1000_0010, victim.as_low().0);
+        assert!(victim.as_high().iter().eq(&[0, 12]));
+
+        assert_eq!(Some(8), victim.next_after(7));
+        assert_eq!(Ok(20), victim.nth_after(2, 1));
+        assert_eq!(Ok(8), victim.nth_after(2, 0));
+        assert_eq!(Err(1.try_into().unwrap()), victim.nth_after(4, 0));
+    }
+
+    #[test]
+    fn from_parts() {
+        let low = UnsignedChunk(0b1000_0010u8);
+        let high = BTreeSet::from([0, 12]);
+
+        //  Safety:
+        //  -   Low: 1 and 7 are both below 8.
+        //  -   High: 0 and 12 are both at most `u8::MAX - 8`.
+        let victim = unsafe { Victim::from_parts(low, high, 8) };
+
+        assert_eq!(8, victim.split());
+        assert!(IndexOrdSet::with_store(victim).iter().eq([1, 7, 8, 20]));
+    }
+
+    #[test]
+    fn insertion_error() {
+        let mut victim = InlineVictim::with_split(100);
+
+        for index in [0, 1, 2, 3] {
+            victim.insert(index).unwrap();
+        }
+
+        assert_eq!(Err(ConcatInsertionError::Low(OutOfCapacityError)), victim.insert(4));
+        assert_eq!(Ok(true), victim.insert(1_000));
+        assert_eq!(5, victim.len());
+    }
+
+    #[test]
+    fn set() {
+        let set = IndexOrdSet::with_store(Tester::victim(&[2, 3, 5, 7, 11, 13]));
+
+        assert!(set.range(4..12).eq([5, 7, 11]));
+        assert!(set.iter_rev().eq([13, 11, 7, 5, 3, 2]));
+    }
+} // mod tests
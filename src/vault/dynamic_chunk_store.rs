@@ -1,16 +1,28 @@
 //! A dynamically-sized chunk-based store.
 
-use core::{cmp, mem, ops::Bound};
+#[cfg(any(feature = "std", test))]
+mod stream;
+
+use core::{
+    cmp,
+    marker::PhantomData,
+    mem,
+    num::NonZeroUsize,
+    ops::{Bound, RangeBounds},
+};
 
 #[cfg(feature = "nightly")]
-use core::hint;
+use core::{alloc::Allocator, hint};
+
+use alloc::boxed::Box;
 
 use crate::{
     Never,
-    chunk::IndexChunk,
+    chunk::{self, IndexChunk},
     index::{
         IndexBackward, IndexBackwardChunked, IndexCollection, IndexForward, IndexForwardChunked, IndexOrdered,
-        IndexOrderedChunked, IndexStore, IndexStoreChunked, IndexVault, IndexView, IndexViewChunked,
+        IndexOrderedChunked, IndexOrderedStore, IndexStore, IndexStoreChunked, IndexStoreRange, IndexVault, IndexView,
+        IndexViewCached, IndexViewChunked,
     },
     not::{
         IndexBackwardChunkedNot, IndexBackwardNot, IndexForwardChunkedNot, IndexForwardNot, IndexOrderedChunkedNot,
@@ -19,21 +31,207 @@ use crate::{
 };
 
 /// A dynamically-sized chunk-based store.
+///
+/// The `I` parameter is the type of the fused indexes, see `DynamicChunkIndex`.
+///
+/// The `A` parameter is the allocator of the chunks, see `DynamicChunkAllocator`.
 #[derive(Debug)]
-pub struct DynamicChunkStore<C> {
+pub struct DynamicChunkStore<C, I = u64, A = Global>
+where
+    A: DynamicChunkAllocator,
+{
     count: usize,
+    #[cfg(feature = "nightly")]
+    chunks: Box<[C], A>,
+    #[cfg(not(feature = "nightly"))]
     chunks: Box<[C]>,
+    _index: PhantomData<(I, A)>,
 }
 
+impl<C, I, A> Clone for DynamicChunkStore<C, I, A>
+where
+    C: IndexChunk,
+    A: DynamicChunkAllocator,
+{
+    fn clone(&self) -> Self {
+        let count = self.count;
+        let chunks = self.chunks.clone();
+        let _index = PhantomData;
+
+        Self { count, chunks, _index }
+    }
+
+    /// Reuses the chunks of `self` if there are enough of them, rather than allocating anew.
+    fn clone_from(&mut self, source: &Self) {
+        if self.chunks.len() < source.chunks.len() {
+            *self = source.clone();
+            return;
+        }
+
+        let (head, tail) = self.chunks.split_at_mut(source.chunks.len());
+
+        head.copy_from_slice(&source.chunks);
+        tail.fill(C::default());
+
+        self.count = source.count;
+    }
+}
+
+/// A dynamically-sized chunk-based store, with 32-bits indexes.
+pub type DynamicChunkStore32<C> = DynamicChunkStore<C, u32>;
+
+/// A dynamically-sized chunk-based store, with 64-bits indexes.
+pub type DynamicChunkStore64<C> = DynamicChunkStore<C, u64>;
+
+/// The allocator of the chunks of a `DynamicChunkStore`.
+///
+/// With the "nightly" feature, any `Allocator` which is `Clone` -- such as a reference to an arena -- is suitable.
+/// Without, the chunks are always allocated with the global allocator, and only `Global` implements this trait.
+///
+/// The trait is sealed, as its implementations differ with the "nightly" feature.
+#[cfg(feature = "nightly")]
+pub trait DynamicChunkAllocator: Allocator + Clone + sealed::Sealed {}
+
+#[cfg(feature = "nightly")]
+impl<A> DynamicChunkAllocator for A where A: Allocator + Clone {}
+
+/// The allocator of the chunks of a `DynamicChunkStore`.
+///
+/// With the "nightly" feature, any `Allocator` which is `Clone` -- such as a reference to an arena -- is suitable.
+/// Without, the chunks are always allocated with the global allocator, and only `Global` implements this trait.
+///
+/// The trait is sealed, as its implementations differ with the "nightly" feature.
+#[cfg(not(feature = "nightly"))]
+pub trait DynamicChunkAllocator: Clone + sealed::Sealed {}
+
+#[cfg(not(feature = "nightly"))]
+impl DynamicChunkAllocator for Global {}
+
+mod sealed {
+    #[cfg(feature = "nightly")]
+    use core::alloc::Allocator;
+
+    //  Private supertrait of `DynamicChunkAllocator`, preventing implementations outside this crate.
+    pub trait Sealed {}
+
+    #[cfg(feature = "nightly")]
+    impl<A> Sealed for A where A: Allocator + Clone {}
+
+    #[cfg(not(feature = "nightly"))]
+    impl Sealed for super::Global {}
+}
+
+#[cfg(feature = "nightly")]
+pub use alloc::alloc::Global;
+
+/// The global allocator.
+///
+/// Stand-in for `alloc::alloc::Global`, which requires the "nightly" feature.
+#[cfg(not(feature = "nightly"))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Global;
+
+/// The type of the indexes of a `DynamicChunkStore`.
+///
+/// #   Safety
+///
+/// -   SplitFuse: `index` == `Self::fuse(index.split(bits), bits)`, for any `bits` which is a power of 2.
+pub unsafe trait DynamicChunkIndex: Copy + Eq + Ord {
+    /// Number of bits in this index.
+    const BITS: u32;
+
+    /// Minimum value of this index.
+    const MIN: Self;
+
+    /// Fuses a tuple (chunk index, index-in-chunk) into a single index, for chunks of `bits` bits.
+    ///
+    /// Only defined if the resulting index is representable.
+    fn fuse(outer: usize, inner: u16, bits: u32) -> Self;
+
+    /// Splits an index into a tuple (chunk index, index-in-chunk), for chunks of `bits` bits.
+    ///
+    /// If the chunk index does not fit in `usize`, as may happen on 32-bits targets, it is `usize::MAX` instead, which
+    /// is out of bounds of any allocation.
+    fn split(self, bits: u32) -> (usize, u16);
+}
+
+macro_rules! impl_dynamic_chunk_index {
+    ($($i:ident)*) => { $(
+        //  #   Safety
+        //
+        //  -   SplitFuse: `split` and `fuse` are one another inverse.
+        unsafe impl DynamicChunkIndex for $i {
+            const BITS: u32 = $i::BITS;
+
+            const MIN: Self = $i::MIN;
+
+            fn fuse(outer: usize, inner: u16, bits: u32) -> Self {
+                let bits = bits as $i;
+
+                let outer = outer as $i;
+                let inner: $i = inner.into();
+
+                outer * bits + inner
+            }
+
+            fn split(self, bits: u32) -> (usize, u16) {
+                let bits = bits as $i;
+
+                let (outer, inner) = (self / bits, self % bits);
+
+                (usize::try_from(outer).unwrap_or(usize::MAX), inner as u16)
+            }
+        }
+    )* };
+}
+
+impl_dynamic_chunk_index!(u32 u64);
+
+/// The type of the indexes within the chunks of a `DynamicChunkStore`.
+///
+/// `DynamicChunkIndex` fuses and splits indexes-in-chunk as `u16`, which the indexes of the chunks are widened to,
+/// and narrowed from. Byte-indexed chunks, such as `UnsignedChunk`, may thus be used as well as `ArrayChunk`.
+///
+/// #   Safety
+///
+/// -   Roundtrip: `inner` == `Self::narrow(inner.into())`, for any `inner`.
+pub unsafe trait DynamicChunkInnerIndex: Copy + Eq + Ord + Into<u16> {
+    /// Number of bits in this index.
+    const BITS: u32;
+
+    /// Narrows an index-in-chunk into `Self`.
+    ///
+    /// Only defined if `inner` is representable.
+    fn narrow(inner: u16) -> Self;
+}
+
+macro_rules! impl_dynamic_chunk_inner_index {
+    ($($i:ident)*) => { $(
+        //  #   Safety
+        //
+        //  -   Roundtrip: `narrow` is the inverse of the lossless widening to `u16`.
+        unsafe impl DynamicChunkInnerIndex for $i {
+            const BITS: u32 = $i::BITS;
+
+            fn narrow(inner: u16) -> Self {
+                inner as $i
+            }
+        }
+    )* };
+}
+
+impl_dynamic_chunk_inner_index!(u8 u16);
+
 //  #   Safety
 //
 //  -   NoPhantom: the store will only ever return indexes that have been inserted and have not been removed since.
-unsafe impl<C> IndexView for DynamicChunkStore<C>
+unsafe impl<C, I, A> IndexView for DynamicChunkStore<C, I, A>
 where
-    C: IndexChunk<Index = u16> + IndexView,
+    C: IndexChunk<Index: DynamicChunkInnerIndex> + IndexView,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
 {
-    //  Fixed-width, for easier conversions.
-    type Index = u64;
+    type Index = I;
 
     fn is_empty(&self) -> bool {
         self.count == 0
@@ -48,15 +246,55 @@ where
 
         self.chunks.get(outer).is_some_and(|c| c.contains(inner))
     }
+
+    /// Fetches the chunk of an index only if it differs from that of the previous index. Indexes beyond the allocated
+    /// chunks are missing, without any allocation.
+    fn contains_all<It>(&self, indexes: It) -> bool
+    where
+        It: IntoIterator<Item = Self::Index>,
+    {
+        let mut cached = None;
+
+        indexes.into_iter().all(|index| {
+            let (outer, inner) = Self::split(index);
+
+            chunk::cached_chunk(&self.chunks, &mut cached, outer).is_some_and(|c| c.contains(inner))
+        })
+    }
+
+    /// Fetches the chunk of an index only if it differs from that of the previous index, so that runs of nearby
+    /// indexes are probed against a single chunk.
+    fn contains_any<It>(&self, indexes: It) -> bool
+    where
+        It: IntoIterator<Item = Self::Index>,
+    {
+        let mut cached = None;
+
+        indexes.into_iter().any(|index| {
+            let (outer, inner) = Self::split(index);
+
+            chunk::cached_chunk(&self.chunks, &mut cached, outer).is_some_and(|c| c.contains(inner))
+        })
+    }
+}
+
+impl<C, I, A> IndexViewCached for DynamicChunkStore<C, I, A>
+where
+    C: IndexChunk<Index: DynamicChunkInnerIndex> + IndexView,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
+{
 }
 
 //  Safety:
 //
 //  -   NoPhantom: the store will only ever return that it contains an index if the index was inserted, and was not
 //      removed since.
-unsafe impl<C> IndexViewNot for DynamicChunkStore<C>
+unsafe impl<C, I, A> IndexViewNot for DynamicChunkStore<C, I, A>
 where
-    C: IndexChunk<Index = u16> + IndexViewNot,
+    C: IndexChunk<Index: DynamicChunkInnerIndex> + IndexViewNot,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
 {
     fn len_not(&self) -> usize {
         //  Well, it's unreachable in practice, for obvious reasons...
@@ -65,74 +303,168 @@ where
     }
 }
 
-impl<C> IndexCollection for DynamicChunkStore<C>
+impl<C, I, A> IndexCollection for DynamicChunkStore<C, I, A>
 where
-    C: IndexChunk<Index = u16> + IndexCollection,
+    C: IndexChunk<Index: DynamicChunkInnerIndex> + IndexCollection,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator + Default,
 {
     fn span() -> (Bound<Self::Index>, Bound<Self::Index>) {
-        (Bound::Included(0), Bound::Unbounded)
+        (Bound::Included(I::MIN), Bound::Unbounded)
     }
 
     fn new() -> Self {
+        #[cfg(feature = "nightly")]
+        let chunks = Box::new_in([], A::default());
+
+        #[cfg(not(feature = "nightly"))]
         let chunks = Box::new([]);
+
         let count = 0;
+        let _index = PhantomData;
 
-        Self { count, chunks }
+        Self { count, chunks, _index }
     }
 
     fn with_span(range: (Bound<Self::Index>, Bound<Self::Index>)) -> Self {
         let mut this = Self::new();
 
-        let n = match range.1 {
-            Bound::Included(n) => n,
-            Bound::Excluded(0) => return this,
-            Bound::Excluded(n) => n - 1,
-            Bound::Unbounded => return this,
+        IndexCollection::reserve(&mut this, range);
+
+        this
+    }
+
+    /// Reserves the chunks necessary to store any index within the given span.
+    ///
+    /// If the span is unbounded, does nothing.
+    fn reserve(&mut self, range: (Bound<Self::Index>, Bound<Self::Index>)) {
+        let chunks = match range.1 {
+            Bound::Included(n) => Self::split(n).0.saturating_add(1),
+            Bound::Excluded(n) => {
+                let (outer, inner) = Self::split(n);
+
+                outer + usize::from(inner != C::Index::narrow(0))
+            }
+            Bound::Unbounded => return,
+        };
+
+        if chunks > self.chunks.len() {
+            self.reserve_chunks(chunks - self.chunks.len());
+        }
+    }
+
+    fn capacity_span(&self) -> (Bound<Self::Index>, Bound<Self::Index>) {
+        //  Fusing `self.chunks.len()` may overflow, fusing the last index of the last chunk may not.
+        let end = match self.chunks.len().checked_sub(1) {
+            Some(last) => Bound::Included(Self::fuse(last, C::Index::narrow((C::BITS - 1) as u16))),
+            None => Bound::Excluded(I::MIN),
         };
 
-        let (upto, _) = Self::split(n);
+        (Bound::Included(I::MIN), end)
+    }
+}
+
+impl<C, I, A> DynamicChunkStore<C, I, A>
+where
+    C: IndexChunk<Index: DynamicChunkInnerIndex> + IndexCollection,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator + Default,
+{
+    /// Creates a new, empty, instance, with at least `chunks` chunks pre-allocated.
+    pub fn with_capacity(chunks: usize) -> Self {
+        let mut this = Self::new();
+
+        if chunks > 0 {
+            this.reserve_chunks(chunks);
+        }
+
+        this
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<C, I, A> DynamicChunkStore<C, I, A>
+where
+    C: IndexChunk,
+    A: DynamicChunkAllocator,
+{
+    /// Creates a new, empty, instance, whose chunks are allocated with `allocator`.
+    pub fn new_in(allocator: A) -> Self {
+        let count = 0;
+        let chunks = Box::new_in([], allocator);
+        let _index = PhantomData;
+
+        Self { count, chunks, _index }
+    }
+
+    /// Creates a new, empty, instance, with at least `chunks` chunks pre-allocated with `allocator`.
+    pub fn with_capacity_in(chunks: usize, allocator: A) -> Self {
+        let mut this = Self::new_in(allocator);
 
-        this.reserve(upto + 1);
+        if chunks > 0 {
+            this.reserve_chunks(chunks);
+        }
 
         this
     }
+
+    /// Returns the allocator of the chunks.
+    pub fn allocator(&self) -> &A {
+        Box::allocator(&self.chunks)
+    }
 }
 
 //  #   Safety
 //
 //  -   NoPhantom: the store will only ever return indexes that have been inserted and have not been removed since.
-unsafe impl<C> IndexStore for DynamicChunkStore<C>
+unsafe impl<C, I, A> IndexStore for DynamicChunkStore<C, I, A>
 where
-    C: IndexChunk<Index = u16> + IndexStore,
+    C: IndexChunk<Index: DynamicChunkInnerIndex> + IndexStore,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
 {
     type InsertionError = Never;
 
     fn clear(&mut self) {
+        //  Stops as soon as `remaining` indexes have been cleared, as all further chunks are then empty, which avoids
+        //  walking the whole capacity of sparse stores.
         #[inline(never)]
-        fn do_clear<C>(chunks: &mut [C])
+        fn do_clear<C>(chunks: &mut [C], mut remaining: usize)
         where
             C: IndexStore,
         {
-            chunks.iter_mut().for_each(|c| c.clear());
+            for chunk in chunks {
+                if remaining == 0 {
+                    break;
+                }
+
+                if chunk.is_empty() {
+                    continue;
+                }
+
+                remaining -= chunk.len();
+                chunk.clear();
+            }
         }
 
         if hint::likely(self.count == 0) {
             return;
         }
 
+        do_clear(&mut self.chunks, self.count);
         self.count = 0;
-        do_clear(&mut self.chunks);
     }
 
     fn insert(&mut self, index: Self::Index) -> Result<bool, Self::InsertionError> {
         let (outer, inner) = Self::split(index);
 
         if hint::unlikely(outer >= self.chunks.len()) {
-            self.grow(outer + 1);
+            self.grow(outer.saturating_add(1));
         }
 
         //  Safety:
-        //  -   InBounds: `self.grow(outer + 1)` guarantees that `self.chunks.len() >= outer + 1`.
+        //  -   InBounds: `self.grow(outer.saturating_add(1))` guarantees that `self.chunks.len() >= outer + 1`, or
+        //      panics if `outer` is `usize::MAX`.
         let chunk = unsafe { self.chunks.get_unchecked_mut(outer) };
 
         //  C should never return Err for an in-bounds index, and `Self::split` ensure `inner` is in-bounds for C,
@@ -165,13 +497,183 @@ where
 
         removed
     }
+
+    fn toggle(&mut self, index: Self::Index) -> Result<bool, Self::InsertionError> {
+        let (outer, inner) = Self::split(index);
+
+        //  If out-of-bounds, then the index is not in the store.
+        let Some(chunk) = self.chunks.get_mut(outer) else {
+            return self.insert(index);
+        };
+
+        //  As per `insert`, C should never drop an in-bounds index, hence `Ok(false)` means the index was removed.
+        match chunk.toggle(inner) {
+            Ok(true) => {
+                self.count += 1;
+
+                Ok(true)
+            }
+            Ok(false) => {
+                self.count -= 1;
+
+                Ok(false)
+            }
+            Err(_) => Ok(false),
+        }
+    }
+
+    fn replace_with<F>(&mut self, index: Self::Index, f: F) -> Result<bool, Self::InsertionError>
+    where
+        F: FnOnce(bool) -> bool,
+    {
+        let (outer, inner) = Self::split(index);
+
+        //  If out-of-bounds, then the index is not in the store.
+        let Some(chunk) = self.chunks.get_mut(outer) else {
+            return if f(false) { self.insert(index) } else { Ok(false) };
+        };
+
+        let mut contained = false;
+
+        //  As per `insert`, C should never return Err for an in-bounds index. Should it still, Err(_) has the same
+        //  semantics (not changed) as Ok(false), and the store's own error type cannot represent C's, hence they
+        //  are folded together.
+        let changed = chunk
+            .replace_with(inner, |c| {
+                contained = c;
+
+                f(c)
+            })
+            .is_ok_and(|r| r);
+
+        if changed {
+            if contained {
+                self.count -= 1;
+            } else {
+                self.count += 1;
+            }
+        }
+
+        Ok(changed)
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: the store will only ever insert indexes within the given range.
+unsafe impl<C, I, A> IndexStoreRange for DynamicChunkStore<C, I, A>
+where
+    C: IndexChunk<Index: DynamicChunkInnerIndex> + IndexStoreRange<InsertionError = Never>,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
+{
+    /// Fills the chunks fully within `range` wholesale, and only masks the boundary chunks.
+    ///
+    /// #   Panics
+    ///
+    /// If the end of `range` is unbounded.
+    fn insert_range<R>(&mut self, range: R) -> Result<usize, Self::InsertionError>
+    where
+        R: RangeBounds<Self::Index>,
+    {
+        assert!(
+            !matches!(range.end_bound(), Bound::Unbounded),
+            "cannot insert an unbounded range"
+        );
+
+        let Some(((start_outer, start_inner), (end_outer, end_inner))) = self.split_range(&range) else {
+            return Ok(0);
+        };
+
+        if end_outer >= self.chunks.len() {
+            self.grow(end_outer.saturating_add(1));
+        }
+
+        let last = (C::BITS - 1) as u16;
+        let mut inserted = 0;
+
+        for outer in start_outer..=end_outer {
+            let lower = if outer == start_outer { start_inner } else { 0 };
+            let upper = if outer == end_outer { end_inner } else { last };
+
+            let chunk = &mut self.chunks[outer];
+
+            if lower == 0 && upper == last {
+                inserted += C::BITS as usize - chunk.len();
+
+                *chunk = C::full();
+
+                continue;
+            }
+
+            let Ok(n) = chunk.insert_range(C::Index::narrow(lower)..=C::Index::narrow(upper));
+
+            inserted += n;
+        }
+
+        self.count += inserted;
+
+        Ok(inserted)
+    }
+
+    /// Clears the chunks fully within `range` wholesale, and only masks the boundary chunks.
+    fn remove_range<R>(&mut self, range: R) -> usize
+    where
+        R: RangeBounds<Self::Index>,
+    {
+        let Some(((start_outer, start_inner), (end_outer, end_inner))) = self.split_range(&range) else {
+            return 0;
+        };
+
+        //  If out-of-bounds, then there's nothing to remove.
+        if start_outer >= self.chunks.len() {
+            return 0;
+        }
+
+        let last = (C::BITS - 1) as u16;
+
+        let (end_outer, end_inner) = if end_outer >= self.chunks.len() {
+            (self.chunks.len() - 1, last)
+        } else {
+            (end_outer, end_inner)
+        };
+
+        let mut removed = 0;
+
+        for outer in start_outer..=end_outer {
+            let lower = if outer == start_outer { start_inner } else { 0 };
+            let upper = if outer == end_outer { end_inner } else { last };
+
+            let chunk = &mut self.chunks[outer];
+
+            if lower == 0 && upper == last {
+                removed += chunk.len();
+
+                chunk.clear();
+
+                continue;
+            }
+
+            removed += chunk.remove_range(C::Index::narrow(lower)..=C::Index::narrow(upper));
+        }
+
+        self.count -= removed;
+
+        removed
+    }
 }
 
 //  #   Safety
 //
 //  -   NoTheft: the vault will never return that it does not contain an index if the index was inserted, and was
 //      not removed since.
-unsafe impl<C> IndexVault for DynamicChunkStore<C> where C: IndexChunk<Index = u16> + IndexVault {}
+unsafe impl<C, I, A> IndexVault for DynamicChunkStore<C, I, A>
+where
+    C: IndexChunk<Index: DynamicChunkInnerIndex> + IndexVault,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
+{
+}
 
 //  #   Safety
 //
@@ -179,9 +681,11 @@ unsafe impl<C> IndexVault for DynamicChunkStore<C> where C: IndexChunk<Index = u
 //  -   NoPhantom: the view SHALL only ever return that it contains an index if the index was inserted, and was not
 //      removed since.
 //  -   NoTheft: if `Self` implements `IndexVault`, the view shall return all indexes.
-unsafe impl<C> IndexForward for DynamicChunkStore<C>
+unsafe impl<C, I, A> IndexForward for DynamicChunkStore<C, I, A>
 where
-    C: IndexChunk<Index = u16> + IndexForward,
+    C: IndexChunk<Index: DynamicChunkInnerIndex> + IndexForward,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
 {
     fn first(&self) -> Option<Self::Index> {
         let (outer, inner) = self
@@ -204,11 +708,59 @@ where
             .chunks
             .iter()
             .enumerate()
-            .skip(outer + 1)
+            .skip(outer.saturating_add(1))
             .find_map(|(i, c)| c.first().map(|r| (i, r)))?;
 
         Some(Self::fuse(outer, inner))
     }
+
+    /// Skips over whole chunks, using their length, rather than advancing one index at a time.
+    fn nth_after(&self, n: usize, current: Self::Index) -> Result<Self::Index, NonZeroUsize> {
+        let (outer, inner) = Self::split(current);
+
+        //  Number of indexes left to advance over, including the one to return.
+        let mut remaining = match self.chunks.get(outer).map(|chunk| chunk.nth_after(n, inner)) {
+            Some(Ok(inner)) => return Ok(Self::fuse(outer, inner)),
+            Some(Err(remaining)) => remaining.get(),
+            None => n.saturating_add(1),
+        };
+
+        for (i, chunk) in self.chunks.iter().enumerate().skip(outer.saturating_add(1)) {
+            let len = chunk.len();
+
+            if remaining > len {
+                remaining -= len;
+                continue;
+            }
+
+            //  `remaining` is within `1..=len`, hence the chunk contains the index to return.
+            let first = chunk.first();
+
+            let inner = match remaining {
+                1 => first,
+                _ => first.and_then(|first| chunk.nth_after(remaining - 2, first).ok()),
+            };
+
+            if let Some(inner) = inner {
+                return Ok(Self::fuse(i, inner));
+            }
+        }
+
+        //  Safety:
+        //  -   NonZero: `remaining` starts at 1 or more, and is only ever decremented by less than its value.
+        Err(unsafe { NonZeroUsize::new_unchecked(remaining) })
+    }
+
+    /// Seeks the chunk containing `from`, rather than advancing one index at a time from the first index.
+    fn first_at(&self, from: Self::Index) -> Option<Self::Index> {
+        let (outer, inner) = Self::split(from);
+
+        if self.chunks.get(outer).is_some_and(|chunk| chunk.contains(inner)) {
+            return Some(from);
+        }
+
+        self.next_after(from)
+    }
 }
 
 //  Safety:
@@ -217,9 +769,11 @@ where
 //  -   NoPhantom: the view will only ever return that it contains an index if the index was inserted, and was not
 //      removed since.
 //  -   NoTheft: the view will return all indexes.
-unsafe impl<C> IndexForwardNot for DynamicChunkStore<C>
+unsafe impl<C, I, A> IndexForwardNot for DynamicChunkStore<C, I, A>
 where
-    C: IndexChunk<Index = u16> + IndexForwardNot,
+    C: IndexChunk<Index: DynamicChunkInnerIndex> + IndexForwardNot,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
 {
     fn first_not(&self) -> Option<Self::Index> {
         let (outer, inner) = self
@@ -242,7 +796,7 @@ where
             .chunks
             .iter()
             .enumerate()
-            .skip(outer + 1)
+            .skip(outer.saturating_add(1))
             .find_map(|(i, c)| c.first_not().map(|r| (i, r)))?;
 
         Some(Self::fuse(outer, inner))
@@ -252,9 +806,11 @@ where
 //  #   Safety
 //
 //  -   Reverse: the view WILL return indexes in the exact opposite sequence than `IndexForward` does.
-unsafe impl<C> IndexBackward for DynamicChunkStore<C>
+unsafe impl<C, I, A> IndexBackward for DynamicChunkStore<C, I, A>
 where
-    C: IndexChunk<Index = u16> + IndexBackward,
+    C: IndexChunk<Index: DynamicChunkInnerIndex> + IndexBackward,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
 {
     fn last(&self) -> Option<Self::Index> {
         let (outer, inner) = self
@@ -286,14 +842,27 @@ where
 
         Some(Self::fuse(outer, inner))
     }
+
+    /// Seeks the chunk containing `to`, rather than advancing one index at a time from the last index.
+    fn last_at(&self, to: Self::Index) -> Option<Self::Index> {
+        let (outer, inner) = Self::split(to);
+
+        if self.chunks.get(outer).is_some_and(|chunk| chunk.contains(inner)) {
+            return Some(to);
+        }
+
+        self.next_before(to)
+    }
 }
 
 //  Safety:
 //
 //  -   Reverse: the view will return indexes in the exact opposite sequence than `IndexForward` does.
-unsafe impl<C> IndexBackwardNot for DynamicChunkStore<C>
+unsafe impl<C, I, A> IndexBackwardNot for DynamicChunkStore<C, I, A>
 where
-    C: IndexChunk<Index = u16> + IndexBackwardNot,
+    C: IndexChunk<Index: DynamicChunkInnerIndex> + IndexBackwardNot,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
 {
     fn last_not(&self) -> Option<Self::Index> {
         let (outer, inner) = self
@@ -330,13 +899,46 @@ where
 //  Safety:
 //
 //  -   Ordered: the `IndexForward` implementation will return indexes in strictly increasing order.
-unsafe impl<C> IndexOrdered for DynamicChunkStore<C> where C: IndexChunk<Index = u16> + IndexOrdered {}
+unsafe impl<C, I, A> IndexOrdered for DynamicChunkStore<C, I, A>
+where
+    C: IndexChunk<Index: DynamicChunkInnerIndex> + IndexOrdered,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
+{
+}
+
+//  Safety:
+//
+//  -   Rank: the store will sum the lengths of the chunks before the chunk of `index`, and the rank within.
+unsafe impl<C, I, A> IndexOrderedStore for DynamicChunkStore<C, I, A>
+where
+    C: IndexChunk<Index: DynamicChunkInnerIndex> + IndexOrderedStore,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
+{
+    fn rank(&self, index: Self::Index) -> usize {
+        let (outer, inner) = Self::split(index);
+
+        let Some(chunk) = self.chunks.get(outer) else {
+            return self.count;
+        };
+
+        let before: usize = self.chunks[..outer].iter().map(|chunk| chunk.len()).sum();
+
+        before + chunk.rank(inner)
+    }
+}
 
 //  Safety:
 //
 //  -   Ordered: the `IndexForward` implementation will return indexes in strictly increasing order.
-unsafe impl<C> IndexOrderedNot for DynamicChunkStore<C> where C: IndexChunk<Index = u16> + IndexForwardNot + IndexOrdered
-{}
+unsafe impl<C, I, A> IndexOrderedNot for DynamicChunkStore<C, I, A>
+where
+    C: IndexChunk<Index: DynamicChunkInnerIndex> + IndexForwardNot + IndexOrdered,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
+{
+}
 
 //  Safety:
 //
@@ -344,9 +946,11 @@ unsafe impl<C> IndexOrderedNot for DynamicChunkStore<C> where C: IndexChunk<Inde
 //      removed since.
 //  -   SplitFuse: `split` and `fuse` are one another inverse.
 //  -   TwoLevels: `split` and `fuse` are consistent with `IndexView`.
-unsafe impl<C> IndexViewChunked for DynamicChunkStore<C>
+unsafe impl<C, I, A> IndexViewChunked for DynamicChunkStore<C, I, A>
 where
-    C: IndexChunk<Index = u16>,
+    C: IndexChunk<Index: DynamicChunkInnerIndex>,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
 {
     type ChunkIndex = usize;
     type Chunk = C;
@@ -356,24 +960,17 @@ where
             assert!(core::mem::size_of::<usize>() <= core::mem::size_of::<u64>());
         };
 
-        let bits: u64 = C::BITS.into();
-
-        let outer = outer as u64;
-        let inner: u64 = inner.into();
-
-        outer * bits + inner
+        I::fuse(outer, inner.into(), C::BITS)
     }
 
     fn split(index: Self::Index) -> (Self::ChunkIndex, C::Index) {
         const {
-            assert!(C::BITS <= (u16::MAX as u32 + 1));
+            assert!(C::BITS <= 1 << <C::Index as DynamicChunkInnerIndex>::BITS);
         };
 
-        let bits: u64 = C::BITS.into();
+        let (outer, inner) = index.split(C::BITS);
 
-        let (outer, inner) = (index / bits, index % bits);
-
-        (outer as usize, inner as u16)
+        (outer, C::Index::narrow(inner))
     }
 
     fn get_chunk(&self, index: Self::ChunkIndex) -> Option<Self::Chunk> {
@@ -384,9 +981,11 @@ where
 //  #   Safety
 //
 //  -   NoPhantom: the store will only ever return indexes that have been inserted and have not been removed since.
-unsafe impl<C> IndexStoreChunked for DynamicChunkStore<C>
+unsafe impl<C, I, A> IndexStoreChunked for DynamicChunkStore<C, I, A>
 where
-    C: IndexChunk<Index = u16> + IndexView,
+    C: IndexChunk<Index: DynamicChunkInnerIndex> + IndexView,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
 {
     type SetError = Never;
 
@@ -417,9 +1016,11 @@ where
 //  -   NoPhantom: the view will only ever return that it contains an index if the index was inserted, and was not
 //      removed since.
 //  -   NoTheft: the view will return all indexes.
-unsafe impl<C> IndexForwardChunked for DynamicChunkStore<C>
+unsafe impl<C, I, A> IndexForwardChunked for DynamicChunkStore<C, I, A>
 where
-    C: IndexChunk<Index = u16>,
+    C: IndexChunk<Index: DynamicChunkInnerIndex>,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
 {
     fn first_chunk(&self) -> Option<Self::ChunkIndex> {
         (!self.chunks.is_empty()).then_some(0)
@@ -428,6 +1029,10 @@ where
     fn next_chunk_after(&self, current: Self::ChunkIndex) -> Option<Self::ChunkIndex> {
         (current + 1 < self.chunks.len()).then_some(current + 1)
     }
+
+    fn populated_chunks(&self) -> usize {
+        self.chunks.iter().filter(|chunk| !chunk.is_empty()).count()
+    }
 }
 
 //  #   Safety
@@ -436,9 +1041,11 @@ where
 //  -   NoPhantom: the view will only ever return that it contains an index if the index was inserted, and was not
 //      removed since.
 //  -   NoTheft: the view will return all indexes.
-unsafe impl<C> IndexForwardChunkedNot for DynamicChunkStore<C>
+unsafe impl<C, I, A> IndexForwardChunkedNot for DynamicChunkStore<C, I, A>
 where
-    C: IndexChunk<Index = u16> + IndexViewNot,
+    C: IndexChunk<Index: DynamicChunkInnerIndex> + IndexViewNot,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
 {
     #[inline(always)]
     fn first_chunk_not(&self) -> Option<Self::ChunkIndex> {
@@ -454,9 +1061,11 @@ where
 //  Safety:
 //
 //  -   Reverse: the view will return indexes in the exact opposite sequence than `IndexForwardChunked` does.
-unsafe impl<C> IndexBackwardChunked for DynamicChunkStore<C>
+unsafe impl<C, I, A> IndexBackwardChunked for DynamicChunkStore<C, I, A>
 where
-    C: IndexChunk<Index = u16>,
+    C: IndexChunk<Index: DynamicChunkInnerIndex>,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
 {
     fn last_chunk(&self) -> Option<Self::ChunkIndex> {
         self.chunks.len().checked_sub(1)
@@ -470,9 +1079,11 @@ where
 //  Safety:
 //
 //  -   Reverse: the view will return indexes in the exact opposite sequence than `IndexForwardChunked` does.
-unsafe impl<C> IndexBackwardChunkedNot for DynamicChunkStore<C>
+unsafe impl<C, I, A> IndexBackwardChunkedNot for DynamicChunkStore<C, I, A>
 where
-    C: IndexChunk<Index = u16> + IndexViewNot,
+    C: IndexChunk<Index: DynamicChunkInnerIndex> + IndexViewNot,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
 {
     #[inline(always)]
     fn last_chunk_not(&self) -> Option<Self::ChunkIndex> {
@@ -488,20 +1099,81 @@ where
 //  #   Safety
 //
 //  -   Ordered: the view will return indexes in strictly increasing order.
-unsafe impl<C> IndexOrderedChunked for DynamicChunkStore<C> where C: IndexChunk<Index = u16> {}
+unsafe impl<C, I, A> IndexOrderedChunked for DynamicChunkStore<C, I, A>
+where
+    C: IndexChunk<Index: DynamicChunkInnerIndex>,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
+{
+}
 
 //  #   Safety
 //
 //  -   Ordered: the view will return indexes in strictly increasing order.
-unsafe impl<C> IndexOrderedChunkedNot for DynamicChunkStore<C> where C: IndexChunk<Index = u16> + IndexViewNot {}
+unsafe impl<C, I, A> IndexOrderedChunkedNot for DynamicChunkStore<C, I, A>
+where
+    C: IndexChunk<Index: DynamicChunkInnerIndex> + IndexViewNot,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
+{
+}
+
+//
+//  Implementation (ranges)
+//
+
+impl<C, I, A> DynamicChunkStore<C, I, A>
+where
+    C: IndexChunk<Index: DynamicChunkInnerIndex>,
+    I: DynamicChunkIndex,
+    A: DynamicChunkAllocator,
+{
+    //  Returns the first and last index within `range`, split, or None if `range` is empty.
+    //
+    //  An unbounded end is replaced by the last index of the last chunk, if any.
+    fn split_range<R>(&self, range: &R) -> Option<((usize, u16), (usize, u16))>
+    where
+        R: RangeBounds<I>,
+    {
+        let last = (C::BITS - 1) as u16;
+
+        let split = |index| {
+            let (outer, inner) = Self::split(index);
+
+            (outer, inner.into())
+        };
+
+        let start = match range.start_bound() {
+            Bound::Included(&start) => split(start),
+            Bound::Excluded(&start) => match split(start) {
+                (outer, inner) if inner == last => (outer.saturating_add(1), 0),
+                (outer, inner) => (outer, inner + 1),
+            },
+            Bound::Unbounded => (0, 0),
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(&end) => split(end),
+            Bound::Excluded(&end) if end == I::MIN => return None,
+            Bound::Excluded(&end) => match split(end) {
+                (outer, 0) => (outer - 1, last),
+                (outer, inner) => (outer, inner - 1),
+            },
+            Bound::Unbounded => (self.chunks.len().checked_sub(1)?, last),
+        };
+
+        (start <= end).then_some((start, end))
+    }
+}
 
 //
 //  Implementation (memory)
 //
 
-impl<C> DynamicChunkStore<C>
+impl<C, I, A> DynamicChunkStore<C, I, A>
 where
     C: IndexChunk,
+    A: DynamicChunkAllocator,
 {
     //  #   Safety
     //
@@ -514,14 +1186,20 @@ where
 
         let additional = target - self.chunks.len();
 
-        self.reserve(additional);
+        self.reserve_chunks(additional);
     }
 
     #[inline(never)]
-    fn reserve(&mut self, additional: usize) {
-        let chunks = mem::replace(&mut self.chunks, Box::new([]));
+    fn reserve_chunks(&mut self, additional: usize) {
+        #[cfg(feature = "nightly")]
+        let empty = Box::new_in([], Box::allocator(&self.chunks).clone());
+
+        #[cfg(not(feature = "nightly"))]
+        let empty = Box::new([]);
 
-        let mut chunks: Vec<_> = chunks.into();
+        let chunks = mem::replace(&mut self.chunks, empty);
+
+        let mut chunks = chunks.into_vec();
 
         chunks.reserve(additional);
 
@@ -545,3 +1223,467 @@ mod hint {
         b
     }
 } // mod hint
+
+#[cfg(test)]
+mod tests {
+    macro_rules! test_dynamic_chunk_store {
+        ($($name:ident => $c:ty, $i:ident);*) => { $(
+            mod $name {
+                use crate::{
+                    index::{IndexCollection, IndexStore},
+                    vault::DynamicChunkStore,
+                };
+
+                struct Tester;
+
+                impl crate::test::IndexTester for Tester {
+                    type Index = $i;
+                    type Victim = DynamicChunkStore<$c, $i>;
+
+                    fn upper_bound() -> u8 { 8 * 2 * 4 - 1 }
+
+                    fn victim(indexes: &[u8]) -> Self::Victim {
+                        let mut victim = Self::Victim::new();
+
+                        for &index in indexes {
+                            let _ = victim.insert(index.into());
+                        }
+
+                        victim
+                    }
+
+                    fn index(i: u8) -> Self::Index { i.into() }
+                }
+
+                crate::test_index_view!(Tester);
+                crate::test_index_collection!(Tester);
+                crate::test_index_store!(Tester);
+                crate::test_index_forward!(Tester);
+                crate::test_index_backward!(Tester);
+                crate::test_index_ordered_store!(Tester);
+                crate::test_index_view_chunked!(Tester);
+                crate::test_index_forward_chunked!(Tester);
+                crate::test_index_backward_chunked!(Tester);
+            }
+        )* };
+    }
+
+    //  Chunks of 16 indexes, either `u16` or `u8` indexed.
+    test_dynamic_chunk_store!(
+        u32 => crate::chunk::ArrayChunk<crate::chunk::UnsignedChunk<u8>, 2>, u32;
+        u64 => crate::chunk::ArrayChunk<crate::chunk::UnsignedChunk<u8>, 2>, u64;
+        u32_unsigned => crate::chunk::UnsignedChunk<u16>, u32;
+        u64_unsigned => crate::chunk::UnsignedChunk<u16>, u64
+    );
+
+    mod clear {
+        use crate::{
+            chunk::{ArrayChunk, UnsignedChunk},
+            index::{IndexCollection, IndexForward, IndexStore, IndexView},
+            vault::DynamicChunkStore,
+        };
+
+        type Victim = DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>, u64>;
+
+        #[test]
+        fn sparse() {
+            //  1,000 chunks of capacity, only 3 of which are non-empty.
+            let mut victim = Victim::new();
+
+            for index in [3, 17, 200, 15_999] {
+                victim.insert(index).unwrap();
+            }
+
+            victim.remove(15_999);
+
+            assert_eq!(3, victim.len());
+            assert_eq!(1_000, victim.chunks.len());
+
+            victim.clear();
+
+            assert_eq!(0, victim.len());
+            assert_eq!(None, victim.first());
+            assert!(victim.chunks.iter().all(|chunk| chunk.is_empty()));
+        }
+
+        #[test]
+        fn dense() {
+            let mut victim = Victim::new();
+
+            for index in 0..1_000 {
+                victim.insert(index).unwrap();
+            }
+
+            victim.clear();
+
+            assert_eq!(0, victim.len());
+            assert!(victim.chunks.iter().all(|chunk| chunk.is_empty()));
+
+            victim.insert(999).unwrap();
+
+            assert_eq!(Some(999), victim.first());
+        }
+    } // mod clear
+
+    mod populated_chunks {
+        use crate::{
+            chunk::{ArrayChunk, UnsignedChunk},
+            index::{IndexCollection, IndexForwardChunked, IndexStore},
+            vault::DynamicChunkStore,
+        };
+
+        type Victim = DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>, u64>;
+
+        #[test]
+        fn sparse() {
+            let mut victim = Victim::new();
+
+            for index in [3, 17, 15_999] {
+                victim.insert(index).unwrap();
+            }
+
+            victim.remove(15_999);
+
+            assert_eq!(1_000, victim.chunks.len());
+            assert_eq!(2, victim.populated_chunks());
+
+            victim.clear();
+
+            assert_eq!(0, victim.populated_chunks());
+        }
+    } // mod populated_chunks
+
+    mod nth_after {
+        use crate::{
+            chunk::{ArrayChunk, UnsignedChunk},
+            index::{IndexCollection, IndexForward, IndexStore},
+            vault::DynamicChunkStore,
+        };
+
+        type Victim = DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>, u64>;
+
+        //  The default implementation, advancing one index at a time.
+        fn nth_after_default(victim: &Victim, n: usize, mut current: u64) -> Result<u64, usize> {
+            for i in 0..=n {
+                current = victim.next_after(current).ok_or(n + 1 - i)?;
+            }
+
+            Ok(current)
+        }
+
+        #[test]
+        fn across_chunks() {
+            //  Spread over multiple chunks, with empty chunks in-between.
+            const INDEXES: [u64; 12] = [0, 3, 15, 16, 17, 31, 64, 65, 100, 127, 200, 255];
+
+            let mut victim = Victim::new();
+
+            for index in INDEXES {
+                victim.insert(index).unwrap();
+            }
+
+            for current in [0, 1, 3, 15, 16, 30, 31, 32, 64, 99, 200, 255, 256, 1_000] {
+                for n in 0..16 {
+                    let expected = nth_after_default(&victim, n, current);
+                    let actual = victim.nth_after(n, current).map_err(|e| e.get());
+
+                    assert_eq!(expected, actual, "{n} after {current}");
+                }
+            }
+        }
+    } // mod nth_after
+
+    mod not {
+        use crate::{
+            chunk::{ArrayChunk, UnsignedChunk},
+            index::{IndexCollection, IndexStore, IndexStoreRange},
+            not::{IndexBackwardNot, IndexForwardNot},
+            vault::DynamicChunkStore,
+        };
+
+        type Victim = DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>, u32>;
+
+        #[test]
+        fn within_allocation() {
+            let mut victim = Victim::new();
+
+            victim.insert_range(0..32).unwrap();
+            victim.insert(40).unwrap();
+
+            assert_eq!(4, victim.chunks.len());
+
+            //  The NOT iteration stops at the end of the allocated chunks, as `NotWithin` and `first_gap_from` expect.
+            assert_eq!(Some(32), victim.first_not());
+            assert_eq!(Some(41), victim.next_after_not(39));
+            assert_eq!(Some(48), victim.next_after_not(47));
+            assert_eq!(None, victim.next_after_not(63));
+            assert_eq!(None, victim.next_after_not(1_000));
+
+            assert_eq!(Some(63), victim.last_not());
+            assert_eq!(Some(63), victim.next_before_not(1_000));
+            assert_eq!(Some(39), victim.next_before_not(40));
+            assert_eq!(None, victim.next_before_not(31));
+        }
+    } // mod not
+
+    mod rank {
+        use alloc::collections::BTreeSet;
+
+        use crate::{
+            chunk::{ArrayChunk, UnsignedChunk},
+            index::{IndexCollection, IndexOrderedStore, IndexStore},
+            vault::DynamicChunkStore,
+        };
+
+        type Victim = DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>, u32>;
+
+        #[test]
+        fn across_chunks() {
+            const INDEXES: [u32; 9] = [3, 15, 16, 40, 41, 100, 255, 256, 1_000];
+
+            let mut victim = Victim::new();
+
+            for index in INDEXES {
+                victim.insert(index).unwrap();
+            }
+
+            let reference: BTreeSet<_> = INDEXES.into_iter().collect();
+
+            for index in 0..1_100 {
+                assert_eq!(reference.range(..index).count(), victim.rank(index), "{index}");
+            }
+
+            assert_eq!(INDEXES.len(), victim.rank(u32::MAX));
+        }
+    } // mod rank
+
+    mod insert_range {
+        use core::ops::Bound;
+
+        use alloc::vec::Vec;
+
+        use crate::{
+            chunk::{ArrayChunk, UnsignedChunk},
+            index::{IndexCollection, IndexForward, IndexStore, IndexStoreRange, IndexView},
+            vault::DynamicChunkStore,
+        };
+
+        type Victim = DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>, u64>;
+
+        fn victim(indexes: &[u64]) -> Victim {
+            let mut victim = Victim::new();
+
+            for &index in indexes {
+                victim.insert(index).unwrap();
+            }
+
+            victim
+        }
+
+        #[test]
+        fn against_insert() {
+            //  Chunks of 16 indexes.
+            const INDEXES: [u64; 6] = [0, 7, 15, 16, 40, 63];
+
+            for start in 0..=64 {
+                for end in start..=72 {
+                    for range in [
+                        (Bound::Included(start), Bound::Excluded(end)),
+                        (Bound::Excluded(start), Bound::Included(end)),
+                    ] {
+                        let mut expected = victim(&INDEXES);
+                        let mut victim = victim(&INDEXES);
+
+                        let inserted = (0..=end)
+                            .filter(|index| core::ops::RangeBounds::contains(&range, index))
+                            .filter(|&index| expected.insert(index).unwrap())
+                            .count();
+
+                        assert_eq!(Ok(inserted), victim.insert_range(range), "{range:?}");
+                        assert_eq!(expected.len(), victim.len(), "{range:?}");
+
+                        let expected = core::iter::successors(expected.first(), |&i| expected.next_after(i));
+                        let actual = core::iter::successors(victim.first(), |&i| victim.next_after(i));
+
+                        assert!(expected.eq(actual), "{range:?}");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        #[should_panic(expected = "cannot insert an unbounded range")]
+        fn unbounded() {
+            let _ = Victim::new().insert_range(3..);
+        }
+
+        #[test]
+        fn remove_against_remove() {
+            //  Chunks of 16 indexes, including a full one.
+            let indexes: Vec<u64> = [0, 7, 15, 40, 63].into_iter().chain(16..32).collect();
+
+            for start in 0..=64 {
+                for end in start..=72 {
+                    for range in [
+                        (Bound::Included(start), Bound::Excluded(end)),
+                        (Bound::Excluded(start), Bound::Included(end)),
+                        (Bound::Included(start), Bound::Unbounded),
+                    ] {
+                        let mut expected = victim(&indexes);
+                        let mut victim = victim(&indexes);
+
+                        let removed = (0..=end.max(64))
+                            .filter(|index| core::ops::RangeBounds::contains(&range, index))
+                            .filter(|&index| expected.remove(index))
+                            .count();
+
+                        assert_eq!(removed, victim.remove_range(range), "{range:?}");
+                        assert_eq!(expected.len(), victim.len(), "{range:?}");
+
+                        let expected = core::iter::successors(expected.first(), |&i| expected.next_after(i));
+                        let actual = core::iter::successors(victim.first(), |&i| victim.next_after(i));
+
+                        assert!(expected.eq(actual), "{range:?}");
+                    }
+                }
+            }
+
+            assert_eq!(0, Victim::new().remove_range(..));
+        }
+    } // mod insert_range
+
+    mod byte_indexed {
+        use core::ops::Bound;
+
+        use crate::{
+            chunk::{ArrayChunk, UnsignedChunk},
+            index::{IndexCollection, IndexForward, IndexStore, IndexStoreRange, IndexView},
+            vault::{DynamicChunkStore, DynamicChunkStore32},
+        };
+
+        //  Chunks of 64 indexes, either `u8` or `u16` indexed.
+        type Victim = DynamicChunkStore32<UnsignedChunk<u64>>;
+        type Reference = DynamicChunkStore<ArrayChunk<UnsignedChunk<u64>, 1>, u32>;
+
+        #[test]
+        fn against_array() {
+            let mut victim = Victim::new();
+            let mut reference = Reference::new();
+
+            for index in [0, 63, 64, 200, 1_000] {
+                assert_eq!(reference.insert(index), victim.insert(index));
+            }
+
+            assert_eq!(reference.insert_range(60..130), victim.insert_range(60..130));
+            assert_eq!(reference.remove_range(100..=255), victim.remove_range(100..=255));
+            assert_eq!(reference.len(), victim.len());
+
+            let expected = core::iter::successors(reference.first(), |&i| reference.next_after(i));
+            let actual = core::iter::successors(victim.first(), |&i| victim.next_after(i));
+
+            assert!(expected.eq(actual));
+        }
+
+        #[test]
+        fn capacity_span() {
+            let mut victim = Victim::new();
+            let mut reference = Reference::new();
+
+            assert_eq!(reference.capacity_span(), victim.capacity_span());
+
+            victim.reserve((Bound::Included(0), Bound::Excluded(129)));
+            reference.reserve((Bound::Included(0), Bound::Excluded(129)));
+
+            assert_eq!(reference.capacity_span(), victim.capacity_span());
+            assert!(matches!(victim.capacity_span().1, Bound::Included(end) if end >= 191));
+        }
+    } // mod byte_indexed
+
+    #[cfg(feature = "nightly")]
+    mod allocator {
+        use core::{
+            alloc::{AllocError, Allocator, Layout},
+            cell::Cell,
+            ptr::NonNull,
+        };
+
+        use alloc::alloc::Global;
+
+        use crate::{
+            chunk::UnsignedChunk,
+            index::{IndexForward, IndexStore, IndexView},
+            vault::DynamicChunkStore,
+        };
+
+        //  Counts the allocations and deallocations, forwarding them to `Global`.
+        #[derive(Default)]
+        struct Counter {
+            allocations: Cell<usize>,
+            deallocations: Cell<usize>,
+        }
+
+        #[derive(Clone, Copy)]
+        struct CountingAllocator<'a>(&'a Counter);
+
+        //  #   Safety
+        //
+        //  -   Forwarded: all (de)allocations are forwarded to `Global`.
+        unsafe impl Allocator for CountingAllocator<'_> {
+            fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+                self.0.allocations.set(self.0.allocations.get() + 1);
+
+                Global.allocate(layout)
+            }
+
+            unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+                self.0.deallocations.set(self.0.deallocations.get() + 1);
+
+                //  Safety:
+                //  -   `ptr` was allocated by `Global`, with `layout`, as per pre-conditions.
+                unsafe { Global.deallocate(ptr, layout) }
+            }
+        }
+
+        type Victim<'a> = DynamicChunkStore<UnsignedChunk<u64>, u32, CountingAllocator<'a>>;
+
+        #[test]
+        fn counting() {
+            let counter = Counter::default();
+
+            {
+                let mut victim = Victim::new_in(CountingAllocator(&counter));
+
+                assert_eq!(0, counter.allocations.get());
+
+                for index in [3, 64, 1_000, 70_000] {
+                    assert_eq!(Ok(true), victim.insert(index));
+                }
+
+                assert!(counter.allocations.get() > 0);
+                assert_eq!(4, victim.len());
+                assert_eq!(Some(3), victim.first());
+
+                let clone = victim.clone();
+
+                assert!(core::ptr::eq(&counter, clone.allocator().0));
+                assert_eq!(Some(1_000), clone.next_after(64));
+            }
+
+            assert_eq!(counter.allocations.get(), counter.deallocations.get());
+
+            {
+                let before = counter.allocations.get();
+
+                let mut victim = Victim::with_capacity_in(4, CountingAllocator(&counter));
+
+                assert_eq!(before + 1, counter.allocations.get());
+
+                //  Within the pre-allocated chunks, no further allocation.
+                assert_eq!(Ok(true), victim.insert(255));
+                assert_eq!(before + 1, counter.allocations.get());
+            }
+
+            assert_eq!(counter.allocations.get(), counter.deallocations.get());
+        }
+    } // mod allocator
+} // mod tests
@@ -0,0 +1,303 @@
+//! Streaming (de)serialization of a `DynamicChunkStore`.
+//!
+//! #   Format
+//!
+//! The indexes are encoded in strictly ascending order, as a sequence of unsigned LEB128 varints. The first index is
+//! encoded as is, and each subsequent index is encoded as the strictly positive delta from its predecessor.
+
+use std::io::{self, Read, Write};
+
+use crate::{
+    chunk::IndexChunk,
+    index::{IndexCollection, IndexForward, IndexStoreChunked, IndexViewChunked},
+};
+
+use super::{DynamicChunkIndex, DynamicChunkInnerIndex, DynamicChunkStore};
+
+impl<C, I> DynamicChunkStore<C, I>
+where
+    C: IndexChunk<Index: DynamicChunkInnerIndex>,
+    I: DynamicChunkIndex + Into<u64> + TryFrom<u64>,
+{
+    /// Creates a new instance from a stream of indexes, as per the format described in the module documentation.
+    ///
+    /// The store is built chunk by chunk: no more than a single chunk of pending indexes is held, aside from the store
+    /// itself. Since `reader` is read one byte at a time, it is best buffered.
+    ///
+    /// #   Errors
+    ///
+    /// Returns an error of kind `InvalidData` if a delta is zero or an index is not representable as `I`, and of kind
+    /// `UnexpectedEof` if the stream ends in the middle of a varint. Errors of `reader` are forwarded as is.
+    pub fn from_sorted_index_reader<R>(mut reader: R) -> io::Result<Self>
+    where
+        R: Read,
+    {
+        let mut this = Self::new();
+
+        let mut pending: Option<(usize, C)> = None;
+        let mut previous: Option<u64> = None;
+
+        while let Some(value) = read_varint(&mut reader)? {
+            let index = match previous {
+                None => value,
+                Some(_) if value == 0 => return Err(invalid_data("zero delta: indexes are not strictly ascending")),
+                Some(previous) => previous
+                    .checked_add(value)
+                    .ok_or_else(|| invalid_data("index overflow"))?,
+            };
+
+            previous = Some(index);
+
+            let index = I::try_from(index).map_err(|_| invalid_data("index overflow"))?;
+
+            let (outer, inner) = Self::split(index);
+
+            if let Some((current, chunk)) = pending.take_if(|(current, _)| *current != outer) {
+                let _ = this.set_chunk(current, chunk);
+            }
+
+            let (_, chunk) = pending.get_or_insert_with(|| (outer, C::default()));
+
+            let _ = chunk.insert(inner);
+        }
+
+        if let Some((current, chunk)) = pending {
+            let _ = this.set_chunk(current, chunk);
+        }
+
+        Ok(this)
+    }
+
+    /// Writes the indexes of the store to `writer`, as per the format described in the module documentation.
+    ///
+    /// Since `writer` is written to one varint at a time, it is best buffered.
+    ///
+    /// #   Errors
+    ///
+    /// Errors of `writer` are forwarded as is.
+    pub fn to_sorted_index_writer<W>(&self, mut writer: W) -> io::Result<()>
+    where
+        W: Write,
+        C: IndexForward,
+    {
+        let mut previous: Option<u64> = None;
+        let mut cursor = self.first();
+
+        while let Some(index) = cursor {
+            let value: u64 = index.into();
+
+            write_varint(&mut writer, previous.map_or(value, |previous| value - previous))?;
+
+            previous = Some(value);
+            cursor = self.next_after(index);
+        }
+
+        Ok(())
+    }
+}
+
+//
+//  Implementation
+//
+
+fn invalid_data(message: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+//  Reads a varint, returns `None` if the stream ends cleanly before it.
+fn read_varint<R>(reader: &mut R) -> io::Result<Option<u64>>
+where
+    R: Read,
+{
+    let mut result = 0u64;
+    let mut shift = 0;
+
+    loop {
+        let mut byte = [0u8];
+
+        let read = match reader.read(&mut byte) {
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            read => read?,
+        };
+
+        if read == 0 {
+            if shift == 0 {
+                return Ok(None);
+            }
+
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+
+        let payload = u64::from(byte[0] & 0x7F);
+
+        if shift >= u64::BITS || (payload << shift) >> shift != payload {
+            return Err(invalid_data("varint overflow"));
+        }
+
+        result |= payload << shift;
+        shift += 7;
+
+        if byte[0] & 0x80 == 0 {
+            return Ok(Some(result));
+        }
+    }
+}
+
+fn write_varint<W>(writer: &mut W, mut value: u64) -> io::Result<()>
+where
+    W: Write,
+{
+    let mut buffer = [0u8; 10];
+    let mut length = 0;
+
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            buffer[length] = byte;
+            length += 1;
+
+            return writer.write_all(&buffer[..length]);
+        }
+
+        buffer[length] = byte | 0x80;
+        length += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use crate::{
+        chunk::{ArrayChunk, UnsignedChunk},
+        index::{IndexStore, IndexView},
+    };
+
+    use super::*;
+
+    type Victim = DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>>;
+
+    type Victim32 = DynamicChunkStore<ArrayChunk<UnsignedChunk<u8>, 2>, u32>;
+
+    fn indexes<S>(store: &S) -> Vec<S::Index>
+    where
+        S: IndexForward,
+    {
+        let mut result = Vec::new();
+        let mut cursor = store.first();
+
+        while let Some(index) = cursor {
+            result.push(index);
+
+            cursor = store.next_after(index);
+        }
+
+        result
+    }
+
+    #[test]
+    fn round_trip() {
+        const INDEXES: [u64; 7] = [0, 1, 2, 3, 5, 130, 1 << 20];
+
+        let mut original = Victim::new();
+
+        for index in INDEXES {
+            let _ = original.insert(index);
+        }
+
+        let mut buffer = Vec::new();
+
+        original.to_sorted_index_writer(&mut buffer).unwrap();
+
+        let victim = Victim::from_sorted_index_reader(&buffer[..]).unwrap();
+
+        assert_eq!(INDEXES.len(), victim.len());
+        assert_eq!(&INDEXES[..], &indexes(&victim)[..]);
+    }
+
+    #[test]
+    fn round_trip_empty() {
+        let mut buffer = Vec::new();
+
+        Victim::new().to_sorted_index_writer(&mut buffer).unwrap();
+
+        assert!(buffer.is_empty());
+
+        let victim = Victim::from_sorted_index_reader(&buffer[..]).unwrap();
+
+        assert!(victim.is_empty());
+    }
+
+    #[test]
+    fn retry_interrupted() {
+        //  Interrupts every other read.
+        struct Flaky<'a> {
+            bytes: &'a [u8],
+            interrupt: bool,
+        }
+
+        impl Read for Flaky<'_> {
+            fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+                self.interrupt = !self.interrupt;
+
+                if self.interrupt {
+                    return Err(io::ErrorKind::Interrupted.into());
+                }
+
+                self.bytes.read(buffer)
+            }
+        }
+
+        const INDEXES: [u64; 3] = [1, 130, 1 << 20];
+
+        let mut original = Victim::new();
+
+        for index in INDEXES {
+            let _ = original.insert(index);
+        }
+
+        let mut buffer = Vec::new();
+
+        original.to_sorted_index_writer(&mut buffer).unwrap();
+
+        let reader = Flaky {
+            bytes: &buffer[..],
+            interrupt: false,
+        };
+
+        let victim = Victim::from_sorted_index_reader(reader).unwrap();
+
+        assert_eq!(&INDEXES[..], &indexes(&victim)[..]);
+    }
+
+    #[test]
+    fn reject_zero_delta() {
+        let error = Victim::from_sorted_index_reader(&[3u8, 0][..]).unwrap_err();
+
+        assert_eq!(io::ErrorKind::InvalidData, error.kind());
+    }
+
+    #[test]
+    fn reject_truncated() {
+        let error = Victim::from_sorted_index_reader(&[3u8, 0x80][..]).unwrap_err();
+
+        assert_eq!(io::ErrorKind::UnexpectedEof, error.kind());
+    }
+
+    #[test]
+    fn reject_overflow() {
+        let mut buffer = Vec::new();
+
+        write_varint(&mut buffer, u64::from(u32::MAX) + 1).unwrap();
+
+        let error = Victim32::from_sorted_index_reader(&buffer[..]).unwrap_err();
+
+        assert_eq!(io::ErrorKind::InvalidData, error.kind());
+
+        let error = Victim::from_sorted_index_reader(&[0xFFu8; 11][..]).unwrap_err();
+
+        assert_eq!(io::ErrorKind::InvalidData, error.kind());
+    }
+} // mod tests
@@ -1,17 +1,236 @@
-//! Implementation of IndexXxx traits for HashSet.
+//! Implementation of IndexXxx traits for HashSet, and the `HashSetStore` wrapper.
+//!
+//! `HashSet` is a store and a vault, usable with `IndexSet`, but it is neither `IndexForward` nor `IndexOrdered`: its
+//! iteration order is arbitrary, and it cannot resume iteration after a given index. It is therefore unsuitable for
+//! `IndexOrdSet`, and only usable as the right-hand side of set operations, where only `contains` is required.
+//!
+//! `HashSetStore` is a hash-based store which does implement `IndexForward`, in arbitrary order.
 
 use core::{
+    fmt,
     hash::{BuildHasher, Hash},
+    num::NonZeroUsize,
     ops::Bound,
+    slice,
 };
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+
+use alloc::vec::Vec;
 
 use crate::{
     Never,
-    index::{IndexCollection, IndexStore, IndexVault, IndexView},
+    index::{IndexCollection, IndexForward, IndexStore, IndexVault, IndexView, IndexViewCached},
+    set::IndexSet,
 };
 
+/// A hash-based store, which iterates over its indexes in arbitrary order.
+///
+/// Unlike `HashSet`, it implements `IndexForward`, and is therefore usable as the left-hand side of the set operations
+/// of `IndexSet`. It is NOT `IndexOrdered`, and is therefore unsuitable for `IndexOrdSet`, unless wrapped in `Sorted`.
+///
+/// #   Order
+///
+/// The order of iteration is arbitrary, and changes as indexes are inserted and removed. It is only guaranteed that
+/// removing the current index does not affect the next index, hence `retain` and co. visit every index.
+///
+/// #   Cost
+///
+/// The indexes are held in a vector, alongside a map of each index to its position in the vector, hence `contains`,
+/// `insert`, `remove`, and `next_after` are all O(1) on average, at the cost of holding each index twice.
+#[derive(Clone)]
+pub struct HashSetStore<I> {
+    //  Invariant: `positions[indexes[i]] == i` for all `i`, and `positions` has no other entry.
+    positions: HashMap<I, usize>,
+    //  Iterated over from the back, so that swapping the last index into the position of a removed index only moves an
+    //  index which was already visited.
+    indexes: Vec<I>,
+}
+
+impl<I> HashSetStore<I> {
+    /// Returns an iterator over the indexes, in the order of `IndexForward`.
+    pub fn iter(&self) -> core::iter::Rev<slice::Iter<'_, I>> {
+        self.indexes.iter().rev()
+    }
+}
+
+impl<I> HashSetStore<I>
+where
+    I: Copy + Eq + Hash,
+{
+    //  Returns the position of `index`, if contained.
+    fn position(&self, index: I) -> Option<usize> {
+        self.positions.get(&index).copied()
+    }
+}
+
+impl<I> Default for HashSetStore<I> {
+    fn default() -> Self {
+        Self {
+            positions: HashMap::new(),
+            indexes: Vec::new(),
+        }
+    }
+}
+
+impl<I> fmt::Debug for HashSetStore<I>
+where
+    I: fmt::Debug,
+{
+    /// Formats the indexes, in the order of `IndexForward`, as `{5, 1, 3, 2}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, I> IntoIterator for &'a HashSetStore<I> {
+    type Item = &'a I;
+    type IntoIter = core::iter::Rev<slice::Iter<'a, I>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<I> PartialEq for HashSetStore<I>
+where
+    I: Copy + Eq + Hash,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.indexes.len() == other.indexes.len()
+            && self.indexes.iter().all(|index| other.positions.contains_key(index))
+    }
+}
+
+impl<I> Eq for HashSetStore<I> where I: Copy + Eq + Hash {}
+
+//  #   Safety
+//
+//  -   NoPhantom: the store will only ever return indexes that have been inserted and have not been removed since.
+unsafe impl<I> IndexView for HashSetStore<I>
+where
+    I: Copy + Eq + Hash + Ord,
+{
+    type Index = I;
+
+    fn is_empty(&self) -> bool {
+        self.indexes.is_empty()
+    }
+
+    fn len(&self) -> usize {
+        self.indexes.len()
+    }
+
+    fn contains(&self, index: Self::Index) -> bool {
+        self.positions.contains_key(&index)
+    }
+}
+
+impl<I> IndexViewCached for HashSetStore<I> where I: Copy + Eq + Hash + Ord {}
+
+impl<I> IndexCollection for HashSetStore<I>
+where
+    I: Copy + Eq + Hash + Ord,
+{
+    fn span() -> (Bound<Self::Index>, Bound<Self::Index>) {
+        (Bound::Unbounded, Bound::Unbounded)
+    }
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_span(_range: (Bound<Self::Index>, Bound<Self::Index>)) -> Self {
+        Self::default()
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: the store will only ever return indexes that have been inserted and have not been removed since.
+unsafe impl<I> IndexStore for HashSetStore<I>
+where
+    I: Copy + Eq + Hash + Ord,
+{
+    type InsertionError = Never;
+
+    fn clear(&mut self) {
+        self.positions.clear();
+        self.indexes.clear();
+    }
+
+    fn insert(&mut self, index: Self::Index) -> Result<bool, Self::InsertionError> {
+        if self.positions.contains_key(&index) {
+            return Ok(false);
+        }
+
+        self.positions.insert(index, self.indexes.len());
+        self.indexes.push(index);
+
+        Ok(true)
+    }
+
+    fn remove(&mut self, index: Self::Index) -> bool {
+        let Some(position) = self.positions.remove(&index) else {
+            return false;
+        };
+
+        self.indexes.swap_remove(position);
+
+        //  The last index, if any, now occupies the position of the removed one.
+        if let Some(&moved) = self.indexes.get(position) {
+            self.positions.insert(moved, position);
+        }
+
+        true
+    }
+}
+
+//  #   Safety
+//
+//  -   NoTheft: the vault will never return that it does not contain an index if the index was inserted, and was
+//      not removed since.
+unsafe impl<I> IndexVault for HashSetStore<I> where I: Copy + Eq + Hash + Ord {}
+
+//  #   Safety
+//
+//  -   NoDuplicate: each index is held once in the vector, which is iterated over from the back to the front.
+//  -   NoPhantom: only the indexes of the vector, which are those contained, are returned.
+//  -   NoTheft: all the indexes of the vector are returned.
+unsafe impl<I> IndexForward for HashSetStore<I>
+where
+    I: Copy + Eq + Hash + Ord,
+{
+    fn first(&self) -> Option<Self::Index> {
+        self.indexes.last().copied()
+    }
+
+    //  The result is unspecified, as permitted, if `current` is not contained: there is no next index.
+    fn next_after(&self, current: Self::Index) -> Option<Self::Index> {
+        let position = self.position(current)?;
+
+        self.indexes.get(position.checked_sub(1)?).copied()
+    }
+
+    fn nth_after(&self, n: usize, current: Self::Index) -> Result<Self::Index, NonZeroUsize> {
+        //  The number of indexes to advance by, from `current`.
+        let steps = n.saturating_add(1);
+
+        //  Safety:
+        //  -   NonZero: `steps > n`, or `steps == usize::MAX`.
+        let position = self
+            .position(current)
+            .ok_or(unsafe { NonZeroUsize::new_unchecked(steps) })?;
+
+        match position.checked_sub(steps) {
+            Some(next) => Ok(self.indexes[next]),
+            //  Safety:
+            //  -   NonZero: `position < steps`.
+            None => Err(unsafe { NonZeroUsize::new_unchecked(steps - position) }),
+        }
+    }
+}
+
 //  #   Safety
 //
 //  -   NoPhantom: the store will only ever return indexes that have been inserted and have not been removed since.
@@ -35,6 +254,13 @@ where
     }
 }
 
+impl<I, S> IndexViewCached for HashSet<I, S>
+where
+    I: Copy + Eq + Hash + Ord,
+    S: BuildHasher,
+{
+}
+
 impl<I, S> IndexCollection for HashSet<I, S>
 where
     I: Copy + Eq + Hash + Ord,
@@ -87,8 +313,21 @@ where
 {
 }
 
+//  `IndexSet` only formats its indexes for `IndexForward` stores, hence the dedicated implementation.
+impl<I, S> fmt::Debug for IndexSet<HashSet<I, S>>
+where
+    I: fmt::Debug,
+{
+    /// Formats the indexes of the set, in arbitrary order, as `{5, 1, 3, 2}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.as_store().iter()).finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::format;
+
     use crate::test::IndexTester;
 
     use super::*;
@@ -115,4 +354,156 @@ mod tests {
     crate::test_index_view!(Tester);
     crate::test_index_collection!(Tester);
     crate::test_index_store!(Tester);
+
+    #[test]
+    fn set() {
+        let mut set = IndexSet::<HashSet<u32>>::new();
+
+        assert_eq!(Ok(true), set.insert(5));
+        assert_eq!(Ok(true), set.insert(1_000));
+        assert_eq!(Ok(false), set.insert(5));
+
+        assert_eq!(2, set.len());
+        assert!(set.contains(1_000));
+        assert!(!set.contains(1));
+
+        //  The order of iteration of the store is arbitrary.
+        let mut indexes: Vec<_> = set.as_store().iter().copied().collect();
+        indexes.sort();
+
+        assert_eq!([5, 1_000], *indexes);
+
+        assert!(set.remove(5));
+        assert!(!set.remove(5));
+        assert_eq!(1, set.len());
+    }
+
+    #[test]
+    fn debug() {
+        let set = IndexSet::<HashSet<u32>>::from_iter([5]);
+
+        assert_eq!("{5}", format!("{set:?}"));
+    }
+
+    mod store {
+        use std::{format, vec::Vec};
+
+        use crate::{
+            index::{IndexForward, IndexStore},
+            set::{IndexOrdSet, IndexSet},
+            test::IndexTester,
+            vault::Sorted,
+        };
+
+        use super::super::HashSetStore;
+
+        struct Tester;
+
+        impl IndexTester for Tester {
+            type Index = u8;
+            type Victim = HashSetStore<u8>;
+
+            fn upper_bound() -> u8 {
+                u8::MAX
+            }
+
+            fn victim(indexes: &[u8]) -> Self::Victim {
+                let mut victim = HashSetStore::default();
+
+                for &index in indexes {
+                    let _ = victim.insert(index);
+                }
+
+                victim
+            }
+
+            fn index(i: u8) -> Self::Index {
+                i
+            }
+        }
+
+        crate::test_index_view!(Tester);
+        crate::test_index_collection!(Tester);
+        crate::test_index_store!(Tester);
+
+        //  The order of iteration is arbitrary, hence the indexes are sorted prior to comparison.
+        fn sorted(set: &IndexSet<HashSetStore<u32>>) -> Vec<u32> {
+            let mut indexes: Vec<_> = set.iter().collect();
+            indexes.sort();
+            indexes
+        }
+
+        #[test]
+        fn forward() {
+            let mut set: IndexSet<HashSetStore<u32>> = [7, 1_000, 3, 42].into_iter().collect();
+
+            assert_eq!(4, set.iter().len());
+            assert_eq!([3, 7, 42, 1_000], *sorted(&set));
+
+            assert!(set.remove(7));
+
+            assert_eq!(3, set.iter().count());
+            assert_eq!([3, 42, 1_000], *sorted(&set));
+        }
+
+        #[test]
+        fn nth_after() {
+            let set: IndexSet<HashSetStore<u32>> = (0..5).collect();
+            let store = set.as_store();
+
+            let first = store.first().unwrap();
+            let order: Vec<_> = set.iter().collect();
+
+            assert_eq!(Ok(order[3]), store.nth_after(2, first));
+            assert_eq!(Err(core::num::NonZeroUsize::MIN), store.nth_after(4, first));
+            assert_eq!(Err(core::num::NonZeroUsize::new(3).unwrap()), store.nth_after(6, first));
+            assert_eq!(Err(core::num::NonZeroUsize::new(3).unwrap()), store.nth_after(2, 42));
+        }
+
+        #[test]
+        fn retain() {
+            let mut set: IndexSet<HashSetStore<u32>> = (0..100).collect();
+
+            //  Removing the current index swaps the last index into its position, yet every index is visited.
+            let mut visited = 0;
+
+            set.retain(|index| {
+                visited += 1;
+                index % 3 == 0
+            });
+
+            assert_eq!(100, visited);
+            assert_eq!((0..100).step_by(3).collect::<Vec<_>>(), sorted(&set));
+
+            let extracted: Vec<_> = set.extract_if(|index| index % 2 == 0).collect();
+
+            assert_eq!(17, extracted.len());
+            assert_eq!((3..100).step_by(6).collect::<Vec<_>>(), sorted(&set));
+        }
+
+        #[test]
+        fn difference() {
+            let left: IndexSet<HashSetStore<u32>> = [1, 2, 3, 5, 7].into_iter().collect();
+            let right: IndexSet<HashSetStore<u32>> = [2, 4, 6].into_iter().collect();
+
+            let mut difference: Vec<_> = left.difference(&right).collect();
+            difference.sort();
+
+            assert_eq!([1, 3, 5, 7], *difference);
+        }
+
+        #[test]
+        fn sorted_store() {
+            let set: IndexOrdSet<Sorted<HashSetStore<u32>>> = [9, 1, 1_000, 5].into_iter().collect();
+
+            assert!(set.iter().eq([1, 5, 9, 1_000]));
+        }
+
+        #[test]
+        fn debug() {
+            let set: IndexSet<HashSetStore<u32>> = [5].into_iter().collect();
+
+            assert_eq!("{5}", format!("{set:?}"));
+        }
+    } // mod store
 } // mod tests
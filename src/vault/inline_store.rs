@@ -0,0 +1,356 @@
+//! A fixed-capacity, inline, store.
+
+use core::{fmt, mem::MaybeUninit, num::NonZeroUsize, ops::Bound, slice};
+
+use crate::index::{
+    IndexBackward, IndexCollection, IndexForward, IndexOrdered, IndexOrderedStore, IndexStore, IndexVault, IndexView,
+    IndexViewCached,
+};
+
+/// A fixed-capacity store, holding up to `N` indexes inline.
+///
+/// The indexes are kept sorted in an inline array, hence the store never allocates, and is usable in `no_std`
+/// environments without `alloc`. Attempting to insert an index in a full store fails with `OutOfCapacityError`.
+///
+/// All operations are linear in the number of indexes, making the store suitable for tiny sets only.
+pub struct InlineStore<I, const N: usize> {
+    //  Invariant: `len <= N`, and `indexes[..len]` is initialized, and sorted in strictly increasing order.
+    len: usize,
+    indexes: [MaybeUninit<I>; N],
+}
+
+/// The error returned when attempting to insert an index in a full `InlineStore`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutOfCapacityError;
+
+impl<I, const N: usize> InlineStore<I, N> {
+    /// Returns the capacity of the store, that is the maximum number of indexes it may contain.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the indexes of the store, in strictly increasing order.
+    pub fn as_slice(&self) -> &[I] {
+        //  Safety:
+        //  -   Initialized: as per invariant, `indexes[..len]` is initialized.
+        //  -   Layout: `MaybeUninit<I>` has the same layout as `I`.
+        unsafe { slice::from_raw_parts(self.indexes.as_ptr() as *const I, self.len) }
+    }
+}
+
+impl<I, const N: usize> InlineStore<I, N>
+where
+    I: Copy + Ord,
+{
+    //  Returns the position of `index`, if present, or the position at which to insert it, otherwise.
+    //
+    //  A linear scan beats a binary search for the tiny number of indexes this store is intended for.
+    fn search(&self, index: I) -> Result<usize, usize> {
+        let indexes = self.as_slice();
+
+        match indexes.iter().position(|&i| i >= index) {
+            Some(position) if indexes[position] == index => Ok(position),
+            Some(position) => Err(position),
+            None => Err(indexes.len()),
+        }
+    }
+
+    //  Returns the position of the first index strictly after `current`.
+    fn position_after(&self, current: I) -> usize {
+        match self.search(current) {
+            Ok(position) => position + 1,
+            Err(position) => position,
+        }
+    }
+
+    //  Returns the number of indexes strictly before `current`.
+    fn position_before(&self, current: I) -> usize {
+        match self.search(current) {
+            Ok(position) | Err(position) => position,
+        }
+    }
+}
+
+impl<I, const N: usize> Clone for InlineStore<I, N>
+where
+    I: Copy,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I, const N: usize> Copy for InlineStore<I, N> where I: Copy {}
+
+impl<I, const N: usize> fmt::Debug for InlineStore<I, N>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: the store will only ever return indexes that have been inserted and have not been removed since.
+unsafe impl<I, const N: usize> IndexView for InlineStore<I, N>
+where
+    I: Copy + Eq + Ord,
+{
+    type Index = I;
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn contains(&self, index: Self::Index) -> bool {
+        self.search(index).is_ok()
+    }
+}
+
+impl<I, const N: usize> IndexViewCached for InlineStore<I, N> where I: Copy + Eq + Ord {}
+
+impl<I, const N: usize> IndexCollection for InlineStore<I, N>
+where
+    I: Copy + Eq + Ord,
+{
+    fn span() -> (Bound<Self::Index>, Bound<Self::Index>) {
+        (Bound::Unbounded, Bound::Unbounded)
+    }
+
+    fn new() -> Self {
+        let len = 0;
+        let indexes = [MaybeUninit::uninit(); N];
+
+        Self { len, indexes }
+    }
+
+    fn with_span(_range: (Bound<Self::Index>, Bound<Self::Index>)) -> Self {
+        Self::new()
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: the store will only ever return indexes that have been inserted and have not been removed since.
+unsafe impl<I, const N: usize> IndexStore for InlineStore<I, N>
+where
+    I: Copy + Eq + Ord,
+{
+    type InsertionError = OutOfCapacityError;
+
+    fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    fn insert(&mut self, index: Self::Index) -> Result<bool, Self::InsertionError> {
+        let Err(position) = self.search(index) else {
+            return Ok(false);
+        };
+
+        if self.len == N {
+            return Err(OutOfCapacityError);
+        }
+
+        self.indexes.copy_within(position..self.len, position + 1);
+        self.indexes[position].write(index);
+        self.len += 1;
+
+        Ok(true)
+    }
+
+    fn remove(&mut self, index: Self::Index) -> bool {
+        let Ok(position) = self.search(index) else {
+            return false;
+        };
+
+        self.indexes.copy_within(position + 1..self.len, position);
+        self.len -= 1;
+
+        true
+    }
+}
+
+//  #   Safety
+//
+//  -   NoTheft: the vault will never return that it does not contain an index if the index was inserted, and was
+//      not removed since.
+unsafe impl<I, const N: usize> IndexVault for InlineStore<I, N> where I: Copy + Eq + Ord {}
+
+//  #   Safety
+//
+//  -   NoDuplicate: the view WILL never return the same index a second time.
+//  -   NoPhantom: the view WILL only ever return that it contains an index if the index was inserted, and was not
+//      removed since.
+//  -   NoTheft: the view WILL return all indexes.
+unsafe impl<I, const N: usize> IndexForward for InlineStore<I, N>
+where
+    I: Copy + Eq + Ord,
+{
+    fn first(&self) -> Option<Self::Index> {
+        self.as_slice().first().copied()
+    }
+
+    fn next_after(&self, current: Self::Index) -> Option<Self::Index> {
+        self.as_slice().get(self.position_after(current)).copied()
+    }
+
+    fn nth_after(&self, n: usize, current: Self::Index) -> Result<Self::Index, NonZeroUsize> {
+        let position = self.position_after(current);
+
+        if let Some(index) = self.as_slice().get(position.saturating_add(n)) {
+            return Ok(*index);
+        }
+
+        //  Safety:
+        //  -   NonZero: `position + n >= len`, hence `n + 1 > len - position`.
+        Err(unsafe { NonZeroUsize::new_unchecked(n + 1 - (self.len - position)) })
+    }
+}
+
+//  #   Safety
+//
+//  -   Reverse: the view WILL return indexes in the exact opposite sequence than `IndexForward` does.
+unsafe impl<I, const N: usize> IndexBackward for InlineStore<I, N>
+where
+    I: Copy + Eq + Ord,
+{
+    fn last(&self) -> Option<Self::Index> {
+        self.as_slice().last().copied()
+    }
+
+    fn next_before(&self, current: Self::Index) -> Option<Self::Index> {
+        let position = self.position_before(current).checked_sub(1)?;
+
+        Some(self.as_slice()[position])
+    }
+
+    fn nth_before(&self, n: usize, current: Self::Index) -> Result<Self::Index, NonZeroUsize> {
+        let position = self.position_before(current);
+
+        if n < position {
+            return Ok(self.as_slice()[position - 1 - n]);
+        }
+
+        //  Safety:
+        //  -   NonZero: `n >= position`, hence `n + 1 > position`.
+        Err(unsafe { NonZeroUsize::new_unchecked(n + 1 - position) })
+    }
+}
+
+//  #   Safety
+//
+//  -   Ordered: the `IndexForward` implementation WILL return indexes in strictly increasing order.
+unsafe impl<I, const N: usize> IndexOrdered for InlineStore<I, N> where I: Copy + Eq + Ord {}
+
+//  #   Safety
+//
+//  -   Rank: the position of `index` in the sorted array WILL be the number of indexes strictly less than it.
+unsafe impl<I, const N: usize> IndexOrderedStore for InlineStore<I, N>
+where
+    I: Copy + Eq + Ord,
+{
+    fn rank(&self, index: Self::Index) -> usize {
+        self.position_before(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    macro_rules! test_inline_store {
+        ($($name:ident => $n:literal),*) => { $(
+            mod $name {
+                use crate::{index::IndexStore, vault::InlineStore};
+
+                struct Tester;
+
+                impl crate::test::IndexTester for Tester {
+                    type Index = u8;
+                    type Victim = InlineStore<u8, $n>;
+
+                    fn upper_bound() -> u8 { u8::MAX }
+
+                    fn victim(indexes: &[u8]) -> Self::Victim {
+                        let mut victim = <Self::Victim as crate::index::IndexCollection>::new();
+
+                        for &index in indexes {
+                            victim.insert(index).unwrap();
+                        }
+
+                        victim
+                    }
+
+                    fn index(i: u8) -> Self::Index { i }
+                }
+
+                crate::test_index_view!(Tester);
+                crate::test_index_collection!(Tester);
+                crate::test_index_store!(Tester);
+                crate::test_index_forward!(Tester);
+                crate::test_index_backward!(Tester);
+                crate::test_index_ordered_store!(Tester);
+            }
+        )* };
+    }
+
+    //  The test-suites hold at most 6 indexes at once.
+    test_inline_store!(inline_6 => 6, inline_32 => 32);
+
+    mod capacity {
+        use crate::{
+            index::{IndexBackward, IndexCollection, IndexForward, IndexStore, IndexView},
+            vault::{InlineStore, OutOfCapacityError},
+        };
+
+        type Victim = InlineStore<u32, 4>;
+
+        #[test]
+        fn insert_full() {
+            let mut victim = Victim::new();
+
+            for index in [40, 10, 30, 20] {
+                assert_eq!(Ok(true), victim.insert(index));
+            }
+
+            assert_eq!(4, victim.capacity());
+            assert_eq!(&[10, 20, 30, 40][..], victim.as_slice());
+
+            //  Already present indexes are still accepted.
+            assert_eq!(Ok(false), victim.insert(30));
+            assert_eq!(Err(OutOfCapacityError), victim.insert(25));
+            assert_eq!(Err(OutOfCapacityError), victim.insert(50));
+            assert_eq!(&[10, 20, 30, 40][..], victim.as_slice());
+
+            assert!(victim.remove(20));
+            assert_eq!(Ok(true), victim.insert(25));
+            assert_eq!(&[10, 25, 30, 40][..], victim.as_slice());
+        }
+
+        #[test]
+        fn nth() {
+            let mut victim = Victim::new();
+
+            for index in [10, 20, 30, 40] {
+                victim.insert(index).unwrap();
+            }
+
+            assert_eq!(Ok(30), victim.nth_after(1, 15));
+            assert_eq!(Ok(40), victim.nth_after(1, 20));
+            assert_eq!(Err(1.try_into().unwrap()), victim.nth_after(2, 20));
+            assert_eq!(Err(3.try_into().unwrap()), victim.nth_after(2, 40));
+
+            assert_eq!(Ok(10), victim.nth_before(1, 25));
+            assert_eq!(Ok(20), victim.nth_before(1, 40));
+            assert_eq!(Err(1.try_into().unwrap()), victim.nth_before(2, 30));
+            assert_eq!(Err(3.try_into().unwrap()), victim.nth_before(2, 5));
+
+            assert!(victim.first() < victim.last());
+            assert_eq!(4, victim.len());
+        }
+    }
+} // mod tests
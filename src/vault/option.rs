@@ -0,0 +1,267 @@
+//! Implementation of IndexXxx traits for Option.
+//!
+//! `None` is a permanently empty store: it contains no index, and rejects all insertions.
+//!
+//! Beware that `Option::insert` shadows `IndexStore::insert` in method call syntax, outside of generic code.
+
+use core::{num::NonZeroUsize, ops::Bound};
+
+use crate::index::{
+    IndexBackward, IndexCollection, IndexForward, IndexOrdered, IndexOrderedStore, IndexStore, IndexVault, IndexView,
+    IndexViewCached,
+};
+
+/// The error returned when attempting to insert an index in an `Option` store.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OptionInsertionError<E> {
+    /// The store is `None`, and thus rejects all insertions.
+    Absent,
+    /// The store is `Some`, and its insertion failed.
+    Store(E),
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: inherited, as `None` contains no index.
+unsafe impl<S> IndexView for Option<S>
+where
+    S: IndexView,
+{
+    type Index = S::Index;
+
+    fn is_empty(&self) -> bool {
+        self.as_ref().is_none_or(|store| store.is_empty())
+    }
+
+    fn len(&self) -> usize {
+        self.as_ref().map_or(0, |store| store.len())
+    }
+
+    fn contains(&self, index: Self::Index) -> bool {
+        self.as_ref().is_some_and(|store| store.contains(index))
+    }
+}
+
+impl<S> IndexViewCached for Option<S> where S: IndexViewCached {}
+
+impl<S> IndexCollection for Option<S>
+where
+    S: IndexCollection,
+{
+    fn span() -> (Bound<Self::Index>, Bound<Self::Index>) {
+        S::span()
+    }
+
+    //  An empty store, rather than `None`, so that a set created anew may be inserted into.
+    fn new() -> Self {
+        Some(S::new())
+    }
+
+    fn with_span(range: (Bound<Self::Index>, Bound<Self::Index>)) -> Self {
+        Some(S::with_span(range))
+    }
+
+    fn reserve(&mut self, range: (Bound<Self::Index>, Bound<Self::Index>)) {
+        if let Some(store) = self {
+            store.reserve(range);
+        }
+    }
+
+    fn capacity_span(&self) -> (Bound<Self::Index>, Bound<Self::Index>) {
+        match self {
+            Some(store) => store.capacity_span(),
+            None => Self::span(),
+        }
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: inherited, as `None` rejects all insertions.
+unsafe impl<S> IndexStore for Option<S>
+where
+    S: IndexStore,
+{
+    type InsertionError = OptionInsertionError<S::InsertionError>;
+
+    fn clear(&mut self) {
+        if let Some(store) = self {
+            store.clear();
+        }
+    }
+
+    fn insert(&mut self, index: Self::Index) -> Result<bool, Self::InsertionError> {
+        let store = self.as_mut().ok_or(OptionInsertionError::Absent)?;
+
+        store.insert(index).map_err(OptionInsertionError::Store)
+    }
+
+    fn remove(&mut self, index: Self::Index) -> bool {
+        self.as_mut().is_some_and(|store| store.remove(index))
+    }
+}
+
+//  #   Safety
+//
+//  -   NoTheft: inherited, as `None` never accepted any index.
+unsafe impl<S> IndexVault for Option<S> where S: IndexVault {}
+
+//  #   Safety
+//
+//  -   NoDuplicate: inherited.
+//  -   NoPhantom: inherited, as `None` returns no index.
+//  -   NoTheft: inherited, as `None` contains no index.
+unsafe impl<S> IndexForward for Option<S>
+where
+    S: IndexForward,
+{
+    fn first(&self) -> Option<Self::Index> {
+        self.as_ref()?.first()
+    }
+
+    fn next_after(&self, current: Self::Index) -> Option<Self::Index> {
+        self.as_ref()?.next_after(current)
+    }
+
+    fn nth_after(&self, n: usize, current: Self::Index) -> Result<Self::Index, NonZeroUsize> {
+        match self {
+            Some(store) => store.nth_after(n, current),
+            None => Err(NonZeroUsize::MIN.saturating_add(n)),
+        }
+    }
+
+    fn first_at(&self, from: Self::Index) -> Option<Self::Index> {
+        self.as_ref()?.first_at(from)
+    }
+}
+
+//  #   Safety
+//
+//  -   Reverse: inherited.
+unsafe impl<S> IndexBackward for Option<S>
+where
+    S: IndexBackward,
+{
+    fn last(&self) -> Option<Self::Index> {
+        self.as_ref()?.last()
+    }
+
+    fn next_before(&self, current: Self::Index) -> Option<Self::Index> {
+        self.as_ref()?.next_before(current)
+    }
+
+    fn nth_before(&self, n: usize, current: Self::Index) -> Result<Self::Index, NonZeroUsize> {
+        match self {
+            Some(store) => store.nth_before(n, current),
+            None => Err(NonZeroUsize::MIN.saturating_add(n)),
+        }
+    }
+
+    fn last_at(&self, to: Self::Index) -> Option<Self::Index> {
+        self.as_ref()?.last_at(to)
+    }
+}
+
+//  #   Safety
+//
+//  -   Ordered: inherited.
+unsafe impl<S> IndexOrdered for Option<S> where S: IndexOrdered {}
+
+//  #   Safety
+//
+//  -   Rank: inherited, as `None` contains no index.
+unsafe impl<S> IndexOrderedStore for Option<S>
+where
+    S: IndexOrderedStore,
+{
+    fn rank(&self, index: Self::Index) -> usize {
+        self.as_ref().map_or(0, |store| store.rank(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        chunk::UnsignedChunk,
+        index::{IndexCollection, IndexStore},
+        set::{IndexOrdSet, IndexSet},
+        test::IndexTester,
+    };
+
+    use super::OptionInsertionError;
+
+    type Victim = Option<UnsignedChunk<u8>>;
+
+    struct Tester;
+
+    impl IndexTester for Tester {
+        type Index = u8;
+        type Victim = Victim;
+
+        fn upper_bound() -> u8 {
+            7
+        }
+
+        fn victim(indexes: &[u8]) -> Self::Victim {
+            let mut victim = <Victim as IndexCollection>::new();
+
+            for &index in indexes {
+                IndexStore::insert(&mut victim, index).unwrap();
+            }
+
+            victim
+        }
+
+        fn index(i: u8) -> Self::Index {
+            i
+        }
+    }
+
+    crate::test_index_view!(Tester);
+    crate::test_index_collection!(Tester);
+    crate::test_index_store!(Tester);
+    crate::test_index_forward!(Tester);
+    crate::test_index_backward!(Tester);
+    crate::test_index_ordered_store!(Tester);
+
+    #[test]
+    fn absent() {
+        let mut set = IndexSet::<Victim>::with_store(None);
+
+        assert!(set.is_empty());
+        assert_eq!(0, set.len());
+        assert!(!set.contains(3));
+        assert_eq!(None, set.iter().next());
+
+        assert_eq!(Err(OptionInsertionError::Absent), set.insert(3));
+        assert_eq!(Err(OptionInsertionError::Absent), set.toggle(3));
+        assert!(!set.remove(3));
+
+        set.clear();
+
+        assert!(set.is_empty());
+        assert_eq!(None, set.as_store().as_ref());
+    }
+
+    #[test]
+    fn absent_ordered() {
+        let set = IndexOrdSet::<Victim>::with_store(None);
+
+        assert_eq!(None, set.iter().next());
+        assert_eq!(None, set.iter_rev().next());
+        assert_eq!(None, set.range(2..).next());
+    }
+
+    #[test]
+    fn present() {
+        let mut set = IndexSet::<Victim>::new();
+
+        assert_eq!(Ok(true), set.insert(5));
+        assert_eq!(Ok(true), set.insert(1));
+        assert_eq!(Ok(false), set.insert(5));
+
+        assert_eq!(2, set.len());
+        assert!(set.iter().eq([1, 5]));
+        assert_eq!(Some(&UnsignedChunk(0b10_0010)), set.as_store().as_ref());
+    }
+} // mod tests
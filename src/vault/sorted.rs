@@ -0,0 +1,453 @@
+//! An adapter offering ordered iteration over an unordered store.
+
+use core::{cell::OnceCell, fmt, num::NonZeroUsize, ops::Bound};
+
+use alloc::vec::Vec;
+
+use crate::index::{
+    IndexBackward, IndexCollection, IndexForward, IndexOrdered, IndexOrderedStore, IndexStore, IndexVault, IndexView,
+    IndexViewCached,
+};
+
+/// Adapts an unordered store, such as a `HashSet`, so as to iterate over its indexes in strictly increasing order.
+///
+/// The indexes of the store are enumerated through `&S: IntoIterator`, and materialized in a sorted snapshot, which
+/// lets hash-based stores be used in `IndexOrdSet`, and participate in the ordered set operations.
+///
+/// #   Cost
+///
+/// The snapshot is taken lazily, on the first ordered query following a modification of the store, at the cost of an
+/// allocation and an O(n log n) sort. Further ordered queries are then O(log n), until the next modification, which
+/// discards the snapshot.
+///
+/// Interleaving modifications and ordered queries, as `retain` does, therefore takes a snapshot per modification.
+///
+/// #   Thread-safety
+///
+/// The snapshot is lazily initialized through a `OnceCell`, hence `Sorted` is `!Sync`: it may be sent to another
+/// thread, but not shared between threads.
+pub struct Sorted<S>
+where
+    S: IndexView,
+{
+    store: S,
+    //  Invariant: if initialized, contains exactly the indexes of `store`, in strictly increasing order.
+    snapshot: OnceCell<Vec<S::Index>>,
+}
+
+impl<S> Sorted<S>
+where
+    S: IndexView,
+{
+    /// Creates a new instance, wrapping `store`.
+    pub fn new(store: S) -> Self {
+        let snapshot = OnceCell::new();
+
+        Self { store, snapshot }
+    }
+
+    /// Returns a reference to the store.
+    pub fn as_store(&self) -> &S {
+        &self.store
+    }
+
+    /// Returns a mutable reference to the store.
+    ///
+    /// The snapshot, if any, is discarded.
+    pub fn as_store_mut(&mut self) -> &mut S {
+        self.snapshot.take();
+
+        &mut self.store
+    }
+
+    /// Returns the store.
+    pub fn into_store(self) -> S {
+        self.store
+    }
+}
+
+impl<S> Sorted<S>
+where
+    S: IndexView,
+    for<'a> &'a S: IntoIterator<Item = &'a S::Index>,
+{
+    /// Returns the indexes of the store, in strictly increasing order.
+    ///
+    /// Takes the snapshot, if not already taken.
+    ///
+    /// #   Panics
+    ///
+    /// If `&S: IntoIterator` does not yield all indexes of the store.
+    pub fn as_slice(&self) -> &[S::Index] {
+        self.snapshot.get_or_init(|| {
+            let mut snapshot: Vec<_> = self.store.into_iter().copied().collect();
+
+            snapshot.sort_unstable();
+
+            //  `&S: IntoIterator` is a safe trait, hence neither uniqueness nor containment may be trusted.
+            snapshot.dedup();
+            snapshot.retain(|&index| self.store.contains(index));
+
+            assert_eq!(
+                self.store.len(),
+                snapshot.len(),
+                "`&S: IntoIterator` missed some indexes"
+            );
+
+            snapshot
+        })
+    }
+
+    //  Returns the position of the first index strictly after `current`.
+    fn position_after(&self, current: S::Index) -> usize {
+        self.as_slice().partition_point(|&index| index <= current)
+    }
+
+    //  Returns the number of indexes strictly before `current`.
+    fn position_before(&self, current: S::Index) -> usize {
+        self.as_slice().partition_point(|&index| index < current)
+    }
+}
+
+impl<S> Clone for Sorted<S>
+where
+    S: Clone + IndexView,
+{
+    fn clone(&self) -> Self {
+        let store = self.store.clone();
+        let snapshot = self.snapshot.clone();
+
+        Self { store, snapshot }
+    }
+}
+
+impl<S> fmt::Debug for Sorted<S>
+where
+    S: fmt::Debug + IndexView,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Sorted").field(&self.store).finish()
+    }
+}
+
+impl<S> Default for Sorted<S>
+where
+    S: Default + IndexView,
+{
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: inherited.
+unsafe impl<S> IndexView for Sorted<S>
+where
+    S: IndexView,
+{
+    type Index = S::Index;
+
+    fn is_empty(&self) -> bool {
+        self.store.is_empty()
+    }
+
+    fn len(&self) -> usize {
+        self.store.len()
+    }
+
+    fn contains(&self, index: Self::Index) -> bool {
+        self.store.contains(index)
+    }
+}
+
+impl<S> IndexViewCached for Sorted<S> where S: IndexViewCached {}
+
+impl<S> IndexCollection for Sorted<S>
+where
+    S: IndexCollection,
+{
+    fn span() -> (Bound<Self::Index>, Bound<Self::Index>) {
+        S::span()
+    }
+
+    fn new() -> Self {
+        Self::new(S::new())
+    }
+
+    fn with_span(range: (Bound<Self::Index>, Bound<Self::Index>)) -> Self {
+        Self::new(S::with_span(range))
+    }
+
+    fn reserve(&mut self, range: (Bound<Self::Index>, Bound<Self::Index>)) {
+        self.store.reserve(range);
+    }
+
+    fn capacity_span(&self) -> (Bound<Self::Index>, Bound<Self::Index>) {
+        self.store.capacity_span()
+    }
+}
+
+//  #   Safety
+//
+//  -   NoPhantom: inherited.
+unsafe impl<S> IndexStore for Sorted<S>
+where
+    S: IndexStore,
+{
+    type InsertionError = S::InsertionError;
+
+    fn clear(&mut self) {
+        self.snapshot.take();
+
+        self.store.clear();
+    }
+
+    fn insert(&mut self, index: Self::Index) -> Result<bool, Self::InsertionError> {
+        let inserted = self.store.insert(index)?;
+
+        if inserted {
+            self.snapshot.take();
+        }
+
+        Ok(inserted)
+    }
+
+    fn remove(&mut self, index: Self::Index) -> bool {
+        let removed = self.store.remove(index);
+
+        if removed {
+            self.snapshot.take();
+        }
+
+        removed
+    }
+}
+
+//  #   Safety
+//
+//  -   NoTheft: inherited.
+unsafe impl<S> IndexVault for Sorted<S> where S: IndexVault {}
+
+//  #   Safety
+//
+//  -   NoDuplicate: the snapshot is deduplicated.
+//  -   NoPhantom: the snapshot only retains indexes contained by the store, and is discarded on any modification of the
+//      store.
+//  -   NoTheft: the snapshot contains as many indexes as the store, all contained, hence all its indexes.
+unsafe impl<S> IndexForward for Sorted<S>
+where
+    S: IndexView,
+    for<'a> &'a S: IntoIterator<Item = &'a S::Index>,
+{
+    fn first(&self) -> Option<Self::Index> {
+        self.as_slice().first().copied()
+    }
+
+    fn next_after(&self, current: Self::Index) -> Option<Self::Index> {
+        self.as_slice().get(self.position_after(current)).copied()
+    }
+
+    fn nth_after(&self, n: usize, current: Self::Index) -> Result<Self::Index, NonZeroUsize> {
+        let position = self.position_after(current);
+        let indexes = self.as_slice();
+
+        if let Some(index) = indexes.get(position.saturating_add(n)) {
+            return Ok(*index);
+        }
+
+        //  Safety:
+        //  -   NonZero: `position + n >= len`, hence `n + 1 > len - position`.
+        Err(unsafe { NonZeroUsize::new_unchecked(n + 1 - (indexes.len() - position)) })
+    }
+
+    fn first_at(&self, from: Self::Index) -> Option<Self::Index> {
+        self.as_slice().get(self.position_before(from)).copied()
+    }
+}
+
+//  #   Safety
+//
+//  -   Reverse: the view WILL return indexes in the exact opposite sequence than `IndexForward` does.
+unsafe impl<S> IndexBackward for Sorted<S>
+where
+    S: IndexView,
+    for<'a> &'a S: IntoIterator<Item = &'a S::Index>,
+{
+    fn last(&self) -> Option<Self::Index> {
+        self.as_slice().last().copied()
+    }
+
+    fn next_before(&self, current: Self::Index) -> Option<Self::Index> {
+        let position = self.position_before(current).checked_sub(1)?;
+
+        Some(self.as_slice()[position])
+    }
+
+    fn nth_before(&self, n: usize, current: Self::Index) -> Result<Self::Index, NonZeroUsize> {
+        let position = self.position_before(current);
+
+        if n < position {
+            return Ok(self.as_slice()[position - 1 - n]);
+        }
+
+        //  Safety:
+        //  -   NonZero: `n >= position`, hence `n + 1 > position`.
+        Err(unsafe { NonZeroUsize::new_unchecked(n + 1 - position) })
+    }
+
+    fn last_at(&self, to: Self::Index) -> Option<Self::Index> {
+        let position = self.position_after(to).checked_sub(1)?;
+
+        Some(self.as_slice()[position])
+    }
+}
+
+//  #   Safety
+//
+//  -   Ordered: the `IndexForward` implementation WILL return indexes in strictly increasing order.
+unsafe impl<S> IndexOrdered for Sorted<S>
+where
+    S: IndexView,
+    for<'a> &'a S: IntoIterator<Item = &'a S::Index>,
+{
+}
+
+//  #   Safety
+//
+//  -   Rank: the position of `index` in the snapshot WILL be the number of indexes strictly less than it.
+unsafe impl<S> IndexOrderedStore for Sorted<S>
+where
+    S: IndexStore,
+    for<'a> &'a S: IntoIterator<Item = &'a S::Index>,
+{
+    fn rank(&self, index: Self::Index) -> usize {
+        self.position_before(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, vec::Vec};
+
+    use crate::{
+        index::{IndexCollection, IndexStore, IndexView},
+        set::IndexOrdSet,
+        test::IndexTester,
+    };
+
+    use super::Sorted;
+
+    type Victim = Sorted<HashSet<u8>>;
+
+    struct Tester;
+
+    impl IndexTester for Tester {
+        type Index = u8;
+        type Victim = Victim;
+
+        fn upper_bound() -> u8 {
+            u8::MAX
+        }
+
+        fn victim(indexes: &[u8]) -> Self::Victim {
+            Sorted::new(indexes.iter().copied().collect())
+        }
+
+        fn index(i: u8) -> Self::Index {
+            i
+        }
+    }
+
+    crate::test_index_view!(Tester);
+    crate::test_index_collection!(Tester);
+    crate::test_index_store!(Tester);
+    crate::test_index_forward!(Tester);
+    crate::test_index_backward!(Tester);
+    crate::test_index_ordered_store!(Tester);
+
+    #[test]
+    fn increasing() {
+        let indexes = [907, 3, 1_000_000, 42, 77, 5, 12_345];
+
+        let set = IndexOrdSet::<Sorted<HashSet<u32>>>::from_iter(indexes);
+
+        assert!(set.iter().eq([3, 5, 42, 77, 907, 12_345, 1_000_000]));
+        assert!(set.iter_rev().eq([1_000_000, 12_345, 907, 77, 42, 5, 3]));
+        assert!(set.range(42..=907).eq([42, 77, 907]));
+    }
+
+    #[test]
+    fn snapshot_discarded() {
+        let mut victim = <Victim as IndexCollection>::new();
+
+        IndexStore::insert(&mut victim, 5).unwrap();
+        IndexStore::insert(&mut victim, 1).unwrap();
+
+        assert_eq!([1, 5], victim.as_slice());
+
+        IndexStore::insert(&mut victim, 3).unwrap();
+
+        assert_eq!([1, 3, 5], victim.as_slice());
+
+        assert!(IndexStore::remove(&mut victim, 1));
+
+        assert_eq!([3, 5], victim.as_slice());
+
+        victim.as_store_mut().insert(7);
+
+        assert_eq!([3, 5, 7], victim.as_slice());
+    }
+
+    #[test]
+    fn untrusted_into_iter() {
+        //  Yields its indexes twice, and a phantom index.
+        struct Sloppy(Vec<u8>);
+
+        //  #   Safety
+        //
+        //  -   NoPhantom: only the indexes of the vector are contained.
+        unsafe impl IndexView for Sloppy {
+            type Index = u8;
+
+            fn is_empty(&self) -> bool {
+                self.0.is_empty()
+            }
+
+            fn len(&self) -> usize {
+                self.0.len()
+            }
+
+            fn contains(&self, index: Self::Index) -> bool {
+                self.0.contains(&index)
+            }
+        }
+
+        impl<'a> IntoIterator for &'a Sloppy {
+            type Item = &'a u8;
+            type IntoIter = std::vec::IntoIter<&'a u8>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                let mut indexes: Vec<_> = self.0.iter().chain(&self.0).collect();
+
+                indexes.push(&42);
+
+                indexes.into_iter()
+            }
+        }
+
+        let victim = Sorted::new(Sloppy(Vec::from([7, 3, 5])));
+
+        assert_eq!([3, 5, 7], victim.as_slice());
+    }
+
+    #[test]
+    fn ordered_operations() {
+        let primes = IndexOrdSet::<Sorted<HashSet<u32>>>::from_iter([2, 3, 5, 7, 11, 13]);
+        let odds = IndexOrdSet::<Sorted<HashSet<u32>>>::from_iter([1, 3, 5, 7, 9, 11, 13, 15]);
+
+        assert!(primes.intersection(&odds).eq([3, 5, 7, 11, 13]));
+        assert!(odds.difference(&primes).eq([1, 9, 15]));
+        assert!(primes.union(&odds).eq([1, 2, 3, 5, 7, 9, 11, 13, 15]));
+    }
+} // mod tests